@@ -19,9 +19,9 @@ use crate::{
 	relay_chain_specs::{KusamaChainSpec, PolkadotChainSpec},
 	system_parachains_specs::{
 		AssetHubKusamaChainSpec, AssetHubPolkadotChainSpec, BridgeHubKusamaChainSpec,
-		BridgeHubPolkadotChainSpec, CollectivesPolkadotChainSpec, CoretimeKusamaChainSpec,
-		CoretimePolkadotChainSpec, EncointerKusamaChainSpec, GluttonKusamaChainSpec,
-		PeopleKusamaChainSpec, PeoplePolkadotChainSpec,
+		BridgeHubPolkadotChainSpec, CollectivesKusamaChainSpec, CollectivesPolkadotChainSpec,
+		CoretimeKusamaChainSpec, CoretimePolkadotChainSpec, EncointerKusamaChainSpec,
+		GluttonKusamaChainSpec, PeopleKusamaChainSpec, PeoplePolkadotChainSpec,
 	},
 	ChainSpec,
 };
@@ -46,6 +46,8 @@ pub fn from_json_file(filepath: &str, supported: String) -> Result<Box<dyn Chain
 			Ok(Box::new(AssetHubPolkadotChainSpec::from_json_file(path)?)),
 		x if x.starts_with("asset-hub-kusama") =>
 			Ok(Box::new(AssetHubKusamaChainSpec::from_json_file(path)?)),
+		x if x.starts_with("collectives-kusama") =>
+			Ok(Box::new(CollectivesKusamaChainSpec::from_json_file(path)?)),
 		x if x.starts_with("collectives-polkadot") =>
 			Ok(Box::new(CollectivesPolkadotChainSpec::from_json_file(path)?)),
 		x if x.starts_with("bridge-hub-polkadot") =>
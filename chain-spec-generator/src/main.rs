@@ -60,6 +60,12 @@ fn main() -> Result<(), String> {
 				Box::new(system_parachains_specs::asset_hub_polkadot_local_testnet_config)
 					as Box<_>,
 			),
+			#[cfg(feature = "collectives-kusama")]
+			(
+				"collectives-kusama-local",
+				Box::new(system_parachains_specs::collectives_kusama_local_testnet_config)
+					as Box<_>,
+			),
 			#[cfg(feature = "collectives-polkadot")]
 			(
 				"collectives-polkadot-local",
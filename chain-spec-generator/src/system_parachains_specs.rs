@@ -32,6 +32,8 @@ pub type AssetHubPolkadotChainSpec = sc_chain_spec::GenericChainSpec<Extensions>
 
 pub type AssetHubKusamaChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
 
+pub type CollectivesKusamaChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
+
 pub type CollectivesPolkadotChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
 
 pub type BridgeHubPolkadotChainSpec = sc_chain_spec::GenericChainSpec<Extensions>;
@@ -119,6 +121,28 @@ pub fn collectives_polkadot_local_testnet_config(
 	))
 }
 
+#[cfg(feature = "collectives-kusama")]
+pub fn collectives_kusama_local_testnet_config() -> Result<Box<dyn sc_chain_spec::ChainSpec>, String>
+{
+	let mut properties = sc_chain_spec::Properties::new();
+	properties.insert("ss58Format".into(), 2.into());
+	properties.insert("tokenSymbol".into(), "KSM".into());
+	properties.insert("tokenDecimals".into(), 12.into());
+
+	Ok(Box::new(
+		CollectivesKusamaChainSpec::builder(
+			collectives_kusama_runtime::WASM_BINARY.expect("CollectivesKusama wasm not available!"),
+			Extensions { relay_chain: "kusama-local".into(), para_id: 1003 },
+		)
+		.with_name("Kusama Collectives Local")
+		.with_id("collectives-kusama-local")
+		.with_chain_type(sc_chain_spec::ChainType::Local)
+		.with_genesis_config_preset_name("local_testnet")
+		.with_properties(properties)
+		.build(),
+	))
+}
+
 #[cfg(feature = "bridge-hub-polkadot")]
 pub fn bridge_hub_polkadot_local_testnet_config(
 ) -> Result<Box<dyn sc_chain_spec::ChainSpec>, String> {
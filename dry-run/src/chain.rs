@@ -0,0 +1,499 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Runtime independent parts of the dry-run: executing a proposal, delivering messages and
+//! reporting what happened.
+
+use codec::Decode;
+use frame_support::{
+	storage::{
+		migration::storage_key_iter, storage_prefix, unhashed, with_transaction, TransactionOutcome,
+	},
+	traits::{
+		schedule::{v3::Anon, DispatchTime, HIGHEST_PRIORITY},
+		OnInitialize, PalletInfoAccess, QueryPreimage,
+	},
+	BoundedVec, Twox64Concat,
+};
+use polkadot_primitives::InboundDownwardMessage;
+use sp_core::crypto::{Ss58AddressFormat, Ss58Codec};
+use sp_runtime::{
+	traits::{BlockNumberProvider, One, Saturating},
+	AccountId32, DispatchError,
+};
+use std::{collections::BTreeSet, fmt, marker::PhantomData};
+
+/// Where a chain sits in the network.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Hop {
+	Relay,
+	Para(u32),
+}
+
+impl fmt::Display for Hop {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Hop::Relay => write!(f, "relay chain"),
+			Hop::Para(id) => write!(f, "para {id}"),
+		}
+	}
+}
+
+/// The transport of an XCM message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Channel {
+	/// Relay chain to parachain.
+	Dmp,
+	/// Parachain to relay chain.
+	Ump,
+	/// Parachain to parachain.
+	Hrmp,
+}
+
+/// The proposal to dry-run.
+pub enum Proposal {
+	/// A SCALE encoded call.
+	Call(Vec<u8>),
+	/// The hash of a call that was noted in `pallet-preimage`.
+	Preimage(sp_core::H256),
+	/// An ongoing referendum, dispatched with its own origin.
+	Referendum { pallet: String, index: u32 },
+}
+
+/// Messages sent by one chain to another.
+pub struct Outbound {
+	pub from: Hop,
+	pub to: Hop,
+	pub channel: Channel,
+	pub messages: Vec<Vec<u8>>,
+}
+
+impl Outbound {
+	/// Human readable form of the messages.
+	///
+	/// HRMP pages carry several messages and are only summarized.
+	pub fn describe(&self) -> Vec<String> {
+		self.messages
+			.iter()
+			.map(|message| match self.channel {
+				Channel::Dmp | Channel::Ump =>
+					match xcm::VersionedXcm::<()>::decode(&mut &message[..]) {
+						Ok(xcm) => format!("{xcm:?}"),
+						Err(_) => format!("undecodable message of {} bytes", message.len()),
+					},
+				Channel::Hrmp => format!("XCMP page of {} bytes", message.len()),
+			})
+			.collect()
+	}
+}
+
+/// The change of the total balance of an account.
+pub struct BalanceDiff {
+	pub who: AccountId32,
+	pub before: u128,
+	pub after: u128,
+}
+
+/// Everything that happened on one chain in one step of the dry-run.
+pub struct Report {
+	pub chain: &'static str,
+	pub label: String,
+	pub ss58_prefix: u16,
+	pub events: Vec<String>,
+	pub balances: Vec<BalanceDiff>,
+}
+
+impl Report {
+	/// Events that indicate a failed dispatch or message.
+	pub fn failures(&self) -> impl Iterator<Item = &String> {
+		self.events.iter().filter(|event| is_failure(event))
+	}
+}
+
+impl fmt::Display for Report {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		writeln!(f, "\n=== {}: {} ===", self.chain, self.label)?;
+		for event in &self.events {
+			let prefix = if is_failure(event) { "!!!" } else { "   " };
+			writeln!(f, "  {prefix} {event}")?;
+		}
+		if !self.balances.is_empty() {
+			writeln!(f, "  Balance changes:")?;
+		}
+		for BalanceDiff { who, before, after } in &self.balances {
+			let who = who.to_ss58check_with_version(Ss58AddressFormat::custom(self.ss58_prefix));
+			let diff = *after as i128 - *before as i128;
+			writeln!(f, "      {who}: {before} -> {after} ({diff:+})")?;
+		}
+		Ok(())
+	}
+}
+
+/// Whether the debug representation of an event indicates a failure.
+fn is_failure(event: &str) -> bool {
+	[
+		"Err(",
+		"success: false",
+		"ProcessingFailed",
+		"OverweightEnqueued",
+		"PermanentlyOverweight",
+		"CallUnavailable",
+		"Incomplete {",
+		"outcome: Error",
+	]
+	.iter()
+	.any(|needle| event.contains(needle))
+}
+
+/// The runtime specific parts of a chain.
+///
+/// All functions are called within the externalities of the chain.
+pub trait DryRunRuntime: 'static {
+	type Block: sp_runtime::traits::Block<
+		Hash = sp_core::H256,
+		Header = sp_runtime::generic::Header<u32, sp_runtime::traits::BlakeTwo256>,
+	>;
+
+	/// The name of the chain used in the output.
+	const NAME: &'static str;
+	/// Where the chain sits in the network.
+	const HOP: Hop;
+	/// The SS58 prefix used to print accounts.
+	const SS58_PREFIX: u16;
+
+	/// Schedule the `proposal` for dispatch in the current block.
+	///
+	/// `origin` is the name of the dispatch origin, it is ignored for referenda. Returns a
+	/// description of the scheduled call.
+	fn schedule(proposal: &Proposal, origin: &str) -> Result<String, String>;
+
+	/// Service the scheduler agenda of the current block.
+	fn service_scheduler();
+
+	/// Enqueue and process `messages` received from `from`.
+	fn receive(from: Hop, messages: &[Vec<u8>]) -> Result<(), String>;
+
+	/// Take all messages that wait to be sent to other chains.
+	fn take_outbound() -> Vec<Outbound>;
+
+	/// Clear the events of the current block.
+	fn reset_events();
+
+	/// The debug representation of the events of the current block.
+	fn events() -> Vec<String>;
+
+	/// The accounts which balances were touched by the events of the current block.
+	fn touched_accounts() -> BTreeSet<AccountId32>;
+
+	/// The total balance of `who`.
+	fn total_balance(who: &AccountId32) -> u128;
+}
+
+/// A chain of the dry-run, running on top of a state snapshot.
+pub trait Chain {
+	/// The name of the chain.
+	fn name(&self) -> &'static str;
+
+	/// Where the chain sits in the network.
+	fn hop(&self) -> Hop;
+
+	/// Dispatch `proposal` through the scheduler.
+	fn dispatch(&mut self, proposal: &Proposal, origin: &str) -> Result<Report, String>;
+
+	/// Process `messages` received from `from`.
+	fn receive(
+		&mut self,
+		from: Hop,
+		channel: Channel,
+		messages: &[Vec<u8>],
+	) -> Result<Report, String>;
+
+	/// Take all messages that wait to be sent to other chains.
+	fn take_outbound(&mut self) -> Vec<Outbound>;
+}
+
+/// A [`Chain`] for the runtime `R`.
+pub struct Node<R> {
+	ext: sp_io::TestExternalities,
+	_runtime: PhantomData<R>,
+}
+
+impl<R: DryRunRuntime> Node<R> {
+	/// Wrap the externalities of a snapshot of `R`.
+	///
+	/// Messages that were already queued in the snapshot are dropped, so that only messages
+	/// caused by the proposal are followed.
+	pub fn new(mut ext: sp_io::TestExternalities) -> Self {
+		let pending: usize =
+			ext.execute_with(|| R::take_outbound().iter().map(|o| o.messages.len()).sum());
+		if pending > 0 {
+			log::info!(
+				target: crate::LOG_TARGET,
+				"{}: dropped {pending} messages that were pending in the snapshot",
+				R::NAME
+			);
+		}
+		Self { ext, _runtime: PhantomData }
+	}
+
+	/// Run `f` and report its events and balance changes.
+	///
+	/// `f` is executed twice: first in a storage layer that is rolled back to find the touched
+	/// accounts, then for real with their balances recorded before and after.
+	fn run<T>(
+		&mut self,
+		label: String,
+		f: impl Fn() -> Result<T, String>,
+	) -> Result<(T, Report), String> {
+		self.ext.execute_with(|| {
+			let touched = with_transaction(|| {
+				R::reset_events();
+				let touched = f().map(|_| R::touched_accounts());
+				TransactionOutcome::Rollback(Ok::<_, DispatchError>(touched))
+			})
+			.map_err(|e| format!("{e:?}"))??;
+			let before: Vec<_> =
+				touched.into_iter().map(|who| (R::total_balance(&who), who)).collect();
+
+			R::reset_events();
+			let result = f()?;
+
+			let balances = before
+				.into_iter()
+				.map(|(before, who)| BalanceDiff { after: R::total_balance(&who), who, before })
+				.filter(|diff| diff.before != diff.after)
+				.collect();
+			let report = Report {
+				chain: R::NAME,
+				label,
+				ss58_prefix: R::SS58_PREFIX,
+				events: R::events(),
+				balances,
+			};
+			Ok((result, report))
+		})
+	}
+}
+
+impl<R: DryRunRuntime> Chain for Node<R> {
+	fn name(&self) -> &'static str {
+		R::NAME
+	}
+
+	fn hop(&self) -> Hop {
+		R::HOP
+	}
+
+	fn dispatch(&mut self, proposal: &Proposal, origin: &str) -> Result<Report, String> {
+		let (call, mut report) = self.run("dispatch".into(), || {
+			let call = R::schedule(proposal, origin)?;
+			R::service_scheduler();
+			Ok(call)
+		})?;
+		report.label = format!("dispatch {call}");
+		Ok(report)
+	}
+
+	fn receive(
+		&mut self,
+		from: Hop,
+		channel: Channel,
+		messages: &[Vec<u8>],
+	) -> Result<Report, String> {
+		let label = format!("{} {channel:?} message(s) from the {from}", messages.len());
+		self.run(label, || R::receive(from, messages)).map(|(_, report)| report)
+	}
+
+	fn take_outbound(&mut self) -> Vec<Outbound> {
+		self.ext.execute_with(R::take_outbound)
+	}
+}
+
+/// Find the variant of the field-less origin enum `O` with the debug representation `name`.
+pub fn origin_by_name<O: Decode + fmt::Debug>(name: &str) -> Option<O> {
+	(0..=u8::MAX)
+		.filter_map(|index| O::decode(&mut &[index][..]).ok())
+		.find(|origin| format!("{origin:?}") == name)
+}
+
+/// Describe a bounded call, as far as its preimage is available.
+pub fn describe_call<T: pallet_scheduler::Config>(
+	call: &pallet_scheduler::BoundedCallOf<T>,
+) -> String {
+	match T::Preimages::peek(call) {
+		Ok((call, _)) => format!("{call:?}"),
+		Err(_) => format!("unavailable preimage {:?}", call.hash()),
+	}
+}
+
+/// Schedule `call` so that it is dispatched by the next [`service_scheduler`].
+///
+/// The call is scheduled regularly and then moved to the agenda of the current block, which is
+/// the only agenda the scheduler looks at. Other tasks of the current block are dropped.
+pub fn schedule_now<T: pallet_scheduler::Config>(
+	call: pallet_scheduler::BoundedCallOf<T>,
+	origin: T::PalletsOrigin,
+) -> Result<(), String> {
+	let now = T::BlockNumberProvider::current_block_number();
+	let (when, index) = <pallet_scheduler::Pallet<T> as Anon<_, _, _>>::schedule(
+		DispatchTime::At(now.saturating_add(One::one())),
+		None,
+		HIGHEST_PRIORITY,
+		origin,
+		call,
+	)
+	.map_err(|e| format!("Failed to schedule the proposal: {e:?}"))?;
+
+	let mut agenda = pallet_scheduler::Agenda::<T>::get(when);
+	let task = agenda
+		.get_mut(index as usize)
+		.and_then(Option::take)
+		.ok_or("The scheduled task is not in the agenda")?;
+	pallet_scheduler::Agenda::<T>::insert(when, agenda);
+
+	let mut agenda = BoundedVec::default();
+	agenda.try_push(Some(task)).map_err(|_| "The agenda can not hold any task")?;
+	pallet_scheduler::Agenda::<T>::insert(now, agenda);
+	unhashed::put(
+		&storage_prefix(
+			<pallet_scheduler::Pallet<T> as PalletInfoAccess>::name().as_bytes(),
+			b"IncompleteSince",
+		),
+		&now,
+	);
+	Ok(())
+}
+
+/// Service the agenda of the current block.
+pub fn service_scheduler<T: pallet_scheduler::Config>() {
+	<pallet_scheduler::Pallet<T> as OnInitialize<_>>::on_initialize(
+		frame_system::Pallet::<T>::block_number(),
+	);
+}
+
+/// Take the upward messages of a parachain that were not yet sent.
+pub fn take_upward_messages() -> Vec<Vec<u8>> {
+	unhashed::take(&storage_prefix(b"ParachainSystem", b"PendingUpwardMessages"))
+		.unwrap_or_default()
+}
+
+/// Take the downward messages queued on the relay chain, per parachain.
+pub fn take_downward_messages() -> Vec<(u32, Vec<Vec<u8>>)> {
+	storage_key_iter::<u32, Vec<InboundDownwardMessage>, Twox64Concat>(
+		b"Dmp",
+		b"DownwardMessageQueues",
+	)
+	.drain()
+	.map(|(para, messages)| (para, messages.into_iter().map(|m| m.msg).collect()))
+	.collect()
+}
+
+/// The accounts that are referenced by a balances event.
+pub fn balance_event_accounts<T: pallet_balances::Config<I>, I: 'static>(
+	event: pallet_balances::Event<T, I>,
+) -> Vec<T::AccountId> {
+	use pallet_balances::Event::*;
+
+	match event {
+		Endowed { account, .. } | DustLost { account, .. } => vec![account],
+		Transfer { from, to, .. } | ReserveRepatriated { from, to, .. } => vec![from, to],
+		BalanceSet { who, .. } |
+		Reserved { who, .. } |
+		Unreserved { who, .. } |
+		Deposit { who, .. } |
+		Withdraw { who, .. } |
+		Slashed { who, .. } |
+		Minted { who, .. } |
+		Burned { who, .. } |
+		Suspended { who, .. } |
+		Restored { who, .. } |
+		Upgraded { who, .. } |
+		Locked { who, .. } |
+		Unlocked { who, .. } |
+		Frozen { who, .. } |
+		Thawed { who, .. } => vec![who],
+		_ => Vec::new(),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use codec::Encode;
+
+	#[derive(Debug, Decode, PartialEq)]
+	enum TestOrigin {
+		Root,
+		WhitelistedCaller,
+		Fellows,
+	}
+
+	#[test]
+	fn origin_by_name_works() {
+		assert_eq!(origin_by_name::<TestOrigin>("Root"), Some(TestOrigin::Root));
+		assert_eq!(origin_by_name::<TestOrigin>("Fellows"), Some(TestOrigin::Fellows));
+		assert_eq!(origin_by_name::<TestOrigin>("Signed"), None);
+	}
+
+	#[test]
+	fn failures_are_detected() {
+		let report = Report {
+			chain: "Test",
+			label: "dispatch".into(),
+			ss58_prefix: 0,
+			events: vec![
+				"Scheduler(Dispatched { task: (1, 0), id: None, result: Ok(()) })".into(),
+				"Scheduler(Dispatched { task: (1, 1), id: None, result: Err(BadOrigin) })".into(),
+				"MessageQueue(Processed { id: 0x00, origin: Parent, weight_used: 0, success: false })"
+					.into(),
+			],
+			balances: Vec::new(),
+		};
+		assert_eq!(report.failures().count(), 2);
+		assert!(report.to_string().contains("!!! Scheduler(Dispatched { task: (1, 1)"));
+	}
+
+	#[test]
+	fn balance_changes_are_printed() {
+		let report = Report {
+			chain: "Test",
+			label: "dispatch".into(),
+			ss58_prefix: 0,
+			events: Vec::new(),
+			balances: vec![BalanceDiff { who: AccountId32::new([0; 32]), before: 10, after: 3 }],
+		};
+		assert!(report.to_string().contains(": 10 -> 3 (-7)"));
+	}
+
+	#[test]
+	fn describe_outbound_works() {
+		let xcm = xcm::VersionedXcm::<()>::from(xcm::latest::Xcm::<()>(vec![
+			xcm::latest::Instruction::ClearOrigin,
+		]));
+		let outbound = Outbound {
+			from: Hop::Relay,
+			to: Hop::Para(1000),
+			channel: Channel::Dmp,
+			messages: vec![xcm.encode(), vec![0xff]],
+		};
+		let described = outbound.describe();
+		assert!(described[0].contains("ClearOrigin"));
+		assert_eq!(described[1], "undecodable message of 1 bytes");
+
+		let outbound = Outbound { channel: Channel::Hrmp, ..outbound };
+		assert_eq!(outbound.describe()[0], format!("XCMP page of {} bytes", xcm.encoded_size()));
+	}
+}
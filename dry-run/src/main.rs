@@ -0,0 +1,298 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Dry-run a governance proposal against offline state snapshots.
+//!
+//! The snapshots are created with `try-runtime create-snapshot`. The proposal is dispatched
+//! through the scheduler of one chain with the real runtime. Afterwards the XCM messages it
+//! caused are delivered to the other chains for which a snapshot was given, until no more
+//! messages are sent. The events and balance changes of every step are printed, failed
+//! dispatches and messages are highlighted and make the tool exit with an error.
+
+use chain::{Chain, Proposal, Report};
+use clap::{ArgGroup, Parser, ValueEnum};
+use sp_core::H256;
+use std::path::PathBuf;
+
+mod chain;
+mod runtimes;
+
+const LOG_TARGET: &str = "dry-run";
+
+#[derive(Clone, Copy, Debug, ValueEnum)]
+pub enum Network {
+	Polkadot,
+	Kusama,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ValueEnum)]
+pub enum ChainKind {
+	Relay,
+	AssetHub,
+	Collectives,
+	People,
+	Coretime,
+	BridgeHub,
+}
+
+#[derive(Parser)]
+#[command(group(
+	ArgGroup::new("proposal").required(true).args(["call_data", "preimage_hash", "referendum"])
+))]
+struct Cli {
+	/// The network of the snapshots.
+	#[arg(long, value_enum)]
+	network: Network,
+
+	/// The chain that dispatches the proposal.
+	#[arg(long, value_enum, default_value = "asset-hub")]
+	on: ChainKind,
+
+	/// Snapshot of the relay chain.
+	#[arg(long)]
+	relay: Option<PathBuf>,
+
+	/// Snapshot of Asset Hub.
+	#[arg(long)]
+	asset_hub: Option<PathBuf>,
+
+	/// Snapshot of the Collectives chain.
+	#[arg(long)]
+	collectives: Option<PathBuf>,
+
+	/// Snapshot of the People chain.
+	#[arg(long)]
+	people: Option<PathBuf>,
+
+	/// Snapshot of the Coretime chain.
+	#[arg(long)]
+	coretime: Option<PathBuf>,
+
+	/// Snapshot of the Bridge Hub.
+	#[arg(long)]
+	bridge_hub: Option<PathBuf>,
+
+	/// Hex encoded call to dispatch.
+	#[arg(long)]
+	call_data: Option<String>,
+
+	/// Hash of a noted preimage to dispatch.
+	#[arg(long)]
+	preimage_hash: Option<String>,
+
+	/// Index of an ongoing referendum to dispatch with its own origin.
+	#[arg(long)]
+	referendum: Option<u32>,
+
+	/// The referenda pallet of `--referendum`, e.g. `FellowshipReferenda`.
+	#[arg(long, default_value = "Referenda")]
+	referenda_pallet: String,
+
+	/// Dispatch origin of `--call-data` and `--preimage-hash`, e.g. `Root`, `WhitelistedCaller`
+	/// or `Fellows`.
+	#[arg(long, default_value = "Root")]
+	origin: String,
+
+	/// The maximum number of message hops to follow.
+	#[arg(long, default_value_t = 8)]
+	max_hops: u32,
+}
+
+impl Cli {
+	fn snapshots(&self) -> impl Iterator<Item = (ChainKind, PathBuf)> {
+		[
+			(ChainKind::Relay, &self.relay),
+			(ChainKind::AssetHub, &self.asset_hub),
+			(ChainKind::Collectives, &self.collectives),
+			(ChainKind::People, &self.people),
+			(ChainKind::Coretime, &self.coretime),
+			(ChainKind::BridgeHub, &self.bridge_hub),
+		]
+		.into_iter()
+		.filter_map(|(kind, snapshot)| snapshot.clone().map(|snapshot| (kind, snapshot)))
+	}
+
+	fn proposal(&self) -> Result<Proposal, String> {
+		if let Some(call) = &self.call_data {
+			return decode_hex(call).map(Proposal::Call);
+		}
+		if let Some(hash) = &self.preimage_hash {
+			let hash = decode_hex(hash)?;
+			if hash.len() != 32 {
+				return Err(format!("Preimage hash must be 32 bytes, got {}", hash.len()));
+			}
+			return Ok(Proposal::Preimage(H256::from_slice(&hash)));
+		}
+		match self.referendum {
+			Some(index) =>
+				Ok(Proposal::Referendum { pallet: self.referenda_pallet.clone(), index }),
+			None => Err("No proposal given".into()),
+		}
+	}
+}
+
+fn decode_hex(value: &str) -> Result<Vec<u8>, String> {
+	hex::decode(value.trim_start_matches("0x")).map_err(|e| format!("Invalid hex `{value}`: {e}"))
+}
+
+#[tokio::main]
+async fn main() -> Result<(), String> {
+	sp_tracing::try_init_simple();
+	let cli = Cli::parse();
+	let proposal = cli.proposal()?;
+
+	let mut chains: Vec<(ChainKind, Box<dyn Chain>)> = Vec::new();
+	for (kind, snapshot) in cli.snapshots() {
+		log::info!(target: LOG_TARGET, "Loading {kind:?} from {}", snapshot.display());
+		let chain = runtimes::load(cli.network, kind, snapshot).await?;
+		log::info!(target: LOG_TARGET, "Loaded {} at the {}", chain.name(), chain.hop());
+		chains.push((kind, chain));
+	}
+
+	let (_, sender) = chains
+		.iter_mut()
+		.find(|(kind, _)| *kind == cli.on)
+		.ok_or_else(|| format!("No snapshot of the dispatching chain {:?}", cli.on))?;
+	let report = sender.dispatch(&proposal, &cli.origin)?;
+	println!("{report}");
+	let mut reports = vec![report];
+
+	let mut hops = 0;
+	loop {
+		let outbound: Vec<_> =
+			chains.iter_mut().flat_map(|(_, chain)| chain.take_outbound()).collect();
+		if outbound.is_empty() {
+			break;
+		}
+		if hops == cli.max_hops {
+			println!(
+				"\nStopped after {hops} hops, {} message batch(es) not followed",
+				outbound.len()
+			);
+			break;
+		}
+		hops += 1;
+
+		for batch in outbound {
+			println!("\n--- {:?} from the {} to the {} ---", batch.channel, batch.from, batch.to);
+			for message in batch.describe() {
+				println!("  {message}");
+			}
+			let Some((_, receiver)) = chains.iter_mut().find(|(_, chain)| chain.hop() == batch.to)
+			else {
+				println!("  Not followed: no snapshot of the {}", batch.to);
+				continue;
+			};
+			let report = receiver.receive(batch.from, batch.channel, &batch.messages)?;
+			println!("{report}");
+			reports.push(report);
+		}
+	}
+
+	summarize(&reports)
+}
+
+/// Print the failures of all steps.
+fn summarize(reports: &[Report]) -> Result<(), String> {
+	let failures: Vec<_> = reports
+		.iter()
+		.flat_map(|report| report.failures().map(move |event| (report.chain, event)))
+		.collect();
+
+	println!("\n--- Done ---");
+	if failures.is_empty() {
+		println!("No failures.");
+		return Ok(());
+	}
+	for (chain, event) in &failures {
+		println!("  !!! {chain}: {event}");
+	}
+	Err(format!("{} failure(s) during the dry-run", failures.len()))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(args: &[&str]) -> Result<Cli, clap::Error> {
+		Cli::try_parse_from(
+			["dry-run", "--network", "polkadot", "--asset-hub", "ah.snap"]
+				.iter()
+				.chain(args),
+		)
+	}
+
+	#[test]
+	fn decode_hex_works() {
+		assert_eq!(decode_hex("0x0001ff"), Ok(vec![0, 1, 255]));
+		assert_eq!(decode_hex("0001ff"), Ok(vec![0, 1, 255]));
+		assert_eq!(decode_hex("0x"), Ok(vec![]));
+		assert!(decode_hex("0x0").is_err());
+		assert!(decode_hex("0xzz").is_err());
+	}
+
+	#[test]
+	fn call_data_proposal_works() {
+		let cli = parse(&["--call-data", "0x0000"]).unwrap();
+		assert!(matches!(cli.proposal(), Ok(Proposal::Call(call)) if call == vec![0, 0]));
+		assert_eq!(cli.origin, "Root");
+		assert_eq!(cli.on, ChainKind::AssetHub);
+
+		let cli = parse(&["--call-data", "0xinvalid"]).unwrap();
+		assert!(cli.proposal().is_err());
+	}
+
+	#[test]
+	fn preimage_proposal_works() {
+		let hash = format!("0x{}", "ab".repeat(32));
+		let cli = parse(&["--preimage-hash", &hash]).unwrap();
+		assert!(
+			matches!(cli.proposal(), Ok(Proposal::Preimage(h)) if h == H256::repeat_byte(0xab))
+		);
+
+		let cli = parse(&["--preimage-hash", "0xabab"]).unwrap();
+		assert_eq!(cli.proposal().err(), Some("Preimage hash must be 32 bytes, got 2".to_string()));
+	}
+
+	#[test]
+	fn referendum_proposal_works() {
+		let cli = parse(&["--referendum", "42"]).unwrap();
+		assert!(matches!(
+			cli.proposal(),
+			Ok(Proposal::Referendum { pallet, index: 42 }) if pallet == "Referenda"
+		));
+
+		let cli =
+			parse(&["--referendum", "7", "--referenda-pallet", "FellowshipReferenda"]).unwrap();
+		assert!(matches!(
+			cli.proposal(),
+			Ok(Proposal::Referendum { pallet, index: 7 }) if pallet == "FellowshipReferenda"
+		));
+	}
+
+	#[test]
+	fn exactly_one_proposal_is_required() {
+		assert!(parse(&[]).is_err());
+		assert!(parse(&["--call-data", "0x00", "--referendum", "1"]).is_err());
+	}
+
+	#[test]
+	fn snapshots_are_collected() {
+		let cli = parse(&["--relay", "relay.snap", "--call-data", "0x00"]).unwrap();
+		let snapshots: Vec<_> = cli.snapshots().map(|(kind, _)| kind).collect();
+		assert_eq!(snapshots, vec![ChainKind::Relay, ChainKind::AssetHub]);
+	}
+}
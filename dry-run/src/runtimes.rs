@@ -0,0 +1,436 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! The runtimes supported by the dry-run.
+
+use crate::{
+	chain::{Chain, DryRunRuntime, Node},
+	ChainKind, Network,
+};
+use remote_externalities::{Builder, Mode, OfflineConfig};
+use std::path::PathBuf;
+
+/// Implement [`DryRunRuntime`] for a runtime.
+///
+/// `messaging` is either `relay` or `para`. `governance` is either `none` for chains without a
+/// scheduler, or lists the referenda pallets by name and the custom origins that can be
+/// selected by name.
+macro_rules! impl_dry_run_runtime {
+	(
+		$chain:ident {
+			runtime: $rt:ident,
+			name: $name:literal,
+			hop: $hop:expr,
+			ss58_prefix: $ss58:expr,
+			messaging: $messaging:ident,
+			governance: $governance:tt $(,)?
+		}
+	) => {
+		pub struct $chain;
+
+		impl $crate::chain::DryRunRuntime for $chain {
+			type Block = $rt::Block;
+
+			const NAME: &'static str = $name;
+			const HOP: $crate::chain::Hop = $hop;
+			const SS58_PREFIX: u16 = $ss58;
+
+			impl_governance!($rt, $governance);
+			impl_messaging!($rt, $messaging);
+
+			fn reset_events() {
+				$rt::System::reset_events();
+			}
+
+			fn events() -> Vec<String> {
+				$rt::System::read_events_no_consensus()
+					.map(|record| format!("{:?}", record.event))
+					.collect()
+			}
+
+			fn touched_accounts() -> std::collections::BTreeSet<sp_runtime::AccountId32> {
+				$rt::System::read_events_no_consensus()
+					.filter_map(|record| {
+						TryInto::<pallet_balances::Event<$rt::Runtime>>::try_into(record.event).ok()
+					})
+					.flat_map($crate::chain::balance_event_accounts::<$rt::Runtime, ()>)
+					.collect()
+			}
+
+			fn total_balance(who: &sp_runtime::AccountId32) -> u128 {
+				<$rt::Balances as frame_support::traits::fungible::Inspect<_>>::total_balance(who)
+			}
+		}
+	};
+}
+
+macro_rules! impl_governance {
+	($rt:ident, none) => {
+		fn schedule(_: &$crate::chain::Proposal, _: &str) -> Result<String, String> {
+			Err(format!("{} has no scheduler", Self::NAME))
+		}
+
+		fn service_scheduler() {}
+	};
+	(
+		$rt:ident,
+		{
+			referenda: [$( $referenda:literal => $instance:ty ),* $(,)?],
+			origins: [$( $origin:path ),* $(,)?] $(,)?
+		}
+	) => {
+		fn schedule(proposal: &$crate::chain::Proposal, origin: &str) -> Result<String, String> {
+			use $crate::chain::Proposal;
+			use frame_support::traits::{Bounded, QueryPreimage, StorePreimage};
+
+			let origin_by_name = |name: &str| -> Result<$rt::OriginCaller, String> {
+				if name == "Root" {
+					return Ok($rt::OriginCaller::system(frame_system::RawOrigin::Root));
+				}
+				$(
+					if let Some(origin) = $crate::chain::origin_by_name(name) {
+						return Ok($origin(origin));
+					}
+				)*
+				Err(format!("Unknown origin `{name}` on {}", Self::NAME))
+			};
+
+			let (call, origin) = match proposal {
+				Proposal::Call(data) => {
+					let call = <$rt::RuntimeCall as codec::Decode>::decode(&mut &data[..])
+						.map_err(|e| format!("Failed to decode the call: {e}"))?;
+					let call = <$rt::Preimage as StorePreimage>::bound(call)
+						.map_err(|e| format!("Failed to note the call: {e:?}"))?;
+					(call, origin_by_name(origin)?)
+				},
+				Proposal::Preimage(hash) => {
+					let len = <$rt::Preimage as QueryPreimage>::len(hash)
+						.ok_or_else(|| format!("Preimage {hash:?} not found"))?;
+					(Bounded::Lookup { hash: *hash, len }, origin_by_name(origin)?)
+				},
+				Proposal::Referendum { pallet, index } => match pallet.as_str() {
+					$(
+						$referenda => match pallet_referenda::ReferendumInfoFor::<
+							$rt::Runtime,
+							$instance,
+						>::get(index)
+						{
+							Some(pallet_referenda::ReferendumInfo::Ongoing(status)) =>
+								(status.proposal, status.origin),
+							_ => return Err(format!("Referendum {index} is not ongoing")),
+						},
+					)*
+					_ => return Err(format!("Unknown referenda pallet `{pallet}` on {}", Self::NAME)),
+				},
+			};
+
+			let description = $crate::chain::describe_call::<$rt::Runtime>(&call);
+			$crate::chain::schedule_now::<$rt::Runtime>(call, origin)?;
+			Ok(description)
+		}
+
+		fn service_scheduler() {
+			$crate::chain::service_scheduler::<$rt::Runtime>();
+		}
+	};
+}
+
+macro_rules! impl_messaging {
+	($rt:ident, relay) => {
+		fn receive(from: $crate::chain::Hop, messages: &[Vec<u8>]) -> Result<(), String> {
+			use frame_support::{
+				traits::{EnqueueMessage, ServiceQueues},
+				weights::Weight,
+				BoundedSlice,
+			};
+			use runtime_parachains::inclusion::{AggregateMessageOrigin, UmpQueueId};
+
+			let $crate::chain::Hop::Para(para) = from else {
+				return Err("The relay chain only receives upward messages".into());
+			};
+			for message in messages {
+				let message = BoundedSlice::try_from(&message[..]).map_err(|_| {
+					format!("Upward message of {} bytes is too large", message.len())
+				})?;
+				<$rt::MessageQueue as EnqueueMessage<_>>::enqueue_message(
+					message,
+					AggregateMessageOrigin::Ump(UmpQueueId::Para(para.into())),
+				);
+			}
+			<$rt::MessageQueue as ServiceQueues>::service_queues(Weight::MAX);
+			Ok(())
+		}
+
+		fn take_outbound() -> Vec<$crate::chain::Outbound> {
+			use $crate::chain::{Channel, Hop, Outbound};
+
+			$crate::chain::take_downward_messages()
+				.into_iter()
+				.map(|(para, messages)| Outbound {
+					from: Hop::Relay,
+					to: Hop::Para(para),
+					channel: Channel::Dmp,
+					messages,
+				})
+				.collect()
+		}
+	};
+	($rt:ident, para) => {
+		fn receive(from: $crate::chain::Hop, messages: &[Vec<u8>]) -> Result<(), String> {
+			use cumulus_primitives_core::{AggregateMessageOrigin, ParaId, XcmpMessageHandler};
+			use frame_support::{
+				traits::{EnqueueMessage, ServiceQueues},
+				weights::Weight,
+				BoundedSlice,
+			};
+
+			match from {
+				$crate::chain::Hop::Relay =>
+					for message in messages {
+						let message = BoundedSlice::try_from(&message[..]).map_err(|_| {
+							format!("Downward message of {} bytes is too large", message.len())
+						})?;
+						<$rt::MessageQueue as EnqueueMessage<_>>::enqueue_message(
+							message,
+							AggregateMessageOrigin::Parent,
+						);
+					},
+				$crate::chain::Hop::Para(sibling) => {
+					<$rt::XcmpQueue as XcmpMessageHandler>::handle_xcmp_messages(
+						messages.iter().map(|page| (ParaId::from(sibling), 0, &page[..])),
+						Weight::MAX,
+					);
+				},
+			}
+			<$rt::MessageQueue as ServiceQueues>::service_queues(Weight::MAX);
+			Ok(())
+		}
+
+		fn take_outbound() -> Vec<$crate::chain::Outbound> {
+			use cumulus_primitives_core::XcmpMessageSource;
+			use $crate::chain::{Channel, Hop, Outbound};
+
+			let mut outbound = Vec::new();
+			let upward = $crate::chain::take_upward_messages();
+			if !upward.is_empty() {
+				outbound.push(Outbound {
+					from: Self::HOP,
+					to: Hop::Relay,
+					channel: Channel::Ump,
+					messages: upward,
+				});
+			}
+			for (sibling, page) in
+				<$rt::XcmpQueue as XcmpMessageSource>::take_outbound_messages(usize::MAX)
+			{
+				outbound.push(Outbound {
+					from: Self::HOP,
+					to: Hop::Para(sibling.into()),
+					channel: Channel::Hrmp,
+					messages: vec![page],
+				});
+			}
+			outbound
+		}
+	};
+}
+
+#[cfg(feature = "polkadot")]
+mod polkadot {
+	use crate::chain::Hop;
+	use polkadot_runtime_constants::system_parachain::*;
+
+	const SS58_PREFIX: u16 = 0;
+
+	impl_dry_run_runtime!(PolkadotRelay {
+		runtime: polkadot_runtime,
+		name: "Polkadot",
+		hop: Hop::Relay,
+		ss58_prefix: SS58_PREFIX,
+		messaging: relay,
+		governance: {
+			referenda: ["Referenda" => ()],
+			origins: [polkadot_runtime::OriginCaller::Origins],
+		},
+	});
+
+	impl_dry_run_runtime!(AssetHubPolkadot {
+		runtime: asset_hub_polkadot_runtime,
+		name: "Polkadot Asset Hub",
+		hop: Hop::Para(ASSET_HUB_ID),
+		ss58_prefix: SS58_PREFIX,
+		messaging: para,
+		governance: {
+			referenda: ["Referenda" => ()],
+			origins: [asset_hub_polkadot_runtime::OriginCaller::Origins],
+		},
+	});
+
+	impl_dry_run_runtime!(CollectivesPolkadot {
+		runtime: collectives_polkadot_runtime,
+		name: "Polkadot Collectives",
+		hop: Hop::Para(COLLECTIVES_ID),
+		ss58_prefix: SS58_PREFIX,
+		messaging: para,
+		governance: {
+			referenda: [
+				"FellowshipReferenda" =>
+					collectives_polkadot_runtime::fellowship::FellowshipReferendaInstance,
+				"AmbassadorReferenda" =>
+					collectives_polkadot_runtime::ambassador::AmbassadorReferendaInstance,
+			],
+			origins: [
+				collectives_polkadot_runtime::OriginCaller::FellowshipOrigins,
+				collectives_polkadot_runtime::OriginCaller::AmbassadorOrigins,
+			],
+		},
+	});
+
+	impl_dry_run_runtime!(PeoplePolkadot {
+		runtime: people_polkadot_runtime,
+		name: "Polkadot People",
+		hop: Hop::Para(PEOPLE_ID),
+		ss58_prefix: SS58_PREFIX,
+		messaging: para,
+		governance: none,
+	});
+
+	impl_dry_run_runtime!(CoretimePolkadot {
+		runtime: coretime_polkadot_runtime,
+		name: "Polkadot Coretime",
+		hop: Hop::Para(BROKER_ID),
+		ss58_prefix: SS58_PREFIX,
+		messaging: para,
+		governance: none,
+	});
+
+	impl_dry_run_runtime!(BridgeHubPolkadot {
+		runtime: bridge_hub_polkadot_runtime,
+		name: "Polkadot Bridge Hub",
+		hop: Hop::Para(BRIDGE_HUB_ID),
+		ss58_prefix: SS58_PREFIX,
+		messaging: para,
+		governance: none,
+	});
+}
+
+#[cfg(feature = "kusama")]
+mod kusama {
+	use crate::chain::Hop;
+	use kusama_runtime_constants::system_parachain::*;
+
+	const SS58_PREFIX: u16 = 2;
+
+	impl_dry_run_runtime!(KusamaRelay {
+		runtime: kusama_runtime,
+		name: "Kusama",
+		hop: Hop::Relay,
+		ss58_prefix: SS58_PREFIX,
+		messaging: relay,
+		governance: {
+			referenda: [
+				"Referenda" => (),
+				"FellowshipReferenda" => kusama_runtime::governance::FellowshipReferendaInstance,
+			],
+			origins: [kusama_runtime::OriginCaller::Origins],
+		},
+	});
+
+	impl_dry_run_runtime!(AssetHubKusama {
+		runtime: asset_hub_kusama_runtime,
+		name: "Kusama Asset Hub",
+		hop: Hop::Para(ASSET_HUB_ID),
+		ss58_prefix: SS58_PREFIX,
+		messaging: para,
+		governance: {
+			referenda: ["Referenda" => ()],
+			origins: [asset_hub_kusama_runtime::OriginCaller::Origins],
+		},
+	});
+
+	impl_dry_run_runtime!(PeopleKusama {
+		runtime: people_kusama_runtime,
+		name: "Kusama People",
+		hop: Hop::Para(PEOPLE_ID),
+		ss58_prefix: SS58_PREFIX,
+		messaging: para,
+		governance: none,
+	});
+
+	impl_dry_run_runtime!(CoretimeKusama {
+		runtime: coretime_kusama_runtime,
+		name: "Kusama Coretime",
+		hop: Hop::Para(BROKER_ID),
+		ss58_prefix: SS58_PREFIX,
+		messaging: para,
+		governance: none,
+	});
+
+	impl_dry_run_runtime!(BridgeHubKusama {
+		runtime: bridge_hub_kusama_runtime,
+		name: "Kusama Bridge Hub",
+		hop: Hop::Para(BRIDGE_HUB_ID),
+		ss58_prefix: SS58_PREFIX,
+		messaging: para,
+		governance: none,
+	});
+}
+
+/// Load the snapshot of the chain `kind` of `network`.
+pub async fn load(
+	network: Network,
+	kind: ChainKind,
+	snapshot: PathBuf,
+) -> Result<Box<dyn Chain>, String> {
+	match (network, kind) {
+		#[cfg(feature = "polkadot")]
+		(Network::Polkadot, ChainKind::Relay) => load_node::<polkadot::PolkadotRelay>(snapshot).await,
+		#[cfg(feature = "polkadot")]
+		(Network::Polkadot, ChainKind::AssetHub) =>
+			load_node::<polkadot::AssetHubPolkadot>(snapshot).await,
+		#[cfg(feature = "polkadot")]
+		(Network::Polkadot, ChainKind::Collectives) =>
+			load_node::<polkadot::CollectivesPolkadot>(snapshot).await,
+		#[cfg(feature = "polkadot")]
+		(Network::Polkadot, ChainKind::People) => load_node::<polkadot::PeoplePolkadot>(snapshot).await,
+		#[cfg(feature = "polkadot")]
+		(Network::Polkadot, ChainKind::Coretime) =>
+			load_node::<polkadot::CoretimePolkadot>(snapshot).await,
+		#[cfg(feature = "polkadot")]
+		(Network::Polkadot, ChainKind::BridgeHub) =>
+			load_node::<polkadot::BridgeHubPolkadot>(snapshot).await,
+		#[cfg(feature = "kusama")]
+		(Network::Kusama, ChainKind::Relay) => load_node::<kusama::KusamaRelay>(snapshot).await,
+		#[cfg(feature = "kusama")]
+		(Network::Kusama, ChainKind::AssetHub) => load_node::<kusama::AssetHubKusama>(snapshot).await,
+		#[cfg(feature = "kusama")]
+		(Network::Kusama, ChainKind::People) => load_node::<kusama::PeopleKusama>(snapshot).await,
+		#[cfg(feature = "kusama")]
+		(Network::Kusama, ChainKind::Coretime) => load_node::<kusama::CoretimeKusama>(snapshot).await,
+		#[cfg(feature = "kusama")]
+		(Network::Kusama, ChainKind::BridgeHub) => load_node::<kusama::BridgeHubKusama>(snapshot).await,
+		_ => Err(format!("{kind:?} of {network:?} is not supported by this build")),
+	}
+}
+
+async fn load_node<R: DryRunRuntime>(snapshot: PathBuf) -> Result<Box<dyn Chain>, String> {
+	let ext = Builder::<R::Block>::default()
+		.mode(Mode::Offline(OfflineConfig { state_snapshot: snapshot.into() }))
+		.build()
+		.await
+		.map_err(|e| format!("Failed to load the {} snapshot: {e}", R::NAME))?;
+	Ok(Box::new(Node::<R>::new(ext.inner_ext)))
+}
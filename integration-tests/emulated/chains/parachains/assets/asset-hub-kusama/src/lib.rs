@@ -50,9 +50,15 @@ decl_test_parachains! {
 			ForeignAssets: asset_hub_kusama_runtime::ForeignAssets,
 			PoolAssets: asset_hub_kusama_runtime::PoolAssets,
 			AssetConversion: asset_hub_kusama_runtime::AssetConversion,
+			CoretimePurchase: asset_hub_kusama_runtime::CoretimePurchase,
+			ForeignAssetReserves: asset_hub_kusama_runtime::ForeignAssetReserves,
 			Balances: asset_hub_kusama_runtime::Balances,
 			Treasury: asset_hub_kusama_runtime::Treasury,
 			AssetRate: asset_hub_kusama_runtime::AssetRate,
+			Parameters: asset_hub_kusama_runtime::Parameters,
+			Staking: asset_hub_kusama_runtime::Staking,
+			StakingRcClient: asset_hub_kusama_runtime::StakingRcClient,
+			MultiBlockElection: asset_hub_kusama_runtime::MultiBlockElection,
 		}
 	},
 }
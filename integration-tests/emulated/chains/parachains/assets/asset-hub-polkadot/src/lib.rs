@@ -51,10 +51,16 @@ decl_test_parachains! {
 			ForeignAssets: asset_hub_polkadot_runtime::ForeignAssets,
 			PoolAssets: asset_hub_polkadot_runtime::PoolAssets,
 			AssetConversion: asset_hub_polkadot_runtime::AssetConversion,
+			CoretimePurchase: asset_hub_polkadot_runtime::CoretimePurchase,
+			ForeignAssetReserves: asset_hub_polkadot_runtime::ForeignAssetReserves,
 			SnowbridgeSystemFrontend: asset_hub_polkadot_runtime::SnowbridgeSystemFrontend,
 			Preimage: asset_hub_polkadot_runtime::Preimage,
 			Treasury: asset_hub_polkadot_runtime::Treasury,
 			AssetRate: asset_hub_polkadot_runtime::AssetRate,
+			Parameters: asset_hub_polkadot_runtime::Parameters,
+			Staking: asset_hub_polkadot_runtime::Staking,
+			StakingRcClient: asset_hub_polkadot_runtime::StakingRcClient,
+			MultiBlockElection: asset_hub_polkadot_runtime::MultiBlockElection,
 		}
 	},
 }
@@ -0,0 +1,71 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use collectives_kusama_runtime::xcm_config::{RelayTreasuryPalletAccount, StakingPot};
+use emulated_integration_tests_common::{
+	accounts, build_genesis_storage, collators, SAFE_XCM_VERSION,
+};
+use parachains_common::Balance;
+use sp_core::storage::Storage;
+
+pub const PARA_ID: u32 = 1003;
+pub const ED: Balance = collectives_kusama_runtime::ExistentialDeposit::get();
+
+pub fn genesis() -> Storage {
+	let genesis_config = collectives_kusama_runtime::RuntimeGenesisConfig {
+		system: collectives_kusama_runtime::SystemConfig::default(),
+		balances: collectives_kusama_runtime::BalancesConfig {
+			balances: accounts::init_balances()
+				.into_iter()
+				.chain([RelayTreasuryPalletAccount::get(), StakingPot::get()])
+				.map(|k| (k, ED * 4096))
+				.collect(),
+			dev_accounts: None,
+		},
+		parachain_info: collectives_kusama_runtime::ParachainInfoConfig {
+			parachain_id: PARA_ID.into(),
+			..Default::default()
+		},
+		collator_selection: collectives_kusama_runtime::CollatorSelectionConfig {
+			invulnerables: collators::invulnerables().iter().cloned().map(|(acc, _)| acc).collect(),
+			candidacy_bond: ED * 16,
+			..Default::default()
+		},
+		session: collectives_kusama_runtime::SessionConfig {
+			keys: collators::invulnerables()
+				.into_iter()
+				.map(|(acc, aura)| {
+					(
+						acc.clone(),                                        // account id
+						acc,                                                // validator id
+						collectives_kusama_runtime::SessionKeys { aura }, // session keys
+					)
+				})
+				.collect(),
+			..Default::default()
+		},
+		polkadot_xcm: collectives_kusama_runtime::PolkadotXcmConfig {
+			safe_xcm_version: Some(SAFE_XCM_VERSION),
+			..Default::default()
+		},
+		..Default::default()
+	};
+
+	build_genesis_storage(
+		&genesis_config,
+		collectives_kusama_runtime::WASM_BINARY
+			.expect("WASM binary was not built, please build it!"),
+	)
+}
@@ -39,6 +39,8 @@ decl_test_parachains! {
 			MessageOrigin: cumulus_primitives_core::AggregateMessageOrigin,
 		},
 		pallets = {
+			Assets: coretime_kusama_runtime::Assets,
+			AssetRate: coretime_kusama_runtime::AssetRate,
 			PolkadotXcm: coretime_kusama_runtime::PolkadotXcm,
 			Balances: coretime_kusama_runtime::Balances,
 			Broker: coretime_kusama_runtime::Broker,
@@ -41,6 +41,8 @@ decl_test_parachains! {
 			MessageOrigin: cumulus_primitives_core::AggregateMessageOrigin,
 		},
 		pallets = {
+			Assets: coretime_polkadot_runtime::Assets,
+			AssetRate: coretime_polkadot_runtime::AssetRate,
 			PolkadotXcm: coretime_polkadot_runtime::PolkadotXcm,
 			Balances: coretime_polkadot_runtime::Balances,
 			Broker: coretime_polkadot_runtime::Broker,
@@ -40,6 +40,8 @@ decl_test_parachains! {
 			MessageOrigin: cumulus_primitives_core::AggregateMessageOrigin,
 		},
 		pallets = {
+			Assets: people_kusama_runtime::Assets,
+			AssetRate: people_kusama_runtime::AssetRate,
 			PolkadotXcm: people_kusama_runtime::PolkadotXcm,
 			Balances: people_kusama_runtime::Balances,
 			Identity: people_kusama_runtime::Identity,
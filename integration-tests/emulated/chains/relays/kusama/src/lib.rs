@@ -39,6 +39,9 @@ decl_test_relay_chains! {
 			Hrmp: kusama_runtime::Hrmp,
 			Treasury: kusama_runtime::Treasury,
 			AssetRate: kusama_runtime::AssetRate,
+			Session: kusama_runtime::Session,
+			StakingAhClient: kusama_runtime::StakingAhClient,
+			Parameters: kusama_runtime::Parameters,
 		}
 	},
 }
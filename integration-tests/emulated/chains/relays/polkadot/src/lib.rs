@@ -40,6 +40,9 @@ decl_test_relay_chains! {
 			AssetRate: polkadot_runtime::AssetRate,
 			Preimage: polkadot_runtime::Preimage,
 			Hrmp: polkadot_runtime::Hrmp,
+			Session: polkadot_runtime::Session,
+			StakingAhClient: polkadot_runtime::StakingAhClient,
+			Parameters: polkadot_runtime::Parameters,
 		}
 	},
 }
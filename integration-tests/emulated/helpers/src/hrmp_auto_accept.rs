@@ -0,0 +1,184 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the `pallet_hrmp_auto_accept` instance of an Asset Hub.
+
+/// Set the channel policy of `$asset_hub` and let `$sender` request a channel to it through
+/// the `Hrmp` pallet of `$relay`.
+#[macro_export]
+macro_rules! request_auto_accepted_channel {
+	( $asset_hub:ident, $relay:ident, $sender:expr, $request_back:expr, $denied:expr ) => {{
+		use $crate::{
+			frame_support::assert_ok,
+			pallet_hrmp_auto_accept::{AcceptMode, ChannelPolicy, Listing},
+			runtime_parachains,
+			xcm_emulator::{assert_expected_events, Chain},
+		};
+
+		$asset_hub::execute_with(|| {
+			type HrmpAutoAccept =
+				$crate::pallet_hrmp_auto_accept::Pallet<<$asset_hub as Chain>::Runtime>;
+
+			assert_ok!(HrmpAutoAccept::set_policy(
+				<$asset_hub as Chain>::RuntimeOrigin::root(),
+				ChannelPolicy {
+					mode: AcceptMode::AllParachains,
+					max_channels: 10,
+					request_back: $request_back,
+					..Default::default()
+				},
+			));
+			if $denied {
+				assert_ok!(HrmpAutoAccept::set_listing(
+					<$asset_hub as Chain>::RuntimeOrigin::root(),
+					$sender,
+					Some(Listing::Denied),
+				));
+			}
+		});
+
+		$relay::execute_with(|| {
+			type RuntimeEvent = <$relay as Chain>::RuntimeEvent;
+			type Dmp = runtime_parachains::dmp::Pallet<<$relay as Chain>::Runtime>;
+			type Hrmp = runtime_parachains::hrmp::Pallet<<$relay as Chain>::Runtime>;
+
+			Dmp::make_parachain_reachable($asset_hub::para_id());
+			assert_ok!(Hrmp::hrmp_init_open_channel(
+				runtime_parachains::origin::Origin::Parachain($sender).into(),
+				$asset_hub::para_id(),
+				8,
+				1_024,
+			));
+
+			assert_expected_events!(
+				$relay,
+				vec![
+					RuntimeEvent::Hrmp(runtime_parachains::hrmp::Event::OpenChannelRequested { .. }) => {},
+				]
+			);
+		});
+	}};
+}
+
+/// Whether the channel from `$sender` to `$recipient` exists on `$relay`.
+#[macro_export]
+macro_rules! hrmp_channel_exists {
+	( $relay:ident, $sender:expr, $recipient:expr ) => {
+		$relay::execute_with(|| {
+			$crate::runtime_parachains::hrmp::HrmpChannels::<
+				<$relay as $crate::xcm_emulator::Chain>::Runtime,
+			>::contains_key($crate::xcm_emulator::HrmpChannelId {
+				sender: $sender,
+				recipient: $recipient,
+			})
+		})
+	};
+}
+
+/// `$asset_hub` accepts the channel request of `$para` and requests a channel back.
+///
+/// The accepted channel is tracked as pending until it opens.
+#[macro_export]
+macro_rules! test_hrmp_channel_is_auto_accepted {
+	( $asset_hub:ident, $relay:ident, $para:ident ) => {{
+		use $crate::{
+			pallet_hrmp_auto_accept::{ChannelStatus, Channels},
+			runtime_parachains,
+			xcm_emulator::{assert_expected_events, Chain},
+		};
+
+		let para = $para::para_id();
+		let asset_hub = $asset_hub::para_id();
+		$crate::request_auto_accepted_channel!($asset_hub, $relay, para, true, false);
+
+		$asset_hub::execute_with(|| {
+			type RuntimeEvent = <$asset_hub as Chain>::RuntimeEvent;
+
+			assert_expected_events!(
+				$asset_hub,
+				vec![
+					RuntimeEvent::HrmpAutoAccept(
+						$crate::pallet_hrmp_auto_accept::Event::ChannelAccepted { sender }
+					) => { sender: *sender == para, },
+					RuntimeEvent::HrmpAutoAccept(
+						$crate::pallet_hrmp_auto_accept::Event::ChannelRequested { recipient }
+					) => { recipient: *recipient == para, },
+				]
+			);
+			assert_eq!(
+				Channels::<<$asset_hub as Chain>::Runtime>::get(para)
+					.and_then(|state| state.inbound),
+				Some(ChannelStatus::Accepting)
+			);
+		});
+
+		$relay::execute_with(|| {
+			type RuntimeEvent = <$relay as Chain>::RuntimeEvent;
+
+			assert_expected_events!(
+				$relay,
+				vec![
+					RuntimeEvent::Hrmp(
+						runtime_parachains::hrmp::Event::OpenChannelAccepted { sender, recipient }
+					) => { sender: *sender == para, recipient: *recipient == asset_hub, },
+					RuntimeEvent::Hrmp(
+						runtime_parachains::hrmp::Event::OpenChannelRequested { sender, recipient, .. }
+					) => { sender: *sender == asset_hub, recipient: *recipient == para, },
+				]
+			);
+		});
+
+		$relay::force_process_hrmp_open(para, asset_hub);
+		assert!($crate::hrmp_channel_exists!($relay, para, asset_hub));
+		assert!(!$crate::hrmp_channel_exists!($relay, asset_hub, para));
+	}};
+}
+
+/// `$asset_hub` rejects the channel request of the denied `$para`.
+#[macro_export]
+macro_rules! test_hrmp_channel_of_denied_para_is_rejected {
+	( $asset_hub:ident, $relay:ident, $para:ident ) => {{
+		use $crate::{
+			pallet_hrmp_auto_accept::RejectReason,
+			runtime_parachains,
+			xcm_emulator::{assert_expected_events, Chain},
+		};
+
+		let para = $para::para_id();
+		$crate::request_auto_accepted_channel!($asset_hub, $relay, para, true, true);
+
+		$asset_hub::execute_with(|| {
+			type RuntimeEvent = <$asset_hub as Chain>::RuntimeEvent;
+
+			assert_expected_events!(
+				$asset_hub,
+				vec![
+					RuntimeEvent::HrmpAutoAccept(
+						$crate::pallet_hrmp_auto_accept::Event::ChannelRejected { sender, reason }
+					) => { sender: *sender == para, reason: *reason == RejectReason::Denied, },
+				]
+			);
+		});
+
+		$relay::execute_with(|| {
+			type RuntimeEvent = <$relay as Chain>::RuntimeEvent;
+
+			assert!(!<$relay as Chain>::events().iter().any(|event| matches!(
+				event,
+				RuntimeEvent::Hrmp(runtime_parachains::hrmp::Event::OpenChannelAccepted { .. })
+			)));
+		});
+	}};
+}
@@ -13,20 +13,45 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+pub use codec;
 pub use paste;
 
 // Substrate
+pub use frame_support;
+pub use frame_system;
+pub use pallet_asset_rate;
+pub use pallet_assets;
+pub use pallet_authorship;
 pub use pallet_balances;
+pub use pallet_election_provider_multi_block;
 pub use pallet_message_queue;
+pub use pallet_session;
+pub use pallet_staking_async;
+pub use pallet_staking_async_ah_client;
+pub use pallet_staking_async_rc_client;
+pub use pallet_timestamp;
+pub use sp_externalities;
+pub use sp_keystore;
+pub use sp_staking;
 
 // Polkadot
 pub use pallet_xcm;
+pub use runtime_parachains;
+pub use xcm;
+pub use xcm_executor;
 pub use xcm::prelude::{AccountId32, VersionedAssetId, VersionedAssets, Weight, WeightLimit};
 pub use xcm_runtime_apis::fees::runtime_decl_for_xcm_payment_api::XcmPaymentApiV2;
 
 // Cumulus
 pub use cumulus_pallet_xcmp_queue;
 pub use emulated_integration_tests_common::*;
+pub use parachains_common;
 pub use xcm_emulator::Chain;
 
+// Runtimes
+pub use pallet_hrmp_auto_accept;
+
 pub mod common;
+pub mod hrmp_auto_accept;
+pub mod staking;
+pub mod xcm_fees;
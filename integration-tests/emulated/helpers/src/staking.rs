@@ -0,0 +1,672 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! End-to-end tests of async staking between a relay chain and its Asset Hub.
+//!
+//! [`impl_async_staking_helpers`] declares the helpers driving both chains, the `test_*` macros
+//! expect them in scope.
+
+/// Declares the helpers driving async staking between `$relay` and `$asset_hub`.
+///
+/// `$relay_runtime` and `$asset_hub_runtime` are the runtime crates of the chains, `$units` is a
+/// unit of the native token and `$session_millis` the length of a relay chain session.
+#[macro_export]
+macro_rules! impl_async_staking_helpers {
+	(
+		relay: $relay:ident,
+		relay_runtime: $relay_runtime:ident,
+		asset_hub: $asset_hub:ident,
+		asset_hub_runtime: $asset_hub_runtime:ident,
+		units: $units:expr,
+		session_millis: $session_millis:expr $(,)?
+	) => {
+		/// Stake bonded by every validator on Asset Hub.
+		pub(crate) const VALIDATOR_BOND: $crate::parachains_common::Balance = 10_000 * $units;
+
+		/// Asset Hub blocks produced between two relay chain sessions.
+		///
+		/// Way shorter than in production, but long enough for a full election to fit in a
+		/// handful of sessions.
+		pub(crate) const AH_BLOCKS_PER_SESSION: u32 = 100;
+
+		/// Length of the unsigned phase on Asset Hub. The signed phase is disabled in these tests.
+		const UNSIGNED_PHASE: u32 = 10;
+
+		/// Relay chain sessions we are willing to wait for an era to be activated.
+		const MAX_SESSIONS: u32 = 30;
+
+		/// Length of a relay chain session, used to advance the relay chain timestamp.
+		const SESSION_MILLIS: u64 = $session_millis;
+
+		/// The Asset Hub pallets whose hooks drive elections and eras.
+		///
+		/// The emulator only initializes the system pallets of a block, so these are ticked by
+		/// hand.
+		type AssetHubStakingHooks = (
+			$asset_hub_runtime::MultiBlockElection,
+			$asset_hub_runtime::MultiBlockElectionVerifier,
+			$asset_hub_runtime::MultiBlockElectionUnsigned,
+			$asset_hub_runtime::MultiBlockElectionSigned,
+			$asset_hub_runtime::Staking,
+			$asset_hub_runtime::StakingRcClient,
+		);
+
+		/// The stashes of the genesis relay chain validators. Their session keys are already set.
+		pub(crate) fn validator_stashes() -> Vec<$crate::parachains_common::AccountId> {
+			$crate::validators::initial_authorities()
+				.into_iter()
+				.map(|keys| keys.0)
+				.collect()
+		}
+
+		/// Switches the relay chain to Asset Hub driven staking and registers `validators` as the
+		/// validators on Asset Hub. Stashes bonded by an earlier call are left as they are.
+		pub(crate) fn setup_staking(validators: &[$crate::parachains_common::AccountId]) {
+			use $asset_hub_runtime::dynamic_params::staking_election;
+			use $crate::{
+				frame_support::assert_ok,
+				pallet_staking_async_ah_client as ah_client,
+				xcm_emulator::{Chain, TestExt},
+			};
+			use $relay_runtime::dynamic_params::ah_client as ah_client_params;
+
+			$relay::execute_with(|| {
+				type RuntimeOrigin = <$relay as Chain>::RuntimeOrigin;
+
+				assert_ok!($relay_runtime::StakingAhClient::set_mode(
+					RuntimeOrigin::root(),
+					ah_client::OperatingMode::Active,
+				));
+				// the genesis network is way smaller than the production minimum.
+				assert_ok!($relay_runtime::Parameters::set_parameter(
+					RuntimeOrigin::root(),
+					$relay_runtime::RuntimeParameters::AhClient(
+						ah_client_params::Parameters::MinimumValidatorSetSize(
+							ah_client_params::MinimumValidatorSetSize,
+							Some(1),
+						),
+					),
+				));
+			});
+
+			$asset_hub::execute_with(|| {
+				type RuntimeOrigin = <$asset_hub as Chain>::RuntimeOrigin;
+				type Runtime = <$asset_hub as Chain>::Runtime;
+				type Staking = $asset_hub_runtime::Staking;
+
+				// skip the signed phase, the tests mine an unsigned solution instead.
+				for parameter in [
+					staking_election::Parameters::SignedPhase(
+						staking_election::SignedPhase,
+						Some(0),
+					),
+					staking_election::Parameters::UnsignedPhase(
+						staking_election::UnsignedPhase,
+						Some(UNSIGNED_PHASE),
+					),
+				] {
+					assert_ok!($asset_hub_runtime::Parameters::set_parameter(
+						RuntimeOrigin::root(),
+						$asset_hub_runtime::RuntimeParameters::StakingElection(parameter),
+					));
+				}
+
+				assert_ok!(Staking::set_validator_count(
+					RuntimeOrigin::root(),
+					validators.len() as u32
+				));
+				for stash in validators {
+					if $crate::pallet_staking_async::Bonded::<Runtime>::contains_key(stash) {
+						continue
+					}
+					assert_ok!($asset_hub_runtime::Balances::force_set_balance(
+						RuntimeOrigin::root(),
+						stash.clone().into(),
+						2 * VALIDATOR_BOND,
+					));
+					assert_ok!(Staking::bond(
+						RuntimeOrigin::signed(stash.clone()),
+						VALIDATOR_BOND,
+						$crate::pallet_staking_async::RewardDestination::Stash,
+					));
+					assert_ok!(Staking::validate(
+						RuntimeOrigin::signed(stash.clone()),
+						Default::default(),
+					));
+				}
+			});
+		}
+
+		/// The active era on Asset Hub.
+		pub(crate) fn active_era() -> $crate::sp_staking::EraIndex {
+			use $crate::xcm_emulator::TestExt;
+
+			$asset_hub::execute_with(|| {
+				$crate::pallet_staking_async::ActiveEra::<$asset_hub_runtime::Runtime>::get()
+					.map(|era| era.index)
+					.unwrap_or_default()
+			})
+		}
+
+		/// Ends the current relay chain session and starts the next one, as BABE would.
+		///
+		/// The session report of the ending session, and any queued offences, are sent to Asset
+		/// Hub.
+		pub(crate) fn rotate_relay_session() {
+			use $crate::{
+				frame_support::traits::OnInitialize,
+				xcm_emulator::{Chain, TestExt},
+			};
+
+			$relay::execute_with(|| {
+				type Runtime = <$relay as Chain>::Runtime;
+
+				// `Timestamp::set` would make BABE check the slot, so write the time directly.
+				$crate::pallet_timestamp::Now::<Runtime>::mutate(|now| *now += SESSION_MILLIS);
+				$relay_runtime::Session::rotate_session();
+				$relay_runtime::StakingAhClient::on_initialize(
+					<$relay as Chain>::System::block_number(),
+				);
+			});
+		}
+
+		/// Runs the staking hooks of Asset Hub for `blocks` blocks.
+		///
+		/// An unsigned solution is mined and submitted whenever the election is waiting for one.
+		pub(crate) fn progress_asset_hub(blocks: u32) {
+			use $crate::{
+				frame_support::{
+					traits::{OnInitialize, OnPoll},
+					weights::WeightMeter,
+				},
+				xcm_emulator::{Chain, TestExt},
+			};
+
+			$asset_hub::execute_with(|| {
+				type System = <$asset_hub as Chain>::System;
+
+				for _ in 0..blocks {
+					let now = System::block_number() + 1;
+					System::set_block_number(now);
+					AssetHubStakingHooks::on_initialize(now);
+					AssetHubStakingHooks::on_poll(now, &mut WeightMeter::new());
+					submit_unsigned_solution();
+				}
+			});
+		}
+
+		/// Mines and submits a solution if the election is in its unsigned phase and has nothing
+		/// queued.
+		fn submit_unsigned_solution() {
+			use $crate::{
+				frame_support::assert_ok,
+				pallet_election_provider_multi_block::{
+					self as multi_block, unsigned::miner::OffchainWorkerMiner, verifier::Verifier,
+					Phase,
+				},
+				xcm_emulator::Chain,
+			};
+			type Runtime = <$asset_hub as Chain>::Runtime;
+			type RuntimeOrigin = <$asset_hub as Chain>::RuntimeOrigin;
+
+			if !matches!(multi_block::Pallet::<Runtime>::current_phase(), Phase::Unsigned(_)) ||
+				multi_block::verifier::Pallet::<Runtime>::queued_score().is_some()
+			{
+				return
+			}
+
+			let solution = OffchainWorkerMiner::<Runtime>::mine_solution(
+				$asset_hub_runtime::dynamic_params::staking_election::MinerPages::get(),
+				false,
+			)
+			.expect("the snapshot is in place, mining must succeed");
+			assert_ok!(multi_block::unsigned::Pallet::<Runtime>::submit_unsigned(
+				RuntimeOrigin::none(),
+				Box::new(solution),
+			));
+		}
+
+		/// Rotates relay chain sessions, with Asset Hub blocks in between, until `done` holds.
+		pub(crate) fn rotate_sessions_until(mut done: impl FnMut() -> bool) {
+			for _ in 0..MAX_SESSIONS {
+				if done() {
+					return
+				}
+				rotate_relay_session();
+				progress_asset_hub(AH_BLOCKS_PER_SESSION);
+			}
+			assert!(done(), "condition not met within {MAX_SESSIONS} sessions");
+		}
+
+		/// Forces a new era on Asset Hub and drives both chains until it is active, returning its
+		/// index.
+		///
+		/// This runs the whole loop: the election on Asset Hub, the validator set being sent to
+		/// the relay chain, the relay chain rotating it in and reporting its activation back.
+		pub(crate) fn activate_next_era(
+			validators: &[$crate::parachains_common::AccountId],
+		) -> $crate::sp_staking::EraIndex {
+			use $crate::{
+				frame_support::assert_ok,
+				xcm_emulator::{Chain, TestExt},
+			};
+
+			let era = active_era() + 1;
+			$asset_hub::execute_with(|| {
+				assert_ok!($asset_hub_runtime::Staking::force_new_era(
+					<$asset_hub as Chain>::RuntimeOrigin::root()
+				));
+			});
+
+			rotate_sessions_until(|| active_era() >= era);
+
+			$relay::execute_with(|| {
+				let mut active = $relay_runtime::Session::validators();
+				let mut expected = validators.to_vec();
+				active.sort();
+				expected.sort();
+				assert_eq!(active, expected);
+			});
+			era
+		}
+
+		/// Reports `offender` on the relay chain, as `pallet-offences` would for an equivocation.
+		pub(crate) fn report_offence(
+			offender: &$crate::parachains_common::AccountId,
+			fraction: $crate::frame_support::sp_runtime::Perbill,
+		) {
+			use $crate::{
+				frame_support::traits::OnInitialize,
+				pallet_staking_async_ah_client as ah_client,
+				sp_staking::offence::{OffenceDetails, OnOffenceHandler},
+				xcm_emulator::{Chain, TestExt},
+			};
+
+			$relay::execute_with(|| {
+				type Runtime = <$relay as Chain>::Runtime;
+				type StakingAhClient = $relay_runtime::StakingAhClient;
+
+				let details = OffenceDetails {
+					offender: (offender.clone(), Default::default()),
+					reporters: vec![],
+				};
+				<StakingAhClient as OnOffenceHandler<_, _, _>>::on_offence(
+					&[details],
+					&[fraction],
+					$relay_runtime::Session::current_index(),
+				);
+				assert_eq!(ah_client::OffenceSendQueue::<Runtime>::count(), 1);
+
+				// offences are sent to Asset Hub in batches when a block starts.
+				StakingAhClient::on_initialize(<$relay as Chain>::System::block_number());
+				assert_eq!(ah_client::OffenceSendQueue::<Runtime>::count(), 0);
+			});
+		}
+
+		/// Generates new relay chain session keys of `stash` on Asset Hub, returning the encoded
+		/// keys and the proof of their ownership by `stash`.
+		pub(crate) fn generate_session_keys(
+			stash: &$crate::parachains_common::AccountId,
+		) -> (Vec<u8>, Vec<u8>) {
+			use $crate::{
+				codec::Encode, sp_externalities::ExternalitiesExt, xcm_emulator::TestExt,
+			};
+
+			$asset_hub::execute_with(|| {
+				// the keys are generated through the keystore, which the emulator doesn't set up.
+				$crate::sp_externalities::with_externalities(|ext| {
+					ext.register_extension($crate::sp_keystore::KeystoreExt::new(
+						$crate::sp_keystore::testing::MemoryKeystore::new(),
+					))
+				})
+				.expect("called within the externalities of Asset Hub; qed")
+				.expect("no keystore is registered yet; qed");
+
+				let generated = $asset_hub_runtime::staking::RelayChainSessionKeys::generate(
+					&stash.encode(),
+					None,
+				);
+				(generated.keys.encode(), generated.proof.encode())
+			})
+		}
+	};
+}
+
+/// An election on `$asset_hub` becomes the validator set of `$relay`.
+#[macro_export]
+macro_rules! test_asset_hub_election_becomes_the_relay_validator_set {
+	( $relay:ident, $asset_hub:ident ) => {{
+		use $crate::{
+			pallet_staking_async_ah_client as ah_client,
+			pallet_staking_async_rc_client as rc_client,
+			xcm_emulator::{Chain, TestExt},
+		};
+
+		// only elect one of the genesis validators so the new set is visible on the relay chain.
+		let elected = vec![validator_stashes()[0].clone()];
+		setup_staking(&elected);
+
+		let era = activate_next_era(&elected);
+
+		$asset_hub::execute_with(|| {
+			type Runtime = <$asset_hub as Chain>::Runtime;
+
+			let exposure =
+				$crate::pallet_staking_async::ErasStakersOverview::<Runtime>::get(era, &elected[0])
+					.expect("the elected validator is exposed in the new era");
+			assert_eq!(exposure.total, VALIDATOR_BOND);
+			assert_eq!(exposure.own, VALIDATOR_BOND);
+			// the relay chain keeps reporting the sessions of the era.
+			assert!(rc_client::LastSessionReportEndingIndex::<Runtime>::get().is_some());
+		});
+		$relay::execute_with(|| {
+			type Runtime = <$relay as Chain>::Runtime;
+			assert_eq!(ah_client::Mode::<Runtime>::get(), ah_client::OperatingMode::Active);
+		});
+	}};
+}
+
+/// Every later election on `$asset_hub` is delivered to the relay chain as well.
+#[macro_export]
+macro_rules! test_relay_validator_set_follows_later_elections {
+	( $asset_hub:ident ) => {{
+		use $crate::xcm_emulator::{Chain, TestExt};
+
+		let stashes = validator_stashes();
+		let first = vec![stashes[0].clone()];
+		setup_staking(&first);
+		let first_era = activate_next_era(&first);
+
+		// a second validator joins, and is part of the relay chain set once the next era starts.
+		setup_staking(&stashes);
+		let second_era = activate_next_era(&stashes);
+		assert!(second_era > first_era);
+
+		$asset_hub::execute_with(|| {
+			type Runtime = <$asset_hub as Chain>::Runtime;
+			for stash in &stashes {
+				assert!(
+					$crate::pallet_staking_async::ErasStakersOverview::<Runtime>::contains_key(
+						second_era, stash
+					)
+				);
+			}
+		});
+	}};
+}
+
+/// An offence reported on `$relay` is recorded on `$asset_hub`, and its slash deferred.
+#[macro_export]
+macro_rules! test_relay_offences_are_slashed_on_asset_hub {
+	( $asset_hub:ident, $asset_hub_runtime:ident ) => {{
+		use $crate::{
+			frame_support::sp_runtime::Perbill,
+			pallet_staking_async_rc_client as rc_client,
+			xcm_emulator::{assert_expected_events, Chain, TestExt},
+		};
+
+		let validators = validator_stashes();
+		setup_staking(&validators);
+		let era = activate_next_era(&validators);
+		let offender = validators[0].clone();
+		let fraction = Perbill::from_percent(10);
+
+		report_offence(&offender, fraction);
+
+		$asset_hub::execute_with(|| {
+			type RuntimeEvent = <$asset_hub as Chain>::RuntimeEvent;
+			assert_expected_events!(
+				$asset_hub,
+				vec![
+					RuntimeEvent::StakingRcClient(
+						rc_client::Event::OffenceReceived { offences_count: 1, .. }
+					) => {},
+				]
+			);
+		});
+		// queued offences are processed one per block.
+		progress_asset_hub(5);
+
+		$asset_hub::execute_with(|| {
+			type Runtime = <$asset_hub as Chain>::Runtime;
+
+			let (recorded, amount) =
+				$crate::pallet_staking_async::ValidatorSlashInEra::<Runtime>::get(era, &offender)
+					.expect("the offence is recorded in the era it happened in");
+			assert_eq!(recorded, fraction);
+			assert_eq!(amount, fraction * VALIDATOR_BOND);
+
+			// the slash is deferred, and only applied after `SlashDeferDuration` eras.
+			let slash_era = era + $asset_hub_runtime::staking::SlashDeferDuration::get();
+			assert!($crate::pallet_staking_async::UnappliedSlashes::<Runtime>::iter_prefix(
+				slash_era
+			)
+			.any(|((who, recorded, _), _)| who == offender && recorded == fraction));
+			// nothing is taken from the stash until then.
+			assert_eq!(
+				$crate::pallet_staking_async::Ledger::<Runtime>::get(&offender).map(|l| l.active),
+				Some(VALIDATOR_BOND)
+			);
+			// the other validator is unaffected.
+			assert!($crate::pallet_staking_async::ValidatorSlashInEra::<Runtime>::get(
+				era,
+				&validators[1]
+			)
+			.is_none());
+		});
+	}};
+}
+
+/// Offences of relay chain validators never elected on `$asset_hub` are not slashed.
+#[macro_export]
+macro_rules! test_offences_of_unknown_validators_are_not_slashed {
+	( $asset_hub:ident ) => {{
+		use $crate::{
+			frame_support::sp_runtime::Perbill,
+			xcm_emulator::{Chain, TestExt},
+		};
+
+		let validators = vec![validator_stashes()[0].clone()];
+		setup_staking(&validators);
+		let era = activate_next_era(&validators);
+		// a genesis validator of the relay chain, but never elected on Asset Hub.
+		let stranger = validator_stashes()[1].clone();
+
+		report_offence(&stranger, Perbill::from_percent(10));
+		progress_asset_hub(5);
+
+		$asset_hub::execute_with(|| {
+			type Runtime = <$asset_hub as Chain>::Runtime;
+			assert!($crate::pallet_staking_async::ValidatorSlashInEra::<Runtime>::get(
+				era, &stranger
+			)
+			.is_none());
+		});
+	}};
+}
+
+/// Era points awarded on `$relay` are paid out on `$asset_hub`, once per era.
+#[macro_export]
+macro_rules! test_relay_era_points_are_paid_out_on_asset_hub {
+	( $relay:ident, $relay_runtime:ident, $asset_hub:ident, $asset_hub_sender:ident ) => {{
+		use $crate::{
+			frame_support::assert_ok,
+			pallet_authorship::EventHandler,
+			xcm_emulator::{assert_expected_events, Chain, TestExt},
+		};
+
+		// era points the relay chain awards per authored block.
+		const POINTS_PER_BLOCK: u32 = 20;
+
+		let validators = validator_stashes();
+		setup_staking(&validators);
+		let era = activate_next_era(&validators);
+
+		// the first validator authors twice as many blocks as the second.
+		let authored = [(validators[0].clone(), 4), (validators[1].clone(), 2)];
+		$relay::execute_with(|| {
+			type StakingAhClient = $relay_runtime::StakingAhClient;
+			for (author, blocks) in &authored {
+				for _ in 0..*blocks {
+					<StakingAhClient as EventHandler<_, _>>::note_author(author.clone());
+				}
+			}
+		});
+		// the points are part of the report of the ending session.
+		rotate_relay_session();
+
+		$asset_hub::execute_with(|| {
+			type Runtime = <$asset_hub as Chain>::Runtime;
+
+			let points = $crate::pallet_staking_async::ErasRewardPoints::<Runtime>::get(era);
+			for (author, blocks) in &authored {
+				assert_eq!(points.individual.get(author), Some(&(blocks * POINTS_PER_BLOCK)));
+			}
+			assert_eq!(points.total, 6 * POINTS_PER_BLOCK);
+		});
+
+		// once the era is over, anyone can trigger the payout of its validators.
+		activate_next_era(&validators);
+		$asset_hub::execute_with(|| {
+			type RuntimeEvent = <$asset_hub as Chain>::RuntimeEvent;
+			type RuntimeOrigin = <$asset_hub as Chain>::RuntimeOrigin;
+			type Staking = $crate::pallet_staking_async::Pallet<<$asset_hub as Chain>::Runtime>;
+
+			for (validator, _) in &authored {
+				assert_ok!(Staking::payout_stakers(
+					RuntimeOrigin::signed($asset_hub_sender::get()),
+					validator.clone(),
+					era,
+				));
+				assert_expected_events!(
+					$asset_hub,
+					vec![
+						RuntimeEvent::Staking($crate::pallet_staking_async::Event::PayoutStarted {
+							era_index,
+							validator_stash,
+							..
+						}) => {
+							era_index: *era_index == era,
+							validator_stash: validator_stash == validator,
+						},
+					]
+				);
+			}
+			// an era is only paid out once.
+			assert!(Staking::payout_stakers(
+				RuntimeOrigin::signed($asset_hub_sender::get()),
+				authored[0].0.clone(),
+				era,
+			)
+			.is_err());
+		});
+	}};
+}
+
+/// Session keys set by a validator on `$asset_hub` become its next keys on `$relay`.
+#[macro_export]
+macro_rules! test_session_keys_set_on_asset_hub_reach_the_relay {
+	( $relay:ident, $asset_hub:ident ) => {{
+		use $crate::{
+			codec::Encode,
+			frame_support::assert_ok,
+			xcm_emulator::{Chain, TestExt},
+		};
+
+		let validators = validator_stashes();
+		setup_staking(&validators);
+		let stash = validators[0].clone();
+		let (keys, proof) = generate_session_keys(&stash);
+
+		$relay::execute_with(|| {
+			type Runtime = <$relay as Chain>::Runtime;
+			// the genesis keys are still in place.
+			assert_ne!(
+				$crate::pallet_session::NextKeys::<Runtime>::get(&stash).map(|k| k.encode()),
+				Some(keys.clone())
+			);
+		});
+
+		$asset_hub::execute_with(|| {
+			type RcClient =
+				$crate::pallet_staking_async_rc_client::Pallet<<$asset_hub as Chain>::Runtime>;
+			type RuntimeOrigin = <$asset_hub as Chain>::RuntimeOrigin;
+
+			assert_ok!(RcClient::set_keys(
+				RuntimeOrigin::signed(stash.clone()),
+				keys.clone(),
+				proof,
+				None,
+			));
+		});
+
+		$relay::execute_with(|| {
+			type Runtime = <$relay as Chain>::Runtime;
+			assert_eq!(
+				$crate::pallet_session::NextKeys::<Runtime>::get(&stash).map(|k| k.encode()),
+				Some(keys.clone())
+			);
+		});
+
+		// the new keys are queued with the next session, and used from the one after.
+		rotate_relay_session();
+		$relay::execute_with(|| {
+			type Runtime = <$relay as Chain>::Runtime;
+			assert!($crate::pallet_session::QueuedKeys::<Runtime>::get()
+				.iter()
+				.any(|(who, queued)| who == &stash && queued.encode() == keys));
+		});
+	}};
+}
+
+/// Session keys purged by a validator on `$asset_hub` are removed from `$relay`.
+#[macro_export]
+macro_rules! test_session_keys_purged_on_asset_hub_leave_the_relay {
+	( $relay:ident, $asset_hub:ident ) => {{
+		use $crate::{
+			frame_support::assert_ok,
+			xcm_emulator::{Chain, TestExt},
+		};
+
+		let validators = validator_stashes();
+		setup_staking(&validators);
+		let stash = validators[0].clone();
+		let (keys, proof) = generate_session_keys(&stash);
+
+		$asset_hub::execute_with(|| {
+			type RcClient =
+				$crate::pallet_staking_async_rc_client::Pallet<<$asset_hub as Chain>::Runtime>;
+			type RuntimeOrigin = <$asset_hub as Chain>::RuntimeOrigin;
+
+			assert_ok!(RcClient::set_keys(RuntimeOrigin::signed(stash.clone()), keys, proof, None));
+		});
+		$relay::execute_with(|| {
+			type Runtime = <$relay as Chain>::Runtime;
+			assert!($crate::pallet_session::NextKeys::<Runtime>::contains_key(&stash));
+		});
+
+		$asset_hub::execute_with(|| {
+			type RcClient =
+				$crate::pallet_staking_async_rc_client::Pallet<<$asset_hub as Chain>::Runtime>;
+			type RuntimeOrigin = <$asset_hub as Chain>::RuntimeOrigin;
+
+			assert_ok!(RcClient::purge_keys(RuntimeOrigin::signed(stash.clone()), None));
+		});
+		$relay::execute_with(|| {
+			type Runtime = <$relay as Chain>::Runtime;
+			assert!(!$crate::pallet_session::NextKeys::<Runtime>::contains_key(&stash));
+		});
+	}};
+}
@@ -0,0 +1,154 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for the `AssetRateTrader` of a system parachain, which charges XCM execution in the
+//! assets with a rate in its `AssetRate`.
+
+/// Create `$asset` on `$chain`, owned by `$staking_pot`, with `$rate` to the native token.
+#[macro_export]
+macro_rules! register_asset_with_rate {
+	( $chain:ident, $staking_pot:ty, $asset:expr, $rate:expr ) => {
+		<$chain as $crate::xcm_emulator::TestExt>::execute_with(|| {
+			use $crate::{frame_support::assert_ok, xcm_emulator::Chain};
+
+			type Runtime = <$chain as Chain>::Runtime;
+			type RuntimeOrigin = <$chain as Chain>::RuntimeOrigin;
+
+			assert_ok!($crate::pallet_assets::Pallet::<Runtime>::force_create(
+				RuntimeOrigin::root(),
+				$asset,
+				<$staking_pot>::get().into(),
+				true,
+				1,
+			));
+			assert_ok!($crate::pallet_asset_rate::Pallet::<Runtime>::create(
+				RuntimeOrigin::root(),
+				Box::new($asset),
+				$rate.into(),
+			));
+		});
+	};
+}
+
+/// A `Transact` from `$asset_hub` to `$chain` pays for its execution with `$asset` only.
+///
+/// `$asset` is registered with `$rate`, the fees go to `$staking_pot` and no native token is
+/// involved.
+#[macro_export]
+macro_rules! test_transact_can_pay_with_asset_with_rate {
+	( $chain:ident, $asset_hub:ident, $xcm_config:ty, $staking_pot:ty, $asset:expr, $rate:expr ) => {{
+		use $crate::{
+			codec::Encode,
+			frame_support::{assert_ok, traits::fungibles::Inspect},
+			xcm::latest::prelude::*,
+			xcm_emulator::{assert_expected_events, Chain, Parachain, TestExt},
+			XcmPaymentApiV2,
+		};
+
+		let asset_hub = $chain::sibling_location_of($asset_hub::para_id());
+		let asset_hub_sovereign = $chain::sovereign_account_id_of(asset_hub.clone());
+		$crate::register_asset_with_rate!($chain, $staking_pot, $asset, $rate);
+
+		$chain::execute_with(|| {
+			type Runtime = <$chain as Chain>::Runtime;
+			type RuntimeCall = <$chain as Chain>::RuntimeCall;
+			type RuntimeEvent = <$chain as Chain>::RuntimeEvent;
+			type Assets = $crate::pallet_assets::Pallet<Runtime>;
+			type Balances = $crate::pallet_balances::Pallet<Runtime>;
+
+			let receiver = $crate::paste::paste! { [<$chain Receiver>]::get() };
+			let staking_pot = <$staking_pot>::get();
+			let sovereign_native_before = Balances::free_balance(&asset_hub_sovereign);
+			let staking_pot_native_before = Balances::free_balance(&staking_pot);
+
+			// the asset is the only one the message brings along.
+			let amount = 10_000_000;
+			let remark = RuntimeCall::System($crate::frame_system::Call::remark_with_event {
+				remark: b"paid in an asset with a rate".to_vec(),
+			});
+			let xcm = Xcm::builder_unsafe()
+				.reserve_asset_deposited(($asset, amount))
+				.buy_execution(($asset, amount), Unlimited)
+				.transact(OriginKind::SovereignAccount, None, remark.encode())
+				.refund_surplus()
+				.deposit_asset(AllCounted(1), receiver.clone())
+				.build();
+			let mut hash = xcm.using_encoded($crate::frame_support::hashing::blake2_256);
+			assert_ok!($crate::xcm_executor::XcmExecutor::<$xcm_config>::prepare_and_execute(
+				asset_hub,
+				xcm.clone(),
+				&mut hash,
+				Weight::MAX,
+				Weight::zero(),
+			)
+			.ensure_complete());
+
+			assert_expected_events!(
+				$chain,
+				vec![
+					RuntimeEvent::System($crate::frame_system::Event::Remarked { sender, .. }) => {
+						sender: *sender == asset_hub_sovereign,
+					},
+				]
+			);
+
+			// the fees were paid in the asset to the staking pot, the rest was deposited.
+			let weight = Runtime::query_xcm_weight(VersionedXcm::from(xcm.into())).unwrap();
+			let fees =
+				Runtime::query_weight_to_asset_fee(weight, VersionedAssetId::from(AssetId($asset)))
+					.unwrap();
+			assert!(fees > 0);
+			assert_eq!(<Assets as Inspect<_>>::balance($asset, &receiver), amount - fees);
+			assert_eq!(<Assets as Inspect<_>>::balance($asset, &staking_pot), fees);
+
+			// no native token was involved.
+			assert_eq!(Balances::free_balance(&asset_hub_sovereign), sovereign_native_before);
+			assert_eq!(Balances::free_balance(&staking_pot), staking_pot_native_before);
+		});
+	}};
+}
+
+/// `$chain` doesn't accept `$asset` without a rate to pay for execution.
+#[macro_export]
+macro_rules! test_asset_without_rate_cannot_pay {
+	( $chain:ident, $asset_hub:ident, $xcm_config:ty, $asset:expr ) => {{
+		use $crate::{
+			codec::Encode,
+			xcm::latest::prelude::*,
+			xcm_emulator::{Chain, Parachain, TestExt},
+		};
+
+		let asset_hub = $chain::sibling_location_of($asset_hub::para_id());
+
+		$chain::execute_with(|| {
+			let amount = 10_000_000;
+			let xcm = Xcm::<<$chain as Chain>::RuntimeCall>::builder_unsafe()
+				.reserve_asset_deposited(($asset, amount))
+				.buy_execution(($asset, amount), Unlimited)
+				.deposit_asset(AllCounted(1), $crate::paste::paste! { [<$chain Receiver>]::get() })
+				.build();
+			let mut hash = xcm.using_encoded($crate::frame_support::hashing::blake2_256);
+			assert!($crate::xcm_executor::XcmExecutor::<$xcm_config>::prepare_and_execute(
+				asset_hub,
+				xcm,
+				&mut hash,
+				Weight::MAX,
+				Weight::zero(),
+			)
+			.ensure_complete()
+			.is_err());
+		});
+	}};
+}
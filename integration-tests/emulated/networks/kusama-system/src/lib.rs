@@ -15,6 +15,7 @@
 
 pub use asset_hub_kusama_emulated_chain;
 pub use bridge_hub_kusama_emulated_chain;
+pub use collectives_kusama_emulated_chain;
 pub use coretime_kusama_emulated_chain;
 pub use encointer_kusama_emulated_chain;
 pub use kusama_emulated_chain;
@@ -23,6 +24,7 @@ pub use people_kusama_emulated_chain;
 
 use asset_hub_kusama_emulated_chain::AssetHubKusama;
 use bridge_hub_kusama_emulated_chain::BridgeHubKusama;
+use collectives_kusama_emulated_chain::CollectivesKusama;
 use coretime_kusama_emulated_chain::CoretimeKusama;
 use encointer_kusama_emulated_chain::EncointerKusama;
 use kusama_emulated_chain::Kusama;
@@ -46,6 +48,7 @@ decl_test_networks! {
 			PeopleKusama,
 			CoretimeKusama,
 			EncointerKusama,
+			CollectivesKusama,
 		],
 		bridge = ()
 	},
@@ -59,5 +62,6 @@ decl_test_sender_receiver_accounts_parameter_types! {
 	PenpalBPara { sender: ALICE, receiver: BOB },
 	PeopleKusamaPara { sender: ALICE, receiver: BOB },
 	CoretimeKusamaPara { sender: ALICE, receiver: BOB },
-	EncointerKusamaPara { sender: ALICE, receiver: BOB }
+	EncointerKusamaPara { sender: ALICE, receiver: BOB },
+	CollectivesKusamaPara { sender: ALICE, receiver: BOB }
 }
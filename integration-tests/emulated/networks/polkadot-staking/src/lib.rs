@@ -0,0 +1,47 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! A minimal Polkadot network for staking tests.
+//!
+//! Async staking only involves the relay chain and Asset Hub: Asset Hub runs the election and
+//! sends the validator set to the relay chain, the relay chain sends session reports and
+//! offences back. Leaving the other system parachains out keeps these tests fast.
+
+pub use asset_hub_polkadot_emulated_chain;
+pub use polkadot_emulated_chain;
+
+use asset_hub_polkadot_emulated_chain::AssetHubPolkadot;
+use polkadot_emulated_chain::Polkadot;
+
+// Cumulus
+use emulated_integration_tests_common::{
+	accounts::{ALICE, BOB},
+	xcm_emulator::{decl_test_networks, decl_test_sender_receiver_accounts_parameter_types},
+};
+
+decl_test_networks! {
+	pub struct PolkadotStakingNet {
+		relay_chain = Polkadot,
+		parachains = vec![
+			AssetHubPolkadot,
+		],
+		bridge = ()
+	},
+}
+
+decl_test_sender_receiver_accounts_parameter_types! {
+	PolkadotRelay { sender: ALICE, receiver: BOB },
+	AssetHubPolkadotPara { sender: ALICE, receiver: BOB }
+}
@@ -14,9 +14,11 @@
 // limitations under the License.
 
 mod aliases;
+mod asset_locks;
 mod claim_assets;
 mod exchange_asset;
 mod foreign_assets;
+mod hrmp_channels;
 mod hybrid_transfers;
 mod reserve_transfer;
 mod send;
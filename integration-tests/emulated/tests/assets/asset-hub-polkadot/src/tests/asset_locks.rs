@@ -0,0 +1,156 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use asset_hub_polkadot_runtime::{Balances, RuntimeFreezeReason, XcmAssetLocker};
+use frame_support::traits::fungible::{InspectFreeze, Mutate};
+use pallet_xcm_asset_locker::FreezeReason;
+
+const FREEZE: RuntimeFreezeReason = RuntimeFreezeReason::XcmAssetLocker(FreezeReason::XcmLock);
+
+fn allow_chain(chain: Location) {
+	AssetHubPolkadot::execute_with(|| {
+		assert_ok!(XcmAssetLocker::allow_chain(
+			<AssetHubPolkadot as Chain>::RuntimeOrigin::root(),
+			bx!(chain.into()),
+		));
+	});
+}
+
+/// `owner` locks `amount` DOT on Asset Hub for `unlocker`.
+fn lock_on_asset_hub(owner: AccountId, unlocker: Location, amount: Balance) -> DispatchResult {
+	AssetHubPolkadot::execute_with(|| {
+		let fees: Asset = (Parent, ASSET_HUB_POLKADOT_ED * 10_000).into();
+		let xcm = Xcm::<<AssetHubPolkadot as Chain>::RuntimeCall>(vec![
+			WithdrawAsset(fees.into()),
+			LockAsset { asset: (Parent, amount).into(), unlocker },
+			DepositAsset { assets: Wild(All), beneficiary: owner.clone().into() },
+		]);
+		<AssetHubPolkadot as AssetHubPolkadotPallet>::PolkadotXcm::execute(
+			<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(owner),
+			bx!(xcm::VersionedXcm::from(xcm)),
+			Weight::MAX,
+		)
+		.map(|_| ())
+		.map_err(|e| e.error)
+	})
+}
+
+fn frozen_on_asset_hub(owner: &AccountId) -> Balance {
+	AssetHubPolkadot::execute_with(|| Balances::balance_frozen(&FREEZE, owner))
+}
+
+#[test]
+fn parachain_locks_and_unlocks_dot_on_asset_hub() {
+	let owner = AssetHubPolkadotSender::get();
+	let amount = ASSET_HUB_POLKADOT_ED * 1_000_000;
+	let penpal_on_ah = AssetHubPolkadot::sibling_location_of(PenpalA::para_id());
+	let penpal_sovereign = AssetHubPolkadot::sovereign_account_id_of(penpal_on_ah.clone());
+	AssetHubPolkadot::fund_accounts(vec![(
+		penpal_sovereign.clone(),
+		ASSET_HUB_POLKADOT_ED * 1_000_000,
+	)]);
+	allow_chain(penpal_on_ah.clone());
+
+	// The owner locks DOT for Penpal, which is told about it with `NoteUnlockable`.
+	assert_ok!(lock_on_asset_hub(owner.clone(), penpal_on_ah.clone(), amount));
+	AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::XcmAssetLocker(
+					pallet_xcm_asset_locker::Event::Locked { owner: who, unlocker, amount: locked, .. }
+				) => {
+					who: *who == owner,
+					unlocker: *unlocker == penpal_on_ah,
+					locked: *locked == amount,
+				},
+			]
+		);
+
+		// The locked DOT can't be moved.
+		let free = Balances::free_balance(&owner);
+		assert!(Balances::transfer(
+			&owner,
+			&AssetHubPolkadotReceiver::get(),
+			free - amount / 2,
+			frame_support::traits::tokens::Preservation::Expendable,
+		)
+		.is_err());
+	});
+	assert_eq!(frozen_on_asset_hub(&owner), amount);
+
+	// Penpal unlocks them again.
+	PenpalA::execute_with(|| {
+		let fees: Asset = (Parent, ASSET_HUB_POLKADOT_ED * 10_000).into();
+		let target = AccountId32Junction { network: None, id: owner.clone().into() }.into();
+		let xcm = Xcm::<()>(vec![
+			WithdrawAsset(fees.clone().into()),
+			BuyExecution { fees, weight_limit: Unlimited },
+			UnlockAsset { asset: (Parent, amount).into(), target },
+			RefundSurplus,
+			DepositAsset { assets: Wild(All), beneficiary: penpal_sovereign.clone().into() },
+		]);
+		assert_ok!(<PenpalA as PenpalAPallet>::PolkadotXcm::send(
+			<PenpalA as Chain>::RuntimeOrigin::root(),
+			bx!(PenpalA::sibling_location_of(AssetHubPolkadot::para_id()).into()),
+			bx!(xcm::VersionedXcm::from(xcm)),
+		));
+		PenpalA::assert_xcm_pallet_sent();
+	});
+	AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::XcmAssetLocker(
+					pallet_xcm_asset_locker::Event::Unlocked { owner: who, unlocker, amount: unlocked, .. }
+				) => {
+					who: *who == owner,
+					unlocker: *unlocker == penpal_on_ah,
+					unlocked: *unlocked == amount,
+				},
+			]
+		);
+	});
+	assert_eq!(frozen_on_asset_hub(&owner), 0);
+}
+
+#[test]
+fn only_allowed_chains_can_request_locks() {
+	let owner = AssetHubPolkadotSender::get();
+	let amount = ASSET_HUB_POLKADOT_ED * 1_000_000;
+	let penpal_on_ah = AssetHubPolkadot::sibling_location_of(PenpalB::para_id());
+
+	assert!(lock_on_asset_hub(owner.clone(), penpal_on_ah.clone(), amount).is_err());
+	assert_eq!(frozen_on_asset_hub(&owner), 0);
+
+	allow_chain(penpal_on_ah.clone());
+	assert_ok!(lock_on_asset_hub(owner.clone(), penpal_on_ah.clone(), amount));
+	assert_eq!(frozen_on_asset_hub(&owner), amount);
+
+	// Disallowing the chain keeps its locks.
+	AssetHubPolkadot::execute_with(|| {
+		assert_ok!(XcmAssetLocker::disallow_chain(
+			<AssetHubPolkadot as Chain>::RuntimeOrigin::root(),
+			bx!(penpal_on_ah.clone().into()),
+		));
+	});
+	assert!(lock_on_asset_hub(owner.clone(), penpal_on_ah, amount * 2).is_err());
+	assert_eq!(frozen_on_asset_hub(&owner), amount);
+}
@@ -526,3 +526,132 @@ fn verify_foreign_asset_origin_checks() {
 		);
 	});
 }
+
+// Reserve-transfers `amount` of the asset from Penpal to AH and returns whether AH accepted it.
+fn reserve_transfer_foreign_asset_from_penpal_to_asset_hub(
+	sender: sp_runtime::AccountId32,
+	receiver: sp_runtime::AccountId32,
+	asset_location_on_penpal: Location,
+	foreign_asset_location_on_ah: Location,
+	amount: u128,
+) -> bool {
+	let ah_receiver_balance_before =
+		foreign_balance_on!(AssetHubPolkadot, foreign_asset_location_on_ah.clone(), &receiver);
+
+	let dest = PenpalA::sibling_location_of(AssetHubPolkadot::para_id());
+	let assets: Assets = vec![(asset_location_on_penpal, amount).into()].into();
+	PenpalA::execute_with(|| {
+		let xcm_on_dest = Xcm(vec![
+			RefundSurplus,
+			DepositAsset { assets: Wild(All), beneficiary: receiver.clone().into() },
+		]);
+		let xcm = Xcm::<()>(vec![
+			WithdrawAsset(assets.clone()),
+			SetFeesMode { jit_withdraw: true },
+			InitiateTransfer {
+				destination: dest,
+				remote_fees: Some(AssetTransferFilter::ReserveDeposit(assets.into())),
+				preserve_origin: false,
+				assets: BoundedVec::new(),
+				remote_xcm: xcm_on_dest,
+			},
+		]);
+		assert_ok!(<PenpalA as PenpalAPallet>::PolkadotXcm::execute(
+			<PenpalA as Chain>::RuntimeOrigin::signed(sender),
+			bx!(xcm::VersionedXcm::from(xcm.into())),
+			Weight::MAX,
+		));
+	});
+
+	let accepted = AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+		<AssetHubPolkadot as Chain>::System::events().iter().any(|record| {
+			matches!(
+				record.event,
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+					success: true,
+					..
+				})
+			)
+		})
+	});
+
+	let ah_receiver_balance_after =
+		foreign_balance_on!(AssetHubPolkadot, foreign_asset_location_on_ah, &receiver);
+	assert_eq!(accepted, ah_receiver_balance_after > ah_receiver_balance_before);
+	accepted
+}
+
+/// Reserve transfers of a foreign asset follow the reserves set by governance: they are accepted
+/// from a reserve added by governance and rejected after governance removed it again, regardless
+/// of the reserves set by the owner of the asset.
+#[test]
+fn reserve_transfer_foreign_asset_follows_reserves_set_by_governance() {
+	let sender = PenpalASender::get();
+	let receiver = AssetHubPolkadotReceiver::get();
+	let new_asset_id = local_penpal_asset(42);
+	let asset_amount_to_send = ASSET_HUB_POLKADOT_ED * 10_000;
+	let (asset_location_on_penpal, foreign_asset_location_on_ah) =
+		set_up_foreign_asset(sender.clone(), new_asset_id.clone(), asset_amount_to_send, false);
+	let penpal_reserve = ForeignAssetReserveData {
+		reserve: AssetHubPolkadot::sibling_location_of(PenpalA::para_id()),
+		teleportable: false,
+	};
+	let transfer = || {
+		reserve_transfer_foreign_asset_from_penpal_to_asset_hub(
+			sender.clone(),
+			receiver.clone(),
+			asset_location_on_penpal.clone(),
+			foreign_asset_location_on_ah.clone(),
+			asset_amount_to_send / 4,
+		)
+	};
+
+	// Governance removes the reserves set up by the owner, AH rejects the transfer.
+	AssetHubPolkadot::execute_with(|| {
+		assert_ok!(
+			<AssetHubPolkadot as AssetHubPolkadotPallet>::ForeignAssetReserves::set_reserves(
+				<AssetHubPolkadot as Chain>::RuntimeOrigin::root(),
+				foreign_asset_location_on_ah.clone(),
+				Default::default(),
+			)
+		);
+	});
+	assert!(!transfer());
+
+	// Governance adds Penpal as reserve, AH accepts the transfer.
+	AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+		assert_ok!(
+			<AssetHubPolkadot as AssetHubPolkadotPallet>::ForeignAssetReserves::add_reserve(
+				<AssetHubPolkadot as Chain>::RuntimeOrigin::root(),
+				foreign_asset_location_on_ah.clone(),
+				penpal_reserve.clone(),
+			)
+		);
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::ForeignAssetReserves(
+					pallet_foreign_asset_reserves::Event::ReservesSet { asset_id, reserves }
+				) => {
+					asset_id: *asset_id == foreign_asset_location_on_ah,
+					reserves: *reserves == vec![penpal_reserve.clone()],
+				},
+			]
+		);
+	});
+	assert!(transfer());
+
+	// Governance removes Penpal as reserve again, AH rejects the transfer.
+	AssetHubPolkadot::execute_with(|| {
+		assert_ok!(
+			<AssetHubPolkadot as AssetHubPolkadotPallet>::ForeignAssetReserves::remove_reserve(
+				<AssetHubPolkadot as Chain>::RuntimeOrigin::root(),
+				foreign_asset_location_on_ah.clone(),
+				penpal_reserve.clone(),
+			)
+		);
+	});
+	assert!(!transfer());
+}
@@ -0,0 +1,29 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use integration_tests_helpers::{
+	test_hrmp_channel_is_auto_accepted, test_hrmp_channel_of_denied_para_is_rejected,
+};
+
+#[test]
+fn asset_hub_accepts_and_requests_back_channels() {
+	test_hrmp_channel_is_auto_accepted!(AssetHubPolkadot, Polkadot, PenpalA);
+}
+
+#[test]
+fn asset_hub_rejects_denied_parachains() {
+	test_hrmp_channel_of_denied_para_is_rejected!(AssetHubPolkadot, Polkadot, PenpalB);
+}
@@ -14,14 +14,18 @@
 // limitations under the License.
 
 use crate::*;
+use asset_hub_polkadot_runtime::treasury::RemotePaymentFee;
 use emulated_integration_tests_common::accounts::{ALICE, BOB};
 use frame_support::traits::fungibles::Mutate;
 use parachains_common::pay::VersionedLocatableAccount;
 use polkadot_runtime_common::impls::VersionedLocatableAsset;
+use xcm_executor::traits::{QueryHandler, QueryResponseStatus};
+
+/// USDT on Asset Hub, identified by its index in the trust backed assets pallet.
+const USDT_ID: u32 = 1984;
 
 #[test]
 fn create_and_claim_treasury_spend_in_usdt() {
-	const USDT_ID: u32 = 1984;
 	const SPEND_AMOUNT: u128 = 1_000_000_000;
 
 	let treasury_account = asset_hub_polkadot_runtime::Treasury::account_id();
@@ -85,3 +89,334 @@ fn create_and_claim_treasury_spend_in_usdt() {
 		assert_eq!(<Assets as Inspect<_>>::balance(USDT_ID, &alice,), SPEND_AMOUNT,);
 	});
 }
+
+/// Creates and pays out a treasury spend of `amount` USDT to a beneficiary on Penpal, without
+/// processing the message it results in on Penpal.
+fn send_treasury_spend_in_usdt_to_penpal(amount: u128, beneficiary: AccountId) {
+	let root = <AssetHubPolkadot as Chain>::RuntimeOrigin::root();
+	let bob_signed =
+		<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(AssetHubPolkadot::account_id_of(BOB));
+	let asset_kind = VersionedLocatableAsset::V5 {
+		location: Location::new(0, []),
+		asset_id: v5::AssetId(
+			(v5::Junction::PalletInstance(50), v5::Junction::GeneralIndex(USDT_ID.into())).into(),
+		),
+	};
+
+	AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+		type Treasury = <AssetHubPolkadot as AssetHubPolkadotPallet>::Treasury;
+		type AssetRate = <AssetHubPolkadot as AssetHubPolkadotPallet>::AssetRate;
+
+		assert_ok!(AssetRate::create(root.clone(), Box::new(asset_kind.clone()), 2.into()));
+		assert_ok!(Treasury::spend(
+			root,
+			Box::new(asset_kind),
+			amount,
+			Box::new(VersionedLocatableAccount::V5 {
+				location: AssetHubPolkadot::sibling_location_of(PenpalB::para_id()),
+				account_id: Location::new(0, Into::<[u8; 32]>::into(beneficiary)),
+			}),
+			None,
+		));
+		assert_ok!(Treasury::payout(bob_signed.clone(), 0));
+		// the payment is not reported back yet.
+		assert_err!(
+			Treasury::check_status(bob_signed, 0),
+			pallet_treasury::Error::<asset_hub_polkadot_runtime::Runtime>::Inconclusive
+		);
+
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::Treasury(pallet_treasury::Event::Paid { .. }) => {},
+				RuntimeEvent::PolkadotXcm(pallet_xcm::Event::Sent { .. }) => {},
+			]
+		);
+	});
+}
+
+/// Creates and pays out a treasury spend of `amount` USDT to a beneficiary on Penpal, and
+/// processes the messages it results in on Penpal.
+fn pay_out_treasury_spend_in_usdt_to_penpal(amount: u128, beneficiary: AccountId) {
+	send_treasury_spend_in_usdt_to_penpal(amount, beneficiary);
+
+	// the payment is executed on Penpal and reported back to Asset Hub.
+	PenpalB::execute_with(|| {});
+}
+
+/// Moves Asset Hub past the timeout of the remote payments and checks the status of spend `0`,
+/// which concludes as unknown without a retry. Returns the query ID of the payment.
+fn time_out_remote_treasury_spend() -> u64 {
+	use asset_hub_polkadot_runtime::treasury::{RemotePaymentTimeout, UnresolvedRemotePayments};
+
+	let bob_signed =
+		<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(AssetHubPolkadot::account_id_of(BOB));
+	AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+		type Treasury = <AssetHubPolkadot as AssetHubPolkadotPallet>::Treasury;
+
+		let now = <AssetHubPolkadot as Chain>::System::block_number();
+		<AssetHubPolkadot as Chain>::System::set_block_number(
+			now + RemotePaymentTimeout::get() + 1,
+		);
+
+		assert_ok!(Treasury::check_status(bob_signed, 0));
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::Treasury(pallet_treasury::Event::SpendProcessed { .. }) => {},
+			]
+		);
+		// the spend can't be paid out again.
+		assert!(pallet_treasury::Spends::<asset_hub_polkadot_runtime::Runtime>::get(0).is_none());
+
+		let query_id = UnresolvedRemotePayments::iter_keys().next().expect("payment is unresolved");
+		// the query is dropped, so a late report is not taken.
+		assert!(matches!(
+			<AssetHubPolkadot as AssetHubPolkadotPallet>::PolkadotXcm::take_response(query_id),
+			QueryResponseStatus::NotFound
+		));
+		query_id
+	})
+}
+
+/// Mints USDT to the treasury and funds it and the sovereign account of Penpal with DOT.
+fn fund_treasury_for_remote_spends(usdt_amount: u128) {
+	let treasury_account = asset_hub_polkadot_runtime::Treasury::account_id();
+	let penpal_sovereign = AssetHubPolkadot::sovereign_account_id_of(
+		AssetHubPolkadot::sibling_location_of(PenpalB::para_id()),
+	);
+	AssetHubPolkadot::fund_accounts(vec![
+		(treasury_account.clone(), ASSET_HUB_POLKADOT_ED * 1_000_000),
+		(penpal_sovereign, ASSET_HUB_POLKADOT_ED),
+	]);
+	AssetHubPolkadot::execute_with(|| {
+		type Assets = <AssetHubPolkadot as AssetHubPolkadotPallet>::Assets;
+		assert_ok!(<Assets as Mutate<_>>::mint_into(USDT_ID, &treasury_account, usdt_amount));
+	});
+}
+
+#[test]
+fn create_and_claim_treasury_spend_in_usdt_on_sibling() {
+	const SPEND_AMOUNT: u128 = 1_000_000_000;
+
+	let treasury_account = asset_hub_polkadot_runtime::Treasury::account_id();
+	let receiver = PenpalBReceiver::get();
+	let bob_signed =
+		<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(AssetHubPolkadot::account_id_of(BOB));
+	fund_treasury_for_remote_spends(SPEND_AMOUNT * 4);
+
+	let receiver_balance_before =
+		assets_balance_on!(PenpalB, PenpalUsdtFromAssetHub::get(), &receiver);
+
+	pay_out_treasury_spend_in_usdt_to_penpal(SPEND_AMOUNT, receiver.clone());
+
+	// beneficiary received the assets from the treasury.
+	assert_eq!(
+		assets_balance_on!(PenpalB, PenpalUsdtFromAssetHub::get(), &receiver),
+		receiver_balance_before + SPEND_AMOUNT
+	);
+
+	AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+		type Treasury = <AssetHubPolkadot as AssetHubPolkadotPallet>::Treasury;
+		type Assets = <AssetHubPolkadot as AssetHubPolkadotPallet>::Assets;
+
+		assert_ok!(Treasury::check_status(bob_signed, 0));
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::Treasury(pallet_treasury::Event::SpendProcessed { .. }) => {},
+			]
+		);
+		assert_eq!(<Assets as Inspect<_>>::balance(USDT_ID, &treasury_account), SPEND_AMOUNT * 3);
+	});
+}
+
+#[test]
+fn failed_treasury_spend_on_sibling_returns_funds() {
+	// below the minimum balance of USDT on Penpal, so the deposit to the beneficiary fails.
+	const SPEND_AMOUNT: u128 = 1_000;
+
+	let treasury_account = asset_hub_polkadot_runtime::Treasury::account_id();
+	let receiver = PenpalBReceiver::get();
+	let bob_signed =
+		<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(AssetHubPolkadot::account_id_of(BOB));
+	fund_treasury_for_remote_spends(SPEND_AMOUNT * 4);
+
+	// the sovereign account of Penpal has to hold USDT to receive such a small amount.
+	let penpal_sovereign = AssetHubPolkadot::sovereign_account_id_of(
+		AssetHubPolkadot::sibling_location_of(PenpalB::para_id()),
+	);
+	AssetHubPolkadot::execute_with(|| {
+		type Assets = <AssetHubPolkadot as AssetHubPolkadotPallet>::Assets;
+		assert_ok!(<Assets as Mutate<_>>::mint_into(USDT_ID, &penpal_sovereign, 1_000_000));
+	});
+
+	pay_out_treasury_spend_in_usdt_to_penpal(SPEND_AMOUNT, receiver.clone());
+
+	// beneficiary did not receive anything.
+	assert_eq!(assets_balance_on!(PenpalB, PenpalUsdtFromAssetHub::get(), &receiver), 0);
+
+	AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+		type Treasury = <AssetHubPolkadot as AssetHubPolkadotPallet>::Treasury;
+		type Assets = <AssetHubPolkadot as AssetHubPolkadotPallet>::Assets;
+
+		assert_ok!(Treasury::check_status(bob_signed, 0));
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::Treasury(pallet_treasury::Event::PaymentFailed { .. }) => {},
+			]
+		);
+		// the assets were returned to the treasury.
+		assert_eq!(<Assets as Inspect<_>>::balance(USDT_ID, &treasury_account), SPEND_AMOUNT * 4);
+		assert_eq!(<Assets as Inspect<_>>::balance(USDT_ID, &penpal_sovereign), 1_000_000);
+	});
+}
+
+#[test]
+fn rejected_treasury_spend_on_sibling_is_left_to_governance_after_timeout() {
+	use asset_hub_polkadot_runtime::treasury::UnresolvedRemotePayments;
+
+	const SPEND_AMOUNT: u128 = 10_000_000_000;
+
+	let treasury_account = asset_hub_polkadot_runtime::Treasury::account_id();
+	let root = <AssetHubPolkadot as Chain>::RuntimeOrigin::root();
+	let bob_signed =
+		<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(AssetHubPolkadot::account_id_of(BOB));
+	let collectives_location =
+		AssetHubPolkadot::sibling_location_of(CollectivesPolkadot::para_id());
+	let collectives_sovereign =
+		AssetHubPolkadot::sovereign_account_id_of(collectives_location.clone());
+	AssetHubPolkadot::fund_accounts(vec![
+		(treasury_account.clone(), ASSET_HUB_POLKADOT_ED * 1_000_000),
+		(collectives_sovereign.clone(), ASSET_HUB_POLKADOT_ED),
+	]);
+
+	let (treasury_balance_before, sovereign_balance_before) =
+		AssetHubPolkadot::execute_with(|| {
+			type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+			type Balances = <AssetHubPolkadot as AssetHubPolkadotPallet>::Balances;
+			type Treasury = <AssetHubPolkadot as AssetHubPolkadotPallet>::Treasury;
+
+			let balances = (
+				Balances::free_balance(&treasury_account),
+				Balances::free_balance(&collectives_sovereign),
+			);
+			// the Collectives chain doesn't take DOT from Asset Hub as a reserve transfer.
+			assert_ok!(Treasury::spend(
+				root,
+				Box::new(VersionedLocatableAsset::V5 {
+					location: Location::new(0, []),
+					asset_id: v5::AssetId(Location::parent()),
+				}),
+				SPEND_AMOUNT,
+				Box::new(VersionedLocatableAccount::V5 {
+					location: collectives_location,
+					account_id: Location::new(
+						0,
+						Into::<[u8; 32]>::into(CollectivesPolkadot::account_id_of(ALICE)),
+					),
+				}),
+				None,
+			));
+			assert_ok!(Treasury::payout(bob_signed.clone(), 0));
+			assert_expected_events!(
+				AssetHubPolkadot,
+				vec![
+					RuntimeEvent::Treasury(pallet_treasury::Event::Paid { .. }) => {},
+				]
+			);
+			balances
+		});
+
+	CollectivesPolkadot::execute_with(|| {
+		type RuntimeEvent = <CollectivesPolkadot as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			CollectivesPolkadot,
+			vec![
+				RuntimeEvent::MessageQueue(pallet_message_queue::Event::Processed {
+					success: false,
+					..
+				}) => {},
+			]
+		);
+	});
+
+	AssetHubPolkadot::execute_with(|| {
+		type Treasury = <AssetHubPolkadot as AssetHubPolkadotPallet>::Treasury;
+
+		// nothing is reported back, the payment stays in progress until it times out.
+		assert_err!(
+			Treasury::check_status(bob_signed.clone(), 0),
+			pallet_treasury::Error::<asset_hub_polkadot_runtime::Runtime>::Inconclusive
+		);
+	});
+
+	let query_id = time_out_remote_treasury_spend();
+
+	AssetHubPolkadot::execute_with(|| {
+		type Balances = <AssetHubPolkadot as AssetHubPolkadotPallet>::Balances;
+
+		// the funds stay in the sovereign account of the Collectives chain for governance.
+		assert_eq!(
+			Balances::free_balance(&treasury_account),
+			treasury_balance_before - SPEND_AMOUNT - RemotePaymentFee::get()
+		);
+		assert_eq!(
+			Balances::free_balance(&collectives_sovereign),
+			sovereign_balance_before + SPEND_AMOUNT + RemotePaymentFee::get()
+		);
+		assert_eq!(
+			UnresolvedRemotePayments::get(query_id),
+			Some((
+				collectives_sovereign.clone(),
+				Location::parent(),
+				SPEND_AMOUNT,
+				RemotePaymentFee::get()
+			))
+		);
+	});
+}
+
+#[test]
+fn treasury_spend_on_sibling_reported_after_timeout_keeps_funds_in_reserve() {
+	use asset_hub_polkadot_runtime::treasury::UnresolvedRemotePayments;
+
+	const SPEND_AMOUNT: u128 = 1_000_000_000;
+
+	let treasury_account = asset_hub_polkadot_runtime::Treasury::account_id();
+	let receiver = PenpalBReceiver::get();
+	let penpal_sovereign = AssetHubPolkadot::sovereign_account_id_of(
+		AssetHubPolkadot::sibling_location_of(PenpalB::para_id()),
+	);
+	fund_treasury_for_remote_spends(SPEND_AMOUNT * 4);
+
+	let receiver_balance_before =
+		assets_balance_on!(PenpalB, PenpalUsdtFromAssetHub::get(), &receiver);
+
+	send_treasury_spend_in_usdt_to_penpal(SPEND_AMOUNT, receiver.clone());
+	// the payment times out before Penpal executes it.
+	let query_id = time_out_remote_treasury_spend();
+	// the payment is executed on Penpal and its report only then sent back.
+	PenpalB::execute_with(|| {});
+
+	// beneficiary received the assets from the treasury.
+	assert_eq!(
+		assets_balance_on!(PenpalB, PenpalUsdtFromAssetHub::get(), &receiver),
+		receiver_balance_before + SPEND_AMOUNT
+	);
+
+	AssetHubPolkadot::execute_with(|| {
+		type Assets = <AssetHubPolkadot as AssetHubPolkadotPallet>::Assets;
+
+		// the late report changes nothing: the assets minted on Penpal stay backed by the
+		// sovereign account of Penpal, and the treasury doesn't get them back.
+		assert_eq!(<Assets as Inspect<_>>::balance(USDT_ID, &treasury_account), SPEND_AMOUNT * 3);
+		assert_eq!(<Assets as Inspect<_>>::balance(USDT_ID, &penpal_sovereign), SPEND_AMOUNT);
+		assert!(UnresolvedRemotePayments::contains_key(query_id));
+	});
+}
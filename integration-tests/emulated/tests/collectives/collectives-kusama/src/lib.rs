@@ -0,0 +1,42 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+pub use frame_support::assert_ok;
+
+// Polkadot
+pub use xcm::prelude::*;
+
+// Cumulus
+pub use emulated_integration_tests_common::xcm_emulator::{
+	assert_expected_events, bx, Chain, Parachain, RelayChain as Relay, TestExt,
+};
+pub use kusama_system_emulated_network::{
+	asset_hub_kusama_emulated_chain::{
+		genesis::ED as ASSET_HUB_KUSAMA_ED, AssetHubKusamaParaPallet as AssetHubKusamaPallet,
+	},
+	collectives_kusama_emulated_chain::{
+		genesis::ED as COLLECTIVES_KUSAMA_ED,
+		CollectivesKusamaParaPallet as CollectivesKusamaPallet,
+	},
+	kusama_emulated_chain::genesis::ED as KUSAMA_ED,
+	AssetHubKusamaPara as AssetHubKusama, AssetHubKusamaParaReceiver as AssetHubKusamaReceiver,
+	AssetHubKusamaParaSender as AssetHubKusamaSender, CollectivesKusamaPara as CollectivesKusama,
+	CollectivesKusamaParaReceiver as CollectivesKusamaReceiver,
+	CollectivesKusamaParaSender as CollectivesKusamaSender, KusamaRelay as Kusama,
+};
+pub use parachains_common::AccountId;
+
+#[cfg(test)]
+mod tests;
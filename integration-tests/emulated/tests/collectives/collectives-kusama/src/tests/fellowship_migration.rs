@@ -0,0 +1,121 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use collectives_kusama_runtime::{
+	fellowship::FellowshipReferendaInstance as CollectivesReferendaInstance, FellowshipCollective,
+};
+use frame_support::traits::{
+	fungible::Mutate, schedule::DispatchTime, OnRuntimeUpgrade, RankedMembers, StorePreimage,
+};
+use kusama_runtime::{
+	governance::{
+		fellowship_migration::MigrateFellowshipToCollectives, pallet_custom_origins::Origin,
+		FellowshipCollectiveInstance, FellowshipReferendaInstance,
+	},
+	Balances, FellowshipReferenda, OriginCaller, Preimage,
+};
+use pallet_referenda::ReferendumInfo;
+use runtime_parachains::paras::{ParaLifecycle, ParaLifecycles};
+
+const RANKS: [u16; 4] = [0, 1, 3, 7];
+
+fn member(index: usize) -> AccountId {
+	AccountId::from([index as u8 + 1; 32])
+}
+
+/// Adds the members of [`RANKS`] to the Fellowship on Kusama and onboards Collectives Kusama.
+/// Must be called inside a `Kusama::execute_with` closure.
+fn set_up_relay_fellowship() {
+	type Runtime = <Kusama as Chain>::Runtime;
+
+	for (index, rank) in RANKS.into_iter().enumerate() {
+		assert_ok!(pallet_ranked_collective::Pallet::<
+			Runtime,
+			FellowshipCollectiveInstance,
+		>::do_add_member_to_rank(member(index), rank, false));
+	}
+	ParaLifecycles::<Runtime>::insert(CollectivesKusama::para_id(), ParaLifecycle::Parachain);
+}
+
+#[test]
+fn fellowship_members_are_moved_to_collectives() {
+	Kusama::execute_with(|| {
+		set_up_relay_fellowship();
+		MigrateFellowshipToCollectives::on_runtime_upgrade();
+	});
+
+	CollectivesKusama::execute_with(|| {
+		CollectivesKusama::assert_dmp_queue_complete(None);
+
+		for (index, rank) in RANKS.into_iter().enumerate() {
+			assert_eq!(FellowshipCollective::rank_of(&member(index)), Some(rank));
+		}
+	});
+}
+
+#[test]
+fn fellowship_referenda_sending_xcm_are_moved_to_collectives() {
+	Kusama::execute_with(|| {
+		type Runtime = <Kusama as Chain>::Runtime;
+		type RuntimeCall = <Kusama as Chain>::RuntimeCall;
+		type RuntimeOrigin = <Kusama as Chain>::RuntimeOrigin;
+
+		set_up_relay_fellowship();
+		let proposer = member(2);
+		assert_ok!(Balances::mint_into(&proposer, KUSAMA_ED * 1_000));
+
+		let send = RuntimeCall::XcmPallet(pallet_xcm::Call::send {
+			dest: bx!(VersionedLocation::from(
+				Kusama::child_location_of(AssetHubKusama::para_id())
+			)),
+			message: bx!(VersionedXcm::from(Xcm::<()>(vec![ClearOrigin]))),
+		});
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![1] });
+		for call in [send, remark] {
+			assert_ok!(FellowshipReferenda::submit(
+				RuntimeOrigin::signed(proposer.clone()),
+				bx!(OriginCaller::Origins(Origin::Fellows)),
+				Preimage::bound(call).unwrap(),
+				DispatchTime::After(10),
+			));
+		}
+
+		MigrateFellowshipToCollectives::on_runtime_upgrade();
+
+		// The referendum sending a message moved, the remark stays frozen on the Relay Chain.
+		let status =
+			pallet_referenda::ReferendumInfoFor::<Runtime, FellowshipReferendaInstance>::get;
+		assert!(matches!(status(0), Some(ReferendumInfo::Cancelled(..))));
+		assert!(matches!(status(1), Some(ReferendumInfo::Ongoing(..))));
+	});
+
+	CollectivesKusama::execute_with(|| {
+		type Runtime = <CollectivesKusama as Chain>::Runtime;
+		type RuntimeEvent = <CollectivesKusama as Chain>::RuntimeEvent;
+
+		CollectivesKusama::assert_dmp_queue_complete(None);
+		assert_expected_events!(
+			CollectivesKusama,
+			vec![
+				RuntimeEvent::FellowshipReferenda(pallet_referenda::Event::Submitted { .. }) => {},
+			]
+		);
+		assert_eq!(
+			pallet_referenda::ReferendumCount::<Runtime, CollectivesReferendaInstance>::get(),
+			1
+		);
+	});
+}
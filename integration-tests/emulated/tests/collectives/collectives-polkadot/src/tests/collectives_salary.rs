@@ -15,11 +15,17 @@
 
 use crate::*;
 use asset_hub_polkadot_runtime::xcm_config::LocationToAccountId;
-use collectives_polkadot_runtime::fellowship::FellowshipSalaryPaymaster;
+use collectives_polkadot_runtime::{
+	dynamic_params::fellowship_salary,
+	parameters::{RuntimeParameters, SalaryAsset},
+	FellowshipCollective, FellowshipSalaryPayouts, SecretarySalaryPayouts,
+};
 use frame_support::{
 	assert_ok,
 	traits::{fungibles::Mutate, tokens::Pay},
 };
+use polkadot_runtime_common::impls::VersionedLocatableAsset;
+use sp_runtime::{FixedU128, Permill};
 use xcm_executor::traits::ConvertLocation;
 
 const FELLOWSHIP_SALARY_PALLET_ID: u8 =
@@ -46,7 +52,7 @@ fn pay_salary_technical_fellowship() {
 	CollectivesPolkadot::execute_with(|| {
 		type RuntimeEvent = <CollectivesPolkadot as Chain>::RuntimeEvent;
 
-		assert_ok!(FellowshipSalaryPaymaster::pay(&pay_to, (), pay_amount));
+		assert_ok!(FellowshipSalaryPayouts::pay(&pay_to, (), pay_amount));
 		assert_expected_events!(
 			CollectivesPolkadot,
 			vec![
@@ -67,10 +73,96 @@ fn pay_salary_technical_fellowship() {
 	});
 }
 
+#[test]
+fn pay_salary_technical_fellowship_split_in_usdt_and_dot() {
+	const USDT_ID: u32 = 1984;
+	let fellowship_salary = (
+		Parent,
+		Parachain(CollectivesPolkadot::para_id().into()),
+		PalletInstance(FELLOWSHIP_SALARY_PALLET_ID),
+	);
+	let pay_from = LocationToAccountId::convert_location(&fellowship_salary.into()).unwrap();
+	let pay_to = Polkadot::account_id_of(ALICE);
+	// 9,000 USDT.
+	let pay_amount = 9_000_000_000;
+	let asset_hub = Location::new(1, [Parachain(AssetHubPolkadot::para_id().into())]);
+	let usdt = VersionedLocatableAsset::V5 {
+		location: asset_hub.clone(),
+		asset_id: Location::new(0, [PalletInstance(50), GeneralIndex(USDT_ID.into())]).into(),
+	};
+	let dot = VersionedLocatableAsset::V5 { location: asset_hub, asset_id: Parent.into() };
+
+	AssetHubPolkadot::fund_accounts(vec![(pay_from.clone(), 10_000_000_000_000)]);
+	AssetHubPolkadot::execute_with(|| {
+		type AssetHubAssets = <AssetHubPolkadot as AssetHubPolkadotPallet>::Assets;
+		assert_ok!(<AssetHubAssets as Mutate<_>>::mint_into(USDT_ID, &pay_from, pay_amount));
+	});
+
+	CollectivesPolkadot::execute_with(|| {
+		type RuntimeEvent = <CollectivesPolkadot as Chain>::RuntimeEvent;
+		type RuntimeOrigin = <CollectivesPolkadot as Chain>::RuntimeOrigin;
+
+		// 1 USDT is worth 0.2 DOT: 1 USDT unit is worth 2,000 plancks.
+		assert_ok!(collectives_polkadot_runtime::AssetRate::create(
+			RuntimeOrigin::root(),
+			Box::new(usdt.clone()),
+			FixedU128::from_u32(2_000),
+		));
+		let assets = vec![
+			SalaryAsset { asset: Box::new(usdt.clone()), budget: 250_000_000_000 },
+			SalaryAsset { asset: Box::new(dot.clone()), budget: 10_000_000_000_000 },
+		];
+		assert_ok!(collectives_polkadot_runtime::Parameters::set_parameter(
+			RuntimeOrigin::root(),
+			RuntimeParameters::FellowshipSalary(fellowship_salary::Parameters::Assets(
+				fellowship_salary::Assets,
+				Some(assets.try_into().unwrap()),
+			)),
+		));
+
+		assert_ok!(FellowshipCollective::do_add_member_to_rank(pay_to.clone(), 1, false));
+		assert_ok!(FellowshipSalaryPayouts::set_preferences(
+			RuntimeOrigin::signed(pay_to.clone()),
+			vec![(usdt, Permill::from_percent(50)), (dot, Permill::from_percent(50))]
+				.try_into()
+				.unwrap(),
+		));
+
+		// 4,500 USDT and 900 DOT.
+		assert_ok!(FellowshipSalaryPayouts::pay(&pay_to, (), pay_amount));
+		assert_expected_events!(
+			CollectivesPolkadot,
+			vec![
+				RuntimeEvent::FellowshipSalaryPayouts(
+					pallet_salary_payouts::Event::LegPaid { amount, .. }
+				) => { amount: *amount == 4_500_000_000, },
+				RuntimeEvent::FellowshipSalaryPayouts(
+					pallet_salary_payouts::Event::LegPaid { amount, .. }
+				) => { amount: *amount == 9_000_000_000_000, },
+			]
+		);
+	});
+
+	AssetHubPolkadot::execute_with(|| {
+		type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::Balances(pallet_balances::Event::Transfer { amount, .. }) => {
+					amount: *amount == 9_000_000_000_000,
+				},
+				RuntimeEvent::Assets(pallet_assets::Event::Transferred { amount, .. }) => {
+					amount: *amount == 4_500_000_000,
+				},
+			]
+		);
+	});
+}
+
 #[test]
 fn pay_salary_secretary() {
 	const USDT_ID: u32 = 1984;
-	// SecretarySalary uses FellowshipSalaryPaymaster, so the pay_from account is derived
+	// SecretarySalary pays from the Fellowship salary account, so the pay_from account is derived
 	// from the fellowship salary pallet's interior location (pallet index 64).
 	let fellowship_salary = (
 		Parent,
@@ -90,7 +182,7 @@ fn pay_salary_secretary() {
 	CollectivesPolkadot::execute_with(|| {
 		type RuntimeEvent = <CollectivesPolkadot as Chain>::RuntimeEvent;
 
-		assert_ok!(FellowshipSalaryPaymaster::pay(&pay_to, (), pay_amount));
+		assert_ok!(SecretarySalaryPayouts::pay(&pay_to, (), pay_amount));
 		assert_expected_events!(
 			CollectivesPolkadot,
 			vec![
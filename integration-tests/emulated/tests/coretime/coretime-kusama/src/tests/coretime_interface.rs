@@ -112,6 +112,7 @@ fn broker_transacts_are_processed_by_relay() {
 	let mut found_core_assigned = false;
 	let mut found_history_dropped = false;
 	let mut found_relay_core_assigned = false;
+	let mut found_relay_chunked_assignment = false;
 	let mut relay_ump_processed = 0u32;
 	// `HistoryDropped` is the terminal event of the round-trip, so it implies all earlier
 	// broker/relay steps have already fired in prior iterations.
@@ -149,6 +150,13 @@ fn broker_transacts_are_processed_by_relay() {
 					RelayEvent::Coretime(runtime_parachains::coretime::Event::CoreAssigned {
 						..
 					}) => found_relay_core_assigned = true,
+					// The 80 interlaced assignments don't fit one message.
+					RelayEvent::ChunkedCoreAssignment(
+						pallet_chunked_core_assignment::Event::AssignmentCompleted {
+							assignments: 80,
+							..
+						},
+					) => found_relay_chunked_assignment = true,
 					_ => {},
 				}
 			}
@@ -170,4 +178,8 @@ fn broker_transacts_are_processed_by_relay() {
 		found_relay_core_assigned,
 		"relay never emitted `coretime::CoreAssigned` (assign_core dispatch failed)",
 	);
+	assert!(
+		found_relay_chunked_assignment,
+		"relay never received the 80 interlaced assignments of the reserved core",
+	);
 }
@@ -17,3 +17,4 @@ mod aliases;
 mod claim_assets;
 mod coretime_interface;
 mod teleport;
+mod xcm_fees;
@@ -0,0 +1,56 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use coretime_kusama_runtime::xcm_config::{StakingPot, XcmConfig};
+use integration_tests_helpers::{
+	test_asset_without_rate_cannot_pay, test_transact_can_pay_with_asset_with_rate,
+};
+use kusama_runtime_constants::system_parachain::ASSET_HUB_ID;
+
+/// The id of USDT in the trust backed assets pallet of Asset Hub.
+const USDT_ID: u128 = 1984;
+
+/// The id of USDC in the trust backed assets pallet of Asset Hub.
+const USDC_ID: u128 = 1337;
+
+/// A trust backed asset of Asset Hub.
+fn asset_hub_asset(id: u128) -> Location {
+	Location::new(1, [Parachain(ASSET_HUB_ID), PalletInstance(50), GeneralIndex(id)])
+}
+
+#[test]
+fn transact_from_asset_hub_can_pay_with_usdt_only() {
+	// 1 USDT (10^6) is worth 0.05 KSM (5 * 10^10).
+	test_transact_can_pay_with_asset_with_rate!(
+		CoretimeKusama,
+		AssetHubKusama,
+		XcmConfig,
+		StakingPot,
+		asset_hub_asset(USDT_ID),
+		50_000u128
+	);
+}
+
+#[test]
+fn assets_without_rate_cannot_pay() {
+	// USDC has no rate on this chain.
+	test_asset_without_rate_cannot_pay!(
+		CoretimeKusama,
+		AssetHubKusama,
+		XcmConfig,
+		asset_hub_asset(USDC_ID)
+	);
+}
@@ -17,4 +17,6 @@
 mod aliases;
 mod claim_assets;
 mod coretime_interface;
+mod purchase_from_asset_hub;
 mod teleport;
+mod xcm_fees;
@@ -0,0 +1,192 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use frame_support::traits::{fungible::Mutate, OnInitialize};
+use pallet_broker::ConfigRecord;
+use pallet_coretime_purchase::Order;
+use polkadot_runtime_constants::currency::UNITS;
+use sp_runtime::{traits::BlockNumberProvider, Perbill};
+
+const ASSET_ID: u32 = 4242;
+const PRICE_LIMIT: Balance = UNITS;
+
+/// Creates a pool of `ASSET_ID` and DOT on Asset Hub and returns the location of `ASSET_ID`.
+fn setup_pool() -> Location {
+	let asset_native = asset_hub_polkadot_runtime::xcm_config::DotLocation::get();
+	let asset = Location::new(0, [PalletInstance(50), GeneralIndex(ASSET_ID.into())]);
+	let sender = AssetHubPolkadotSender::get();
+
+	AssetHubPolkadot::execute_with(|| {
+		let origin = <AssetHubPolkadot as Chain>::RuntimeOrigin::signed(sender.clone());
+
+		<AssetHubPolkadot as AssetHubPolkadotPallet>::Balances::set_balance(
+			&sender,
+			10_000 * UNITS,
+		);
+		assert_ok!(<AssetHubPolkadot as AssetHubPolkadotPallet>::Assets::create(
+			origin.clone(),
+			ASSET_ID.into(),
+			sender.clone().into(),
+			1000,
+		));
+		assert_ok!(<AssetHubPolkadot as AssetHubPolkadotPallet>::Assets::mint(
+			origin.clone(),
+			ASSET_ID.into(),
+			sender.clone().into(),
+			10_000 * UNITS,
+		));
+		assert_ok!(<AssetHubPolkadot as AssetHubPolkadotPallet>::AssetConversion::create_pool(
+			origin.clone(),
+			bx!(asset_native.clone()),
+			bx!(asset.clone()),
+		));
+		assert_ok!(<AssetHubPolkadot as AssetHubPolkadotPallet>::AssetConversion::add_liquidity(
+			origin,
+			bx!(asset_native),
+			bx!(asset.clone()),
+			1_000 * UNITS,
+			2_000 * UNITS,
+			0,
+			0,
+			sender,
+		));
+	});
+
+	asset
+}
+
+/// Starts the sales on the Coretime Chain and waits until the first one is open.
+fn start_sales() {
+	type Runtime = <CoretimePolkadot as Chain>::Runtime;
+
+	CoretimePolkadot::execute_with(|| {
+		let root = <CoretimePolkadot as Chain>::RuntimeOrigin::root();
+		let config = ConfigRecord {
+			advance_notice: 2,
+			interlude_length: 1,
+			leadin_length: 1,
+			region_length: 1,
+			ideal_bulk_proportion: Perbill::from_percent(100),
+			limit_cores_offered: None,
+			renewal_bump: Perbill::from_percent(2),
+			contribution_timeout: 1,
+		};
+		assert_ok!(<CoretimePolkadot as CoretimePolkadotPallet>::Broker::configure(
+			root.clone(),
+			config
+		));
+		assert_ok!(<CoretimePolkadot as CoretimePolkadotPallet>::Broker::start_sales(root, 100, 1));
+	});
+
+	let mut open = false;
+	for _ in 0..100 {
+		CoretimePolkadot::execute_with(|| {
+			// Hooks don't run in emulated tests, so the broker is ticked manually.
+			<CoretimePolkadot as CoretimePolkadotPallet>::Broker::on_initialize(
+				<CoretimePolkadot as Chain>::System::block_number(),
+			);
+			let now = <Runtime as pallet_broker::Config>::RelayChainBlockNumberProvider::current_block_number();
+			open = pallet_broker::SaleInfo::<Runtime>::get()
+				.is_some_and(|sale| sale.sale_start <= now && sale.cores_sold < sale.cores_offered);
+		});
+		if open {
+			return
+		}
+	}
+	panic!("the sale never opened");
+}
+
+fn buy(asset: Location) {
+	type RuntimeEvent = <AssetHubPolkadot as Chain>::RuntimeEvent;
+
+	AssetHubPolkadot::execute_with(|| {
+		assert_ok!(<AssetHubPolkadot as AssetHubPolkadotPallet>::CoretimePurchase::buy(
+			<AssetHubPolkadot as Chain>::RuntimeOrigin::signed(AssetHubPolkadotSender::get()),
+			bx!(asset),
+			10 * UNITS,
+			Order::Purchase,
+			PRICE_LIMIT,
+		));
+
+		assert_expected_events!(
+			AssetHubPolkadot,
+			vec![
+				RuntimeEvent::AssetConversion(
+					pallet_asset_conversion::Event::SwapExecuted { .. }
+				) => {},
+				RuntimeEvent::CoretimePurchase(
+					pallet_coretime_purchase::Event::OrderSent { who, order, price_limit, .. }
+				) => {
+					who: *who == AssetHubPolkadotSender::get(),
+					order: *order == Order::Purchase,
+					price_limit: *price_limit == PRICE_LIMIT,
+				},
+			]
+		);
+	});
+}
+
+#[test]
+fn buy_coretime_with_asset_hub_assets() {
+	type CoretimeEvent = <CoretimePolkadot as Chain>::RuntimeEvent;
+	type Runtime = <CoretimePolkadot as Chain>::Runtime;
+
+	let asset = setup_pool();
+	start_sales();
+	buy(asset);
+
+	CoretimePolkadot::execute_with(|| {
+		let sender = AssetHubPolkadotSender::get();
+
+		assert_expected_events!(
+			CoretimePolkadot,
+			vec![
+				CoretimeEvent::Broker(pallet_broker::Event::Purchased { who, .. }) => {
+					who: *who == sender,
+				},
+			]
+		);
+		assert!(pallet_broker::Regions::<Runtime>::iter_values()
+			.any(|region| region.owner == Some(sender.clone())));
+	});
+}
+
+#[test]
+fn failed_purchase_is_refunded_on_asset_hub() {
+	let asset = setup_pool();
+	buy(asset);
+
+	let balance_after_order = AssetHubPolkadot::execute_with(|| {
+		<AssetHubPolkadot as AssetHubPolkadotPallet>::Balances::free_balance(
+			AssetHubPolkadotSender::get(),
+		)
+	});
+
+	// There are no sales, so the purchase fails on the Coretime Chain.
+	CoretimePolkadot::execute_with(|| {
+		assert!(pallet_broker::Regions::<<CoretimePolkadot as Chain>::Runtime>::iter_values()
+			.all(|region| region.owner != Some(AssetHubPolkadotSender::get())));
+	});
+
+	AssetHubPolkadot::execute_with(|| {
+		let balance = <AssetHubPolkadot as AssetHubPolkadotPallet>::Balances::free_balance(
+			AssetHubPolkadotSender::get(),
+		);
+		assert!(balance > balance_after_order);
+		assert!(balance < balance_after_order + PRICE_LIMIT);
+	});
+}
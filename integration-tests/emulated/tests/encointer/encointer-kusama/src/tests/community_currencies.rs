@@ -0,0 +1,322 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::*;
+use encointer_kusama_runtime::{
+	community_currencies::CommunityCurrencyConvert, BalanceType, CommunityIdentifier,
+};
+use frame_support::traits::fungibles::Inspect as FungiblesInspect;
+use kusama_system_emulated_network::asset_hub_kusama_emulated_chain::AssetHubKusamaParaPallet;
+use pallet_xcm::TransferType;
+use sp_runtime::traits::MaybeEquivalence;
+
+const ONE_KSM: u128 = 1_000_000_000_000;
+
+fn community() -> CommunityIdentifier {
+	CommunityIdentifier::default()
+}
+
+/// Location of the community currency on Encointer.
+fn community_currency() -> Location {
+	CommunityCurrencyConvert::convert_back(&community()).unwrap()
+}
+
+/// Location of the community currency as seen from Asset Hub.
+fn community_currency_on_ah() -> Location {
+	Location::new(1, [Parachain(EncointerKusama::para_id().into())])
+		.appended_with(community_currency().interior().clone())
+		.unwrap()
+}
+
+fn encointer_sovereign_on_ah() -> AccountId {
+	AssetHubKusama::sovereign_account_id_of(AssetHubKusama::sibling_location_of(
+		EncointerKusama::para_id(),
+	))
+}
+
+fn asset_hub_sovereign_on_encointer() -> AccountId {
+	EncointerKusama::sovereign_account_id_of(EncointerKusama::sibling_location_of(
+		AssetHubKusama::para_id(),
+	))
+}
+
+fn reserve_of_asset_hub() -> u128 {
+	pallet_community_reserves::Reserves::<encointer_kusama_runtime::Runtime>::get(
+		community(),
+		asset_hub_sovereign_on_encointer(),
+	)
+}
+
+fn community_balance(who: &AccountId) -> u128 {
+	<encointer_kusama_runtime::EncointerBalances as FungiblesInspect<_>>::balance(community(), who)
+}
+
+fn register_community_currency() {
+	AssetHubKusama::fund_accounts(vec![(encointer_sovereign_on_ah(), 10 * ONE_KSM)]);
+
+	<EncointerKusama as TestExt>::execute_with(|| {
+		type RuntimeEvent = <EncointerKusama as Chain>::RuntimeEvent;
+
+		assert_ok!(encointer_kusama_runtime::CommunityReserves::register_foreign_asset(
+			encointer_kusama_runtime::RuntimeOrigin::root(),
+			community(),
+		));
+		assert_expected_events!(
+			EncointerKusama,
+			vec![
+				RuntimeEvent::CommunityReserves(
+					pallet_community_reserves::Event::RegistrationSent { .. }
+				) => {},
+			]
+		);
+	});
+
+	<AssetHubKusama as TestExt>::execute_with(|| {
+		type RuntimeEvent = <AssetHubKusama as Chain>::RuntimeEvent;
+		type ForeignAssets = <AssetHubKusama as AssetHubKusamaParaPallet>::ForeignAssets;
+
+		assert_expected_events!(
+			AssetHubKusama,
+			vec![
+				RuntimeEvent::ForeignAssets(pallet_assets::Event::Created { asset_id, owner, .. }) => {
+					asset_id: *asset_id == community_currency_on_ah(),
+					owner: *owner == encointer_sovereign_on_ah(),
+				},
+				RuntimeEvent::MessageQueue(
+					pallet_message_queue::Event::Processed { success: true, .. }
+				) => {},
+			]
+		);
+		assert!(ForeignAssets::asset_exists(community_currency_on_ah()));
+	});
+}
+
+fn reserve_transfer_to_asset_hub(sender: AccountId, beneficiary: AccountId, amount: u128) {
+	<EncointerKusama as TestExt>::execute_with(|| {
+		// KSM is teleported to pay for the execution on Asset Hub.
+		assert_ok!(encointer_kusama_runtime::PolkadotXcm::transfer_assets_using_type_and_then(
+			encointer_kusama_runtime::RuntimeOrigin::signed(sender),
+			bx!(EncointerKusama::sibling_location_of(AssetHubKusama::para_id()).into()),
+			bx!(Assets::from(vec![
+				(Parent, ONE_KSM).into(),
+				(community_currency(), amount).into()
+			])
+			.into()),
+			bx!(TransferType::LocalReserve),
+			bx!(AssetId(Location::parent()).into()),
+			bx!(TransferType::Teleport),
+			bx!(VersionedXcm::from(Xcm::<()>(vec![DepositAsset {
+				assets: Wild(AllCounted(2)),
+				beneficiary: AccountId32Junction { network: None, id: beneficiary.into() }.into(),
+			}]))),
+			WeightLimit::Unlimited,
+		));
+	});
+}
+
+fn reserve_transfer_to_encointer(sender: AccountId, beneficiary: AccountId, amount: u128) {
+	<AssetHubKusama as TestExt>::execute_with(|| {
+		type PolkadotXcm = <AssetHubKusama as AssetHubKusamaParaPallet>::PolkadotXcm;
+
+		// KSM is teleported to pay for the execution on Encointer.
+		assert_ok!(PolkadotXcm::transfer_assets_using_type_and_then(
+			<AssetHubKusama as Chain>::RuntimeOrigin::signed(sender),
+			bx!(AssetHubKusama::sibling_location_of(EncointerKusama::para_id()).into()),
+			bx!(Assets::from(vec![
+				(Parent, ONE_KSM).into(),
+				(community_currency_on_ah(), amount).into()
+			])
+			.into()),
+			bx!(TransferType::DestinationReserve),
+			bx!(AssetId(Location::parent()).into()),
+			bx!(TransferType::Teleport),
+			bx!(VersionedXcm::from(Xcm::<()>(vec![DepositAsset {
+				assets: Wild(AllCounted(2)),
+				beneficiary: AccountId32Junction { network: None, id: beneficiary.into() }.into(),
+			}]))),
+			WeightLimit::Unlimited,
+		));
+	});
+}
+
+#[test]
+fn register_community_currency_on_asset_hub_works() {
+	register_community_currency();
+
+	<AssetHubKusama as TestExt>::execute_with(|| {
+		type ForeignAssets = <AssetHubKusama as AssetHubKusamaParaPallet>::ForeignAssets;
+
+		assert_eq!(
+			ForeignAssets::minimum_balance(community_currency_on_ah()),
+			1,
+			"community currencies without existential deposit get the smallest minimum balance"
+		);
+	});
+}
+
+#[test]
+fn reserve_transfer_community_currency_to_asset_hub_and_back_works() {
+	let sender = EncointerKusamaSender::get();
+	let on_ah = AssetHubKusamaReceiver::get();
+	let receiver = EncointerKusamaReceiver::get();
+	register_community_currency();
+
+	<EncointerKusama as TestExt>::execute_with(|| {
+		assert_ok!(encointer_kusama_runtime::EncointerBalances::issue(
+			community(),
+			&sender,
+			BalanceType::from_num(100)
+		));
+	});
+	let amount = <EncointerKusama as TestExt>::execute_with(|| community_balance(&sender)) / 2;
+	AssetHubKusama::fund_accounts(vec![(on_ah.clone(), 10 * ONE_KSM)]);
+
+	reserve_transfer_to_asset_hub(sender, on_ah.clone(), amount);
+
+	<EncointerKusama as TestExt>::execute_with(|| {
+		assert_eq!(community_balance(&asset_hub_sovereign_on_encointer()), amount);
+		assert_eq!(reserve_of_asset_hub(), amount);
+	});
+	<AssetHubKusama as TestExt>::execute_with(|| {
+		type ForeignAssets = <AssetHubKusama as AssetHubKusamaParaPallet>::ForeignAssets;
+
+		assert_eq!(ForeignAssets::balance(community_currency_on_ah(), &on_ah), amount);
+	});
+
+	reserve_transfer_to_encointer(on_ah.clone(), receiver.clone(), amount);
+
+	<AssetHubKusama as TestExt>::execute_with(|| {
+		type ForeignAssets = <AssetHubKusama as AssetHubKusamaParaPallet>::ForeignAssets;
+
+		assert_eq!(ForeignAssets::balance(community_currency_on_ah(), &on_ah), 0);
+	});
+	<EncointerKusama as TestExt>::execute_with(|| {
+		assert_eq!(community_balance(&receiver), amount);
+		assert_eq!(reserve_of_asset_hub(), 0);
+	});
+}
+
+/// Transfers half of the community currency of the sender to Asset Hub and lets demurrage eat into
+/// the holdings of Asset Hub, returning the amount transferred.
+fn transfer_to_asset_hub_and_let_demurrage_act(sender: AccountId, on_ah: AccountId) -> u128 {
+	register_community_currency();
+
+	<EncointerKusama as TestExt>::execute_with(|| {
+		assert_ok!(encointer_kusama_runtime::EncointerBalances::issue(
+			community(),
+			&sender,
+			BalanceType::from_num(100)
+		));
+	});
+	let amount = <EncointerKusama as TestExt>::execute_with(|| community_balance(&sender)) / 2;
+	AssetHubKusama::fund_accounts(vec![(on_ah.clone(), 10 * ONE_KSM)]);
+
+	reserve_transfer_to_asset_hub(sender, on_ah, amount);
+
+	<EncointerKusama as TestExt>::execute_with(|| {
+		let now = frame_system::Pallet::<encointer_kusama_runtime::Runtime>::block_number();
+		frame_system::Pallet::<encointer_kusama_runtime::Runtime>::set_block_number(now + 100_000);
+		assert!(community_balance(&asset_hub_sovereign_on_encointer()) < amount);
+	});
+
+	amount
+}
+
+#[test]
+fn demurrage_on_the_reserve_is_compensated_by_the_community_treasury() {
+	let on_ah = AssetHubKusamaReceiver::get();
+	let receiver = EncointerKusamaReceiver::get();
+	let amount =
+		transfer_to_asset_hub_and_let_demurrage_act(EncointerKusamaSender::get(), on_ah.clone());
+
+	let treasury = <EncointerKusama as TestExt>::execute_with(|| {
+		let treasury =
+			encointer_kusama_runtime::EncointerTreasuries::get_community_treasury_account_unchecked(
+				Some(community()),
+			);
+		assert_ok!(encointer_kusama_runtime::EncointerBalances::issue(
+			community(),
+			&treasury,
+			BalanceType::from_num(100)
+		));
+		assert_ok!(encointer_kusama_runtime::CommunityReserves::set_compensation(
+			encointer_kusama_runtime::RuntimeOrigin::root(),
+			community(),
+			true,
+		));
+		treasury
+	});
+	let funds = <EncointerKusama as TestExt>::execute_with(|| community_balance(&treasury));
+	let issuance = <EncointerKusama as TestExt>::execute_with(|| {
+		<encointer_kusama_runtime::EncointerBalances as FungiblesInspect<_>>::total_issuance(
+			community(),
+		)
+	});
+
+	reserve_transfer_to_encointer(on_ah, receiver.clone(), amount);
+
+	<EncointerKusama as TestExt>::execute_with(|| {
+		type RuntimeEvent = <EncointerKusama as Chain>::RuntimeEvent;
+
+		assert_expected_events!(
+			EncointerKusama,
+			vec![
+				RuntimeEvent::CommunityReserves(
+					pallet_community_reserves::Event::DemurrageCompensated { asset, .. }
+				) => {
+					asset: *asset == community(),
+				},
+			]
+		);
+		// The holder on Asset Hub gets back the nominal amount, paid for by the treasury.
+		assert_eq!(community_balance(&receiver), amount);
+		assert!(community_balance(&treasury) < funds);
+		assert!(
+			<encointer_kusama_runtime::EncointerBalances as FungiblesInspect<_>>::total_issuance(
+				community()
+			) <= issuance
+		);
+	});
+}
+
+#[test]
+fn uncompensated_demurrage_reduces_the_reserve() {
+	let on_ah = AssetHubKusamaReceiver::get();
+	let receiver = EncointerKusamaReceiver::get();
+	let amount =
+		transfer_to_asset_hub_and_let_demurrage_act(EncointerKusamaSender::get(), on_ah.clone());
+
+	reserve_transfer_to_encointer(on_ah, receiver.clone(), amount / 2);
+
+	<EncointerKusama as TestExt>::execute_with(|| {
+		type RuntimeEvent = <EncointerKusama as Chain>::RuntimeEvent;
+
+		assert_expected_events!(
+			EncointerKusama,
+			vec![
+				RuntimeEvent::CommunityReserves(
+					pallet_community_reserves::Event::DemurrageIncurred { asset, .. }
+				) => {
+					asset: *asset == community(),
+				},
+			]
+		);
+		assert_eq!(community_balance(&receiver), amount / 2);
+		// What is left to redeem is what is left in the reserve.
+		assert_eq!(reserve_of_asset_hub(), community_balance(&asset_hub_sovereign_on_encointer()));
+		assert!(reserve_of_asset_hub() < amount - amount / 2);
+	});
+}
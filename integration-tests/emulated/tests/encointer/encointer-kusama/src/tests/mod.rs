@@ -13,5 +13,6 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod community_currencies;
 mod remote_treasury_payout;
 mod teleport;
@@ -19,3 +19,4 @@ mod governance;
 mod identity;
 mod reserve_transfers;
 mod teleport;
+mod xcm_fees;
@@ -0,0 +1,25 @@
+// Copyright (C) Parity Technologies and the various Kusama contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::imports::*;
+
+integration_tests_helpers::impl_async_staking_helpers!(
+	relay: Kusama,
+	relay_runtime: kusama_runtime,
+	asset_hub: AssetHubKusama,
+	asset_hub_runtime: asset_hub_kusama_runtime,
+	units: kusama_runtime_constants::currency::UNITS,
+	session_millis: 60 * 60 * 1000,
+);
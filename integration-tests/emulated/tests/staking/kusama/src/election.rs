@@ -0,0 +1,30 @@
+// Copyright (C) Parity Technologies and the various Kusama contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{common::*, imports::*};
+use integration_tests_helpers::{
+	test_asset_hub_election_becomes_the_relay_validator_set,
+	test_relay_validator_set_follows_later_elections,
+};
+
+#[test]
+fn asset_hub_election_becomes_the_relay_validator_set() {
+	test_asset_hub_election_becomes_the_relay_validator_set!(Kusama, AssetHubKusama);
+}
+
+#[test]
+fn relay_validator_set_follows_later_elections() {
+	test_relay_validator_set_follows_later_elections!(AssetHubKusama);
+}
@@ -0,0 +1,41 @@
+// Copyright (C) Parity Technologies and the various Kusama contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! End-to-end tests of async staking between the Kusama relay chain and Asset Hub.
+//!
+//! The tests are shared with the other relay chains, see `integration_tests_helpers::staking`.
+
+#[cfg(test)]
+mod imports {
+	pub(crate) use kusama_staking_emulated_network::{
+		AssetHubKusamaPara as AssetHubKusama, AssetHubKusamaParaSender as AssetHubKusamaSender,
+		KusamaRelay as Kusama,
+	};
+}
+
+#[cfg(test)]
+mod common;
+
+#[cfg(test)]
+mod election;
+
+#[cfg(test)]
+mod offences;
+
+#[cfg(test)]
+mod rewards;
+
+#[cfg(test)]
+mod session_keys;
@@ -0,0 +1,30 @@
+// Copyright (C) Parity Technologies and the various Kusama contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{common::*, imports::*};
+use integration_tests_helpers::{
+	test_session_keys_purged_on_asset_hub_leave_the_relay,
+	test_session_keys_set_on_asset_hub_reach_the_relay,
+};
+
+#[test]
+fn session_keys_set_on_asset_hub_reach_the_relay() {
+	test_session_keys_set_on_asset_hub_reach_the_relay!(Kusama, AssetHubKusama);
+}
+
+#[test]
+fn session_keys_purged_on_asset_hub_leave_the_relay() {
+	test_session_keys_purged_on_asset_hub_leave_the_relay!(Kusama, AssetHubKusama);
+}
@@ -0,0 +1,30 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use crate::{common::*, imports::*};
+use integration_tests_helpers::{
+	test_offences_of_unknown_validators_are_not_slashed,
+	test_relay_offences_are_slashed_on_asset_hub,
+};
+
+#[test]
+fn relay_offences_are_slashed_on_asset_hub() {
+	test_relay_offences_are_slashed_on_asset_hub!(AssetHubPolkadot, asset_hub_polkadot_runtime);
+}
+
+#[test]
+fn offences_of_unknown_validators_are_not_slashed() {
+	test_offences_of_unknown_validators_are_not_slashed!(AssetHubPolkadot);
+}
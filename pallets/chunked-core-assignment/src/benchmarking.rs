@@ -0,0 +1,141 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Benchmarks for the Chunked Core Assignment Pallet
+
+use super::*;
+use crate::Pallet as ChunkedCoreAssignment;
+use alloc::vec::Vec;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::Get, BoundedVec};
+use frame_system::pallet_prelude::BlockNumberFor;
+
+/// `count` assignments of different tasks, starting at task `first`.
+///
+/// Each task gets an equal share of a core with [`Config::MaxAssignments`] tasks, the last one of
+/// them also gets the rest.
+fn assignments<T: Config, S: Get<u32>>(
+	first: u32,
+	count: u32,
+) -> BoundedVec<(CoreAssignment, PartsOf57600), S> {
+	let max = T::MaxAssignments::get();
+	let parts = (57_600 / max) as PartsOf57600;
+	let assignments = (first..first + count)
+		.map(|task| {
+			let parts = if task + 1 == max { 57_600 - parts * (max as u16 - 1) } else { parts };
+			(CoreAssignment::Task(task), parts)
+		})
+		.collect::<Vec<_>>();
+	BoundedVec::try_from(assignments).expect("at most the bound; qed")
+}
+
+/// Start the updates of the first `count` cores, each with `received` assignments.
+fn start_updates<T: Config>(count: u32, received: u32, begin: BlockNumberFor<T>) {
+	let mut deadlines = Deadlines::<T>::get();
+	for core in 0..count {
+		let core = core as CoreIndex;
+		Pending::<T>::insert(
+			core,
+			PendingAssignment {
+				begin,
+				end_hint: None,
+				assignment: assignments::<T, T::MaxAssignments>(0, received),
+			},
+		);
+		deadlines.try_push((begin, core)).expect("at most `MaxPendingCores`; qed");
+	}
+	Deadlines::<T>::put(deadlines);
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	// The worst case replaces an update with all assignments among the most pending cores.
+	#[benchmark]
+	fn begin_assign_core() -> Result<(), BenchmarkError> {
+		let origin =
+			T::BrokerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let begin: BlockNumberFor<T> = 10u32.into();
+		start_updates::<T>(T::MaxPendingCores::get(), T::MaxAssignments::get(), begin);
+		let chunk = assignments::<T, T::MaxChunkAssignments>(0, T::MaxChunkAssignments::get());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, begin + 1u32.into(), chunk, None);
+
+		assert_eq!(Pending::<T>::get(0).map(|p| p.begin), Some(begin + 1u32.into()));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn continue_assign_core() -> Result<(), BenchmarkError> {
+		let origin =
+			T::BrokerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let begin: BlockNumberFor<T> = 10u32.into();
+		let chunk_size = T::MaxChunkAssignments::get();
+		let received = T::MaxAssignments::get().saturating_sub(chunk_size);
+		start_updates::<T>(1, received, begin);
+		let chunk = assignments::<T, T::MaxChunkAssignments>(received, chunk_size);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, begin, chunk);
+
+		assert_eq!(
+			Pending::<T>::get(0).map(|p| p.assignment.len() as u32),
+			Some(T::MaxAssignments::get())
+		);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn end_assign_core() -> Result<(), BenchmarkError> {
+		let origin =
+			T::BrokerOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let begin: BlockNumberFor<T> = 10u32.into();
+		let chunk_size = T::MaxChunkAssignments::get();
+		let received = T::MaxAssignments::get().saturating_sub(chunk_size);
+		start_updates::<T>(T::MaxPendingCores::get(), received, begin);
+		let chunk = assignments::<T, T::MaxChunkAssignments>(received, chunk_size);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, begin, chunk);
+
+		assert!(!Pending::<T>::contains_key(0));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn drop_incomplete() {
+		let begin: BlockNumberFor<T> = 10u32.into();
+		start_updates::<T>(1, T::MaxAssignments::get(), begin);
+
+		#[block]
+		{
+			ChunkedCoreAssignment::<T>::drop_incomplete(0, begin);
+		}
+
+		assert!(!Pending::<T>::contains_key(0));
+	}
+
+	impl_benchmark_test_suite!(
+		ChunkedCoreAssignment,
+		crate::tests::new_test_ext(),
+		crate::tests::Test
+	);
+}
@@ -0,0 +1,316 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Chunked core assignment pallet
+//!
+//! The Coretime Chain assigns the cores of the relay chain with `coretime::assign_core`, which
+//! takes the complete assignment of a core in one call. With interlaced regions a core can be
+//! split between many tasks, and a call with all of them would not fit the weight and size limits
+//! of a single message. This pallet lets the Coretime Chain send such an assignment in chunks:
+//!
+//! - [`Pallet::begin_assign_core`] starts the update of a core with the first chunk,
+//! - [`Pallet::continue_assign_core`] adds further chunks,
+//! - [`Pallet::end_assign_core`] adds the last chunk and hands the complete assignment to
+//!   [`Config::AssignCore`], which activates all of it at once at `begin`.
+//!
+//! The chunks of an update must all arrive before its `begin` block. An update which is not
+//! ended by then, or which is replaced by a new update of the same core, is dropped with
+//! [`Event::AssignmentIncomplete`] and the core keeps its previous assignment.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use alloc::vec::Vec;
+use frame_support::{dispatch::DispatchResult, weights::Weight};
+pub use pallet_broker::{CoreAssignment, CoreIndex, PartsOf57600};
+
+pub use pallet::*;
+pub use weight::WeightInfo;
+
+/// Assigns a core, like `coretime::assign_core` does.
+pub trait AssignCore<BlockNumber> {
+	/// Assign `core` to `assignment` from `begin` on.
+	fn assign_core(
+		core: CoreIndex,
+		begin: BlockNumber,
+		assignment: Vec<(CoreAssignment, PartsOf57600)>,
+		end_hint: Option<BlockNumber>,
+	) -> DispatchResult;
+
+	/// The weight of [`Self::assign_core`] with `assignments` entries.
+	fn assign_core_weight(assignments: u32) -> Weight;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// An update of the assignment of a core which is not ended yet.
+	#[derive(
+		Encode,
+		Decode,
+		MaxEncodedLen,
+		TypeInfo,
+		CloneNoBound,
+		PartialEqNoBound,
+		EqNoBound,
+		RuntimeDebugNoBound,
+	)]
+	#[scale_info(skip_type_params(T))]
+	pub struct PendingAssignment<T: Config> {
+		/// The block from which the assignment applies.
+		pub begin: BlockNumberFor<T>,
+		/// The block at which the assignment is expected to end.
+		pub end_hint: Option<BlockNumberFor<T>>,
+		/// The assignment received so far.
+		pub assignment: BoundedVec<(CoreAssignment, PartsOf57600), T::MaxAssignments>,
+	}
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+		/// The origin of the Coretime Chain.
+		type BrokerOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// Applies the complete assignments.
+		type AssignCore: AssignCore<BlockNumberFor<Self>>;
+
+		/// The maximum number of assignments in one chunk.
+		#[pallet::constant]
+		type MaxChunkAssignments: Get<u32>;
+
+		/// The maximum number of assignments of a core.
+		#[pallet::constant]
+		type MaxAssignments: Get<u32>;
+
+		/// The maximum number of cores being updated at the same time.
+		#[pallet::constant]
+		type MaxPendingCores: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The updates which are not ended yet.
+	impl<T: Config> PendingAssignment<T> {
+		/// Add `chunk` to the assignment.
+		fn append(
+			&mut self,
+			chunk: BoundedVec<(CoreAssignment, PartsOf57600), T::MaxChunkAssignments>,
+		) -> DispatchResult {
+			let mut assignment = core::mem::take(&mut self.assignment).into_inner();
+			assignment.extend(chunk);
+			self.assignment =
+				BoundedVec::try_from(assignment).map_err(|_| Error::<T>::TooManyAssignments)?;
+			Ok(())
+		}
+	}
+
+	#[pallet::storage]
+	pub type Pending<T: Config> =
+		StorageMap<_, Twox64Concat, CoreIndex, PendingAssignment<T>, OptionQuery>;
+
+	/// The `begin` of the [`Pending`] updates with their core, sorted by `begin`.
+	#[pallet::storage]
+	pub type Deadlines<T: Config> =
+		StorageValue<_, BoundedVec<(BlockNumberFor<T>, CoreIndex), T::MaxPendingCores>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The update of the assignment of a core was started.
+		AssignmentStarted { core: CoreIndex, begin: BlockNumberFor<T> },
+		/// The assignment of a core was received completely and is active from `begin` on.
+		AssignmentCompleted { core: CoreIndex, begin: BlockNumberFor<T>, assignments: u32 },
+		/// The update of the assignment of a core was dropped before it was ended. The core keeps
+		/// its previous assignment.
+		AssignmentIncomplete { core: CoreIndex, begin: BlockNumberFor<T>, received: u32 },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The `begin` block of the update is already reached.
+		TooLate,
+		/// No update of the core with this `begin` was started.
+		NotStarted,
+		/// The assignment of the core has more than [`Config::MaxAssignments`] entries.
+		TooManyAssignments,
+		/// More than [`Config::MaxPendingCores`] cores are being updated.
+		TooManyPendingCores,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut expired = Deadlines::<T>::get().into_inner();
+			let count = expired.iter().take_while(|(begin, _)| *begin <= now).count();
+			if count == 0 {
+				return T::DbWeight::get().reads(1);
+			}
+
+			let rest = expired.split_off(count);
+			for (begin, core) in expired {
+				Self::drop_incomplete(core, begin);
+			}
+			Deadlines::<T>::put(BoundedVec::truncate_from(rest));
+
+			T::DbWeight::get()
+				.reads_writes(1, 1)
+				.saturating_add(T::WeightInfo::drop_incomplete().saturating_mul(count as u64))
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Start an update of the assignment of a core.
+		///
+		/// The dispatch origin for this call must be [`Config::BrokerOrigin`].
+		///
+		/// An update of the core which is not ended yet is dropped.
+		///
+		/// Parameters:
+		/// - `core`: The core to assign.
+		/// - `begin`: The block from which the assignment applies.
+		/// - `assignment`: The first chunk of the assignment.
+		/// - `end_hint`: The block at which the assignment is expected to end.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::begin_assign_core())]
+		pub fn begin_assign_core(
+			origin: OriginFor<T>,
+			core: CoreIndex,
+			begin: BlockNumberFor<T>,
+			assignment: BoundedVec<(CoreAssignment, PartsOf57600), T::MaxChunkAssignments>,
+			end_hint: Option<BlockNumberFor<T>>,
+		) -> DispatchResult {
+			T::BrokerOrigin::ensure_origin(origin)?;
+			ensure!(frame_system::Pallet::<T>::block_number() < begin, Error::<T>::TooLate);
+
+			let mut deadlines = Deadlines::<T>::get();
+			if let Some(previous) = Pending::<T>::get(core) {
+				deadlines.retain(|(_, c)| *c != core);
+				Self::drop_incomplete(core, previous.begin);
+			}
+			let position = deadlines.partition_point(|(b, _)| *b <= begin);
+			deadlines
+				.try_insert(position, (begin, core))
+				.map_err(|_| Error::<T>::TooManyPendingCores)?;
+			Deadlines::<T>::put(deadlines);
+
+			let assignment = BoundedVec::try_from(assignment.into_inner())
+				.map_err(|_| Error::<T>::TooManyAssignments)?;
+			Pending::<T>::insert(core, PendingAssignment { begin, end_hint, assignment });
+
+			Self::deposit_event(Event::AssignmentStarted { core, begin });
+			Ok(())
+		}
+
+		/// Add a chunk to an update of the assignment of a core.
+		///
+		/// The dispatch origin for this call must be [`Config::BrokerOrigin`].
+		///
+		/// Parameters:
+		/// - `core`: The core to assign.
+		/// - `begin`: The block from which the assignment applies.
+		/// - `assignment`: The next chunk of the assignment.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::continue_assign_core())]
+		pub fn continue_assign_core(
+			origin: OriginFor<T>,
+			core: CoreIndex,
+			begin: BlockNumberFor<T>,
+			assignment: BoundedVec<(CoreAssignment, PartsOf57600), T::MaxChunkAssignments>,
+		) -> DispatchResult {
+			T::BrokerOrigin::ensure_origin(origin)?;
+
+			Pending::<T>::try_mutate(core, |pending| -> DispatchResult {
+				let pending =
+					pending.as_mut().filter(|p| p.begin == begin).ok_or(Error::<T>::NotStarted)?;
+				pending.append(assignment)
+			})
+		}
+
+		/// Add the last chunk to an update of the assignment of a core and apply it.
+		///
+		/// The dispatch origin for this call must be [`Config::BrokerOrigin`].
+		///
+		/// Parameters:
+		/// - `core`: The core to assign.
+		/// - `begin`: The block from which the assignment applies.
+		/// - `assignment`: The last chunk of the assignment.
+		#[pallet::call_index(2)]
+		#[pallet::weight(
+			T::WeightInfo::end_assign_core()
+				.saturating_add(T::AssignCore::assign_core_weight(T::MaxAssignments::get()))
+		)]
+		pub fn end_assign_core(
+			origin: OriginFor<T>,
+			core: CoreIndex,
+			begin: BlockNumberFor<T>,
+			assignment: BoundedVec<(CoreAssignment, PartsOf57600), T::MaxChunkAssignments>,
+		) -> DispatchResult {
+			T::BrokerOrigin::ensure_origin(origin)?;
+
+			let mut pending = Pending::<T>::get(core)
+				.filter(|p| p.begin == begin)
+				.ok_or(Error::<T>::NotStarted)?;
+			pending.append(assignment)?;
+
+			Pending::<T>::remove(core);
+			Deadlines::<T>::mutate(|deadlines| deadlines.retain(|(_, c)| *c != core));
+
+			let assignments = pending.assignment.len() as u32;
+			T::AssignCore::assign_core(
+				core,
+				begin,
+				pending.assignment.into_inner(),
+				pending.end_hint,
+			)?;
+
+			Self::deposit_event(Event::AssignmentCompleted { core, begin, assignments });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Drop the update of `core`, which was not ended before `begin`.
+		///
+		/// The caller removes the deadline.
+		pub(crate) fn drop_incomplete(core: CoreIndex, begin: BlockNumberFor<T>) {
+			let Some(pending) = Pending::<T>::take(core) else { return };
+			log::warn!(
+				target: "runtime::chunked-core-assignment",
+				"Update of core {core} at {begin:?} incomplete, dropping it"
+			);
+			Self::deposit_event(Event::AssignmentIncomplete {
+				core,
+				begin,
+				received: pending.assignment.len() as u32,
+			});
+		}
+	}
+}
@@ -0,0 +1,246 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for the Chunked Core Assignment Pallet
+
+use super::*;
+use crate as chunked_core_assignment;
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, derive_impl, parameter_types,
+	traits::{ConstU32, Hooks},
+	BoundedVec,
+};
+use frame_system::EnsureRoot;
+use sp_io::TestExternalities;
+use sp_runtime::{BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+type Assignment = Vec<(CoreAssignment, PartsOf57600)>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		ChunkedCoreAssignment: chunked_core_assignment,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+parameter_types! {
+	pub static Assigned: Vec<(CoreIndex, u64, Assignment, Option<u64>)> = vec![];
+}
+
+/// Records the assignments instead of applying them.
+pub struct RecordAssignments;
+impl AssignCore<u64> for RecordAssignments {
+	fn assign_core(
+		core: CoreIndex,
+		begin: u64,
+		assignment: Assignment,
+		end_hint: Option<u64>,
+	) -> DispatchResult {
+		Assigned::mutate(|assigned| assigned.push((core, begin, assignment, end_hint)));
+		Ok(())
+	}
+
+	fn assign_core_weight(_: u32) -> Weight {
+		Weight::zero()
+	}
+}
+
+impl Config for Test {
+	type BrokerOrigin = EnsureRoot<u64>;
+	type AssignCore = RecordAssignments;
+	type MaxChunkAssignments = ConstU32<28>;
+	type MaxAssignments = ConstU32<81>;
+	type MaxPendingCores = ConstU32<2>;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// A core interlaced between `count` tasks.
+fn interlaced(count: u32) -> Assignment {
+	(0..count)
+		.map(|i| (CoreAssignment::Task(2_000 + i), (57_600 / count) as PartsOf57600))
+		.collect()
+}
+
+fn chunk(
+	assignment: &[(CoreAssignment, PartsOf57600)],
+) -> BoundedVec<(CoreAssignment, PartsOf57600), ConstU32<28>> {
+	BoundedVec::try_from(assignment.to_vec()).unwrap()
+}
+
+fn begin(
+	core: CoreIndex,
+	begin: u64,
+	assignment: &[(CoreAssignment, PartsOf57600)],
+) -> DispatchResult {
+	ChunkedCoreAssignment::begin_assign_core(
+		RuntimeOrigin::root(),
+		core,
+		begin,
+		chunk(assignment),
+		Some(begin + 10),
+	)
+}
+
+fn add(
+	core: CoreIndex,
+	begin: u64,
+	assignment: &[(CoreAssignment, PartsOf57600)],
+) -> DispatchResult {
+	ChunkedCoreAssignment::continue_assign_core(
+		RuntimeOrigin::root(),
+		core,
+		begin,
+		chunk(assignment),
+	)
+}
+
+fn end(
+	core: CoreIndex,
+	begin: u64,
+	assignment: &[(CoreAssignment, PartsOf57600)],
+) -> DispatchResult {
+	ChunkedCoreAssignment::end_assign_core(RuntimeOrigin::root(), core, begin, chunk(assignment))
+}
+
+#[test]
+fn chunked_assignment_is_applied_at_once() {
+	new_test_ext().execute_with(|| {
+		let assignment = interlaced(80);
+
+		assert_ok!(begin(1, 10, &assignment[..28]));
+		System::assert_last_event(Event::AssignmentStarted { core: 1, begin: 10 }.into());
+		assert_ok!(add(1, 10, &assignment[28..56]));
+		assert!(Assigned::get().is_empty());
+
+		assert_ok!(end(1, 10, &assignment[56..]));
+		System::assert_last_event(
+			Event::AssignmentCompleted { core: 1, begin: 10, assignments: 80 }.into(),
+		);
+		assert_eq!(Assigned::get(), vec![(1, 10, assignment, Some(20))]);
+		assert!(!Pending::<Test>::contains_key(1));
+		assert!(Deadlines::<Test>::get().is_empty());
+
+		// The update is over.
+		assert_noop!(add(1, 10, &interlaced(1)), Error::<Test>::NotStarted);
+	});
+}
+
+#[test]
+fn cores_are_updated_independently() {
+	new_test_ext().execute_with(|| {
+		let first = interlaced(80);
+		let second = interlaced(60);
+
+		assert_ok!(begin(1, 10, &first[..28]));
+		assert_ok!(begin(2, 10, &second[..28]));
+		assert_ok!(add(1, 10, &first[28..56]));
+		assert_ok!(add(2, 10, &second[28..56]));
+		assert_ok!(end(2, 10, &second[56..]));
+		assert_ok!(end(1, 10, &first[56..]));
+
+		assert_eq!(Assigned::get(), vec![(2, 10, second, Some(20)), (1, 10, first, Some(20))]);
+	});
+}
+
+#[test]
+fn incomplete_updates_are_dropped_at_begin() {
+	new_test_ext().execute_with(|| {
+		let assignment = interlaced(80);
+		assert_ok!(begin(1, 12, &interlaced(2)));
+		assert_ok!(begin(2, 10, &assignment[..28]));
+		assert_ok!(add(2, 10, &assignment[28..56]));
+		assert_eq!(Deadlines::<Test>::get().into_inner(), vec![(10, 2), (12, 1)]);
+
+		ChunkedCoreAssignment::on_initialize(9);
+		assert!(Pending::<Test>::contains_key(2));
+
+		System::set_block_number(10);
+		ChunkedCoreAssignment::on_initialize(10);
+		System::assert_last_event(
+			Event::AssignmentIncomplete { core: 2, begin: 10, received: 56 }.into(),
+		);
+		assert!(!Pending::<Test>::contains_key(2));
+		assert_eq!(Deadlines::<Test>::get().into_inner(), vec![(12, 1)]);
+
+		// The last chunk is too late.
+		assert_noop!(end(2, 10, &assignment[56..]), Error::<Test>::NotStarted);
+		assert_noop!(begin(2, 10, &assignment[..28]), Error::<Test>::TooLate);
+		assert!(Assigned::get().is_empty());
+
+		// The other core can still be assigned.
+		assert_ok!(end(1, 12, &[]));
+		assert_eq!(Assigned::get(), vec![(1, 12, interlaced(2), Some(22))]);
+	});
+}
+
+#[test]
+fn new_update_replaces_unfinished_one() {
+	new_test_ext().execute_with(|| {
+		let assignment = interlaced(80);
+		assert_ok!(begin(1, 10, &assignment[..28]));
+
+		assert_ok!(begin(1, 12, &assignment[..28]));
+		System::assert_has_event(
+			Event::AssignmentIncomplete { core: 1, begin: 10, received: 28 }.into(),
+		);
+		assert_eq!(Deadlines::<Test>::get().into_inner(), vec![(12, 1)]);
+
+		// Chunks of the replaced update are rejected.
+		assert_noop!(add(1, 10, &assignment[28..56]), Error::<Test>::NotStarted);
+		assert_ok!(add(1, 12, &assignment[28..56]));
+		assert_ok!(end(1, 12, &assignment[56..]));
+		assert_eq!(Assigned::get(), vec![(1, 12, assignment, Some(22))]);
+	});
+}
+
+#[test]
+fn updates_are_bounded() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ChunkedCoreAssignment::begin_assign_core(
+				RuntimeOrigin::signed(1),
+				1,
+				10,
+				chunk(&interlaced(1)),
+				None
+			),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(begin(1, 1, &interlaced(1)), Error::<Test>::TooLate);
+
+		let assignment = interlaced(84);
+		assert_ok!(begin(1, 10, &assignment[..28]));
+		assert_ok!(add(1, 10, &assignment[28..56]));
+		assert_noop!(add(1, 10, &assignment[56..]), Error::<Test>::TooManyAssignments);
+		assert_noop!(end(1, 10, &assignment[56..]), Error::<Test>::TooManyAssignments);
+
+		assert_ok!(begin(2, 10, &assignment[..28]));
+		assert_noop!(begin(3, 10, &assignment[..28]), Error::<Test>::TooManyPendingCores);
+	});
+}
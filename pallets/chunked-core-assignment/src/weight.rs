@@ -0,0 +1,40 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::weights::Weight;
+
+/// Weight functions needed for `pallet_chunked_core_assignment`.
+pub trait WeightInfo {
+	fn begin_assign_core() -> Weight;
+	fn continue_assign_core() -> Weight;
+	fn end_assign_core() -> Weight;
+	fn drop_incomplete() -> Weight;
+}
+
+impl WeightInfo for () {
+	fn begin_assign_core() -> Weight {
+		Weight::MAX
+	}
+	fn continue_assign_core() -> Weight {
+		Weight::MAX
+	}
+	fn end_assign_core() -> Weight {
+		Weight::MAX
+	}
+	fn drop_incomplete() -> Weight {
+		Weight::MAX
+	}
+}
@@ -0,0 +1,391 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+//! Community reserves pallet
+//!
+//! Community currencies, like the ones of Encointer, are subject to demurrage: every balance
+//! shrinks over time. When such a currency is reserve transferred to another chain, the reserve
+//! chain keeps the backing in the sovereign account of the destination, where it shrinks like any
+//! other balance. The derivative minted on the destination however is a plain fungible asset and
+//! does not shrink, so the reserve would slowly become smaller than the derivatives it backs.
+//!
+//! This pallet accounts for the demurrage of the reserves without issuing anything:
+//!
+//! - [`ReserveAccounting`] wraps the asset transactor of the currencies. Whenever an asset is moved
+//!   into or out of the sovereign account of one of the [`Config::RemoteReserves`], the nominal
+//!   amount is recorded in [`Reserves`].
+//! - Before anything is taken out of a reserve, the demurrage it suffered since is settled. For the
+//!   currencies [`Pallet::set_compensation`] enabled it for, the demurrage is transferred from the
+//!   [`Config::CompensationFunds`] of the currency, like the treasury of its community, as far as
+//!   they suffice. Whatever is not compensated is deducted from the nominal amount.
+//!
+//! Economically, a community compensating the demurrage pays for it out of its own funds, so the
+//! holders of the derivatives are not subject to demurrage while the asset is on the other chain.
+//! Without compensation, the reserve shrinks like any other balance while the derivatives don't,
+//! so the derivatives can redeem less than their nominal amount in total: the shortfall falls on
+//! the holders redeeming last.
+//!
+//! ## Registration
+//!
+//! [`Pallet::register_foreign_asset`] asks the remote chain to create the foreign asset for a
+//! currency and to accept this chain as its reserve, through [`Config::Registrar`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use core::marker::PhantomData;
+use frame_support::traits::{
+	fungibles::{Inspect, Mutate},
+	tokens::{Fortitude::Polite, Preservation::Preserve},
+	Contains,
+};
+use sp_runtime::traits::{Convert, Zero};
+use xcm::latest::prelude::*;
+use xcm_executor::{
+	traits::{ConvertLocation, MatchesFungibles, TransactAsset},
+	AssetsInHolding,
+};
+
+pub use pallet::*;
+pub use weight::WeightInfo;
+
+const LOG_TARGET: &str = "runtime::community-reserves";
+
+/// The balance of the currencies.
+pub type BalanceOf<T> =
+	<<T as Config>::Currencies as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The id of a currency.
+pub type AssetIdOf<T> =
+	<<T as Config>::Currencies as Inspect<<T as frame_system::Config>::AccountId>>::AssetId;
+
+/// Registers the currencies as foreign assets on the remote chain.
+pub trait ForeignAssetRegistrar<AssetId> {
+	/// Send the messages creating `asset` on the remote chain, with this chain as its reserve.
+	///
+	/// Returns the id of the sent message.
+	fn register(asset: &AssetId) -> Result<XcmHash, SendError>;
+}
+
+/// Sets up the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId> {
+	/// Create a currency and make sure the messages to the remote chain can be delivered.
+	fn setup_asset() -> AssetId;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+		/// The currencies subject to demurrage. Their balances must already account for it.
+		type Currencies: Mutate<Self::AccountId>;
+
+		/// Matches the XCM assets that are [`Config::Currencies`].
+		type AssetMatcher: MatchesFungibles<AssetIdOf<Self>, BalanceOf<Self>>;
+
+		/// Converts a location into the account of its sovereign on this chain.
+		type LocationToAccountId: ConvertLocation<Self::AccountId>;
+
+		/// The chains whose sovereign accounts hold reserves of the currencies.
+		type RemoteReserves: Contains<Location>;
+
+		/// The origin which may register a currency on the remote chain and decide whether the
+		/// demurrage of its reserves is compensated.
+		type RegisterOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The account funding the demurrage compensation of the reserves of a currency.
+		type CompensationFunds: Convert<AssetIdOf<Self>, Self::AccountId>;
+
+		/// Registers the currencies on the remote chain.
+		type Registrar: ForeignAssetRegistrar<AssetIdOf<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Sets up the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<AssetIdOf<Self>>;
+	}
+
+	/// The nominal amount of a currency held in reserve by the sovereign account of a remote chain.
+	///
+	/// This is what the derivatives on the remote chain can redeem. The actual balance of the
+	/// sovereign account is smaller by the demurrage since the last settlement, see
+	/// [`Pallet::settle`].
+	#[pallet::storage]
+	pub type Reserves<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetIdOf<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		ValueQuery,
+	>;
+
+	/// The currencies whose reserves are compensated for demurrage by their
+	/// [`Config::CompensationFunds`].
+	#[pallet::storage]
+	pub type CompensatedCurrencies<T: Config> =
+		StorageMap<_, Blake2_128Concat, AssetIdOf<T>, (), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The registration of `asset` as foreign asset was sent to the remote chain.
+		RegistrationSent { asset: AssetIdOf<T>, message_id: XcmHash },
+		/// Whether the demurrage of the reserves of `asset` is compensated was set.
+		CompensationSet { asset: AssetIdOf<T>, compensated: bool },
+		/// The demurrage of the reserve of `asset` held by `reserve` was compensated by
+		/// transferring `amount` from the [`Config::CompensationFunds`].
+		DemurrageCompensated { asset: AssetIdOf<T>, reserve: T::AccountId, amount: BalanceOf<T> },
+		/// The nominal amount of the reserve of `asset` held by `reserve` was reduced by the
+		/// `amount` of demurrage which was not compensated.
+		DemurrageIncurred { asset: AssetIdOf<T>, reserve: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The currency does not exist.
+		UnknownAsset,
+		/// The registration could not be sent to the remote chain.
+		SendFailed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register `asset` as foreign asset on the remote chain, with this chain as its reserve.
+		///
+		/// The dispatch origin for this call must be [`Config::RegisterOrigin`].
+		///
+		/// Parameters:
+		/// - `asset`: The currency to register.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::register_foreign_asset())]
+		pub fn register_foreign_asset(origin: OriginFor<T>, asset: AssetIdOf<T>) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+			ensure!(T::Currencies::asset_exists(asset.clone()), Error::<T>::UnknownAsset);
+
+			let message_id = T::Registrar::register(&asset).map_err(|error| {
+				log::debug!(target: LOG_TARGET, "Failed to register {asset:?}: {error:?}");
+				Error::<T>::SendFailed
+			})?;
+			Self::deposit_event(Event::RegistrationSent { asset, message_id });
+
+			Ok(())
+		}
+
+		/// Set whether the demurrage of the reserves of `asset` is compensated by its
+		/// [`Config::CompensationFunds`].
+		///
+		/// The dispatch origin for this call must be [`Config::RegisterOrigin`].
+		///
+		/// Parameters:
+		/// - `asset`: The currency.
+		/// - `compensated`: Whether the demurrage is compensated from now on.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_compensation())]
+		pub fn set_compensation(
+			origin: OriginFor<T>,
+			asset: AssetIdOf<T>,
+			compensated: bool,
+		) -> DispatchResult {
+			T::RegisterOrigin::ensure_origin(origin)?;
+			ensure!(T::Currencies::asset_exists(asset.clone()), Error::<T>::UnknownAsset);
+
+			if compensated {
+				CompensatedCurrencies::<T>::insert(&asset, ());
+			} else {
+				CompensatedCurrencies::<T>::remove(&asset);
+			}
+			Self::deposit_event(Event::CompensationSet { asset, compensated });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Settle the demurrage that the reserve of `asset` held by `reserve` suffered since the last
+	/// settlement, so that its balance matches [`Reserves`] again.
+	///
+	/// The demurrage is compensated from the [`Config::CompensationFunds`] of compensated
+	/// currencies as far as they suffice, and the rest is deducted from [`Reserves`].
+	pub fn settle(asset: AssetIdOf<T>, reserve: &T::AccountId) {
+		let nominal = Reserves::<T>::get(asset.clone(), reserve);
+		let balance = T::Currencies::balance(asset.clone(), reserve);
+		if balance >= nominal {
+			return
+		}
+
+		let demurrage = nominal - balance;
+		let compensated = if CompensatedCurrencies::<T>::contains_key(&asset) {
+			Self::compensate(asset.clone(), reserve, demurrage)
+		} else {
+			Zero::zero()
+		};
+		let incurred = demurrage.saturating_sub(compensated);
+		if !incurred.is_zero() {
+			Self::note_withdrawal(asset.clone(), reserve, incurred);
+			Self::deposit_event(Event::DemurrageIncurred {
+				asset,
+				reserve: reserve.clone(),
+				amount: incurred,
+			});
+		}
+	}
+
+	/// Transfer up to `demurrage` from the [`Config::CompensationFunds`] of `asset` into
+	/// `reserve`, returning the amount transferred.
+	fn compensate(
+		asset: AssetIdOf<T>,
+		reserve: &T::AccountId,
+		demurrage: BalanceOf<T>,
+	) -> BalanceOf<T> {
+		let funds = T::CompensationFunds::convert(asset.clone());
+		let amount = T::Currencies::reducible_balance(asset.clone(), &funds, Preserve, Polite)
+			.min(demurrage);
+		if amount.is_zero() {
+			return amount
+		}
+
+		match T::Currencies::transfer(asset.clone(), &funds, reserve, amount, Preserve) {
+			Ok(_) => {
+				Self::deposit_event(Event::DemurrageCompensated {
+					asset,
+					reserve: reserve.clone(),
+					amount,
+				});
+				amount
+			},
+			Err(error) => {
+				log::warn!(
+					target: LOG_TARGET,
+					"Failed to compensate the demurrage of {asset:?} held by {reserve:?}: {error:?}"
+				);
+				Zero::zero()
+			},
+		}
+	}
+
+	fn note_deposit(asset: AssetIdOf<T>, reserve: &T::AccountId, amount: BalanceOf<T>) {
+		Reserves::<T>::mutate(asset, reserve, |nominal| *nominal = nominal.saturating_add(amount));
+	}
+
+	fn note_withdrawal(asset: AssetIdOf<T>, reserve: &T::AccountId, amount: BalanceOf<T>) {
+		Reserves::<T>::mutate_exists(asset, reserve, |nominal| {
+			let rest = nominal.unwrap_or_default().saturating_sub(amount);
+			*nominal = (!rest.is_zero()).then_some(rest);
+		});
+	}
+
+	/// The currency, the reserve account and the amount, if moving `what` in or out of `who` is
+	/// accounted for.
+	fn reserve_of(
+		what: &Asset,
+		who: &Location,
+	) -> Option<(AssetIdOf<T>, T::AccountId, BalanceOf<T>)> {
+		if !T::RemoteReserves::contains(who) {
+			return None
+		}
+		let (asset, amount) = T::AssetMatcher::matches_fungibles(what).ok()?;
+		let reserve = T::LocationToAccountId::convert_location(who)?;
+		Some((asset, reserve, amount))
+	}
+}
+
+/// Wraps the asset transactor `Inner` of [`Config::Currencies`] to account for the reserves held
+/// by [`Config::RemoteReserves`].
+///
+/// The demurrage of a reserve is settled before anything is withdrawn from it.
+pub struct ReserveAccounting<T, Inner>(PhantomData<(T, Inner)>);
+
+impl<T: Config, Inner: TransactAsset> TransactAsset for ReserveAccounting<T, Inner> {
+	fn can_check_in(origin: &Location, what: &Asset, context: &XcmContext) -> XcmResult {
+		Inner::can_check_in(origin, what, context)
+	}
+
+	fn check_in(origin: &Location, what: &Asset, context: &XcmContext) {
+		Inner::check_in(origin, what, context)
+	}
+
+	fn can_check_out(dest: &Location, what: &Asset, context: &XcmContext) -> XcmResult {
+		Inner::can_check_out(dest, what, context)
+	}
+
+	fn check_out(dest: &Location, what: &Asset, context: &XcmContext) {
+		Inner::check_out(dest, what, context)
+	}
+
+	fn deposit_asset(what: &Asset, who: &Location, context: Option<&XcmContext>) -> XcmResult {
+		Inner::deposit_asset(what, who, context)?;
+		if let Some((asset, reserve, amount)) = Pallet::<T>::reserve_of(what, who) {
+			Pallet::<T>::note_deposit(asset, &reserve, amount);
+		}
+		Ok(())
+	}
+
+	fn withdraw_asset(
+		what: &Asset,
+		who: &Location,
+		maybe_context: Option<&XcmContext>,
+	) -> Result<AssetsInHolding, XcmError> {
+		let reserve = Pallet::<T>::reserve_of(what, who);
+		if let Some((asset, reserve, _)) = &reserve {
+			Pallet::<T>::settle(asset.clone(), reserve);
+		}
+		let withdrawn = Inner::withdraw_asset(what, who, maybe_context)?;
+		if let Some((asset, reserve, amount)) = reserve {
+			Pallet::<T>::note_withdrawal(asset, &reserve, amount);
+		}
+		Ok(withdrawn)
+	}
+
+	fn internal_transfer_asset(
+		what: &Asset,
+		from: &Location,
+		to: &Location,
+		context: &XcmContext,
+	) -> Result<AssetsInHolding, XcmError> {
+		let source = Pallet::<T>::reserve_of(what, from);
+		if let Some((asset, reserve, _)) = &source {
+			Pallet::<T>::settle(asset.clone(), reserve);
+		}
+		let transferred = Inner::internal_transfer_asset(what, from, to, context)?;
+		if let Some((asset, reserve, amount)) = source {
+			Pallet::<T>::note_withdrawal(asset, &reserve, amount);
+		}
+		if let Some((asset, reserve, amount)) = Pallet::<T>::reserve_of(what, to) {
+			Pallet::<T>::note_deposit(asset, &reserve, amount);
+		}
+		Ok(transferred)
+	}
+}
@@ -0,0 +1,407 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+// Tests for the Community Reserves Pallet
+
+use super::*;
+use crate as community_reserves;
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, derive_impl, parameter_types,
+	traits::{
+		fungibles::Mutate as _,
+		tokens::{Fortitude, Precision, Preservation},
+		AsEnsureOriginWithArg, Equals,
+	},
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_io::TestExternalities;
+use sp_runtime::{BuildStorage, DispatchError};
+use xcm_builder::{FungiblesAdapter, NoChecking};
+use xcm_executor::traits::Error as MatchError;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		CommunityReserves: community_reserves,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+}
+
+parameter_types! {
+	pub RemoteReserve: Location = Location::new(1, [Parachain(1000)]);
+	pub const CheckingAccount: u64 = 999;
+	/// The currencies registered on the remote chain.
+	pub static Registered: Vec<u32> = vec![];
+	pub static RegistrationFails: bool = false;
+}
+
+/// Matches `GeneralIndex(id)` as the currency `id`.
+pub struct CurrencyMatcher;
+impl MatchesFungibles<u32, u64> for CurrencyMatcher {
+	fn matches_fungibles(what: &Asset) -> Result<(u32, u64), MatchError> {
+		match (what.id.0.unpack(), &what.fun) {
+			((0, [GeneralIndex(id)]), Fungible(amount)) => Ok((*id as u32, *amount as u64)),
+			_ => Err(MatchError::AssetNotHandled),
+		}
+	}
+}
+
+/// Sibling parachains are the accounts `SOVEREIGN + id`, local accounts their index.
+pub struct TestLocationToAccountId;
+impl ConvertLocation<u64> for TestLocationToAccountId {
+	fn convert_location(location: &Location) -> Option<u64> {
+		match location.unpack() {
+			(1, [Parachain(id)]) => Some(SOVEREIGN + *id as u64),
+			(0, [AccountIndex64 { index, .. }]) => Some(*index),
+			_ => None,
+		}
+	}
+}
+
+pub struct TestRegistrar;
+impl ForeignAssetRegistrar<u32> for TestRegistrar {
+	fn register(asset: &u32) -> Result<XcmHash, SendError> {
+		if RegistrationFails::get() {
+			return Err(SendError::Transport("closed"));
+		}
+		Registered::mutate(|registered| registered.push(*asset));
+		Ok([*asset as u8; 32])
+	}
+}
+
+/// Every currency is compensated from the same treasury.
+pub struct TestTreasury;
+impl Convert<u32, u64> for TestTreasury {
+	fn convert(_: u32) -> u64 {
+		TREASURY
+	}
+}
+
+impl Config for Test {
+	type Currencies = Assets;
+	type AssetMatcher = CurrencyMatcher;
+	type LocationToAccountId = TestLocationToAccountId;
+	type RemoteReserves = Equals<RemoteReserve>;
+	type RegisterOrigin = EnsureRoot<u64>;
+	type CompensationFunds = TestTreasury;
+	type Registrar = TestRegistrar;
+	type WeightInfo = ();
+}
+
+type Transactor = ReserveAccounting<
+	Test,
+	FungiblesAdapter<
+		Assets,
+		CurrencyMatcher,
+		TestLocationToAccountId,
+		u64,
+		NoChecking,
+		CheckingAccount,
+	>,
+>;
+
+const CURRENCY: u32 = 1;
+const OTHER_CURRENCY: u32 = 2;
+const ALICE: u64 = 1;
+const BOB: u64 = 2;
+const TREASURY: u64 = 3;
+const SOVEREIGN: u64 = 1_000;
+const RESERVE: u64 = SOVEREIGN + 1000;
+
+pub fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Registered::take();
+		RegistrationFails::set(false);
+		assert_ok!(Assets::force_create(RuntimeOrigin::root(), CURRENCY, ALICE, true, 1));
+		assert_ok!(Assets::mint_into(CURRENCY, &ALICE, 1_000));
+	});
+	ext
+}
+
+fn currency(amount: u64) -> Asset {
+	(Location::new(0, [GeneralIndex(CURRENCY.into())]), amount as u128).into()
+}
+
+fn account(index: u64) -> Location {
+	AccountIndex64 { network: None, index }.into()
+}
+
+fn context() -> XcmContext {
+	XcmContext::with_message_id([0; 32])
+}
+
+/// Compensates the demurrage of the currency from a treasury holding `funds`.
+fn compensate_from(funds: u64) {
+	assert_ok!(CommunityReserves::set_compensation(RuntimeOrigin::root(), CURRENCY, true));
+	assert_ok!(Assets::mint_into(CURRENCY, &TREASURY, funds));
+}
+
+/// Burns `amount` from the reserve, like demurrage would over time.
+fn demurrage(amount: u64) {
+	assert_ok!(Assets::burn_from(
+		CURRENCY,
+		&RESERVE,
+		amount,
+		Preservation::Expendable,
+		Precision::Exact,
+		Fortitude::Force,
+	));
+}
+
+#[test]
+fn transfers_to_a_remote_reserve_are_recorded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Transactor::transfer_asset(
+			&currency(100),
+			&account(ALICE),
+			&RemoteReserve::get(),
+			&context()
+		));
+
+		assert_eq!(Reserves::<Test>::get(CURRENCY, RESERVE), 100);
+		assert_eq!(Assets::balance(CURRENCY, RESERVE), 100);
+		assert_eq!(Assets::balance(CURRENCY, ALICE), 900);
+	});
+}
+
+#[test]
+fn deposits_and_withdrawals_of_reserves_are_recorded() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Transactor::deposit_asset(&currency(100), &RemoteReserve::get(), None));
+		assert_eq!(Reserves::<Test>::get(CURRENCY, RESERVE), 100);
+
+		assert_ok!(Transactor::withdraw_asset(&currency(40), &RemoteReserve::get(), None));
+		assert_eq!(Reserves::<Test>::get(CURRENCY, RESERVE), 60);
+		assert_eq!(Assets::balance(CURRENCY, RESERVE), 60);
+
+		assert_ok!(Transactor::withdraw_asset(&currency(60), &RemoteReserve::get(), None));
+		assert!(!Reserves::<Test>::contains_key(CURRENCY, RESERVE));
+	});
+}
+
+#[test]
+fn demurrage_of_reserves_is_compensated_before_withdrawal() {
+	new_test_ext().execute_with(|| {
+		compensate_from(50);
+		assert_ok!(Transactor::transfer_asset(
+			&currency(100),
+			&account(ALICE),
+			&RemoteReserve::get(),
+			&context()
+		));
+		demurrage(10);
+		assert_eq!(Assets::balance(CURRENCY, RESERVE), 90);
+
+		// the derivatives redeem the full nominal amount.
+		assert_ok!(Transactor::transfer_asset(
+			&currency(100),
+			&RemoteReserve::get(),
+			&account(BOB),
+			&context()
+		));
+
+		assert_eq!(Assets::balance(CURRENCY, BOB), 100);
+		assert_eq!(Assets::balance(CURRENCY, RESERVE), 0);
+		assert!(!Reserves::<Test>::contains_key(CURRENCY, RESERVE));
+		System::assert_has_event(
+			Event::<Test>::DemurrageCompensated { asset: CURRENCY, reserve: RESERVE, amount: 10 }
+				.into(),
+		);
+		// The treasury paid for it, nothing was issued.
+		assert_eq!(Assets::balance(CURRENCY, TREASURY), 40);
+		assert_eq!(Assets::total_issuance(CURRENCY), 1_040);
+	});
+}
+
+#[test]
+fn uncompensated_demurrage_reduces_the_reserve() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Transactor::transfer_asset(
+			&currency(100),
+			&account(ALICE),
+			&RemoteReserve::get(),
+			&context()
+		));
+		demurrage(10);
+
+		// the derivatives redeem what is left.
+		assert!(Transactor::withdraw_asset(&currency(100), &RemoteReserve::get(), None).is_err());
+		assert_eq!(Reserves::<Test>::get(CURRENCY, RESERVE), 90);
+		System::assert_has_event(
+			Event::<Test>::DemurrageIncurred { asset: CURRENCY, reserve: RESERVE, amount: 10 }
+				.into(),
+		);
+
+		assert_ok!(Transactor::transfer_asset(
+			&currency(90),
+			&RemoteReserve::get(),
+			&account(BOB),
+			&context()
+		));
+		assert_eq!(Assets::balance(CURRENCY, BOB), 90);
+		assert!(!Reserves::<Test>::contains_key(CURRENCY, RESERVE));
+		assert_eq!(Assets::total_issuance(CURRENCY), 990);
+	});
+}
+
+#[test]
+fn demurrage_is_compensated_as_far_as_the_funds_suffice() {
+	new_test_ext().execute_with(|| {
+		// One unit is kept for the minimum balance.
+		compensate_from(5);
+		assert_ok!(Transactor::deposit_asset(&currency(100), &RemoteReserve::get(), None));
+		demurrage(10);
+
+		assert_ok!(Transactor::withdraw_asset(&currency(50), &RemoteReserve::get(), None));
+
+		System::assert_has_event(
+			Event::<Test>::DemurrageCompensated { asset: CURRENCY, reserve: RESERVE, amount: 4 }
+				.into(),
+		);
+		System::assert_has_event(
+			Event::<Test>::DemurrageIncurred { asset: CURRENCY, reserve: RESERVE, amount: 6 }
+				.into(),
+		);
+		assert_eq!(Assets::balance(CURRENCY, TREASURY), 1);
+		assert_eq!(Reserves::<Test>::get(CURRENCY, RESERVE), 44);
+		assert_eq!(Assets::balance(CURRENCY, RESERVE), 44);
+	});
+}
+
+#[test]
+fn withdrawals_above_the_reserve_are_not_compensated() {
+	new_test_ext().execute_with(|| {
+		compensate_from(50);
+		assert_ok!(Transactor::deposit_asset(&currency(100), &RemoteReserve::get(), None));
+		demurrage(10);
+
+		// only the nominal amount is backed.
+		assert!(Transactor::withdraw_asset(&currency(110), &RemoteReserve::get(), None).is_err());
+		assert_eq!(Reserves::<Test>::get(CURRENCY, RESERVE), 100);
+		assert_eq!(Assets::balance(CURRENCY, RESERVE), 100);
+	});
+}
+
+#[test]
+fn other_accounts_are_not_accounted_for() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Transactor::transfer_asset(
+			&currency(100),
+			&account(ALICE),
+			&account(BOB),
+			&context()
+		));
+		assert_ok!(Transactor::deposit_asset(
+			&currency(100),
+			&Location::new(1, [Parachain(2000)]),
+			None
+		));
+
+		assert_eq!(Reserves::<Test>::iter().count(), 0);
+		assert_eq!(Assets::balance(CURRENCY, BOB), 100);
+	});
+}
+
+#[test]
+fn unmatched_assets_are_not_accounted_for() {
+	new_test_ext().execute_with(|| {
+		let other: Asset = (Location::new(0, [GeneralIndex(OTHER_CURRENCY.into())]), 100).into();
+		assert!(Transactor::deposit_asset(&other, &RemoteReserve::get(), None).is_err());
+		let native: Asset = (Here, 100).into();
+		assert!(Transactor::deposit_asset(&native, &RemoteReserve::get(), None).is_err());
+
+		assert_eq!(Reserves::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn register_foreign_asset_sends_the_registration() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CommunityReserves::register_foreign_asset(RuntimeOrigin::signed(ALICE), CURRENCY),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CommunityReserves::register_foreign_asset(RuntimeOrigin::root(), OTHER_CURRENCY),
+			Error::<Test>::UnknownAsset
+		);
+
+		assert_ok!(CommunityReserves::register_foreign_asset(RuntimeOrigin::root(), CURRENCY));
+
+		assert_eq!(Registered::get(), vec![CURRENCY]);
+		System::assert_last_event(
+			Event::<Test>::RegistrationSent { asset: CURRENCY, message_id: [1; 32] }.into(),
+		);
+	});
+}
+
+#[test]
+fn failed_registrations_are_reported() {
+	new_test_ext().execute_with(|| {
+		RegistrationFails::set(true);
+
+		assert_noop!(
+			CommunityReserves::register_foreign_asset(RuntimeOrigin::root(), CURRENCY),
+			Error::<Test>::SendFailed
+		);
+	});
+}
+
+#[test]
+fn set_compensation_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CommunityReserves::set_compensation(RuntimeOrigin::signed(ALICE), CURRENCY, true),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			CommunityReserves::set_compensation(RuntimeOrigin::root(), OTHER_CURRENCY, true),
+			Error::<Test>::UnknownAsset
+		);
+
+		assert_ok!(CommunityReserves::set_compensation(RuntimeOrigin::root(), CURRENCY, true));
+		assert!(CompensatedCurrencies::<Test>::contains_key(CURRENCY));
+		System::assert_last_event(
+			Event::<Test>::CompensationSet { asset: CURRENCY, compensated: true }.into(),
+		);
+
+		assert_ok!(CommunityReserves::set_compensation(RuntimeOrigin::root(), CURRENCY, false));
+		assert!(!CompensatedCurrencies::<Test>::contains_key(CURRENCY));
+	});
+}
@@ -0,0 +1,31 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+use frame_support::weights::Weight;
+
+/// Weight functions needed for `pallet_community_reserves`.
+pub trait WeightInfo {
+	fn register_foreign_asset() -> Weight;
+	fn set_compensation() -> Weight;
+}
+
+impl WeightInfo for () {
+	fn register_foreign_asset() -> Weight {
+		Weight::MAX
+	}
+	fn set_compensation() -> Weight {
+		Weight::MAX
+	}
+}
@@ -0,0 +1,117 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as CoretimeMarket;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+fn funded<T: Config>(name: &'static str) -> T::AccountId {
+	let who: T::AccountId = account(name, 0, 0);
+	let amount = T::Currency::minimum_balance()
+		.saturating_add(T::ListingDeposit::get())
+		.saturating_mul(1_000u32.into());
+	T::Currency::set_balance(&who, amount);
+	who
+}
+
+fn decaying<T: Config>() -> Price<BalanceOf<T>, BlockNumberFor<T>> {
+	let start = T::Currency::minimum_balance().saturating_mul(10u32.into());
+	Price::Decaying { start, floor: T::Currency::minimum_balance(), until: 100u32.into() }
+}
+
+/// List a partition of a new region of `seller`, the most expensive listing to buy.
+fn listed<T: Config>(seller: &T::AccountId) -> RegionId {
+	let region = T::BenchmarkHelper::create_region(seller);
+	CoretimeMarket::<T>::list(
+		RawOrigin::Signed(seller.clone()).into(),
+		region,
+		Part::Partition { pivot: 1 },
+		decaying::<T>(),
+	)
+	.expect("the region was just created; qed");
+	region
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn list() {
+		let seller = funded::<T>("seller");
+		let region = T::BenchmarkHelper::create_region(&seller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller), region, Part::Partition { pivot: 1 }, decaying::<T>());
+
+		assert!(Listings::<T>::contains_key(region));
+	}
+
+	#[benchmark]
+	fn cancel() {
+		let seller = funded::<T>("seller");
+		let region = listed::<T>(&seller);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(seller.clone()), region);
+
+		assert!(!Listings::<T>::contains_key(region));
+		assert_eq!(T::Regions::owner(&region), Some(seller));
+	}
+
+	#[benchmark]
+	fn purchase() {
+		let seller = funded::<T>("seller");
+		let buyer = funded::<T>("buyer");
+		let region = listed::<T>(&seller);
+		let max_price = T::Currency::minimum_balance().saturating_mul(10u32.into());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer.clone()), region, max_price);
+
+		assert!(!Listings::<T>::contains_key(region));
+		assert_eq!(T::Regions::owner(&region), Some(buyer));
+	}
+
+	#[benchmark]
+	fn clear() {
+		let seller = funded::<T>("seller");
+		let caller = funded::<T>("caller");
+		// A listing of a region which no longer exists.
+		let region = RegionId { begin: 0, core: u16::MAX, mask: CoreMask::complete() };
+		let deposit = T::ListingDeposit::get();
+		T::Currency::hold(&HoldReason::ListingDeposit.into(), &seller, deposit)
+			.expect("the seller is funded; qed");
+		Listings::<T>::insert(
+			region,
+			Listing {
+				seller,
+				part: Part::Whole,
+				price: decaying::<T>(),
+				listed_at: Zero::zero(),
+				deposit,
+			},
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), region);
+
+		assert!(!Listings::<T>::contains_key(region));
+	}
+
+	impl_benchmark_test_suite!(CoretimeMarket, crate::tests::new_test_ext(), crate::tests::Test);
+}
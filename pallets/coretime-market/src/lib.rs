@@ -0,0 +1,590 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Coretime market pallet
+//!
+//! Regions of `pallet_broker` can be transferred, partitioned and interlaced by their owner, but
+//! selling one to another team needs trust or an escrow multisig. This pallet is an on-chain
+//! secondary market for regions.
+//!
+//! The owner of a region lists it with [`Pallet::list`] at a [`Price`], which is either fixed or
+//! decays linearly until a given block. The listing offers a [`Part`] of the region: the whole
+//! region, the timeslices before a pivot or a subset of its core mask. The region is moved into
+//! the escrow account of the pallet until the listing is bought or cancelled.
+//!
+//! [`Pallet::purchase`] pays the current price to the seller and hands the region over in one
+//! go. If only a part is offered, the escrow partitions or interlaces the region first, the buyer
+//! gets the offered part and the seller the remainder. Sales are settled in the native currency
+//! and the pallet takes no fee or royalty.
+//!
+//! The listings are exposed with their current price through the
+//! [`CoretimeMarketApi`](runtime_api::CoretimeMarketApi).
+//!
+//! ## Deposits
+//!
+//! Every listing holds [`Config::ListingDeposit`] of the seller. It is released when the listing
+//! is bought or cancelled. A listing whose region was dropped by the broker after its end can be
+//! removed by anyone with [`Pallet::clear`], which releases the deposit as well.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use core::marker::PhantomData;
+use frame_support::{
+	ensure,
+	traits::{
+		fungible::{Inspect, Mutate, MutateHold},
+		tokens::{Precision, Preservation},
+		Get,
+	},
+};
+use frame_system::pallet_prelude::BlockNumberFor;
+use pallet_broker::{CoreMask, RegionId, Timeslice};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{AccountIdConversion, Saturating, UniqueSaturatedInto, Zero},
+	DispatchError, DispatchResult, Perbill,
+};
+
+pub use pallet::*;
+pub use weight::WeightInfo;
+
+/// The balance of the currency used to settle sales.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Inspect<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The listing type used by the pallet.
+pub type ListingOf<T> =
+	Listing<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+/// The listing info type used by the pallet.
+pub type ListingInfoOf<T> =
+	ListingInfo<<T as frame_system::Config>::AccountId, BalanceOf<T>, BlockNumberFor<T>>;
+
+/// The regions which can be traded.
+pub trait RegionProvider<AccountId> {
+	/// The owner of `region`, `None` if the region does not exist or has no owner.
+	fn owner(region: &RegionId) -> Option<AccountId>;
+
+	/// The end of `region`, `None` if the region does not exist.
+	fn end(region: &RegionId) -> Option<Timeslice>;
+
+	/// Transfer `region` from `owner` to `new_owner`.
+	fn transfer(region: RegionId, owner: &AccountId, new_owner: &AccountId) -> DispatchResult;
+
+	/// Split `region` of `owner` at `pivot` timeslices after its beginning.
+	///
+	/// Returns the regions before and after the pivot.
+	fn partition(
+		region: RegionId,
+		owner: &AccountId,
+		pivot: Timeslice,
+	) -> Result<(RegionId, RegionId), DispatchError>;
+
+	/// Split `region` of `owner` into the cores in `mask` and the remaining cores.
+	///
+	/// Returns the regions with the cores in `mask` and with the remaining cores.
+	fn interlace(
+		region: RegionId,
+		owner: &AccountId,
+		mask: CoreMask,
+	) -> Result<(RegionId, RegionId), DispatchError>;
+}
+
+/// Sets up the regions for the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId> {
+	/// Create a region of `owner` that spans several timeslices of a full core.
+	fn create_region(owner: &AccountId) -> RegionId;
+}
+
+/// [`RegionProvider`] for the regions of `pallet_broker`.
+///
+/// The calls are dispatched with a signed origin of the owner, like the owner would.
+pub struct BrokerRegions<T>(PhantomData<T>);
+impl<T: pallet_broker::Config> RegionProvider<T::AccountId> for BrokerRegions<T> {
+	fn owner(region: &RegionId) -> Option<T::AccountId> {
+		pallet_broker::Regions::<T>::get(region).and_then(|record| record.owner)
+	}
+
+	fn end(region: &RegionId) -> Option<Timeslice> {
+		pallet_broker::Regions::<T>::get(region).map(|record| record.end)
+	}
+
+	fn transfer(
+		region: RegionId,
+		owner: &T::AccountId,
+		new_owner: &T::AccountId,
+	) -> DispatchResult {
+		pallet_broker::Pallet::<T>::transfer(
+			frame_system::RawOrigin::Signed(owner.clone()).into(),
+			region,
+			new_owner.clone(),
+		)?;
+		Ok(())
+	}
+
+	fn partition(
+		region: RegionId,
+		owner: &T::AccountId,
+		pivot: Timeslice,
+	) -> Result<(RegionId, RegionId), DispatchError> {
+		pallet_broker::Pallet::<T>::partition(
+			frame_system::RawOrigin::Signed(owner.clone()).into(),
+			region,
+			pivot,
+		)?;
+		Ok((region, RegionId { begin: region.begin.saturating_add(pivot), ..region }))
+	}
+
+	fn interlace(
+		region: RegionId,
+		owner: &T::AccountId,
+		mask: CoreMask,
+	) -> Result<(RegionId, RegionId), DispatchError> {
+		pallet_broker::Pallet::<T>::interlace(
+			frame_system::RawOrigin::Signed(owner.clone()).into(),
+			region,
+			mask,
+		)?;
+		Ok((RegionId { mask, ..region }, RegionId { mask: region.mask ^ mask, ..region }))
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<T: pallet_broker::Config> BenchmarkHelper<T::AccountId> for BrokerRegions<T> {
+	fn create_region(owner: &T::AccountId) -> RegionId {
+		let region = RegionId { begin: 1, core: 0, mask: CoreMask::complete() };
+		pallet_broker::Regions::<T>::insert(
+			region,
+			pallet_broker::RegionRecord { end: 11, owner: Some(owner.clone()), paid: None },
+		);
+		region
+	}
+}
+
+/// The part of a region offered by a listing.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub enum Part {
+	/// The whole region.
+	Whole,
+	/// The timeslices before `pivot` timeslices after the beginning of the region.
+	Partition { pivot: Timeslice },
+	/// The cores in `mask`, which must be a strict subset of the mask of the region.
+	Interlace { mask: CoreMask },
+}
+
+/// The price of a listing.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub enum Price<Balance, BlockNumber> {
+	/// The same price until the listing is bought or cancelled.
+	Fixed(Balance),
+	/// A price which decays linearly from `start` at the listing to `floor` at block `until`,
+	/// and stays at `floor` afterwards.
+	Decaying { start: Balance, floor: Balance, until: BlockNumber },
+}
+
+/// A region listed for sale.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct Listing<AccountId, Balance, BlockNumber> {
+	/// The account which listed the region and receives the payment.
+	pub seller: AccountId,
+	/// The part of the region which is offered.
+	pub part: Part,
+	/// The price of the offered part.
+	pub price: Price<Balance, BlockNumber>,
+	/// The block in which the region was listed.
+	pub listed_at: BlockNumber,
+	/// The deposit held from the seller.
+	pub deposit: Balance,
+}
+
+/// A listing with its region and current price, as returned by the runtime API.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct ListingInfo<AccountId, Balance, BlockNumber> {
+	/// The listed region.
+	pub region: RegionId,
+	/// The end of the listed region.
+	pub end: Timeslice,
+	/// The listing.
+	pub listing: Listing<AccountId, Balance, BlockNumber>,
+	/// The price to pay in the current block.
+	pub current_price: Balance,
+}
+
+impl<AccountId, Balance, BlockNumber> Listing<AccountId, Balance, BlockNumber>
+where
+	Balance: sp_runtime::traits::AtLeast32BitUnsigned + Copy,
+	BlockNumber: sp_runtime::traits::AtLeast32BitUnsigned + Copy,
+{
+	/// The price of the listing at block `now`.
+	pub fn price_at(&self, now: BlockNumber) -> Balance {
+		match self.price {
+			Price::Fixed(price) => price,
+			Price::Decaying { start, floor, until } => {
+				if now >= until {
+					return floor;
+				}
+				let elapsed: u32 = now.saturating_sub(self.listed_at).unique_saturated_into();
+				let total: u32 = until.saturating_sub(self.listed_at).unique_saturated_into();
+				let decay = Perbill::from_rational(elapsed, total);
+				start.saturating_sub(decay.mul_floor(start.saturating_sub(floor)))
+			},
+		}
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+		/// The currency used to settle sales and hold the listing deposits.
+		type Currency: Mutate<Self::AccountId>
+			+ MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// The regions which can be traded.
+		type Regions: RegionProvider<Self::AccountId>;
+
+		/// The deposit held for every listing.
+		#[pallet::constant]
+		type ListingDeposit: Get<BalanceOf<Self>>;
+
+		/// The id of the pallet, used to derive the escrow account of the listed regions.
+		#[pallet::constant]
+		type PalletId: Get<frame_support::PalletId>;
+
+		/// Whether regions can be listed with [`Part::Interlace`].
+		#[pallet::constant]
+		type AllowInterlace: Get<bool>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Sets up the regions for the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AccountId>;
+	}
+
+	/// The reasons for which the pallet holds funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The deposit of a listing.
+		#[codec(index = 0)]
+		ListingDeposit,
+	}
+
+	/// The listed regions, held in the escrow account of the pallet.
+	#[pallet::storage]
+	pub type Listings<T: Config> =
+		StorageMap<_, Blake2_128Concat, RegionId, ListingOf<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// `part` of `region` was listed by `seller` at `price`.
+		Listed {
+			region: RegionId,
+			seller: T::AccountId,
+			part: Part,
+			price: Price<BalanceOf<T>, BlockNumberFor<T>>,
+		},
+		/// The listing of `region` was cancelled and the region returned to its seller.
+		Cancelled { region: RegionId },
+		/// `buyer` bought the listing of `region` for `price` and received the region `bought`.
+		Sold {
+			region: RegionId,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+			price: BalanceOf<T>,
+			bought: RegionId,
+		},
+		/// The listing of `region` was removed because the region no longer exists.
+		Cleared { region: RegionId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The region does not exist or is not owned by the caller.
+		NotOwner,
+		/// The region is not listed.
+		NotListed,
+		/// The caller is not the seller of the listing.
+		NotSeller,
+		/// The offered part is not a strict part of the region.
+		InvalidPart,
+		/// Listing parts of the core mask is not allowed on this chain.
+		InterlaceDisabled,
+		/// The decaying price rises or ends in the past.
+		InvalidPrice,
+		/// The current price is above the maximum price of the buyer.
+		PriceTooHigh,
+		/// The region of the listing still exists.
+		RegionExists,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// List a region for sale.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the owner of the region. The
+		/// region is moved into the escrow account and [`Config::ListingDeposit`] is held.
+		///
+		/// Parameters:
+		/// - `region`: The region to list.
+		/// - `part`: The part of the region which is offered.
+		/// - `price`: The price of the offered part.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::list())]
+		pub fn list(
+			origin: OriginFor<T>,
+			region: RegionId,
+			part: Part,
+			price: Price<BalanceOf<T>, BlockNumberFor<T>>,
+		) -> DispatchResult {
+			let seller = ensure_signed(origin)?;
+			ensure!(T::Regions::owner(&region).as_ref() == Some(&seller), Error::<T>::NotOwner);
+			let end = T::Regions::end(&region).ok_or(Error::<T>::NotOwner)?;
+			Self::check_part(&region, end, &part)?;
+
+			let now = frame_system::Pallet::<T>::block_number();
+			if let Price::Decaying { start, floor, until } = price {
+				ensure!(floor <= start && until > now, Error::<T>::InvalidPrice);
+			}
+
+			let deposit = T::ListingDeposit::get();
+			T::Currency::hold(&HoldReason::ListingDeposit.into(), &seller, deposit)?;
+			T::Regions::transfer(region, &seller, &Self::account_id())?;
+
+			Listings::<T>::insert(
+				region,
+				Listing { seller: seller.clone(), part, price, listed_at: now, deposit },
+			);
+			Self::deposit_event(Event::Listed { region, seller, part, price });
+
+			Ok(())
+		}
+
+		/// Cancel a listing and return the region to its seller.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the seller.
+		///
+		/// Parameters:
+		/// - `region`: The listed region.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::cancel())]
+		pub fn cancel(origin: OriginFor<T>, region: RegionId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let listing = Listings::<T>::take(region).ok_or(Error::<T>::NotListed)?;
+			ensure!(listing.seller == who, Error::<T>::NotSeller);
+
+			T::Regions::transfer(region, &Self::account_id(), &listing.seller)?;
+			Self::release_deposit(&listing);
+			Self::deposit_event(Event::Cancelled { region });
+
+			Ok(())
+		}
+
+		/// Buy a listing at its current price.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the buyer. The price is paid to
+		/// the seller, the buyer receives the offered part of the region and the seller the
+		/// remainder.
+		///
+		/// Parameters:
+		/// - `region`: The listed region.
+		/// - `max_price`: The maximum price the buyer is willing to pay.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::purchase())]
+		pub fn purchase(
+			origin: OriginFor<T>,
+			region: RegionId,
+			max_price: BalanceOf<T>,
+		) -> DispatchResult {
+			let buyer = ensure_signed(origin)?;
+			let listing = Listings::<T>::take(region).ok_or(Error::<T>::NotListed)?;
+
+			let price = listing.price_at(frame_system::Pallet::<T>::block_number());
+			ensure!(price <= max_price, Error::<T>::PriceTooHigh);
+			if !price.is_zero() {
+				T::Currency::transfer(&buyer, &listing.seller, price, Preservation::Preserve)?;
+			}
+
+			let escrow = Self::account_id();
+			let (bought, rest) = match listing.part {
+				Part::Whole => (region, None),
+				Part::Partition { pivot } => {
+					let (bought, rest) = T::Regions::partition(region, &escrow, pivot)?;
+					(bought, Some(rest))
+				},
+				Part::Interlace { mask } => {
+					let (bought, rest) = T::Regions::interlace(region, &escrow, mask)?;
+					(bought, Some(rest))
+				},
+			};
+			T::Regions::transfer(bought, &escrow, &buyer)?;
+			if let Some(rest) = rest {
+				T::Regions::transfer(rest, &escrow, &listing.seller)?;
+			}
+
+			Self::release_deposit(&listing);
+			Self::deposit_event(Event::Sold {
+				region,
+				seller: listing.seller,
+				buyer,
+				price,
+				bought,
+			});
+
+			Ok(())
+		}
+
+		/// Remove a listing whose region no longer exists.
+		///
+		/// The dispatch origin for this call must be _Signed_. The deposit is released to the
+		/// seller.
+		///
+		/// Parameters:
+		/// - `region`: The listed region.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::clear())]
+		pub fn clear(origin: OriginFor<T>, region: RegionId) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(T::Regions::end(&region).is_none(), Error::<T>::RegionExists);
+			let listing = Listings::<T>::take(region).ok_or(Error::<T>::NotListed)?;
+
+			Self::release_deposit(&listing);
+			Self::deposit_event(Event::Cleared { region });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// The escrow account holding the listed regions.
+	pub fn account_id() -> T::AccountId {
+		T::PalletId::get().into_account_truncating()
+	}
+
+	/// All listings with their current price.
+	pub fn listings() -> Vec<ListingInfoOf<T>> {
+		Listings::<T>::iter()
+			.filter_map(|(region, listing)| Self::listing_info(region, listing))
+			.collect()
+	}
+
+	/// The listing of `region` with its current price.
+	pub fn listing(region: RegionId) -> Option<ListingInfoOf<T>> {
+		Listings::<T>::get(region).and_then(|listing| Self::listing_info(region, listing))
+	}
+
+	fn listing_info(region: RegionId, listing: ListingOf<T>) -> Option<ListingInfoOf<T>> {
+		let end = T::Regions::end(&region)?;
+		let current_price = listing.price_at(frame_system::Pallet::<T>::block_number());
+		Some(ListingInfo { region, end, listing, current_price })
+	}
+
+	/// Check that `part` is a strict part of `region`, which ends at `end`.
+	fn check_part(region: &RegionId, end: Timeslice, part: &Part) -> DispatchResult {
+		match *part {
+			Part::Whole => {},
+			Part::Partition { pivot } => ensure!(
+				pivot > 0 && region.begin.saturating_add(pivot) < end,
+				Error::<T>::InvalidPart
+			),
+			Part::Interlace { mask } => {
+				ensure!(T::AllowInterlace::get(), Error::<T>::InterlaceDisabled);
+				ensure!(
+					!mask.is_void() && mask != region.mask && (mask & !region.mask).is_void(),
+					Error::<T>::InvalidPart
+				);
+			},
+		}
+		Ok(())
+	}
+
+	fn release_deposit(listing: &ListingOf<T>) {
+		let _ = T::Currency::release(
+			&HoldReason::ListingDeposit.into(),
+			&listing.seller,
+			listing.deposit,
+			Precision::BestEffort,
+		);
+	}
+}
+
+/// The runtime API of the coretime market.
+pub mod runtime_api {
+	use super::*;
+
+	sp_api::decl_runtime_apis! {
+		/// API to browse the regions listed on the coretime market.
+		pub trait CoretimeMarketApi<AccountId, Balance, BlockNumber>
+		where
+			AccountId: codec::Codec,
+			Balance: codec::Codec,
+			BlockNumber: codec::Codec,
+		{
+			/// Returns all listings with their current price.
+			fn listings() -> Vec<ListingInfo<AccountId, Balance, BlockNumber>>;
+
+			/// Returns the listing of `region` with its current price, if it is listed.
+			fn listing(region: RegionId) -> Option<ListingInfo<AccountId, Balance, BlockNumber>>;
+		}
+	}
+}
@@ -0,0 +1,368 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for the Coretime Market Pallet
+
+use super::*;
+use crate as coretime_market;
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, derive_impl, parameter_types,
+	traits::fungible::InspectHold, PalletId,
+};
+use sp_io::TestExternalities;
+use sp_runtime::{BuildStorage, TokenError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		CoretimeMarket: coretime_market,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type RuntimeHoldReason = RuntimeHoldReason;
+}
+
+parameter_types! {
+	pub const MarketPalletId: PalletId = PalletId(*b"py/ctmkt");
+	pub const ListingDeposit: u64 = DEPOSIT;
+	pub static AllowInterlace: bool = false;
+	/// The regions with their owner and end.
+	pub static Regions: Vec<(RegionId, u64, Timeslice)> = vec![];
+}
+
+pub struct TestRegions;
+impl TestRegions {
+	fn get(region: &RegionId) -> Option<(u64, Timeslice)> {
+		Regions::get()
+			.into_iter()
+			.find(|(id, ..)| id == region)
+			.map(|(_, owner, end)| (owner, end))
+	}
+
+	fn take_owned(region: &RegionId, owner: &u64) -> Result<Timeslice, DispatchError> {
+		let (current, end) = Self::get(region).ok_or(DispatchError::Other("unknown region"))?;
+		if current != *owner {
+			return Err(DispatchError::BadOrigin);
+		}
+		Regions::mutate(|regions| regions.retain(|(id, ..)| id != region));
+		Ok(end)
+	}
+}
+
+impl RegionProvider<u64> for TestRegions {
+	fn owner(region: &RegionId) -> Option<u64> {
+		Self::get(region).map(|(owner, _)| owner)
+	}
+
+	fn end(region: &RegionId) -> Option<Timeslice> {
+		Self::get(region).map(|(_, end)| end)
+	}
+
+	fn transfer(region: RegionId, owner: &u64, new_owner: &u64) -> DispatchResult {
+		let end = Self::take_owned(&region, owner)?;
+		add_region(region, *new_owner, end);
+		Ok(())
+	}
+
+	fn partition(
+		region: RegionId,
+		owner: &u64,
+		pivot: Timeslice,
+	) -> Result<(RegionId, RegionId), DispatchError> {
+		let end = Self::take_owned(&region, owner)?;
+		let pivot = region.begin + pivot;
+		let after = RegionId { begin: pivot, ..region };
+		add_region(region, *owner, pivot);
+		add_region(after, *owner, end);
+		Ok((region, after))
+	}
+
+	fn interlace(
+		region: RegionId,
+		owner: &u64,
+		mask: CoreMask,
+	) -> Result<(RegionId, RegionId), DispatchError> {
+		let end = Self::take_owned(&region, owner)?;
+		let one = RegionId { mask, ..region };
+		let other = RegionId { mask: region.mask ^ mask, ..region };
+		add_region(one, *owner, end);
+		add_region(other, *owner, end);
+		Ok((one, other))
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<u64> for TestRegions {
+	fn create_region(owner: &u64) -> RegionId {
+		add_region(region(), *owner, 20);
+		region()
+	}
+}
+
+impl Config for Test {
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Regions = TestRegions;
+	type ListingDeposit = ListingDeposit;
+	type PalletId = MarketPalletId;
+	type AllowInterlace = AllowInterlace;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestRegions;
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(SELLER, 100), (BUYER, 100)],
+		..Default::default()
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Regions::take();
+		AllowInterlace::set(false);
+	});
+	ext
+}
+
+const SELLER: u64 = 1;
+const BUYER: u64 = 2;
+const DEPOSIT: u64 = 10;
+
+fn region() -> RegionId {
+	RegionId { begin: 10, core: 3, mask: CoreMask::complete() }
+}
+
+fn add_region(region: RegionId, owner: u64, end: Timeslice) {
+	Regions::mutate(|regions| regions.push((region, owner, end)));
+}
+
+fn list(part: Part, price: Price<u64, u64>) {
+	add_region(region(), SELLER, 20);
+	assert_ok!(CoretimeMarket::list(RuntimeOrigin::signed(SELLER), region(), part, price));
+}
+
+fn held(who: u64) -> u64 {
+	Balances::balance_on_hold(&HoldReason::ListingDeposit.into(), &who)
+}
+
+#[test]
+fn list_and_cancel() {
+	new_test_ext().execute_with(|| {
+		list(Part::Whole, Price::Fixed(20));
+
+		assert_eq!(TestRegions::owner(&region()), Some(CoretimeMarket::account_id()));
+		assert_eq!(held(SELLER), DEPOSIT);
+		System::assert_last_event(
+			Event::Listed {
+				region: region(),
+				seller: SELLER,
+				part: Part::Whole,
+				price: Price::Fixed(20),
+			}
+			.into(),
+		);
+
+		assert_noop!(
+			CoretimeMarket::cancel(RuntimeOrigin::signed(BUYER), region()),
+			Error::<Test>::NotSeller
+		);
+		assert_ok!(CoretimeMarket::cancel(RuntimeOrigin::signed(SELLER), region()));
+
+		assert_eq!(TestRegions::owner(&region()), Some(SELLER));
+		assert_eq!(held(SELLER), 0);
+		assert!(Listings::<Test>::get(region()).is_none());
+		System::assert_last_event(Event::Cancelled { region: region() }.into());
+	});
+}
+
+#[test]
+fn list_checks_region_part_and_price() {
+	new_test_ext().execute_with(|| {
+		add_region(region(), SELLER, 20);
+		let list = |who, part, price| {
+			CoretimeMarket::list(RuntimeOrigin::signed(who), region(), part, price)
+		};
+
+		assert_noop!(list(BUYER, Part::Whole, Price::Fixed(20)), Error::<Test>::NotOwner);
+		assert_noop!(
+			list(SELLER, Part::Partition { pivot: 0 }, Price::Fixed(20)),
+			Error::<Test>::InvalidPart
+		);
+		assert_noop!(
+			list(SELLER, Part::Partition { pivot: 10 }, Price::Fixed(20)),
+			Error::<Test>::InvalidPart
+		);
+
+		let half = CoreMask::from_chunk(0, 40);
+		assert_noop!(
+			list(SELLER, Part::Interlace { mask: half }, Price::Fixed(20)),
+			Error::<Test>::InterlaceDisabled
+		);
+		AllowInterlace::set(true);
+		assert_noop!(
+			list(SELLER, Part::Interlace { mask: CoreMask::complete() }, Price::Fixed(20)),
+			Error::<Test>::InvalidPart
+		);
+		assert_noop!(
+			list(SELLER, Part::Interlace { mask: CoreMask::void() }, Price::Fixed(20)),
+			Error::<Test>::InvalidPart
+		);
+
+		assert_noop!(
+			list(SELLER, Part::Whole, Price::Decaying { start: 10, floor: 20, until: 10 }),
+			Error::<Test>::InvalidPrice
+		);
+		assert_noop!(
+			list(SELLER, Part::Whole, Price::Decaying { start: 20, floor: 10, until: 1 }),
+			Error::<Test>::InvalidPrice
+		);
+
+		assert_ok!(list(SELLER, Part::Interlace { mask: half }, Price::Fixed(20)));
+	});
+}
+
+#[test]
+fn purchase_whole_region() {
+	new_test_ext().execute_with(|| {
+		list(Part::Whole, Price::Fixed(20));
+
+		assert_noop!(
+			CoretimeMarket::purchase(RuntimeOrigin::signed(BUYER), region(), 19),
+			Error::<Test>::PriceTooHigh
+		);
+		assert_ok!(CoretimeMarket::purchase(RuntimeOrigin::signed(BUYER), region(), 20));
+
+		assert_eq!(TestRegions::owner(&region()), Some(BUYER));
+		assert_eq!(Balances::free_balance(BUYER), 80);
+		assert_eq!(Balances::free_balance(SELLER), 100 - DEPOSIT + 20 + DEPOSIT);
+		assert_eq!(held(SELLER), 0);
+		assert!(Listings::<Test>::get(region()).is_none());
+		System::assert_last_event(
+			Event::Sold {
+				region: region(),
+				seller: SELLER,
+				buyer: BUYER,
+				price: 20,
+				bought: region(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn purchase_partition_returns_remainder_to_seller() {
+	new_test_ext().execute_with(|| {
+		list(Part::Partition { pivot: 4 }, Price::Fixed(20));
+
+		assert_ok!(CoretimeMarket::purchase(RuntimeOrigin::signed(BUYER), region(), 20));
+
+		let rest = RegionId { begin: 14, ..region() };
+		assert_eq!(TestRegions::get(&region()), Some((BUYER, 14)));
+		assert_eq!(TestRegions::get(&rest), Some((SELLER, 20)));
+	});
+}
+
+#[test]
+fn purchase_interlace_returns_remainder_to_seller() {
+	new_test_ext().execute_with(|| {
+		AllowInterlace::set(true);
+		let mask = CoreMask::from_chunk(0, 40);
+		list(Part::Interlace { mask }, Price::Fixed(20));
+
+		assert_ok!(CoretimeMarket::purchase(RuntimeOrigin::signed(BUYER), region(), 20));
+
+		let bought = RegionId { mask, ..region() };
+		let rest = RegionId { mask: CoreMask::from_chunk(40, 80), ..region() };
+		assert_eq!(TestRegions::get(&bought), Some((BUYER, 20)));
+		assert_eq!(TestRegions::get(&rest), Some((SELLER, 20)));
+		System::assert_last_event(
+			Event::Sold { region: region(), seller: SELLER, buyer: BUYER, price: 20, bought }
+				.into(),
+		);
+	});
+}
+
+#[test]
+fn decaying_price_reaches_floor() {
+	new_test_ext().execute_with(|| {
+		list(Part::Whole, Price::Decaying { start: 90, floor: 10, until: 9 });
+		let price = || CoretimeMarket::listing(region()).unwrap().current_price;
+
+		assert_eq!(price(), 90);
+		System::set_block_number(5);
+		assert_eq!(price(), 50);
+		System::set_block_number(9);
+		assert_eq!(price(), 10);
+		System::set_block_number(100);
+		assert_eq!(price(), 10);
+
+		System::set_block_number(7);
+		assert_eq!(CoretimeMarket::listings().len(), 1);
+		assert_ok!(CoretimeMarket::purchase(RuntimeOrigin::signed(BUYER), region(), 30));
+		assert_eq!(Balances::free_balance(BUYER), 70);
+	});
+}
+
+#[test]
+fn failed_payment_keeps_listing() {
+	new_test_ext().execute_with(|| {
+		list(Part::Partition { pivot: 4 }, Price::Fixed(20));
+
+		assert_noop!(
+			CoretimeMarket::purchase(RuntimeOrigin::signed(3), region(), 20),
+			TokenError::FundsUnavailable
+		);
+		assert_eq!(TestRegions::owner(&region()), Some(CoretimeMarket::account_id()));
+	});
+}
+
+#[test]
+fn clear_listing_of_dropped_region() {
+	new_test_ext().execute_with(|| {
+		list(Part::Whole, Price::Fixed(20));
+
+		assert_noop!(
+			CoretimeMarket::clear(RuntimeOrigin::signed(BUYER), region()),
+			Error::<Test>::RegionExists
+		);
+
+		// The broker drops the region after its end.
+		Regions::take();
+		assert!(CoretimeMarket::listings().is_empty());
+		assert_ok!(CoretimeMarket::clear(RuntimeOrigin::signed(BUYER), region()));
+
+		assert_eq!(held(SELLER), 0);
+		assert!(Listings::<Test>::get(region()).is_none());
+		System::assert_last_event(Event::Cleared { region: region() }.into());
+	});
+}
@@ -0,0 +1,38 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as CoretimePurchase;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	// The worst case swaps the asset for the native tokens.
+	#[benchmark]
+	fn buy() {
+		let buyer: T::AccountId = whitelisted_caller();
+		let asset = T::BenchmarkHelper::setup_buyer(&buyer);
+		let price_limit = T::RemoteFee::get();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(buyer), Box::new(asset), u128::MAX, Order::Purchase, price_limit);
+	}
+
+	// The benchmarks need a real XCM executor and are tested by the runtimes.
+}
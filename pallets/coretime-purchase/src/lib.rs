@@ -0,0 +1,261 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Coretime purchase pallet
+//!
+//! Coretime is sold by `pallet_broker` on the Coretime Chain for the native token of the relay
+//! chain. Teams holding stablecoins on Asset Hub would have to swap them, teleport the native
+//! tokens and then buy on the Coretime Chain. This pallet does all of it with [`Pallet::buy`]:
+//!
+//! 1. The given asset is swapped through [`Config::Swap`] for the price limit plus
+//!    [`Config::RemoteFee`] and [`Config::DeliveryFee`] in native tokens. Paying with the native
+//!    token skips the swap.
+//! 2. [`program::local`] withdraws the native tokens from the buyer and teleports them to the
+//!    Coretime Chain, preserving the origin of the buyer.
+//! 3. On the Coretime Chain, [`program::remote`] aliases into the account of the buyer, deposits
+//!    the tokens and dispatches `purchase` or `renew` on the broker. The region belongs to the
+//!    account of the buyer on the Coretime Chain, which has the same id as on Asset Hub.
+//!
+//! A failure on Asset Hub reverts the whole call, including the swap.
+//!
+//! ## Refunds
+//!
+//! If the broker call fails on the Coretime Chain, the error handler [`program::refund`] teleports
+//! the price limit back to the buyer on Asset Hub, where it arrives in native tokens less the
+//! delivery fees. The unused part of the remote fee and of the price limit of a successful purchase
+//! stay with the buyer on the Coretime Chain.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod program;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use alloc::{boxed::Box, vec, vec::Vec};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::traits::Get;
+use pallet_asset_conversion::Swap;
+use scale_info::TypeInfo;
+use sp_runtime::traits::TryConvert;
+use xcm::latest::prelude::*;
+
+pub use pallet::*;
+pub use weight::WeightInfo;
+
+const LOG_TARGET: &str = "runtime::coretime-purchase";
+
+/// The calls of the broker pallet on the Coretime Chain.
+pub trait BrokerCalls {
+	/// The encoded `purchase` call.
+	fn purchase(price_limit: u128) -> Vec<u8>;
+
+	/// The encoded `renew` call.
+	fn renew(core: u16) -> Vec<u8>;
+}
+
+/// Sets up a buyer for the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetKind, AccountId> {
+	/// Fund `who` with an asset that can be swapped for the native asset, and make sure the
+	/// messages to the Coretime Chain can be delivered.
+	///
+	/// Returns the asset.
+	fn setup_buyer(who: &AccountId) -> AssetKind;
+}
+
+/// What to buy on the Coretime Chain.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub enum Order {
+	/// A new region of the current bulk sale.
+	Purchase,
+	/// The renewal of the lease on `core`.
+	Renew { core: u16 },
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+		/// The assets which can be swapped.
+		type AssetKind: Parameter + MaxEncodedLen;
+
+		/// The native asset, which the coretime is paid with.
+		type NativeAsset: Get<Self::AssetKind>;
+
+		/// The swap of the assets for the native asset.
+		type Swap: Swap<Self::AccountId, Balance = u128, AssetKind = Self::AssetKind>;
+
+		/// The location of an account on this chain, which is the same on the Coretime Chain.
+		type AccountIdToLocation: for<'a> TryConvert<&'a Self::AccountId, Location>;
+
+		/// The executor of the local program.
+		type XcmExecutor: ExecuteXcm<<Self as frame_system::Config>::RuntimeCall>;
+
+		/// The location of the Coretime Chain.
+		type CoretimeLocation: Get<Location>;
+
+		/// The location of this chain as seen from the Coretime Chain.
+		type SelfLocation: Get<Location>;
+
+		/// The calls of the broker pallet on the Coretime Chain.
+		type BrokerCalls: BrokerCalls;
+
+		/// The amount of native tokens sent along to pay the execution on the Coretime Chain,
+		/// including a refund.
+		#[pallet::constant]
+		type RemoteFee: Get<u128>;
+
+		/// The amount of native tokens swapped in addition to pay the delivery of the message.
+		#[pallet::constant]
+		type DeliveryFee: Get<u128>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Sets up a buyer for the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetKind, Self::AccountId>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// `who` sent an `order` with `price_limit` to the Coretime Chain, paying `amount_in` of
+		/// `asset`.
+		OrderSent {
+			who: T::AccountId,
+			asset: T::AssetKind,
+			amount_in: u128,
+			order: Order,
+			price_limit: u128,
+			message_id: XcmHash,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account has no location on the Coretime Chain.
+		BadBuyer,
+		/// The price limit and the fees overflow.
+		PriceTooHigh,
+		/// The program could not be executed.
+		ExecutionFailed,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Buy coretime on the Coretime Chain with `asset`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the buyer.
+		///
+		/// Parameters:
+		/// - `asset`: The asset to pay with.
+		/// - `max_amount_in`: The maximum amount of `asset` to swap for the native tokens.
+		/// - `order`: What to buy.
+		/// - `price_limit`: The maximum price of the coretime in native tokens. This amount is sent
+		///   to the Coretime Chain, on top of [`Config::RemoteFee`].
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::buy())]
+		pub fn buy(
+			origin: OriginFor<T>,
+			asset: Box<T::AssetKind>,
+			max_amount_in: u128,
+			order: Order,
+			price_limit: u128,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let buyer =
+				T::AccountIdToLocation::try_convert(&who).map_err(|_| Error::<T>::BadBuyer)?;
+			let amount =
+				price_limit.checked_add(T::RemoteFee::get()).ok_or(Error::<T>::PriceTooHigh)?;
+
+			let native = T::NativeAsset::get();
+			let amount_in = if *asset == native {
+				0
+			} else {
+				let amount_out = amount.saturating_add(T::DeliveryFee::get());
+				T::Swap::swap_tokens_for_exact_tokens(
+					who.clone(),
+					vec![(*asset).clone(), native],
+					amount_out,
+					Some(max_amount_in),
+					who.clone(),
+					true,
+				)?
+			};
+
+			let call = match order {
+				Order::Purchase => T::BrokerCalls::purchase(price_limit),
+				Order::Renew { core } => T::BrokerCalls::renew(core),
+			};
+			let message = program::local(
+				amount,
+				T::RemoteFee::get(),
+				T::CoretimeLocation::get(),
+				program::remote(&buyer, price_limit, call, T::SelfLocation::get()),
+			);
+			let mut message_id = message.using_encoded(sp_io::hashing::blake2_256);
+			// The program is built here, its weight is covered by the weight of the call.
+			T::XcmExecutor::prepare_and_execute(
+				buyer,
+				message,
+				&mut message_id,
+				Weight::MAX,
+				Weight::MAX,
+			)
+			.ensure_complete()
+			.map_err(|error| {
+				log::debug!(target: LOG_TARGET, "Failed to send the order of {who:?}: {error:?}");
+				Error::<T>::ExecutionFailed
+			})?;
+
+			Self::deposit_event(Event::OrderSent {
+				who,
+				asset: *asset,
+				amount_in,
+				order,
+				price_limit,
+				message_id,
+			});
+
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,92 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! The XCM programs of a coretime order.
+//!
+//! The native token is the token of the relay chain, which is `Parent` on Asset Hub and on the
+//! Coretime Chain.
+
+use alloc::{vec, vec::Vec};
+use xcm::latest::prelude::*;
+
+/// The program executed on Asset Hub with the origin of the buyer.
+///
+/// Withdraws `amount` native tokens and teleports them to `destination`, where `remote_fee` of
+/// them pay for the execution of `remote_xcm` with the origin of the buyer. The delivery fees are
+/// withdrawn from the account of the buyer.
+pub fn local<Call>(
+	amount: u128,
+	remote_fee: u128,
+	destination: Location,
+	remote_xcm: Xcm<()>,
+) -> Xcm<Call> {
+	Xcm(vec![
+		SetFeesMode { jit_withdraw: true },
+		WithdrawAsset((Parent, amount).into()),
+		InitiateTransfer {
+			destination,
+			remote_fees: Some(AssetTransferFilter::Teleport(Definite((Parent, remote_fee).into()))),
+			preserve_origin: true,
+			assets: vec![AssetTransferFilter::Teleport(Wild(AllCounted(1)))]
+				.try_into()
+				.unwrap_or_default(),
+			remote_xcm,
+		},
+	])
+}
+
+/// The program executed on the Coretime Chain after the teleport.
+///
+/// Aliases into `buyer`, deposits the `price_limit` to it and dispatches the encoded broker
+/// `call` with its origin. If the call fails, the [`refund`] to `origin`, the location of Asset
+/// Hub, is executed. Whatever is left in the end is deposited to `buyer`.
+pub fn remote(buyer: &Location, price_limit: u128, call: Vec<u8>, origin: Location) -> Xcm<()> {
+	Xcm(vec![
+		AliasOrigin(buyer.clone()),
+		SetAppendix(Xcm(vec![
+			RefundSurplus,
+			DepositAsset { assets: Wild(AllCounted(1)), beneficiary: buyer.clone() },
+		])),
+		DepositAsset { assets: Wild(AllCounted(1)), beneficiary: buyer.clone() },
+		SetErrorHandler(refund(buyer, price_limit, origin)),
+		Transact {
+			origin_kind: OriginKind::SovereignAccount,
+			fallback_max_weight: None,
+			call: call.into(),
+		},
+		ExpectTransactStatus(MaybeErrorCode::Success),
+	])
+}
+
+/// The error handler of [`remote`].
+///
+/// Withdraws the `price_limit` from `buyer` again and teleports it back to `buyer` on
+/// `destination`, paying the fees there with the teleported tokens.
+pub fn refund(buyer: &Location, price_limit: u128, destination: Location) -> Xcm<()> {
+	Xcm(vec![
+		WithdrawAsset((Parent, price_limit).into()),
+		InitiateTransfer {
+			destination,
+			remote_fees: Some(AssetTransferFilter::Teleport(Wild(AllCounted(1)))),
+			preserve_origin: false,
+			assets: Default::default(),
+			remote_xcm: Xcm(vec![DepositAsset {
+				assets: Wild(AllCounted(1)),
+				beneficiary: buyer.clone(),
+			}]),
+		},
+	])
+}
@@ -0,0 +1,254 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for the Coretime Purchase Pallet
+
+use super::*;
+use crate as coretime_purchase;
+use frame_support::{assert_noop, construct_runtime, derive_impl, parameter_types};
+use sp_io::TestExternalities;
+use sp_runtime::{BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		CoretimePurchase: coretime_purchase,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+parameter_types! {
+	pub const Native: u32 = NATIVE;
+	pub CoretimeLocation: Location = Location::new(1, [Parachain(1005)]);
+	pub SelfLocation: Location = Location::new(1, [Parachain(1000)]);
+	pub const RemoteFee: u128 = REMOTE_FEE;
+	pub const DeliveryFee: u128 = DELIVERY_FEE;
+	/// The swaps with their path, amount out and maximum amount in.
+	pub static Swaps: Vec<(Vec<u32>, u128, Option<u128>)> = vec![];
+}
+
+/// Swaps at a price of two units of any asset for one native token.
+pub struct TestSwap;
+impl Swap<u64> for TestSwap {
+	type Balance = u128;
+	type AssetKind = u32;
+
+	fn max_path_len() -> u32 {
+		2
+	}
+
+	fn swap_exact_tokens_for_tokens(
+		_: u64,
+		_: Vec<u32>,
+		_: u128,
+		_: Option<u128>,
+		_: u64,
+		_: bool,
+	) -> Result<u128, DispatchError> {
+		unimplemented!("not used by the pallet")
+	}
+
+	fn swap_tokens_for_exact_tokens(
+		_: u64,
+		path: Vec<u32>,
+		amount_out: u128,
+		amount_in_max: Option<u128>,
+		_: u64,
+		_: bool,
+	) -> Result<u128, DispatchError> {
+		let amount_in = amount_out * 2;
+		if amount_in_max.is_some_and(|max| max < amount_in) {
+			return Err(DispatchError::Other("slippage"));
+		}
+		Swaps::mutate(|swaps| swaps.push((path, amount_out, amount_in_max)));
+		Ok(amount_in)
+	}
+}
+
+pub struct TestAccountToLocation;
+impl TryConvert<&u64, Location> for TestAccountToLocation {
+	fn try_convert(who: &u64) -> Result<Location, &u64> {
+		let mut id = [0; 32];
+		id[..8].copy_from_slice(&who.to_le_bytes());
+		Ok(AccountId32 { network: None, id }.into())
+	}
+}
+
+pub struct TestBrokerCalls;
+impl BrokerCalls for TestBrokerCalls {
+	fn purchase(price_limit: u128) -> Vec<u8> {
+		(5u8, price_limit).encode()
+	}
+
+	fn renew(core: u16) -> Vec<u8> {
+		(6u8, core).encode()
+	}
+}
+
+impl Config for Test {
+	type AssetKind = u32;
+	type NativeAsset = Native;
+	type Swap = TestSwap;
+	type AccountIdToLocation = TestAccountToLocation;
+	// Fails every program, the programs are tested in the emulated tests.
+	type XcmExecutor = ();
+	type CoretimeLocation = CoretimeLocation;
+	type SelfLocation = SelfLocation;
+	type BrokerCalls = TestBrokerCalls;
+	type RemoteFee = RemoteFee;
+	type DeliveryFee = DeliveryFee;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Swaps::take();
+	});
+	ext
+}
+
+const NATIVE: u32 = 0;
+const USDT: u32 = 1984;
+const BUYER: u64 = 1;
+const REMOTE_FEE: u128 = 10;
+const DELIVERY_FEE: u128 = 5;
+
+fn buyer() -> Location {
+	TestAccountToLocation::try_convert(&BUYER).unwrap()
+}
+
+#[test]
+fn local_program_teleports_with_the_origin_of_the_buyer() {
+	let remote = program::remote(&buyer(), 100, vec![1, 2, 3], SelfLocation::get());
+	let local = program::local::<()>(110, REMOTE_FEE, CoretimeLocation::get(), remote.clone());
+
+	assert_eq!(
+		local,
+		Xcm(vec![
+			SetFeesMode { jit_withdraw: true },
+			WithdrawAsset((Parent, 110).into()),
+			InitiateTransfer {
+				destination: CoretimeLocation::get(),
+				remote_fees: Some(AssetTransferFilter::Teleport(Definite(
+					(Parent, REMOTE_FEE).into()
+				))),
+				preserve_origin: true,
+				assets: vec![AssetTransferFilter::Teleport(Wild(AllCounted(1)))]
+					.try_into()
+					.unwrap(),
+				remote_xcm: remote,
+			},
+		])
+	);
+}
+
+#[test]
+fn remote_program_refunds_a_failed_call() {
+	let remote = program::remote(&buyer(), 100, vec![1, 2, 3], SelfLocation::get());
+
+	assert_eq!(remote.0[0], AliasOrigin(buyer()));
+	assert_eq!(remote.0[3], SetErrorHandler(program::refund(&buyer(), 100, SelfLocation::get())));
+	assert_eq!(
+		remote.0[4],
+		Transact {
+			origin_kind: OriginKind::SovereignAccount,
+			fallback_max_weight: None,
+			call: vec![1, 2, 3].into(),
+		}
+	);
+	assert_eq!(remote.0[5], ExpectTransactStatus(MaybeErrorCode::Success));
+
+	let refund = program::refund(&buyer(), 100, SelfLocation::get());
+	assert_eq!(refund.0[0], WithdrawAsset((Parent, 100).into()));
+	assert!(matches!(
+		&refund.0[1],
+		InitiateTransfer { destination, preserve_origin: false, .. }
+			if *destination == SelfLocation::get()
+	));
+}
+
+#[test]
+fn buy_swaps_for_the_price_and_fees() {
+	new_test_ext().execute_with(|| {
+		// The executor fails, but only after the swap.
+		assert_noop!(
+			CoretimePurchase::buy(
+				RuntimeOrigin::signed(BUYER),
+				Box::new(USDT),
+				1_000,
+				Order::Purchase,
+				100,
+			),
+			Error::<Test>::ExecutionFailed
+		);
+		assert_eq!(
+			Swaps::get(),
+			vec![(vec![USDT, NATIVE], 100 + REMOTE_FEE + DELIVERY_FEE, Some(1_000))]
+		);
+	});
+}
+
+#[test]
+fn buy_with_native_tokens_does_not_swap() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CoretimePurchase::buy(
+				RuntimeOrigin::signed(BUYER),
+				Box::new(NATIVE),
+				0,
+				Order::Renew { core: 3 },
+				100,
+			),
+			Error::<Test>::ExecutionFailed
+		);
+		assert!(Swaps::get().is_empty());
+	});
+}
+
+#[test]
+fn buy_fails_on_slippage_or_overflow() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			CoretimePurchase::buy(
+				RuntimeOrigin::signed(BUYER),
+				Box::new(USDT),
+				229,
+				Order::Purchase,
+				100,
+			),
+			DispatchError::Other("slippage")
+		);
+		assert_noop!(
+			CoretimePurchase::buy(
+				RuntimeOrigin::signed(BUYER),
+				Box::new(USDT),
+				u128::MAX,
+				Order::Purchase,
+				u128::MAX,
+			),
+			Error::<Test>::PriceTooHigh
+		);
+	});
+}
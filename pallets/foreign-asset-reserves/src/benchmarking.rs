@@ -0,0 +1,112 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as ForeignAssetReserves;
+use frame_benchmarking::v2::*;
+use frame_support::{traits::Get, BoundedVec};
+use pallet_assets::BenchmarkHelper as _;
+use sp_runtime::traits::StaticLookup;
+
+fn reserve<T: Config<I>, I: 'static>(index: u32) -> T::ReserveData {
+	<T as Config<I>>::BenchmarkHelper::reserve(index)
+}
+
+/// Create an asset with `count` reserves.
+fn create_asset<T: Config<I>, I: 'static>(
+	count: u32,
+) -> Result<T::AssetIdParameter, BenchmarkError> {
+	let id = <T as pallet_assets::Config<I>>::BenchmarkHelper::create_asset_id_parameter(0);
+	let origin = <T as pallet_assets::Config<I>>::ForceOrigin::try_successful_origin()
+		.map_err(|_| BenchmarkError::Weightless)?;
+	let owner: T::AccountId = account("owner", 0, 0);
+	pallet_assets::Pallet::<T, I>::force_create(
+		origin,
+		id.clone(),
+		T::Lookup::unlookup(owner),
+		true,
+		1u32.into(),
+	)
+	.map_err(|_| BenchmarkError::Stop("asset creation failed"))?;
+	let reserves = (0..count).map(reserve::<T, I>).collect();
+	pallet_assets::Pallet::<T, I>::unchecked_update_reserves(id.clone().into(), reserves)
+		.map_err(|_| BenchmarkError::Stop("too many reserves"))?;
+	Ok(id)
+}
+
+#[instance_benchmarks]
+mod benchmarks {
+	use super::*;
+
+	// The worst case replaces the maximum number of reserves.
+	#[benchmark]
+	fn set_reserves() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let max = T::MaxReserves::get();
+		let id = create_asset::<T, I>(max)?;
+		let reserves = (max..max * 2).map(reserve::<T, I>).collect::<Vec<_>>();
+		let bounded = BoundedVec::try_from(reserves.clone()).expect("`MaxReserves` reserves; qed");
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, id.clone(), bounded);
+
+		assert_eq!(ForeignAssetReserves::<T, I>::reserves(&id.into()), reserves);
+
+		Ok(())
+	}
+
+	// The worst case adds the last reserve.
+	#[benchmark]
+	fn add_reserve() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let max = T::MaxReserves::get();
+		let id = create_asset::<T, I>(max.saturating_sub(1))?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, id.clone(), reserve::<T, I>(max));
+
+		assert_eq!(ForeignAssetReserves::<T, I>::reserves(&id.into()).len() as u32, max);
+
+		Ok(())
+	}
+
+	// The worst case removes the last reserve.
+	#[benchmark]
+	fn remove_reserve() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let max = T::MaxReserves::get();
+		let id = create_asset::<T, I>(max)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, id.clone(), reserve::<T, I>(max.saturating_sub(1)));
+
+		assert_eq!(
+			ForeignAssetReserves::<T, I>::reserves(&id.into()).len() as u32,
+			max.saturating_sub(1)
+		);
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(
+		ForeignAssetReserves,
+		crate::tests::new_test_ext(),
+		crate::tests::Test
+	);
+}
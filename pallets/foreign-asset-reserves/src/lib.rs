@@ -0,0 +1,189 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Foreign asset reserves pallet
+//!
+//! The trusted reserves of the assets of a `pallet_assets` instance, which decide from where
+//! reserve transfers and teleports of an asset are accepted, are normally set by the owner of the
+//! asset. This pallet lets [`Config::AdminOrigin`] manage them too, for example when the bridge or
+//! the issuer of an asset changes or when the owner is unable to act.
+//!
+//! The reserves are written to `pallet_assets` directly, so that the XCM filters reading them take
+//! the changes into account immediately.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use alloc::vec::Vec;
+
+pub use pallet::*;
+pub use weight::WeightInfo;
+
+/// Provides the arguments for the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<ReserveData> {
+	/// A reserve, different for every `index`.
+	fn reserve(index: u32) -> ReserveData;
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>:
+		frame_system::Config<RuntimeEvent: From<Event<Self, I>>> + pallet_assets::Config<I>
+	{
+		/// The origin which manages the reserves.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of reserves of an asset, at most the limit of `pallet_assets`.
+		#[pallet::constant]
+		type MaxReserves: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::ReserveData>;
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// The reserves of an asset were changed by [`Config::AdminOrigin`].
+		ReservesSet { asset_id: T::AssetId, reserves: Vec<T::ReserveData> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The asset does not exist.
+		UnknownAsset,
+		/// The reserve is already set.
+		AlreadyReserve,
+		/// The reserve is not set.
+		NotReserve,
+		/// The asset has already [`Config::MaxReserves`] reserves.
+		TooManyReserves,
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Replace the reserves of an asset.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`].
+		///
+		/// Parameters:
+		/// - `id`: The asset.
+		/// - `reserves`: The new reserves, none to remove them all.
+		#[pallet::call_index(0)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::set_reserves())]
+		pub fn set_reserves(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			reserves: BoundedVec<T::ReserveData, T::MaxReserves>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::update(id.into(), |current| {
+				*current = reserves.into_inner();
+				Ok(())
+			})
+		}
+
+		/// Add a reserve to an asset.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`].
+		///
+		/// Parameters:
+		/// - `id`: The asset.
+		/// - `reserve`: The reserve to add.
+		#[pallet::call_index(1)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::add_reserve())]
+		pub fn add_reserve(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			reserve: T::ReserveData,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::update(id.into(), |current| {
+				ensure!(!current.contains(&reserve), Error::<T, I>::AlreadyReserve);
+				ensure!(
+					(current.len() as u32) < T::MaxReserves::get(),
+					Error::<T, I>::TooManyReserves
+				);
+				current.push(reserve);
+				Ok(())
+			})
+		}
+
+		/// Remove a reserve of an asset.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`].
+		///
+		/// Parameters:
+		/// - `id`: The asset.
+		/// - `reserve`: The reserve to remove.
+		#[pallet::call_index(2)]
+		#[pallet::weight(<T as Config<I>>::WeightInfo::remove_reserve())]
+		pub fn remove_reserve(
+			origin: OriginFor<T>,
+			id: T::AssetIdParameter,
+			reserve: T::ReserveData,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			Self::update(id.into(), |current| {
+				let position =
+					current.iter().position(|r| *r == reserve).ok_or(Error::<T, I>::NotReserve)?;
+				current.remove(position);
+				Ok(())
+			})
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// The reserves of an asset.
+		pub fn reserves(id: &T::AssetId) -> Vec<T::ReserveData> {
+			pallet_assets::Reserves::<T, I>::get(id).into_inner()
+		}
+
+		/// Change the reserves of an existing asset with `f` and store them in `pallet_assets`.
+		fn update(
+			id: T::AssetId,
+			f: impl FnOnce(&mut Vec<T::ReserveData>) -> DispatchResult,
+		) -> DispatchResult {
+			ensure!(pallet_assets::Asset::<T, I>::contains_key(&id), Error::<T, I>::UnknownAsset);
+			let mut reserves = Self::reserves(&id);
+			f(&mut reserves)?;
+			pallet_assets::Pallet::<T, I>::unchecked_update_reserves(id.clone(), reserves.clone())?;
+			Self::deposit_event(Event::ReservesSet { asset_id: id, reserves });
+			Ok(())
+		}
+	}
+}
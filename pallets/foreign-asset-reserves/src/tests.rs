@@ -0,0 +1,189 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for the Foreign Asset Reserves Pallet
+
+use super::*;
+use crate as foreign_asset_reserves;
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, derive_impl,
+	traits::{AsEnsureOriginWithArg, ConstU32},
+	BoundedVec,
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_io::TestExternalities;
+use sp_runtime::{BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		ForeignAssetReserves: foreign_asset_reserves,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type ReserveData = u64;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<u64> for () {
+	fn reserve(index: u32) -> u64 {
+		index.into()
+	}
+}
+
+impl Config for Test {
+	type AdminOrigin = EnsureRoot<u64>;
+	type MaxReserves = ConstU32<3>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+const ASSET: u32 = 1;
+const OWNER: u64 = 1;
+
+pub fn new_test_ext() -> TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_assets::GenesisConfig::<Test> {
+		assets: vec![(ASSET, OWNER, true, 1)],
+		metadata: vec![],
+		accounts: vec![],
+		next_asset_id: None,
+		reserves: vec![],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn reserves(reserves: Vec<u64>) -> BoundedVec<u64, ConstU32<3>> {
+	reserves.try_into().unwrap()
+}
+
+#[test]
+fn only_admin_can_manage_reserves() {
+	new_test_ext().execute_with(|| {
+		let owner = RuntimeOrigin::signed(OWNER);
+		assert_noop!(
+			ForeignAssetReserves::set_reserves(owner.clone(), ASSET, reserves(vec![1])),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ForeignAssetReserves::add_reserve(owner.clone(), ASSET, 1),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			ForeignAssetReserves::remove_reserve(owner, ASSET, 1),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_reserves_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ForeignAssetReserves::set_reserves(
+			RuntimeOrigin::root(),
+			ASSET,
+			reserves(vec![1, 2])
+		));
+		System::assert_last_event(
+			Event::ReservesSet { asset_id: ASSET, reserves: vec![1, 2] }.into(),
+		);
+		assert_eq!(pallet_assets::Reserves::<Test>::get(ASSET).into_inner(), vec![1, 2]);
+
+		assert_ok!(ForeignAssetReserves::set_reserves(
+			RuntimeOrigin::root(),
+			ASSET,
+			reserves(vec![3])
+		));
+		assert_eq!(ForeignAssetReserves::reserves(&ASSET), vec![3]);
+
+		assert_ok!(ForeignAssetReserves::set_reserves(
+			RuntimeOrigin::root(),
+			ASSET,
+			reserves(vec![])
+		));
+		System::assert_last_event(Event::ReservesSet { asset_id: ASSET, reserves: vec![] }.into());
+		assert!(!pallet_assets::Reserves::<Test>::contains_key(ASSET));
+	});
+}
+
+#[test]
+fn add_and_remove_reserve_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ForeignAssetReserves::add_reserve(RuntimeOrigin::root(), ASSET, 1));
+		assert_ok!(ForeignAssetReserves::add_reserve(RuntimeOrigin::root(), ASSET, 2));
+		System::assert_last_event(
+			Event::ReservesSet { asset_id: ASSET, reserves: vec![1, 2] }.into(),
+		);
+		assert_noop!(
+			ForeignAssetReserves::add_reserve(RuntimeOrigin::root(), ASSET, 2),
+			Error::<Test>::AlreadyReserve
+		);
+		assert_ok!(ForeignAssetReserves::add_reserve(RuntimeOrigin::root(), ASSET, 3));
+		assert_noop!(
+			ForeignAssetReserves::add_reserve(RuntimeOrigin::root(), ASSET, 4),
+			Error::<Test>::TooManyReserves
+		);
+
+		assert_ok!(ForeignAssetReserves::remove_reserve(RuntimeOrigin::root(), ASSET, 2));
+		System::assert_last_event(
+			Event::ReservesSet { asset_id: ASSET, reserves: vec![1, 3] }.into(),
+		);
+		assert_noop!(
+			ForeignAssetReserves::remove_reserve(RuntimeOrigin::root(), ASSET, 2),
+			Error::<Test>::NotReserve
+		);
+		assert_eq!(ForeignAssetReserves::reserves(&ASSET), vec![1, 3]);
+	});
+}
+
+#[test]
+fn unknown_assets_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ForeignAssetReserves::set_reserves(RuntimeOrigin::root(), 2, reserves(vec![1])),
+			Error::<Test>::UnknownAsset
+		);
+		assert_noop!(
+			ForeignAssetReserves::add_reserve(RuntimeOrigin::root(), 2, 1),
+			Error::<Test>::UnknownAsset
+		);
+	});
+}
@@ -0,0 +1,174 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as GluttonProfiles;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Hooks;
+use frame_system::pallet_prelude::BlockNumberFor;
+
+const PARA: u32 = 2_000;
+
+fn phase<T: Config>(messages: u32) -> Phase {
+	Phase {
+		load: Load { compute: T::MaxLoad::get(), storage: T::MaxLoad::get() },
+		transition: Transition::Ramp,
+		blocks: 10,
+		burst: (messages > 0).then(|| XcmBurst {
+			para: PARA.into(),
+			messages,
+			size: T::MaxMessageSize::get(),
+		}),
+	}
+}
+
+fn profile<T: Config>(phases: u32, messages: u32) -> ProfileOf<T> {
+	Profile {
+		phases: (0..phases)
+			.map(|_| phase::<T>(messages))
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("within the bounds; qed"),
+		repeat: true,
+	}
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_profile(p: Linear<1, { T::MaxPhases::get() }>) -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let profile = profile::<T>(p, T::MaxBurstMessages::get());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, profile.clone());
+
+		assert_eq!(Profiles::<T>::get(0), Some(profile));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_profile() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Profiles::<T>::insert(0, profile::<T>(T::MaxPhases::get(), 0));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0);
+
+		assert!(!Profiles::<T>::contains_key(0));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn schedule() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Profiles::<T>::insert(0, profile::<T>(1, 0));
+		let at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0, at);
+
+		assert_eq!(Schedule::<T>::get(at), Some(0));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn cancel_schedule() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let at = frame_system::Pallet::<T>::block_number() + 10u32.into();
+		Schedule::<T>::insert(at, 0);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, at);
+
+		assert!(!Schedule::<T>::contains_key(at));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn start() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Profiles::<T>::insert(0, profile::<T>(1, 0));
+		Profiles::<T>::insert(1, profile::<T>(1, 0));
+		GluttonProfiles::<T>::do_start(1, frame_system::Pallet::<T>::block_number());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, 0);
+
+		assert_eq!(Current::<T>::get().map(|running| running.profile), Some(0));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn stop() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		Profiles::<T>::insert(0, profile::<T>(1, 0));
+		GluttonProfiles::<T>::do_start(0, frame_system::Pallet::<T>::block_number());
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin);
+
+		assert!(Current::<T>::get().is_none());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn on_initialize_idle() {
+		let now = frame_system::Pallet::<T>::block_number();
+
+		#[block]
+		{
+			GluttonProfiles::<T>::on_initialize(now);
+		}
+
+		assert!(Current::<T>::get().is_none());
+	}
+
+	#[benchmark]
+	fn on_initialize_phase(m: Linear<0, { T::MaxBurstMessages::get() }>) {
+		let now: BlockNumberFor<T> = frame_system::Pallet::<T>::block_number();
+		let mut profile = profile::<T>(T::MaxPhases::get(), m);
+		profile.phases[0].transition = Transition::Step;
+		Profiles::<T>::insert(0, profile);
+		GluttonProfiles::<T>::do_start(0, now);
+		// The weight of the glutton is added by `LoadTarget::set_load`.
+		AppliedLoad::<T>::put(phase::<T>(0).load);
+		T::BenchmarkHelper::ensure_delivery(PARA.into());
+
+		#[block]
+		{
+			GluttonProfiles::<T>::on_initialize(now);
+		}
+
+		assert_eq!(Metrics::<T>::get().messages_sent, m as u64);
+	}
+
+	impl_benchmark_test_suite!(GluttonProfiles, crate::tests::new_test_ext(), crate::tests::Test);
+}
@@ -0,0 +1,697 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Glutton profiles pallet
+//!
+//! `pallet_glutton` wastes a static share of the compute and the storage proof of every block.
+//! This pallet drives that load through time-varying [`Profile`]s to stress the relay chain with
+//! realistic scenarios: ramps, bursts, PoV-heavy or compute-heavy blocks and bursts of large XCM
+//! messages to sibling parachains.
+//!
+//! A profile is a sequence of [`Phase`]s, each lasting a number of blocks. A phase either steps to
+//! its [`Load`] or ramps linearly to it from the load of the previous phase, the first phase ramps
+//! up from no load. A phase with an [`XcmBurst`] sends its messages in every block of the phase.
+//! A repeating profile starts over after its last phase, otherwise the load is set to zero.
+//!
+//! Profiles are stored with [`Pallet::set_profile`] and started immediately with [`Pallet::start`]
+//! or at a given block with [`Pallet::schedule`]. Only one profile runs at a time, starting a
+//! profile replaces the running one. The load is applied through [`Config::Glutton`] at the
+//! beginning of every block.
+//!
+//! The running profile, its phase, the applied load and the metrics of the run are exposed through
+//! the [`GluttonProfilesApi`](runtime_api::GluttonProfilesApi).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use alloc::{vec, vec::Vec};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use cumulus_primitives_core::ParaId;
+use frame_support::{
+	traits::Get, weights::Weight, BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound,
+	RuntimeDebugNoBound,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{FixedPointNumber, FixedU64};
+use xcm::latest::prelude::*;
+
+pub use pallet::*;
+pub use weight::WeightInfo;
+
+const LOG_TARGET: &str = "runtime::glutton-profiles";
+
+/// The id of a profile.
+pub type ProfileId = u32;
+
+/// The shares of the block resources wasted by the glutton.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+	Default,
+)]
+pub struct Load {
+	/// The share of the block compute.
+	pub compute: FixedU64,
+	/// The share of the block storage proof.
+	pub storage: FixedU64,
+}
+
+impl Load {
+	/// The load after `elapsed` of `blocks` blocks of a linear ramp from `self` to `target`.
+	fn towards(&self, target: &Load, elapsed: u32, blocks: u32) -> Load {
+		Load {
+			compute: lerp(self.compute, target.compute, elapsed, blocks),
+			storage: lerp(self.storage, target.storage, elapsed, blocks),
+		}
+	}
+}
+
+fn lerp(from: FixedU64, to: FixedU64, elapsed: u32, blocks: u32) -> FixedU64 {
+	let (from, to) = (from.into_inner() as u128, to.into_inner() as u128);
+	let (elapsed, blocks) = (elapsed.min(blocks) as u128, blocks.max(1) as u128);
+	let inner = if to >= from {
+		from + (to - from) * elapsed / blocks
+	} else {
+		from - (from - to) * elapsed / blocks
+	};
+	FixedU64::from_inner(inner as u64)
+}
+
+/// How a phase reaches its load.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub enum Transition {
+	/// The load is applied from the first block of the phase.
+	Step,
+	/// The load rises or falls linearly from the load of the previous phase and is reached in the
+	/// last block of the phase.
+	Ramp,
+}
+
+/// XCM messages sent to a sibling parachain in every block of a phase.
+///
+/// The messages are unpaid `Transact`s of `size` zero bytes, the recipient is expected to reject
+/// them. They only load the transport.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub struct XcmBurst {
+	/// The sibling parachain.
+	pub para: ParaId,
+	/// The number of messages per block.
+	pub messages: u32,
+	/// The size of the payload of each message in bytes.
+	pub size: u32,
+}
+
+/// A phase of a profile.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub struct Phase {
+	/// The load of the phase.
+	pub load: Load,
+	/// How the load is reached.
+	pub transition: Transition,
+	/// The number of blocks of the phase.
+	pub blocks: u32,
+	/// The messages sent in every block of the phase.
+	pub burst: Option<XcmBurst>,
+}
+
+/// A sequence of phases.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	CloneNoBound,
+	PartialEqNoBound,
+	EqNoBound,
+	RuntimeDebugNoBound,
+)]
+#[scale_info(skip_type_params(MaxPhases))]
+#[codec(mel_bound())]
+pub struct Profile<MaxPhases: Get<u32>> {
+	/// The phases, in order.
+	pub phases: BoundedVec<Phase, MaxPhases>,
+	/// Start over after the last phase.
+	pub repeat: bool,
+}
+
+impl<MaxPhases: Get<u32>> Profile<MaxPhases> {
+	/// The index of the phase and the load after `elapsed` blocks of the profile.
+	///
+	/// `None` once a profile which does not repeat is over.
+	pub fn at(&self, elapsed: u32) -> Option<(u32, Load)> {
+		let total =
+			self.phases.iter().fold(0u32, |total, phase| total.saturating_add(phase.blocks));
+		if total == 0 || (!self.repeat && elapsed >= total) {
+			return None;
+		}
+
+		let mut elapsed = elapsed % total;
+		let mut previous = Load::default();
+		for (index, phase) in self.phases.iter().enumerate() {
+			if elapsed < phase.blocks {
+				let load = match phase.transition {
+					Transition::Step => phase.load,
+					Transition::Ramp => previous.towards(&phase.load, elapsed + 1, phase.blocks),
+				};
+				return Some((index as u32, load));
+			}
+			elapsed -= phase.blocks;
+			previous = phase.load;
+		}
+
+		None
+	}
+}
+
+/// The running profile.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub struct Running<BlockNumber> {
+	/// The id of the profile.
+	pub profile: ProfileId,
+	/// The block the profile started in.
+	pub started_at: BlockNumber,
+}
+
+/// The metrics of the running or last run profile.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+	Default,
+)]
+pub struct LoadMetrics {
+	/// The number of blocks the profile ran in.
+	pub blocks: u32,
+	/// The number of blocks in which a burst was sent.
+	pub bursts: u32,
+	/// The number of messages sent.
+	pub messages_sent: u64,
+	/// The payload bytes of the messages sent.
+	pub bytes_sent: u64,
+	/// The number of messages which could not be sent.
+	pub send_failures: u64,
+}
+
+/// The state of the load, returned by the runtime API.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct LoadStatus<BlockNumber> {
+	/// The running profile.
+	pub running: Option<Running<BlockNumber>>,
+	/// The index of the current phase of the running profile.
+	pub phase: Option<u32>,
+	/// The load applied to the glutton.
+	pub load: Load,
+	/// The metrics of the running or last run profile.
+	pub metrics: LoadMetrics,
+	/// The scheduled profiles by block.
+	pub scheduled: Vec<(BlockNumber, ProfileId)>,
+}
+
+/// The glutton whose load is driven.
+pub trait LoadTarget {
+	/// Apply `load` and return the consumed weight.
+	fn set_load(load: &Load) -> Weight;
+}
+
+/// Prepares the delivery of the bursts for the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// Make sure messages can be sent to `para`.
+	fn ensure_delivery(para: ParaId);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper for () {
+	fn ensure_delivery(_: ParaId) {}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::SaturatedConversion;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+		/// The origin that can manage and run the profiles.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The glutton whose load is driven.
+		type Glutton: LoadTarget;
+
+		/// The router used to send the bursts to sibling parachains.
+		type XcmSender: SendXcm;
+
+		/// The maximum share of a resource in a [`Load`].
+		#[pallet::constant]
+		type MaxLoad: Get<FixedU64>;
+
+		/// The maximum number of phases of a profile.
+		#[pallet::constant]
+		type MaxPhases: Get<u32>;
+
+		/// The maximum number of messages of a burst.
+		#[pallet::constant]
+		type MaxBurstMessages: Get<u32>;
+
+		/// The maximum payload size of the messages of a burst.
+		#[pallet::constant]
+		type MaxMessageSize: Get<u32>;
+
+		/// Weight information for extrinsics and hooks in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Prepares the delivery of the bursts for the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
+	}
+
+	/// A profile of the pallet.
+	pub type ProfileOf<T> = Profile<<T as Config>::MaxPhases>;
+
+	/// The stored profiles.
+	#[pallet::storage]
+	pub type Profiles<T: Config> =
+		StorageMap<_, Twox64Concat, ProfileId, ProfileOf<T>, OptionQuery>;
+
+	/// The profiles to start by block.
+	#[pallet::storage]
+	pub type Schedule<T: Config> =
+		StorageMap<_, Twox64Concat, BlockNumberFor<T>, ProfileId, OptionQuery>;
+
+	/// The running profile.
+	#[pallet::storage]
+	pub type Current<T: Config> = StorageValue<_, Running<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The index of the current phase of the running profile.
+	#[pallet::storage]
+	pub type CurrentPhase<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	/// The load last applied to the glutton.
+	#[pallet::storage]
+	pub type AppliedLoad<T: Config> = StorageValue<_, Load, ValueQuery>;
+
+	/// The metrics of the running or last run profile.
+	#[pallet::storage]
+	pub type Metrics<T: Config> = StorageValue<_, LoadMetrics, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The profile `id` was stored.
+		ProfileSet { id: ProfileId },
+		/// The profile `id` was removed.
+		ProfileRemoved { id: ProfileId },
+		/// The profile `id` is scheduled to start at block `at`.
+		Scheduled { id: ProfileId, at: BlockNumberFor<T> },
+		/// The profile scheduled at block `at` was cancelled.
+		ScheduleCancelled { at: BlockNumberFor<T> },
+		/// The profile `id` scheduled at block `at` was removed before it started.
+		ScheduleSkipped { id: ProfileId, at: BlockNumberFor<T> },
+		/// The profile `id` started.
+		Started { id: ProfileId },
+		/// The profile `id` was stopped.
+		Stopped { id: ProfileId },
+		/// The profile `id` ran through all its phases.
+		Finished { id: ProfileId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The profile does not exist.
+		UnknownProfile,
+		/// The profile has no phases or a phase without blocks.
+		EmptyProfile,
+		/// A load of the profile exceeds [`Config::MaxLoad`].
+		LoadTooHigh,
+		/// A burst of the profile exceeds [`Config::MaxBurstMessages`] or
+		/// [`Config::MaxMessageSize`].
+		BurstTooLarge,
+		/// The profile is running.
+		ProfileRunning,
+		/// The block is in the past.
+		InThePast,
+		/// A profile is already scheduled at the block.
+		AlreadyScheduled,
+		/// No profile is scheduled at the block.
+		NotScheduled,
+		/// No profile is running.
+		NotRunning,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(now: BlockNumberFor<T>) -> Weight {
+			let mut weight = T::WeightInfo::on_initialize_idle();
+
+			if let Some(id) = Schedule::<T>::take(now) {
+				weight.saturating_accrue(T::WeightInfo::start());
+				if Profiles::<T>::contains_key(id) {
+					Self::do_start(id, now);
+				} else {
+					Self::deposit_event(Event::ScheduleSkipped { id, at: now });
+				}
+			}
+
+			let Some(running) = Current::<T>::get() else { return weight };
+			let elapsed: u32 = now.saturating_sub(running.started_at).saturated_into();
+			let Some(profile) = Profiles::<T>::get(running.profile) else { return weight };
+
+			let Some((index, load)) = profile.at(elapsed) else {
+				Current::<T>::kill();
+				CurrentPhase::<T>::kill();
+				weight.saturating_accrue(T::WeightInfo::stop());
+				weight.saturating_accrue(Self::apply(Load::default()));
+				Self::deposit_event(Event::Finished { id: running.profile });
+				return weight;
+			};
+
+			let burst = profile.phases[index as usize].burst;
+			weight.saturating_accrue(T::WeightInfo::on_initialize_phase(
+				burst.map_or(0, |burst| burst.messages),
+			));
+			CurrentPhase::<T>::put(index);
+			weight.saturating_accrue(Self::apply(load));
+
+			Metrics::<T>::mutate(|metrics| {
+				metrics.blocks.saturating_inc();
+				if let Some(burst) = burst {
+					let sent = Self::send_burst(&burst);
+					metrics.bursts.saturating_inc();
+					metrics.messages_sent.saturating_accrue(sent as u64);
+					metrics.bytes_sent.saturating_accrue(sent as u64 * burst.size as u64);
+					metrics.send_failures.saturating_accrue((burst.messages - sent) as u64);
+				}
+			});
+
+			weight
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Store a profile.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`].
+		///
+		/// Parameters:
+		/// - `id`: The id of the profile. A stored profile with the same id is replaced, which
+		///   applies from the next block if it is running.
+		/// - `profile`: The profile.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_profile(profile.phases.len() as u32))]
+		pub fn set_profile(
+			origin: OriginFor<T>,
+			id: ProfileId,
+			profile: ProfileOf<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Self::check_profile(&profile)?;
+			Profiles::<T>::insert(id, profile);
+			Self::deposit_event(Event::ProfileSet { id });
+
+			Ok(())
+		}
+
+		/// Remove a profile which is not running.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`].
+		///
+		/// Parameters:
+		/// - `id`: The id of the profile.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_profile())]
+		pub fn remove_profile(origin: OriginFor<T>, id: ProfileId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(
+				Current::<T>::get().map_or(true, |running| running.profile != id),
+				Error::<T>::ProfileRunning
+			);
+			Profiles::<T>::take(id).ok_or(Error::<T>::UnknownProfile)?;
+			Self::deposit_event(Event::ProfileRemoved { id });
+
+			Ok(())
+		}
+
+		/// Schedule a profile to start at a block.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`].
+		///
+		/// Parameters:
+		/// - `id`: The id of the profile.
+		/// - `at`: The block to start the profile in, after the current block.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::schedule())]
+		pub fn schedule(
+			origin: OriginFor<T>,
+			id: ProfileId,
+			at: BlockNumberFor<T>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(Profiles::<T>::contains_key(id), Error::<T>::UnknownProfile);
+			ensure!(at > frame_system::Pallet::<T>::block_number(), Error::<T>::InThePast);
+			ensure!(!Schedule::<T>::contains_key(at), Error::<T>::AlreadyScheduled);
+			Schedule::<T>::insert(at, id);
+			Self::deposit_event(Event::Scheduled { id, at });
+
+			Ok(())
+		}
+
+		/// Cancel the profile scheduled at a block.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`].
+		///
+		/// Parameters:
+		/// - `at`: The block of the scheduled profile.
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::cancel_schedule())]
+		pub fn cancel_schedule(origin: OriginFor<T>, at: BlockNumberFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Schedule::<T>::take(at).ok_or(Error::<T>::NotScheduled)?;
+			Self::deposit_event(Event::ScheduleCancelled { at });
+
+			Ok(())
+		}
+
+		/// Start a profile from the next block, replacing the running one.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`].
+		///
+		/// Parameters:
+		/// - `id`: The id of the profile.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::start())]
+		pub fn start(origin: OriginFor<T>, id: ProfileId) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			ensure!(Profiles::<T>::contains_key(id), Error::<T>::UnknownProfile);
+			Self::do_start(
+				id,
+				frame_system::Pallet::<T>::block_number().saturating_add(1u32.into()),
+			);
+
+			Ok(())
+		}
+
+		/// Stop the running profile and set the load to zero.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`].
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::stop())]
+		pub fn stop(origin: OriginFor<T>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			let running = Current::<T>::take().ok_or(Error::<T>::NotRunning)?;
+			CurrentPhase::<T>::kill();
+			Self::apply(Load::default());
+			Self::deposit_event(Event::Stopped { id: running.profile });
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The state of the load.
+		pub fn status() -> LoadStatus<BlockNumberFor<T>> {
+			let mut scheduled: Vec<_> = Schedule::<T>::iter().collect();
+			scheduled.sort();
+			LoadStatus {
+				running: Current::<T>::get(),
+				phase: CurrentPhase::<T>::get(),
+				load: AppliedLoad::<T>::get(),
+				metrics: Metrics::<T>::get(),
+				scheduled,
+			}
+		}
+
+		/// Run the profile `id` from block `at` on.
+		pub(crate) fn do_start(id: ProfileId, at: BlockNumberFor<T>) {
+			Current::<T>::put(Running { profile: id, started_at: at });
+			CurrentPhase::<T>::kill();
+			Metrics::<T>::kill();
+			Self::deposit_event(Event::Started { id });
+		}
+
+		/// Apply `load` to the glutton unless it is applied already.
+		pub(crate) fn apply(load: Load) -> Weight {
+			if AppliedLoad::<T>::get() == load {
+				return Weight::zero();
+			}
+			AppliedLoad::<T>::put(load);
+			T::Glutton::set_load(&load)
+		}
+
+		/// Send the messages of `burst` and return the number of sent messages.
+		pub(crate) fn send_burst(burst: &XcmBurst) -> u32 {
+			let destination = Location::new(1, Parachain(burst.para.into()));
+			let message = Xcm(vec![
+				UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+				Transact {
+					origin_kind: OriginKind::SovereignAccount,
+					fallback_max_weight: None,
+					call: vec![0u8; burst.size as usize].into(),
+				},
+			]);
+
+			let mut sent = 0;
+			for _ in 0..burst.messages {
+				match send_xcm::<T::XcmSender>(destination.clone(), message.clone()) {
+					Ok(_) => sent += 1,
+					Err(error) => {
+						log::debug!(
+							target: LOG_TARGET,
+							"Failed to send a burst message to {:?}: {error:?}",
+							burst.para,
+						);
+					},
+				}
+			}
+			sent
+		}
+
+		fn check_profile(profile: &ProfileOf<T>) -> DispatchResult {
+			ensure!(!profile.phases.is_empty(), Error::<T>::EmptyProfile);
+			for phase in profile.phases.iter() {
+				ensure!(phase.blocks > 0, Error::<T>::EmptyProfile);
+				ensure!(
+					phase.load.compute <= T::MaxLoad::get() &&
+						phase.load.storage <= T::MaxLoad::get(),
+					Error::<T>::LoadTooHigh
+				);
+				if let Some(burst) = phase.burst {
+					ensure!(
+						burst.messages <= T::MaxBurstMessages::get() &&
+							burst.size <= T::MaxMessageSize::get(),
+						Error::<T>::BurstTooLarge
+					);
+				}
+			}
+			Ok(())
+		}
+	}
+}
+
+/// Runtime API of the glutton profiles pallet.
+pub mod runtime_api {
+	use super::*;
+
+	sp_api::decl_runtime_apis! {
+		/// API to monitor the load driven by the glutton profiles.
+		pub trait GluttonProfilesApi<BlockNumber>
+		where
+			BlockNumber: codec::Codec,
+		{
+			/// Returns the running profile, the applied load and the metrics of the run.
+			fn status() -> LoadStatus<BlockNumber>;
+		}
+	}
+}
@@ -0,0 +1,355 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for the Glutton Profiles Pallet
+
+use super::*;
+use crate as glutton_profiles;
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, derive_impl, parameter_types,
+	traits::{ConstU32, Hooks},
+};
+use frame_system::EnsureRoot;
+use sp_io::TestExternalities;
+use sp_runtime::{BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		GluttonProfiles: glutton_profiles,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+parameter_types! {
+	pub const MaxLoad: FixedU64 = FixedU64::from_u32(10);
+	pub static AppliedLoads: Vec<Load> = vec![];
+	pub static SentMessages: Vec<(Location, Xcm<()>)> = vec![];
+	pub static SendFails: bool = false;
+}
+
+pub struct TestGlutton;
+impl LoadTarget for TestGlutton {
+	fn set_load(load: &Load) -> Weight {
+		AppliedLoads::mutate(|loads| loads.push(*load));
+		Weight::zero()
+	}
+}
+
+pub struct TestSender;
+impl SendXcm for TestSender {
+	type Ticket = (Location, Xcm<()>);
+
+	fn validate(
+		dest: &mut Option<Location>,
+		msg: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		if SendFails::get() {
+			return Err(SendError::Transport("failed"));
+		}
+		Ok(((dest.take().unwrap(), msg.take().unwrap()), Assets::new()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		SentMessages::mutate(|sent| sent.push(ticket));
+		Ok([0; 32])
+	}
+}
+
+impl Config for Test {
+	type AdminOrigin = EnsureRoot<u64>;
+	type Glutton = TestGlutton;
+	type XcmSender = TestSender;
+	type MaxLoad = MaxLoad;
+	type MaxPhases = ConstU32<4>;
+	type MaxBurstMessages = ConstU32<8>;
+	type MaxMessageSize = ConstU32<1_024>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		AppliedLoads::take();
+		SentMessages::take();
+		SendFails::set(false);
+	});
+	ext
+}
+
+const PARA: u32 = 2_000;
+
+fn load(compute: u32, storage: u32) -> Load {
+	Load {
+		compute: FixedU64::from_rational(compute as u128, 10),
+		storage: FixedU64::from_rational(storage as u128, 10),
+	}
+}
+
+fn phase(load: Load, transition: Transition, blocks: u32) -> Phase {
+	Phase { load, transition, blocks, burst: None }
+}
+
+fn set_profile(id: ProfileId, phases: Vec<Phase>, repeat: bool) {
+	assert_ok!(GluttonProfiles::set_profile(
+		RuntimeOrigin::root(),
+		id,
+		Profile { phases: phases.try_into().unwrap(), repeat },
+	));
+}
+
+fn run_to(n: u64) {
+	while System::block_number() < n {
+		System::set_block_number(System::block_number() + 1);
+		GluttonProfiles::on_initialize(System::block_number());
+	}
+}
+
+#[test]
+fn steps_are_applied_and_the_load_is_reset_at_the_end() {
+	new_test_ext().execute_with(|| {
+		set_profile(
+			0,
+			vec![phase(load(5, 0), Transition::Step, 2), phase(load(0, 5), Transition::Step, 1)],
+			false,
+		);
+		assert_ok!(GluttonProfiles::start(RuntimeOrigin::root(), 0));
+		System::assert_last_event(Event::Started { id: 0 }.into());
+
+		run_to(3);
+		assert_eq!(AppliedLoads::get(), vec![load(5, 0)]);
+		assert_eq!(CurrentPhase::<Test>::get(), Some(0));
+
+		run_to(4);
+		assert_eq!(AppliedLoads::get(), vec![load(5, 0), load(0, 5)]);
+		assert_eq!(CurrentPhase::<Test>::get(), Some(1));
+
+		run_to(5);
+		assert_eq!(AppliedLoads::get(), vec![load(5, 0), load(0, 5), Load::default()]);
+		assert!(Current::<Test>::get().is_none());
+		assert_eq!(CurrentPhase::<Test>::get(), None);
+		assert_eq!(Metrics::<Test>::get().blocks, 3);
+		System::assert_last_event(Event::Finished { id: 0 }.into());
+	});
+}
+
+#[test]
+fn ramps_interpolate_from_the_previous_phase() {
+	new_test_ext().execute_with(|| {
+		set_profile(
+			0,
+			vec![phase(load(4, 8), Transition::Ramp, 4), phase(load(0, 4), Transition::Ramp, 2)],
+			false,
+		);
+		assert_ok!(GluttonProfiles::start(RuntimeOrigin::root(), 0));
+
+		run_to(7);
+		assert_eq!(
+			AppliedLoads::get(),
+			vec![load(1, 2), load(2, 4), load(3, 6), load(4, 8), load(2, 6), load(0, 4),]
+		);
+	});
+}
+
+#[test]
+fn repeating_profiles_start_over() {
+	new_test_ext().execute_with(|| {
+		set_profile(
+			0,
+			vec![phase(load(1, 0), Transition::Step, 1), phase(load(2, 0), Transition::Step, 1)],
+			true,
+		);
+		assert_ok!(GluttonProfiles::start(RuntimeOrigin::root(), 0));
+
+		run_to(6);
+		assert_eq!(
+			AppliedLoads::get(),
+			vec![load(1, 0), load(2, 0), load(1, 0), load(2, 0), load(1, 0)]
+		);
+		assert!(Current::<Test>::get().is_some());
+	});
+}
+
+#[test]
+fn scheduled_profiles_start_at_their_block() {
+	new_test_ext().execute_with(|| {
+		set_profile(0, vec![phase(load(1, 1), Transition::Step, 10)], false);
+
+		assert_noop!(
+			GluttonProfiles::schedule(RuntimeOrigin::root(), 1, 5),
+			Error::<Test>::UnknownProfile
+		);
+		assert_noop!(
+			GluttonProfiles::schedule(RuntimeOrigin::root(), 0, 1),
+			Error::<Test>::InThePast
+		);
+		assert_ok!(GluttonProfiles::schedule(RuntimeOrigin::root(), 0, 5));
+		assert_noop!(
+			GluttonProfiles::schedule(RuntimeOrigin::root(), 0, 5),
+			Error::<Test>::AlreadyScheduled
+		);
+		assert_ok!(GluttonProfiles::schedule(RuntimeOrigin::root(), 0, 8));
+		assert_ok!(GluttonProfiles::cancel_schedule(RuntimeOrigin::root(), 8));
+		assert_noop!(
+			GluttonProfiles::cancel_schedule(RuntimeOrigin::root(), 8),
+			Error::<Test>::NotScheduled
+		);
+
+		run_to(4);
+		assert!(Current::<Test>::get().is_none());
+		assert!(AppliedLoads::get().is_empty());
+
+		run_to(5);
+		assert_eq!(Current::<Test>::get(), Some(Running { profile: 0, started_at: 5 }));
+		assert_eq!(AppliedLoads::get(), vec![load(1, 1)]);
+		assert!(Schedule::<Test>::iter().next().is_none());
+	});
+}
+
+#[test]
+fn invalid_profiles_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let invalid = |phases: Vec<Phase>| {
+			GluttonProfiles::set_profile(
+				RuntimeOrigin::root(),
+				0,
+				Profile { phases: phases.try_into().unwrap(), repeat: false },
+			)
+		};
+		let burst = |messages, size| Phase {
+			burst: Some(XcmBurst { para: PARA.into(), messages, size }),
+			..phase(load(1, 1), Transition::Step, 1)
+		};
+
+		assert_noop!(invalid(vec![]), Error::<Test>::EmptyProfile);
+		assert_noop!(
+			invalid(vec![phase(load(1, 1), Transition::Step, 0)]),
+			Error::<Test>::EmptyProfile
+		);
+		assert_noop!(
+			invalid(vec![phase(load(101, 0), Transition::Step, 1)]),
+			Error::<Test>::LoadTooHigh
+		);
+		assert_noop!(invalid(vec![burst(9, 1)]), Error::<Test>::BurstTooLarge);
+		assert_noop!(invalid(vec![burst(1, 1_025)]), Error::<Test>::BurstTooLarge);
+		assert_noop!(
+			GluttonProfiles::set_profile(
+				RuntimeOrigin::signed(1),
+				0,
+				Profile { phases: vec![burst(8, 1_024)].try_into().unwrap(), repeat: false },
+			),
+			DispatchError::BadOrigin
+		);
+		assert_ok!(invalid(vec![burst(8, 1_024)]));
+	});
+}
+
+#[test]
+fn bursts_are_sent_and_counted() {
+	new_test_ext().execute_with(|| {
+		set_profile(
+			0,
+			vec![
+				Phase {
+					burst: Some(XcmBurst { para: PARA.into(), messages: 3, size: 100 }),
+					..phase(load(0, 10), Transition::Step, 2)
+				},
+				phase(load(10, 0), Transition::Step, 1),
+			],
+			false,
+		);
+		assert_ok!(GluttonProfiles::start(RuntimeOrigin::root(), 0));
+
+		run_to(2);
+		let sent = SentMessages::get();
+		assert_eq!(sent.len(), 3);
+		assert_eq!(sent[0].0, Location::new(1, Parachain(PARA)));
+		assert!(matches!(
+			sent[0].1.0.as_slice(),
+			[UnpaidExecution { .. }, Transact { call, .. }] if call.clone().into_encoded().len() == 100
+		));
+
+		SendFails::set(true);
+		run_to(4);
+		assert_eq!(SentMessages::get().len(), 3);
+		assert_eq!(
+			Metrics::<Test>::get(),
+			LoadMetrics {
+				blocks: 3,
+				bursts: 2,
+				messages_sent: 3,
+				bytes_sent: 300,
+				send_failures: 3,
+			}
+		);
+	});
+}
+
+#[test]
+fn stop_resets_the_load() {
+	new_test_ext().execute_with(|| {
+		set_profile(0, vec![phase(load(3, 3), Transition::Step, 10)], false);
+		assert_noop!(GluttonProfiles::stop(RuntimeOrigin::root()), Error::<Test>::NotRunning);
+		assert_ok!(GluttonProfiles::start(RuntimeOrigin::root(), 0));
+		run_to(3);
+
+		assert_noop!(
+			GluttonProfiles::remove_profile(RuntimeOrigin::root(), 0),
+			Error::<Test>::ProfileRunning
+		);
+		assert_ok!(GluttonProfiles::stop(RuntimeOrigin::root()));
+		System::assert_last_event(Event::Stopped { id: 0 }.into());
+		assert_eq!(AppliedLoads::get(), vec![load(3, 3), Load::default()]);
+
+		assert_ok!(GluttonProfiles::remove_profile(RuntimeOrigin::root(), 0));
+		assert_noop!(
+			GluttonProfiles::remove_profile(RuntimeOrigin::root(), 0),
+			Error::<Test>::UnknownProfile
+		);
+	});
+}
+
+#[test]
+fn status_reports_the_run() {
+	new_test_ext().execute_with(|| {
+		set_profile(0, vec![phase(load(2, 0), Transition::Step, 10)], false);
+		assert_ok!(GluttonProfiles::schedule(RuntimeOrigin::root(), 0, 20));
+		assert_ok!(GluttonProfiles::schedule(RuntimeOrigin::root(), 0, 10));
+		assert_ok!(GluttonProfiles::start(RuntimeOrigin::root(), 0));
+		run_to(3);
+
+		assert_eq!(
+			GluttonProfiles::status(),
+			LoadStatus {
+				running: Some(Running { profile: 0, started_at: 2 }),
+				phase: Some(0),
+				load: load(2, 0),
+				metrics: LoadMetrics { blocks: 2, ..Default::default() },
+				scheduled: vec![(10, 0), (20, 0)],
+			}
+		);
+	});
+}
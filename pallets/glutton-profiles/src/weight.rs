@@ -0,0 +1,56 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::weights::Weight;
+
+/// Weight functions needed for `pallet_glutton_profiles`.
+pub trait WeightInfo {
+	fn set_profile(p: u32) -> Weight;
+	fn remove_profile() -> Weight;
+	fn schedule() -> Weight;
+	fn cancel_schedule() -> Weight;
+	fn start() -> Weight;
+	fn stop() -> Weight;
+	fn on_initialize_idle() -> Weight;
+	fn on_initialize_phase(m: u32) -> Weight;
+}
+
+impl WeightInfo for () {
+	fn set_profile(_: u32) -> Weight {
+		Weight::MAX
+	}
+	fn remove_profile() -> Weight {
+		Weight::MAX
+	}
+	fn schedule() -> Weight {
+		Weight::MAX
+	}
+	fn cancel_schedule() -> Weight {
+		Weight::MAX
+	}
+	fn start() -> Weight {
+		Weight::MAX
+	}
+	fn stop() -> Weight {
+		Weight::MAX
+	}
+	fn on_initialize_idle() -> Weight {
+		Weight::MAX
+	}
+	fn on_initialize_phase(_: u32) -> Weight {
+		Weight::MAX
+	}
+}
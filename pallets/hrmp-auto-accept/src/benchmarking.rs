@@ -0,0 +1,136 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as HrmpAutoAccept;
+use frame_benchmarking::v2::*;
+use frame_system::RawOrigin;
+
+fn accepting_policy() -> ChannelPolicy {
+	ChannelPolicy {
+		mode: AcceptMode::AllowListOnly,
+		max_channels: 100,
+		request_back: true,
+		relay_fee: 1_000_000_000,
+		accept_deposit: 1_000_000_000,
+		request_deposit: 1_000_000_000,
+	}
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_policy() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, accepting_policy());
+
+		assert_eq!(Policy::<T>::get(), accepting_policy());
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_listing() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let para = ParaId::from(2_000u32);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, para, Some(Listing::Allowed));
+
+		assert_eq!(Listings::<T>::get(para), Some(Listing::Allowed));
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn confirm_channel() {
+		let caller: T::AccountId = whitelisted_caller();
+		let sender = ParaId::from(2_000u32);
+		Channels::<T>::insert(
+			sender,
+			ChannelState { inbound: Some(ChannelStatus::Accepting), outbound: None },
+		);
+		ChannelCount::<T>::put(1);
+		PendingAccepts::<T>::insert(sender, frame_system::Pallet::<T>::block_number());
+		frame_system::Pallet::<T>::set_block_number(
+			frame_system::Pallet::<T>::block_number().saturating_add(T::AcceptTimeout::get()),
+		);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), sender);
+
+		assert!(!PendingAccepts::<T>::contains_key(sender));
+	}
+
+	#[benchmark]
+	fn on_open_request() {
+		let sender = ParaId::from(2_000u32);
+		Policy::<T>::put(accepting_policy());
+		Listings::<T>::insert(sender, Listing::Allowed);
+
+		#[block]
+		{
+			HrmpAutoAccept::<T>::on_open_request(sender, 1_024, 8);
+		}
+	}
+
+	#[benchmark]
+	fn on_channel_accepted() {
+		let recipient = ParaId::from(2_000u32);
+		Channels::<T>::insert(
+			recipient,
+			ChannelState {
+				inbound: Some(ChannelStatus::Accepting),
+				outbound: Some(ChannelStatus::Requested),
+			},
+		);
+
+		#[block]
+		{
+			HrmpAutoAccept::<T>::on_channel_accepted(recipient);
+		}
+
+		assert_eq!(
+			Channels::<T>::get(recipient).and_then(|state| state.outbound),
+			Some(ChannelStatus::Accepted)
+		);
+	}
+
+	#[benchmark]
+	fn on_channel_closing() {
+		let para = ParaId::from(2_000u32);
+		Channels::<T>::insert(
+			para,
+			ChannelState { inbound: Some(ChannelStatus::Accepted), outbound: None },
+		);
+		ChannelCount::<T>::put(1);
+
+		#[block]
+		{
+			HrmpAutoAccept::<T>::on_channel_closing(para, T::SelfParaId::get());
+		}
+
+		assert!(!Channels::<T>::contains_key(para));
+	}
+
+	impl_benchmark_test_suite!(HrmpAutoAccept, crate::tests::new_test_ext(), crate::tests::Test);
+}
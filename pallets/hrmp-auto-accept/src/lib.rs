@@ -0,0 +1,589 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! HRMP auto accept pallet
+//!
+//! Opening an HRMP channel needs both parachains to act on the relay chain: the sender requests
+//! the channel and the recipient accepts it. For a system parachain both steps require a
+//! governance referendum, which does not scale with the number of parachains that want to talk
+//! to it.
+//!
+//! The relay chain notifies the recipient of a channel request with the
+//! `HrmpNewChannelOpenRequest` XCM instruction. This pallet handles the instruction by checking
+//! the request against the governance-set [`ChannelPolicy`] and the per parachain [`Listing`]s.
+//! If the request is acceptable, the pallet sends `hrmp_accept_open_channel` to the relay chain
+//! and, if [`ChannelPolicy::request_back`] is set, requests the channel in the opposite direction
+//! with `hrmp_init_open_channel`. The channels opened by the pallet are tracked in [`Channels`]
+//! until the relay chain reports them as closed.
+//!
+//! The relay chain does not notify the recipient when its acceptance was processed. An accepted
+//! request is therefore tracked as [`ChannelStatus::Accepting`] until anyone calls
+//! [`Pallet::confirm_channel`]: once the channel shows up in the ingress channels of this chain it
+//! is marked as [`ChannelStatus::Accepted`], and once [`Config::AcceptTimeout`] has passed without
+//! the channel opening the entry is dropped again. Requested channels move to
+//! [`ChannelStatus::Accepted`] when the relay chain sends `HrmpChannelAccepted`.
+//!
+//! The pallet implements the HRMP handlers of the XCM executor. Only the relay chain can send
+//! these instructions, the runtime needs a barrier which lets them through.
+//!
+//! ## Deposits and fees
+//!
+//! The relay chain does not charge HRMP deposits for channels with system parachains and lets
+//! them execute without paying. The calls are then sent with unpaid execution, which is the
+//! default of [`ChannelPolicy::relay_fee`]. A non-zero fee is withdrawn from the sovereign
+//! account of this chain on the relay chain to pay for the execution and the surplus is deposited
+//! back.
+//!
+//! If the relay chain charges deposits, [`ChannelPolicy::accept_deposit`] and
+//! [`ChannelPolicy::request_deposit`] are set to the amounts it reserves from the sovereign
+//! account. The message withdraws the deposits of its calls together with the fee and deposits
+//! them back before the calls are dispatched, so a sovereign account which can not cover the
+//! deposits fails the message before any channel call is made.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use alloc::{vec, vec::Vec};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use cumulus_primitives_core::ParaId;
+use frame_support::traits::{Contains, Get};
+use scale_info::TypeInfo;
+use sp_runtime::traits::Saturating;
+use xcm::latest::prelude::*;
+use xcm_executor::traits::{
+	HandleHrmpChannelAccepted, HandleHrmpChannelClosing, HandleHrmpNewChannelOpenRequest,
+};
+
+pub use pallet::*;
+pub use weight::WeightInfo;
+
+/// The channel calls of the relay chain `Hrmp` pallet.
+pub trait RelayHrmpCalls {
+	/// The encoded `hrmp_init_open_channel` call.
+	fn init_open_channel(
+		recipient: ParaId,
+		proposed_max_capacity: u32,
+		proposed_max_message_size: u32,
+	) -> Vec<u8>;
+
+	/// The encoded `hrmp_accept_open_channel` call.
+	fn accept_open_channel(sender: ParaId) -> Vec<u8>;
+}
+
+/// Which parachains get their channel requests accepted.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+	Default,
+)]
+pub enum AcceptMode {
+	/// No requests are accepted.
+	#[default]
+	Disabled,
+	/// Requests of all parachains that are not [`Listing::Denied`] are accepted.
+	AllParachains,
+	/// Only requests of [`Listing::Allowed`] parachains are accepted.
+	AllowListOnly,
+}
+
+/// The policy for accepting channel requests.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+	Debug,
+	Default,
+)]
+pub struct ChannelPolicy {
+	/// Which parachains get their channel requests accepted.
+	pub mode: AcceptMode,
+	/// The maximum number of parachains with channels opened by this pallet.
+	pub max_channels: u32,
+	/// Request a channel back to the parachain whose request was accepted.
+	///
+	/// The requested channel uses the capacity and message size of the accepted one.
+	pub request_back: bool,
+	/// The amount of the relay chain native asset to pay for the execution of the channel calls.
+	///
+	/// Zero sends the calls with unpaid execution.
+	pub relay_fee: u128,
+	/// The amount the relay chain reserves from the sovereign account for accepting a channel.
+	pub accept_deposit: u128,
+	/// The amount the relay chain reserves from the sovereign account for requesting a channel.
+	pub request_deposit: u128,
+}
+
+/// The listing of a parachain.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub enum Listing {
+	/// The requests of the parachain are accepted in [`AcceptMode::AllowListOnly`].
+	Allowed,
+	/// The requests of the parachain are never accepted.
+	Denied,
+}
+
+/// Why a channel request was not accepted.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub enum RejectReason {
+	/// Accepting channel requests is disabled.
+	Disabled,
+	/// The parachain is [`Listing::Denied`].
+	Denied,
+	/// The parachain is not [`Listing::Allowed`].
+	NotAllowed,
+	/// [`ChannelPolicy::max_channels`] is reached.
+	TooManyChannels,
+	/// The channel calls could not be sent to the relay chain.
+	SendFailed,
+}
+
+/// The state of one direction of a channel.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub enum ChannelStatus {
+	/// The channel was requested and waits for the recipient.
+	Requested,
+	/// This chain sent the acceptance of the channel and waits for the channel to open.
+	Accepting,
+	/// The channel was accepted by the recipient and opens at the next session.
+	Accepted,
+}
+
+/// The channels with a parachain.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	PartialEq,
+	Eq,
+	Debug,
+	Default,
+)]
+pub struct ChannelState {
+	/// The channel from the parachain to this chain.
+	pub inbound: Option<ChannelStatus>,
+	/// The channel from this chain to the parachain.
+	pub outbound: Option<ChannelStatus>,
+}
+
+impl ChannelState {
+	fn is_empty(&self) -> bool {
+		self.inbound.is_none() && self.outbound.is_none()
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+		/// The origin that can change the policy and the listings.
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The id of this parachain.
+		type SelfParaId: Get<ParaId>;
+
+		/// The channel calls of the relay chain.
+		type RelayCalls: RelayHrmpCalls;
+
+		/// The router used to send the channel calls to the relay chain.
+		type XcmSender: SendXcm;
+
+		/// The parachains with an open channel to this chain.
+		type IngressChannels: Contains<ParaId>;
+
+		/// The number of blocks after which an accepted channel which did not open is dropped.
+		#[pallet::constant]
+		type AcceptTimeout: Get<BlockNumberFor<Self>>;
+
+		/// Weight information for extrinsics and XCM handlers in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The policy for accepting channel requests.
+	#[pallet::storage]
+	pub type Policy<T: Config> = StorageValue<_, ChannelPolicy, ValueQuery>;
+
+	/// The parachains which are explicitly allowed or denied.
+	#[pallet::storage]
+	pub type Listings<T: Config> = StorageMap<_, Twox64Concat, ParaId, Listing, OptionQuery>;
+
+	/// The channels opened or requested by this pallet.
+	#[pallet::storage]
+	pub type Channels<T: Config> = StorageMap<_, Twox64Concat, ParaId, ChannelState, OptionQuery>;
+
+	/// The number of entries in [`Channels`].
+	#[pallet::storage]
+	pub type ChannelCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The block at which the acceptance of a [`ChannelStatus::Accepting`] channel was sent.
+	#[pallet::storage]
+	pub type PendingAccepts<T: Config> =
+		StorageMap<_, Twox64Concat, ParaId, BlockNumberFor<T>, OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The policy was changed.
+		PolicySet { policy: ChannelPolicy },
+		/// The listing of `para` was changed.
+		ListingSet { para: ParaId, listing: Option<Listing> },
+		/// The acceptance of the channel request of `sender` was sent to the relay chain.
+		ChannelAccepted { sender: ParaId },
+		/// The channel from `sender` is open.
+		ChannelOpened { sender: ParaId },
+		/// The channel from `sender` did not open within [`Config::AcceptTimeout`].
+		AcceptExpired { sender: ParaId },
+		/// A channel to `recipient` was requested.
+		ChannelRequested { recipient: ParaId },
+		/// The channel request of `sender` was not accepted.
+		ChannelRejected { sender: ParaId, reason: RejectReason },
+		/// `recipient` accepted the channel requested by this chain.
+		RequestAccepted { recipient: ParaId },
+		/// The channel from `sender` to `recipient` is closing.
+		ChannelClosing { sender: ParaId, recipient: ParaId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The channel from the parachain is not waiting to open.
+		NotAccepting,
+		/// The channel is not open yet and [`Config::AcceptTimeout`] has not passed.
+		NotYetOpen,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the policy for accepting channel requests.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`].
+		///
+		/// Parameters:
+		/// - `policy`: The new policy. It only applies to future requests.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_policy())]
+		pub fn set_policy(origin: OriginFor<T>, policy: ChannelPolicy) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Policy::<T>::put(&policy);
+			Self::deposit_event(Event::PolicySet { policy });
+
+			Ok(())
+		}
+
+		/// Allow or deny the channel requests of a parachain.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`].
+		///
+		/// Parameters:
+		/// - `para`: The parachain to list.
+		/// - `listing`: The new listing, `None` removes the listing.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::set_listing())]
+		pub fn set_listing(
+			origin: OriginFor<T>,
+			para: ParaId,
+			listing: Option<Listing>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+
+			Listings::<T>::set(para, listing);
+			Self::deposit_event(Event::ListingSet { para, listing });
+
+			Ok(())
+		}
+
+		/// Update a channel whose acceptance was sent to the relay chain.
+		///
+		/// The channel is marked as accepted if it is open, or dropped if it did not open within
+		/// [`Config::AcceptTimeout`].
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `sender`: The parachain which requested the channel.
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::confirm_channel())]
+		pub fn confirm_channel(origin: OriginFor<T>, sender: ParaId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let since = PendingAccepts::<T>::get(sender).ok_or(Error::<T>::NotAccepting)?;
+			let mut state = Channels::<T>::get(sender).ok_or(Error::<T>::NotAccepting)?;
+
+			if T::IngressChannels::contains(&sender) {
+				state.inbound = Some(ChannelStatus::Accepted);
+				Self::deposit_event(Event::ChannelOpened { sender });
+			} else {
+				let now = frame_system::Pallet::<T>::block_number();
+				ensure!(
+					now >= since.saturating_add(T::AcceptTimeout::get()),
+					Error::<T>::NotYetOpen
+				);
+				state.inbound = None;
+				Self::deposit_event(Event::AcceptExpired { sender });
+			}
+
+			PendingAccepts::<T>::remove(sender);
+			Self::update_channel(sender, state);
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Handle the channel request of `sender`.
+	pub(crate) fn on_open_request(sender: ParaId, max_message_size: u32, max_capacity: u32) {
+		let policy = Policy::<T>::get();
+		let state = Channels::<T>::get(sender);
+
+		if let Err(reason) = Self::check_request(&policy, sender, state.is_some()) {
+			Self::deposit_event(Event::ChannelRejected { sender, reason });
+			return;
+		}
+
+		let mut state = state.unwrap_or_default();
+		let request_back = policy.request_back && state.outbound.is_none();
+
+		let mut calls = vec![T::RelayCalls::accept_open_channel(sender)];
+		if request_back {
+			calls.push(T::RelayCalls::init_open_channel(sender, max_capacity, max_message_size));
+		}
+
+		let deposit = policy.accept_deposit.saturating_add(if request_back {
+			policy.request_deposit
+		} else {
+			0
+		});
+		if let Err(error) = send_xcm::<T::XcmSender>(
+			Location::parent(),
+			Self::relay_message(calls, policy.relay_fee, deposit),
+		) {
+			log::debug!(
+				target: LOG_TARGET,
+				"Failed to send the channel calls for {sender:?}: {error:?}",
+			);
+			Self::deposit_event(Event::ChannelRejected {
+				sender,
+				reason: RejectReason::SendFailed,
+			});
+			return;
+		}
+
+		if !Channels::<T>::contains_key(sender) {
+			ChannelCount::<T>::mutate(|count| count.saturating_inc());
+		}
+		if state.inbound != Some(ChannelStatus::Accepted) {
+			state.inbound = Some(ChannelStatus::Accepting);
+			PendingAccepts::<T>::insert(sender, frame_system::Pallet::<T>::block_number());
+		}
+		Self::deposit_event(Event::ChannelAccepted { sender });
+		if request_back {
+			state.outbound = Some(ChannelStatus::Requested);
+			Self::deposit_event(Event::ChannelRequested { recipient: sender });
+		}
+		Channels::<T>::insert(sender, state);
+	}
+
+	/// Handle the acceptance of a channel requested by this chain.
+	pub(crate) fn on_channel_accepted(recipient: ParaId) {
+		Channels::<T>::mutate(recipient, |state| {
+			if let Some(state) = state {
+				state.outbound = Some(ChannelStatus::Accepted);
+				Self::deposit_event(Event::RequestAccepted { recipient });
+			}
+		});
+	}
+
+	/// Handle the closing of the channel from `sender` to `recipient`.
+	pub(crate) fn on_channel_closing(sender: ParaId, recipient: ParaId) {
+		let own = T::SelfParaId::get();
+		let (para, outbound) = match (sender == own, recipient == own) {
+			(true, false) => (recipient, true),
+			(false, true) => (sender, false),
+			_ => return,
+		};
+
+		let Some(mut state) = Channels::<T>::get(para) else { return };
+		if outbound {
+			state.outbound = None;
+		} else {
+			state.inbound = None;
+			PendingAccepts::<T>::remove(para);
+		}
+
+		Self::update_channel(para, state);
+		Self::deposit_event(Event::ChannelClosing { sender, recipient });
+	}
+
+	/// Store the channels with `para`, removing the entry if there are none left.
+	fn update_channel(para: ParaId, state: ChannelState) {
+		if state.is_empty() {
+			Channels::<T>::remove(para);
+			ChannelCount::<T>::mutate(|count| count.saturating_dec());
+		} else {
+			Channels::<T>::insert(para, state);
+		}
+	}
+
+	/// Check the request of `sender` against the `policy`.
+	fn check_request(
+		policy: &ChannelPolicy,
+		sender: ParaId,
+		known: bool,
+	) -> Result<(), RejectReason> {
+		let listing = Listings::<T>::get(sender);
+
+		match (policy.mode, listing) {
+			(AcceptMode::Disabled, _) => return Err(RejectReason::Disabled),
+			(_, Some(Listing::Denied)) => return Err(RejectReason::Denied),
+			(AcceptMode::AllowListOnly, None) => return Err(RejectReason::NotAllowed),
+			_ => {},
+		}
+
+		if !known && ChannelCount::<T>::get() >= policy.max_channels {
+			return Err(RejectReason::TooManyChannels);
+		}
+
+		Ok(())
+	}
+
+	/// Build the message that dispatches `calls` as this parachain on the relay chain.
+	///
+	/// The `deposit` the calls reserve is withdrawn and deposited back before they are
+	/// dispatched, which fails the message early if the sovereign account can not cover it.
+	fn relay_message(calls: Vec<Vec<u8>>, relay_fee: u128, deposit: u128) -> Xcm<()> {
+		let sovereign = Location::new(0, Parachain(T::SelfParaId::get().into()));
+		let mut message = Vec::new();
+
+		if relay_fee == 0 {
+			message.push(UnpaidExecution { weight_limit: Unlimited, check_origin: None });
+		}
+		let withdrawn = relay_fee.saturating_add(deposit);
+		if withdrawn > 0 {
+			message.push(WithdrawAsset((Here, withdrawn).into()));
+		}
+		if relay_fee > 0 {
+			message.push(BuyExecution { fees: (Here, relay_fee).into(), weight_limit: Unlimited });
+		}
+		if deposit > 0 {
+			message.push(DepositAsset {
+				assets: Definite((Here, deposit).into()),
+				beneficiary: sovereign.clone(),
+			});
+		}
+
+		message.extend(calls.into_iter().map(|call| Transact {
+			origin_kind: OriginKind::Native,
+			fallback_max_weight: None,
+			call: call.into(),
+		}));
+
+		if relay_fee > 0 {
+			message.extend([
+				RefundSurplus,
+				DepositAsset { assets: Wild(AllCounted(1)), beneficiary: sovereign },
+			]);
+		}
+
+		Xcm(message)
+	}
+}
+
+const LOG_TARGET: &str = "runtime::hrmp-auto-accept";
+
+impl<T: Config> HandleHrmpNewChannelOpenRequest for Pallet<T> {
+	fn handle(sender: u32, max_message_size: u32, max_capacity: u32) -> XcmResult {
+		Self::on_open_request(sender.into(), max_message_size, max_capacity);
+		Ok(())
+	}
+}
+
+impl<T: Config> HandleHrmpChannelAccepted for Pallet<T> {
+	fn handle(recipient: u32) -> XcmResult {
+		Self::on_channel_accepted(recipient.into());
+		Ok(())
+	}
+}
+
+impl<T: Config> HandleHrmpChannelClosing for Pallet<T> {
+	fn handle(_initiator: u32, sender: u32, recipient: u32) -> XcmResult {
+		Self::on_channel_closing(sender.into(), recipient.into());
+		Ok(())
+	}
+}
@@ -0,0 +1,417 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for the HRMP Auto Accept Pallet
+
+use super::*;
+use crate as hrmp_auto_accept;
+use frame_support::{assert_noop, assert_ok, construct_runtime, derive_impl, parameter_types};
+use frame_system::EnsureRoot;
+use sp_io::TestExternalities;
+use sp_runtime::{BuildStorage, DispatchError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		HrmpAutoAccept: hrmp_auto_accept,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+parameter_types! {
+	pub const SelfParaId: ParaId = ParaId::new(SELF);
+	pub static SentMessages: Vec<(Location, Xcm<()>)> = vec![];
+	pub static SendFails: bool = false;
+	pub static OpenChannels: Vec<ParaId> = vec![];
+	pub const AcceptTimeout: u64 = 10;
+}
+
+pub struct TestIngressChannels;
+impl Contains<ParaId> for TestIngressChannels {
+	fn contains(para: &ParaId) -> bool {
+		OpenChannels::get().contains(para)
+	}
+}
+
+pub struct TestSender;
+impl SendXcm for TestSender {
+	type Ticket = (Location, Xcm<()>);
+
+	fn validate(
+		dest: &mut Option<Location>,
+		msg: &mut Option<Xcm<()>>,
+	) -> SendResult<Self::Ticket> {
+		if SendFails::get() {
+			return Err(SendError::Transport("failed"));
+		}
+		Ok(((dest.take().unwrap(), msg.take().unwrap()), Assets::new()))
+	}
+
+	fn deliver(ticket: Self::Ticket) -> Result<XcmHash, SendError> {
+		SentMessages::mutate(|sent| sent.push(ticket));
+		Ok([0; 32])
+	}
+}
+
+pub struct TestCalls;
+impl RelayHrmpCalls for TestCalls {
+	fn init_open_channel(
+		recipient: ParaId,
+		proposed_max_capacity: u32,
+		proposed_max_message_size: u32,
+	) -> Vec<u8> {
+		(0u8, recipient, proposed_max_capacity, proposed_max_message_size).encode()
+	}
+
+	fn accept_open_channel(sender: ParaId) -> Vec<u8> {
+		(1u8, sender).encode()
+	}
+}
+
+impl Config for Test {
+	type AdminOrigin = EnsureRoot<u64>;
+	type SelfParaId = SelfParaId;
+	type RelayCalls = TestCalls;
+	type XcmSender = TestSender;
+	type IngressChannels = TestIngressChannels;
+	type AcceptTimeout = AcceptTimeout;
+	type WeightInfo = ();
+}
+
+pub(crate) fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		SentMessages::take();
+		SendFails::set(false);
+		OpenChannels::take();
+	});
+	ext
+}
+
+const PARA: u32 = 2_000;
+const OTHER_PARA: u32 = 2_001;
+const SELF: u32 = 1_000;
+
+fn set_policy(mode: AcceptMode, max_channels: u32, request_back: bool, relay_fee: u128) {
+	assert_ok!(HrmpAutoAccept::set_policy(
+		RuntimeOrigin::root(),
+		ChannelPolicy { mode, max_channels, request_back, relay_fee, ..Default::default() },
+	));
+}
+
+fn request(sender: u32) {
+	assert_ok!(<HrmpAutoAccept as HandleHrmpNewChannelOpenRequest>::handle(sender, 1_024, 8));
+}
+
+fn transact(call: Vec<u8>) -> Instruction<()> {
+	Transact { origin_kind: OriginKind::Native, fallback_max_weight: None, call: call.into() }
+}
+
+fn rejected(sender: u32, reason: RejectReason) -> RuntimeEvent {
+	Event::ChannelRejected { sender: sender.into(), reason }.into()
+}
+
+#[test]
+fn requests_are_rejected_by_default() {
+	new_test_ext().execute_with(|| {
+		request(PARA);
+
+		System::assert_last_event(rejected(PARA, RejectReason::Disabled));
+		assert!(SentMessages::get().is_empty());
+		assert_eq!(ChannelCount::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn accepts_and_requests_back() {
+	new_test_ext().execute_with(|| {
+		set_policy(AcceptMode::AllParachains, 10, true, 0);
+		request(PARA);
+
+		assert_eq!(
+			SentMessages::get(),
+			vec![(
+				Location::parent(),
+				Xcm(vec![
+					UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+					transact(TestCalls::accept_open_channel(PARA.into())),
+					transact(TestCalls::init_open_channel(PARA.into(), 8, 1_024)),
+				])
+			)]
+		);
+		System::assert_has_event(Event::ChannelAccepted { sender: PARA.into() }.into());
+		System::assert_last_event(Event::ChannelRequested { recipient: PARA.into() }.into());
+		assert_eq!(
+			Channels::<Test>::get(ParaId::from(PARA)),
+			Some(ChannelState {
+				inbound: Some(ChannelStatus::Accepting),
+				outbound: Some(ChannelStatus::Requested),
+			})
+		);
+		assert_eq!(ChannelCount::<Test>::get(), 1);
+
+		assert_ok!(<HrmpAutoAccept as HandleHrmpChannelAccepted>::handle(PARA));
+		System::assert_last_event(Event::RequestAccepted { recipient: PARA.into() }.into());
+		assert_eq!(
+			Channels::<Test>::get(ParaId::from(PARA)).and_then(|state| state.outbound),
+			Some(ChannelStatus::Accepted)
+		);
+	});
+}
+
+#[test]
+fn relay_fee_pays_for_execution() {
+	new_test_ext().execute_with(|| {
+		set_policy(AcceptMode::AllParachains, 10, false, 100);
+		request(PARA);
+
+		let fees: Asset = (Here, 100).into();
+		assert_eq!(
+			SentMessages::get(),
+			vec![(
+				Location::parent(),
+				Xcm(vec![
+					WithdrawAsset(fees.clone().into()),
+					BuyExecution { fees, weight_limit: Unlimited },
+					transact(TestCalls::accept_open_channel(PARA.into())),
+					RefundSurplus,
+					DepositAsset {
+						assets: Wild(AllCounted(1)),
+						beneficiary: Location::new(0, Parachain(SELF)),
+					},
+				])
+			)]
+		);
+		assert_eq!(
+			Channels::<Test>::get(ParaId::from(PARA)),
+			Some(ChannelState { inbound: Some(ChannelStatus::Accepting), outbound: None })
+		);
+	});
+}
+
+#[test]
+fn deposits_are_checked_before_the_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(HrmpAutoAccept::set_policy(
+			RuntimeOrigin::root(),
+			ChannelPolicy {
+				mode: AcceptMode::AllParachains,
+				max_channels: 10,
+				request_back: true,
+				relay_fee: 0,
+				accept_deposit: 20,
+				request_deposit: 30,
+			},
+		));
+		request(PARA);
+
+		assert_eq!(
+			SentMessages::take(),
+			vec![(
+				Location::parent(),
+				Xcm(vec![
+					UnpaidExecution { weight_limit: Unlimited, check_origin: None },
+					WithdrawAsset((Here, 50).into()),
+					DepositAsset {
+						assets: Definite((Here, 50).into()),
+						beneficiary: Location::new(0, Parachain(SELF)),
+					},
+					transact(TestCalls::accept_open_channel(PARA.into())),
+					transact(TestCalls::init_open_channel(PARA.into(), 8, 1_024)),
+				])
+			)]
+		);
+
+		// With a fee the deposits are withdrawn together with it.
+		set_policy(AcceptMode::AllParachains, 10, false, 100);
+		Policy::<Test>::mutate(|policy| policy.accept_deposit = 20);
+		request(OTHER_PARA);
+
+		assert_eq!(
+			SentMessages::take(),
+			vec![(
+				Location::parent(),
+				Xcm(vec![
+					WithdrawAsset((Here, 120).into()),
+					BuyExecution { fees: (Here, 100).into(), weight_limit: Unlimited },
+					DepositAsset {
+						assets: Definite((Here, 20).into()),
+						beneficiary: Location::new(0, Parachain(SELF)),
+					},
+					transact(TestCalls::accept_open_channel(OTHER_PARA.into())),
+					RefundSurplus,
+					DepositAsset {
+						assets: Wild(AllCounted(1)),
+						beneficiary: Location::new(0, Parachain(SELF)),
+					},
+				])
+			)]
+		);
+	});
+}
+
+#[test]
+fn accepted_channels_are_confirmed_once_open() {
+	new_test_ext().execute_with(|| {
+		set_policy(AcceptMode::AllParachains, 10, false, 0);
+		request(PARA);
+		assert_eq!(PendingAccepts::<Test>::get(ParaId::from(PARA)), Some(1));
+
+		assert_noop!(
+			HrmpAutoAccept::confirm_channel(RuntimeOrigin::signed(1), PARA.into()),
+			Error::<Test>::NotYetOpen
+		);
+		assert_noop!(
+			HrmpAutoAccept::confirm_channel(RuntimeOrigin::signed(1), OTHER_PARA.into()),
+			Error::<Test>::NotAccepting
+		);
+
+		OpenChannels::set(vec![PARA.into()]);
+		assert_ok!(HrmpAutoAccept::confirm_channel(RuntimeOrigin::signed(1), PARA.into()));
+		System::assert_last_event(Event::ChannelOpened { sender: PARA.into() }.into());
+		assert_eq!(
+			Channels::<Test>::get(ParaId::from(PARA)),
+			Some(ChannelState { inbound: Some(ChannelStatus::Accepted), outbound: None })
+		);
+		assert_eq!(PendingAccepts::<Test>::get(ParaId::from(PARA)), None);
+	});
+}
+
+#[test]
+fn accepted_channels_expire() {
+	new_test_ext().execute_with(|| {
+		set_policy(AcceptMode::AllParachains, 10, true, 0);
+		request(PARA);
+
+		System::set_block_number(1 + AcceptTimeout::get());
+		assert_ok!(HrmpAutoAccept::confirm_channel(RuntimeOrigin::signed(1), PARA.into()));
+		System::assert_last_event(Event::AcceptExpired { sender: PARA.into() }.into());
+
+		// The requested channel back is still tracked.
+		assert_eq!(
+			Channels::<Test>::get(ParaId::from(PARA)),
+			Some(ChannelState { inbound: None, outbound: Some(ChannelStatus::Requested) })
+		);
+		assert_eq!(ChannelCount::<Test>::get(), 1);
+
+		assert_ok!(<HrmpAutoAccept as HandleHrmpChannelClosing>::handle(PARA, SELF, PARA));
+		assert_eq!(Channels::<Test>::get(ParaId::from(PARA)), None);
+		assert_eq!(ChannelCount::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn listings_are_respected() {
+	new_test_ext().execute_with(|| {
+		set_policy(AcceptMode::AllowListOnly, 10, false, 0);
+		request(PARA);
+		System::assert_last_event(rejected(PARA, RejectReason::NotAllowed));
+
+		assert_ok!(HrmpAutoAccept::set_listing(
+			RuntimeOrigin::root(),
+			PARA.into(),
+			Some(Listing::Allowed)
+		));
+		request(PARA);
+		System::assert_last_event(Event::ChannelAccepted { sender: PARA.into() }.into());
+
+		set_policy(AcceptMode::AllParachains, 10, false, 0);
+		assert_ok!(HrmpAutoAccept::set_listing(
+			RuntimeOrigin::root(),
+			OTHER_PARA.into(),
+			Some(Listing::Denied)
+		));
+		request(OTHER_PARA);
+		System::assert_last_event(rejected(OTHER_PARA, RejectReason::Denied));
+		assert_eq!(SentMessages::get().len(), 1);
+	});
+}
+
+#[test]
+fn max_channels_is_respected() {
+	new_test_ext().execute_with(|| {
+		set_policy(AcceptMode::AllParachains, 1, false, 0);
+		request(PARA);
+		request(OTHER_PARA);
+		System::assert_last_event(rejected(OTHER_PARA, RejectReason::TooManyChannels));
+
+		// A parachain that already has a channel is not counted twice.
+		request(PARA);
+		System::assert_last_event(Event::ChannelAccepted { sender: PARA.into() }.into());
+		assert_eq!(ChannelCount::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn send_failure_rejects() {
+	new_test_ext().execute_with(|| {
+		set_policy(AcceptMode::AllParachains, 10, true, 0);
+		SendFails::set(true);
+		request(PARA);
+
+		System::assert_last_event(rejected(PARA, RejectReason::SendFailed));
+		assert_eq!(Channels::<Test>::get(ParaId::from(PARA)), None);
+		assert_eq!(ChannelCount::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn closing_channels_are_removed() {
+	new_test_ext().execute_with(|| {
+		set_policy(AcceptMode::AllParachains, 10, true, 0);
+		request(PARA);
+
+		// Channels of other parachains are ignored.
+		assert_ok!(<HrmpAutoAccept as HandleHrmpChannelClosing>::handle(PARA, OTHER_PARA, PARA));
+		assert_eq!(ChannelCount::<Test>::get(), 1);
+
+		assert_ok!(<HrmpAutoAccept as HandleHrmpChannelClosing>::handle(PARA, SELF, PARA));
+		System::assert_last_event(
+			Event::ChannelClosing { sender: SELF.into(), recipient: PARA.into() }.into(),
+		);
+		assert_eq!(
+			Channels::<Test>::get(ParaId::from(PARA)),
+			Some(ChannelState { inbound: Some(ChannelStatus::Accepting), outbound: None })
+		);
+
+		assert_ok!(<HrmpAutoAccept as HandleHrmpChannelClosing>::handle(PARA, PARA, SELF));
+		assert_eq!(Channels::<Test>::get(ParaId::from(PARA)), None);
+		assert_eq!(PendingAccepts::<Test>::get(ParaId::from(PARA)), None);
+		assert_eq!(ChannelCount::<Test>::get(), 0);
+	});
+}
+
+#[test]
+fn only_admin_can_change_policy() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			HrmpAutoAccept::set_policy(RuntimeOrigin::signed(1), ChannelPolicy::default()),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			HrmpAutoAccept::set_listing(RuntimeOrigin::signed(1), PARA.into(), None),
+			DispatchError::BadOrigin
+		);
+	});
+}
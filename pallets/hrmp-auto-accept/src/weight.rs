@@ -0,0 +1,48 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::weights::Weight;
+
+/// Weight functions needed for `pallet_hrmp_auto_accept`.
+pub trait WeightInfo {
+	fn set_policy() -> Weight;
+	fn set_listing() -> Weight;
+	fn confirm_channel() -> Weight;
+	fn on_open_request() -> Weight;
+	fn on_channel_accepted() -> Weight;
+	fn on_channel_closing() -> Weight;
+}
+
+impl WeightInfo for () {
+	fn set_policy() -> Weight {
+		Weight::MAX
+	}
+	fn set_listing() -> Weight {
+		Weight::MAX
+	}
+	fn confirm_channel() -> Weight {
+		Weight::MAX
+	}
+	fn on_open_request() -> Weight {
+		Weight::MAX
+	}
+	fn on_channel_accepted() -> Weight {
+		Weight::MAX
+	}
+	fn on_channel_closing() -> Weight {
+		Weight::MAX
+	}
+}
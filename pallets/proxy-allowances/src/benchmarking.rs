@@ -0,0 +1,169 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as ProxyAllowances;
+use frame_benchmarking::v2::*;
+use frame_support::{
+	traits::{fungible::MutateHold, Get},
+	BoundedVec,
+};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, One, StaticLookup, Zero};
+
+/// Adds `delegate` as an allowance proxy of `delegator` with the maximum number of allowances.
+///
+/// The allowance of `asset(0)`, which is spent by the spending call, comes last so each lookup of
+/// it goes through all allowances.
+fn setup_allowances<T: Config>(delegator: &T::AccountId, delegate: &T::AccountId) {
+	T::BenchmarkHelper::fund(delegator);
+	pallet_proxy::Pallet::<T>::add_proxy_delegate(
+		delegator,
+		delegate.clone(),
+		T::AllowanceProxyType::get(),
+		Zero::zero(),
+	)
+	.expect("the delegator is funded; qed");
+
+	let allowances = (0..T::MaxAllowances::get())
+		.rev()
+		.map(|index| Allowance {
+			asset: T::BenchmarkHelper::asset(index),
+			limit: T::Balance::max_value(),
+			period: One::one(),
+			period_start: Zero::zero(),
+			spent: Zero::zero(),
+		})
+		.collect::<Vec<_>>();
+	let deposit = T::AllowanceDeposit::get().saturating_mul(T::MaxAllowances::get().into());
+	<T as Config>::Currency::hold(&HoldReason::AllowanceDeposit.into(), delegator, deposit)
+		.expect("the delegator is funded; qed");
+	Allowances::<T>::insert(
+		delegator,
+		delegate,
+		(BoundedVec::try_from(allowances).expect("at most `MaxAllowances`; qed"), deposit),
+	);
+}
+
+/// Removes the allowance proxy of `delegator`, leaving its allowances behind.
+fn remove_allowance_proxy<T: Config>(delegator: &T::AccountId, delegate: &T::AccountId) {
+	pallet_proxy::Pallet::<T>::remove_proxy_delegate(
+		delegator,
+		delegate.clone(),
+		T::AllowanceProxyType::get(),
+		Zero::zero(),
+	)
+	.expect("the proxy was added; qed");
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	// The worst case clears the maximum number of allowances left behind by a removed proxy and
+	// adds the proxy again.
+	#[benchmark]
+	fn set_allowance() {
+		let delegator: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		setup_allowances::<T>(&delegator, &delegate);
+		remove_allowance_proxy::<T>(&delegator, &delegate);
+		let asset = T::BenchmarkHelper::asset(0);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(delegator),
+			T::Lookup::unlookup(delegate),
+			asset,
+			One::one(),
+			One::one(),
+		);
+	}
+
+	// The worst case removes the last of the maximum number of allowances.
+	#[benchmark]
+	fn remove_allowance() {
+		let delegator: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		setup_allowances::<T>(&delegator, &delegate);
+		let asset = T::BenchmarkHelper::asset(0);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator.clone()), T::Lookup::unlookup(delegate.clone()), asset);
+
+		assert_eq!(
+			Allowances::<T>::get(&delegator, &delegate).0.len() as u32,
+			T::MaxAllowances::get().saturating_sub(1)
+		);
+	}
+
+	// The weight of the call itself and of `dispatch_metered` for each call it dispatches is added
+	// to the weight of `proxy`, the benchmark measures the lookup of the proxy and the metering of
+	// the call.
+	#[benchmark]
+	fn proxy() {
+		let delegator: T::AccountId = account("delegator", 0, 0);
+		let delegate: T::AccountId = whitelisted_caller();
+		setup_allowances::<T>(&delegator, &delegate);
+		let call = T::BenchmarkHelper::spending_call();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(delegate.clone()),
+			T::Lookup::unlookup(delegator.clone()),
+			Box::new(call),
+		);
+
+		let (allowances, _) = Allowances::<T>::get(&delegator, &delegate);
+		assert!(!allowances[allowances.len() - 1].spent.is_zero());
+	}
+
+	// The weight of the call itself is added to the weight of `dispatch_metered`.
+	#[benchmark]
+	fn dispatch_metered() {
+		let delegator: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		setup_allowances::<T>(&delegator, &delegate);
+		Metering::<T>::put((delegator.clone(), delegate.clone(), false));
+		let call = T::BenchmarkHelper::spending_call();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(delegator.clone()), Box::new(call));
+
+		let (allowances, _) = Allowances::<T>::get(&delegator, &delegate);
+		assert!(!allowances[allowances.len() - 1].spent.is_zero());
+	}
+
+	#[benchmark]
+	fn clear_allowances() {
+		let delegator: T::AccountId = account("delegator", 0, 0);
+		let delegate: T::AccountId = account("delegate", 0, 0);
+		setup_allowances::<T>(&delegator, &delegate);
+		remove_allowance_proxy::<T>(&delegator, &delegate);
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			T::Lookup::unlookup(delegator.clone()),
+			T::Lookup::unlookup(delegate.clone()),
+		);
+
+		assert!(!Allowances::<T>::contains_key(&delegator, &delegate));
+	}
+
+	impl_benchmark_test_suite!(ProxyAllowances, crate::tests::new_test_ext(), crate::tests::Test);
+}
@@ -0,0 +1,656 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Proxy allowances pallet
+//!
+//! The proxy types of `pallet_proxy` allow or forbid whole categories of calls. This pallet adds
+//! proxies that may only spend up to an allowance of each asset per period:
+//!
+//! - The delegator sets an allowance per asset with [`Pallet::set_allowance`], which adds the
+//!   delegate as a proxy of [`Config::AllowanceProxyType`] in `pallet_proxy` if it isn't one yet.
+//!   The allowance is a limit and a period, after which the spent amount resets. A deposit of
+//!   [`Config::AllowanceDeposit`] is held from the delegator for every allowance.
+//! - The delegate dispatches calls of the delegator with [`Pallet::proxy`]. Every call, including
+//!   the calls nested in batches, is inspected by [`Config::Spending`] when it is dispatched. Calls
+//!   it doesn't know are rejected, and the amounts spent by the known ones are metered against the
+//!   allowances. A call spending more than what is left of the allowance is rejected.
+//!
+//! [`Pallet::proxy`] wraps every call it dispatches, down to the calls nested in batches, into
+//! [`Pallet::dispatch_metered`], which charges the allowances and dispatches the call in the same
+//! storage layer. A call which fails doesn't spend the allowances, even inside a batch which goes
+//! on after it.
+//!
+//! Removing the last allowance of a delegate also removes the proxy. A proxy removed through
+//! `pallet_proxy` leaves its allowances behind until anyone clears them with
+//! [`Pallet::clear_allowances`], or the delegator adds the proxy again with
+//! [`Pallet::set_allowance`], which starts over from no allowances.
+//!
+//! The [`Config::AllowanceProxyType`] must not allow any call through `pallet_proxy` itself, as
+//! calls dispatched there are not metered. Nor may `pallet_proxy` add such proxies, which
+//! [`ExcludeAllowanceProxies`] takes care of as part of the call filter of the runtime.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use alloc::{boxed::Box, vec::Vec};
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{Contains, Get, IsSubType, IsType},
+	weights::Weight,
+};
+use scale_info::TypeInfo;
+
+pub use pallet::*;
+pub use weight::WeightInfo;
+
+/// Inspects the assets spent by a call dispatched through an allowance proxy.
+pub trait InspectSpending<Call, AccountId, AssetKind, Balance> {
+	/// The assets and amounts spent from the account of `who` by `call`.
+	///
+	/// Returns `None` if the call must not be dispatched through an allowance proxy. Calls with
+	/// [`Self::nested_calls`] are not inspected, their nested calls are.
+	fn spending(who: &AccountId, call: &Call) -> Option<Vec<(AssetKind, Balance)>>;
+
+	/// The worst case weight of [`Self::spending`], like the balances it reads.
+	fn spending_weight() -> Weight;
+
+	/// The calls dispatched by `call` if it only dispatches other calls, like a batch.
+	fn nested_calls(call: &Call) -> Option<&[Call]>;
+
+	/// Mutable access to [`Self::nested_calls`].
+	fn nested_calls_mut(call: &mut Call) -> Option<&mut Vec<Call>>;
+}
+
+/// Provides the arguments for the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AccountId, AssetKind, Call> {
+	/// The asset to set allowances for.
+	fn asset(index: u32) -> AssetKind;
+
+	/// A call spending some of `asset(0)`, which succeeds once the delegator is funded.
+	fn spending_call() -> Call;
+
+	/// Fund `who` for the deposits of the proxy and the allowances and [`Self::spending_call`].
+	fn fund(who: &AccountId);
+}
+
+/// An allowance of an asset for a delegate.
+#[derive(
+	Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug,
+)]
+pub struct Allowance<AssetKind, Balance, BlockNumber> {
+	/// The asset the allowance is for.
+	pub asset: AssetKind,
+	/// The amount which can be spent per period.
+	pub limit: Balance,
+	/// The length of a period.
+	pub period: BlockNumber,
+	/// The start of the current period.
+	pub period_start: BlockNumber,
+	/// The amount spent in the current period.
+	pub spent: Balance,
+}
+
+/// Filters out the calls of `pallet_proxy` adding proxies of [`Config::AllowanceProxyType`], which
+/// are only added by [`Pallet::set_allowance`].
+pub struct ExcludeAllowanceProxies<T>(core::marker::PhantomData<T>);
+impl<T: Config> Contains<<T as frame_system::Config>::RuntimeCall> for ExcludeAllowanceProxies<T> {
+	fn contains(call: &<T as frame_system::Config>::RuntimeCall) -> bool {
+		let call = <T as pallet_proxy::Config>::RuntimeCall::from_ref(call);
+		match IsSubType::<pallet_proxy::Call<T>>::is_sub_type(call) {
+			Some(
+				pallet_proxy::Call::add_proxy { proxy_type, .. } |
+				pallet_proxy::Call::create_pure { proxy_type, .. },
+			) => proxy_type != &T::AllowanceProxyType::get(),
+			_ => true,
+		}
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{
+		dispatch::GetDispatchInfo,
+		pallet_prelude::*,
+		storage::with_storage_layer,
+		traits::{
+			fungible::{self, MutateHold},
+			tokens::Precision,
+			OriginTrait,
+		},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{
+		AtLeast32BitUnsigned, BlockNumberProvider, Dispatchable, Saturating, StaticLookup, Zero,
+	};
+
+	type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+	pub(crate) type ProxyBlockNumberOf<T> =
+		<<T as pallet_proxy::Config>::BlockNumberProvider as BlockNumberProvider>::BlockNumber;
+	pub(crate) type AllowanceOf<T> =
+		Allowance<<T as Config>::AssetKind, <T as Config>::Balance, ProxyBlockNumberOf<T>>;
+	pub(crate) type DepositBalanceOf<T> = <<T as Config>::Currency as fungible::Inspect<
+		<T as frame_system::Config>::AccountId,
+	>>::Balance;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config:
+		frame_system::Config<RuntimeEvent: From<Event<Self>>>
+		+ pallet_proxy::Config<RuntimeCall: From<Call<Self>> + IsSubType<Call<Self>>>
+	{
+		/// The assets allowances are set for.
+		type AssetKind: Parameter + MaxEncodedLen;
+
+		/// The balance of the assets.
+		type Balance: Parameter + MaxEncodedLen + AtLeast32BitUnsigned + Copy;
+
+		/// The proxy type of the delegates that can spend allowances.
+		type AllowanceProxyType: Get<<Self as pallet_proxy::Config>::ProxyType>;
+
+		/// Inspects the assets spent by the calls dispatched through [`Pallet::proxy`].
+		type Spending: InspectSpending<
+			<Self as pallet_proxy::Config>::RuntimeCall,
+			Self::AccountId,
+			Self::AssetKind,
+			Self::Balance,
+		>;
+
+		/// The maximum number of assets with an allowance per delegate.
+		#[pallet::constant]
+		type MaxAllowances: Get<u32>;
+
+		/// The currency the deposits of the allowances are held in.
+		type Currency: fungible::MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// The deposit held from the delegator for every allowance.
+		#[pallet::constant]
+		type AllowanceDeposit: Get<DepositBalanceOf<Self>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<
+			Self::AccountId,
+			Self::AssetKind,
+			<Self as pallet_proxy::Config>::RuntimeCall,
+		>;
+	}
+
+	/// A reason for the pallet placing a hold on funds.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// The deposit of the allowances of a delegate.
+		#[codec(index = 0)]
+		AllowanceDeposit,
+	}
+
+	/// The allowances of the delegates and the deposit held for them, by delegator and delegate.
+	#[pallet::storage]
+	pub type Allowances<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::AccountId,
+		(BoundedVec<AllowanceOf<T>, T::MaxAllowances>, DepositBalanceOf<T>),
+		ValueQuery,
+	>;
+
+	/// The delegator and the delegate of the [`Pallet::proxy`] call in progress, and whether
+	/// [`Pallet::dispatch_metered`] charged the call it dispatches next.
+	#[pallet::storage]
+	pub type Metering<T: Config> = StorageValue<_, (T::AccountId, T::AccountId, bool), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An allowance was set.
+		AllowanceSet {
+			delegator: T::AccountId,
+			delegate: T::AccountId,
+			asset: T::AssetKind,
+			limit: T::Balance,
+			period: ProxyBlockNumberOf<T>,
+		},
+		/// An allowance was removed.
+		AllowanceRemoved { delegator: T::AccountId, delegate: T::AccountId, asset: T::AssetKind },
+		/// The allowances of a removed proxy were cleared.
+		AllowancesCleared { delegator: T::AccountId, delegate: T::AccountId },
+		/// A delegate spent from an allowance.
+		Spent {
+			delegator: T::AccountId,
+			delegate: T::AccountId,
+			asset: T::AssetKind,
+			amount: T::Balance,
+			remaining: T::Balance,
+		},
+		/// A call was dispatched through an allowance proxy.
+		ProxyExecuted { result: DispatchResult },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The delegate is not an allowance proxy of the delegator.
+		NotAllowanceProxy,
+		/// Announced proxies can not spend allowances.
+		Unannounced,
+		/// The period of an allowance must not be zero.
+		ZeroPeriod,
+		/// The delegate has too many allowances.
+		TooManyAllowances,
+		/// The delegate has no allowance for the asset.
+		NoAllowance,
+		/// The call spends more than what is left of the allowance.
+		AllowanceExceeded,
+		/// The call can not be dispatched through an allowance proxy.
+		CallNotAllowed,
+		/// Metered calls are only dispatched by [`Pallet::proxy`].
+		NotMetered,
+		/// The allowances of a delegate which is still an allowance proxy can't be cleared.
+		StillAllowanceProxy,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the allowance of `delegate` for `asset`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the delegator. `delegate` is added
+		/// as a proxy of [`Config::AllowanceProxyType`] without delay if it isn't one, in which
+		/// case the allowances left behind by a removed proxy are cleared first. An existing
+		/// allowance for the asset is replaced and starts a new period.
+		///
+		/// The deposit of the allowance is held from the delegator.
+		///
+		/// Parameters:
+		/// - `delegate`: The allowance proxy of the caller.
+		/// - `asset`: The asset the allowance is for.
+		/// - `limit`: The amount of `asset` which can be spent per period.
+		/// - `period`: The length of a period, in the blocks of the proxy pallet.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_allowance())]
+		pub fn set_allowance(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			asset: T::AssetKind,
+			limit: T::Balance,
+			period: ProxyBlockNumberOf<T>,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			ensure!(!period.is_zero(), Error::<T>::ZeroPeriod);
+			match pallet_proxy::Pallet::<T>::find_proxy(
+				&delegator,
+				&delegate,
+				Some(T::AllowanceProxyType::get()),
+			) {
+				Ok(def) => ensure!(def.delay.is_zero(), Error::<T>::Unannounced),
+				Err(_) => {
+					// The allowances of a removed proxy are not revived by adding it again.
+					Self::clear(&delegator, &delegate)?;
+					pallet_proxy::Pallet::<T>::add_proxy_delegate(
+						&delegator,
+						delegate.clone(),
+						T::AllowanceProxyType::get(),
+						Zero::zero(),
+					)?;
+				},
+			}
+
+			let allowance = Allowance {
+				asset: asset.clone(),
+				limit,
+				period,
+				period_start: T::BlockNumberProvider::current_block_number(),
+				spent: Zero::zero(),
+			};
+			Allowances::<T>::try_mutate(&delegator, &delegate, |(allowances, deposit)| {
+				match allowances.iter_mut().find(|a| a.asset == asset) {
+					Some(existing) => *existing = allowance,
+					None =>
+						allowances.try_push(allowance).map_err(|_| Error::<T>::TooManyAllowances)?,
+				}
+				Self::update_deposit(&delegator, deposit, allowances.len())
+			})?;
+
+			Self::deposit_event(Event::AllowanceSet { delegator, delegate, asset, limit, period });
+			Ok(())
+		}
+
+		/// Remove the allowance of `delegate` for `asset`.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the delegator. The deposit of the
+		/// allowance is released, and removing the last allowance also removes the proxy.
+		///
+		/// Parameters:
+		/// - `delegate`: The delegate to remove the allowance from.
+		/// - `asset`: The asset of the allowance.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::remove_allowance())]
+		pub fn remove_allowance(
+			origin: OriginFor<T>,
+			delegate: AccountIdLookupOf<T>,
+			asset: T::AssetKind,
+		) -> DispatchResult {
+			let delegator = ensure_signed(origin)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+
+			let emptied =
+				Allowances::<T>::try_mutate_exists(&delegator, &delegate, |maybe_allowances| {
+					let (allowances, deposit) =
+						maybe_allowances.as_mut().ok_or(Error::<T>::NoAllowance)?;
+					let position = allowances
+						.iter()
+						.position(|a| a.asset == asset)
+						.ok_or(Error::<T>::NoAllowance)?;
+					allowances.remove(position);
+					Self::update_deposit(&delegator, deposit, allowances.len())?;
+					let emptied = allowances.is_empty();
+					if emptied {
+						*maybe_allowances = None;
+					}
+					Ok::<_, DispatchError>(emptied)
+				})?;
+
+			Self::deposit_event(Event::AllowanceRemoved {
+				delegator: delegator.clone(),
+				delegate: delegate.clone(),
+				asset,
+			});
+			if emptied {
+				// The proxy may already have been removed through `pallet_proxy`.
+				let _ = pallet_proxy::Pallet::<T>::remove_proxy_delegate(
+					&delegator,
+					delegate,
+					T::AllowanceProxyType::get(),
+					Zero::zero(),
+				);
+			}
+			Ok(())
+		}
+
+		/// Dispatch `call` from the account of `real`, metering the assets it spends against the
+		/// allowances of the caller.
+		///
+		/// The dispatch origin for this call must be _Signed_ by an allowance proxy of `real`.
+		/// Calls which are not known to [`Config::Spending`] or spend more than what is left of an
+		/// allowance are rejected, which also applies to the calls nested in batches.
+		///
+		/// Parameters:
+		/// - `real`: The account that the proxy will make the call on behalf of.
+		/// - `call`: The call to be made by the `real` account.
+		#[pallet::call_index(2)]
+		#[pallet::weight({
+			let di = call.get_dispatch_info();
+			(T::WeightInfo::proxy()
+				// AccountData for inner call origin accountdata.
+				.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+				.saturating_add(Pallet::<T>::metering_weight(call))
+				.saturating_add(di.call_weight),
+			di.class)
+		})]
+		pub fn proxy(
+			origin: OriginFor<T>,
+			real: AccountIdLookupOf<T>,
+			call: Box<<T as pallet_proxy::Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let delegate = ensure_signed(origin)?;
+			let real = T::Lookup::lookup(real)?;
+			Self::ensure_allowance_proxy(&real, &delegate)?;
+
+			let mut call = *call;
+			Self::wrap_metered(&mut call);
+			let mut origin: T::RuntimeOrigin = frame_system::RawOrigin::Signed(real.clone()).into();
+			origin.add_filter(|c: &<T as frame_system::Config>::RuntimeCall| {
+				let c = <T as pallet_proxy::Config>::RuntimeCall::from_ref(c);
+				// Other calls only pass once `dispatch_metered` charged them.
+				matches!(IsSubType::<Call<T>>::is_sub_type(c), Some(Call::dispatch_metered { .. })) ||
+					T::Spending::nested_calls(c).is_some() ||
+					Metering::<T>::mutate(|metering| {
+						metering.as_mut().is_some_and(|(.., charged)| core::mem::take(charged))
+					})
+			});
+
+			Metering::<T>::put((real, delegate, false));
+			let result = with_storage_layer(|| call.dispatch(origin));
+			Metering::<T>::kill();
+
+			Self::deposit_event(Event::ProxyExecuted {
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+			Ok(())
+		}
+
+		/// Charge the assets spent by `call` to the allowances of the delegate and dispatch it.
+		///
+		/// [`Pallet::proxy`] wraps every call it dispatches into this call, so that the allowances
+		/// are charged in the storage layer of the call. It can't be dispatched otherwise.
+		///
+		/// Parameters:
+		/// - `call`: The call to be made by the delegator.
+		#[pallet::call_index(3)]
+		#[pallet::weight({
+			let di = call.get_dispatch_info();
+			(
+				T::WeightInfo::dispatch_metered()
+					.saturating_add(T::Spending::spending_weight())
+					.saturating_add(di.call_weight),
+				di.class,
+			)
+		})]
+		pub fn dispatch_metered(
+			origin: OriginFor<T>,
+			call: Box<<T as pallet_proxy::Config>::RuntimeCall>,
+		) -> DispatchResult {
+			let real = ensure_signed(origin.clone())?;
+			let (delegator, delegate, _) = Metering::<T>::get().ok_or(Error::<T>::NotMetered)?;
+			ensure!(real == delegator, Error::<T>::NotMetered);
+			ensure!(T::Spending::nested_calls(&call).is_none(), Error::<T>::CallNotAllowed);
+
+			let spends = T::Spending::spending(&real, &call).ok_or(Error::<T>::CallNotAllowed)?;
+			Self::spend(&real, &delegate, spends)?;
+
+			Metering::<T>::mutate(|metering| {
+				if let Some((.., charged)) = metering {
+					*charged = true;
+				}
+			});
+			let result = call.dispatch(origin);
+			Metering::<T>::put((delegator, delegate, false));
+
+			result.map(|_| ()).map_err(|e| e.error)
+		}
+
+		/// Clear the allowances left behind by a removed allowance proxy.
+		///
+		/// The dispatch origin for this call must be _Signed_. The deposit of the allowances is
+		/// released to the delegator.
+		///
+		/// Parameters:
+		/// - `delegator`: The account which had added the proxy.
+		/// - `delegate`: The removed proxy.
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::clear_allowances())]
+		pub fn clear_allowances(
+			origin: OriginFor<T>,
+			delegator: AccountIdLookupOf<T>,
+			delegate: AccountIdLookupOf<T>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let delegator = T::Lookup::lookup(delegator)?;
+			let delegate = T::Lookup::lookup(delegate)?;
+			ensure!(Allowances::<T>::contains_key(&delegator, &delegate), Error::<T>::NoAllowance);
+			ensure!(
+				pallet_proxy::Pallet::<T>::find_proxy(
+					&delegator,
+					&delegate,
+					Some(T::AllowanceProxyType::get())
+				)
+				.is_err(),
+				Error::<T>::StillAllowanceProxy
+			);
+
+			Self::clear(&delegator, &delegate)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The weight of metering `call`, that is of [`Pallet::dispatch_metered`] for every call it
+		/// dispatches.
+		///
+		/// The calls are counted down to the calls nested in batches, which are bounded in number
+		/// by the batches themselves.
+		pub fn metering_weight(call: &<T as pallet_proxy::Config>::RuntimeCall) -> Weight {
+			T::WeightInfo::dispatch_metered()
+				.saturating_add(T::Spending::spending_weight())
+				.saturating_mul(Self::metered_calls(call).into())
+		}
+
+		/// The number of calls `call` dispatches through [`Pallet::dispatch_metered`].
+		fn metered_calls(call: &<T as pallet_proxy::Config>::RuntimeCall) -> u32 {
+			T::Spending::nested_calls(call).map_or(1, |calls| {
+				calls.iter().map(Self::metered_calls).fold(0, u32::saturating_add)
+			})
+		}
+
+		/// Wrap `call`, or the calls nested in it, into [`Pallet::dispatch_metered`].
+		fn wrap_metered(call: &mut <T as pallet_proxy::Config>::RuntimeCall) {
+			if let Some(calls) = T::Spending::nested_calls_mut(call) {
+				calls.iter_mut().for_each(Self::wrap_metered);
+				return;
+			}
+
+			let placeholder = frame_system::Call::<T>::remark { remark: Vec::new() }.into();
+			let inner = core::mem::replace(call, placeholder);
+			*call = Call::<T>::dispatch_metered { call: Box::new(inner) }.into();
+		}
+
+		/// Hold the deposit of `count` allowances from `delegator`, who holds `deposit` for them.
+		fn update_deposit(
+			delegator: &T::AccountId,
+			deposit: &mut DepositBalanceOf<T>,
+			count: usize,
+		) -> DispatchResult {
+			let new_deposit = T::AllowanceDeposit::get().saturating_mul((count as u32).into());
+			let reason = HoldReason::AllowanceDeposit.into();
+			if new_deposit > *deposit {
+				<T as Config>::Currency::hold(&reason, delegator, new_deposit - *deposit)?;
+			} else if new_deposit < *deposit {
+				<T as Config>::Currency::release(
+					&reason,
+					delegator,
+					*deposit - new_deposit,
+					Precision::BestEffort,
+				)?;
+			}
+			*deposit = new_deposit;
+			Ok(())
+		}
+
+		/// Remove all the allowances of `delegate` and release their deposit.
+		fn clear(delegator: &T::AccountId, delegate: &T::AccountId) -> DispatchResult {
+			if !Allowances::<T>::contains_key(delegator, delegate) {
+				return Ok(());
+			}
+			let (_, mut deposit) = Allowances::<T>::take(delegator, delegate);
+			Self::update_deposit(delegator, &mut deposit, 0)?;
+
+			Self::deposit_event(Event::AllowancesCleared {
+				delegator: delegator.clone(),
+				delegate: delegate.clone(),
+			});
+			Ok(())
+		}
+
+		/// Ensure that `delegate` is an allowance proxy of `delegator` without delay.
+		fn ensure_allowance_proxy(
+			delegator: &T::AccountId,
+			delegate: &T::AccountId,
+		) -> DispatchResult {
+			let def = pallet_proxy::Pallet::<T>::find_proxy(
+				delegator,
+				delegate,
+				Some(T::AllowanceProxyType::get()),
+			)
+			.map_err(|_| Error::<T>::NotAllowanceProxy)?;
+			ensure!(def.delay.is_zero(), Error::<T>::Unannounced);
+			Ok(())
+		}
+
+		/// Spend `spends` from the allowances of `delegate`.
+		///
+		/// Either all or none of the amounts are spent.
+		pub(crate) fn spend(
+			delegator: &T::AccountId,
+			delegate: &T::AccountId,
+			spends: Vec<(T::AssetKind, T::Balance)>,
+		) -> DispatchResult {
+			if spends.is_empty() {
+				return Ok(());
+			}
+
+			let now = T::BlockNumberProvider::current_block_number();
+			let (mut allowances, deposit) = Allowances::<T>::get(delegator, delegate);
+			for (asset, amount) in spends.iter() {
+				let allowance = allowances
+					.iter_mut()
+					.find(|a| &a.asset == asset)
+					.ok_or(Error::<T>::NoAllowance)?;
+				let elapsed = now.saturating_sub(allowance.period_start);
+				if elapsed >= allowance.period {
+					allowance.period_start = now.saturating_sub(elapsed % allowance.period);
+					allowance.spent = Zero::zero();
+				}
+				let spent = allowance.spent.saturating_add(*amount);
+				ensure!(spent <= allowance.limit, Error::<T>::AllowanceExceeded);
+				allowance.spent = spent;
+			}
+			Allowances::<T>::insert(delegator, delegate, (&allowances, deposit));
+
+			for (asset, amount) in spends {
+				let remaining = allowances
+					.iter()
+					.find(|a| a.asset == asset)
+					.map(|a| a.limit.saturating_sub(a.spent))
+					.unwrap_or_default();
+				Self::deposit_event(Event::Spent {
+					delegator: delegator.clone(),
+					delegate: delegate.clone(),
+					asset,
+					amount,
+					remaining,
+				});
+			}
+			Ok(())
+		}
+	}
+}
@@ -0,0 +1,752 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for the Proxy Allowances Pallet
+
+use super::*;
+use crate as proxy_allowances;
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, derive_impl, parameter_types,
+	traits::{
+		fungible::{Inspect, InspectHold},
+		tokens::{Fortitude::Polite, Preservation},
+		Currency, InstanceFilter,
+	},
+};
+use pallet_balances::Call as BalancesCall;
+use pallet_utility::Call as UtilityCall;
+use sp_core::{ConstU32, ConstU64};
+use sp_io::TestExternalities;
+use sp_runtime::{traits::BlakeTwo256, BuildStorage, DispatchResult, TokenError};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Proxy: pallet_proxy,
+		Utility: pallet_utility,
+		ProxyAllowances: proxy_allowances,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type ReserveIdentifier = [u8; 8];
+	type AccountStore = System;
+}
+
+impl pallet_utility::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = ();
+}
+
+#[derive(
+	Copy,
+	Clone,
+	Eq,
+	Default,
+	PartialEq,
+	Ord,
+	PartialOrd,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	Debug,
+	MaxEncodedLen,
+	scale_info::TypeInfo,
+)]
+pub enum ProxyType {
+	#[default]
+	Any,
+	Allowance,
+}
+
+impl InstanceFilter<RuntimeCall> for ProxyType {
+	fn filter(&self, _: &RuntimeCall) -> bool {
+		match self {
+			ProxyType::Any => true,
+			// Only usable through `ProxyAllowances::proxy`.
+			ProxyType::Allowance => false,
+		}
+	}
+	fn is_superset(&self, o: &Self) -> bool {
+		self == &ProxyType::Any || self == o
+	}
+}
+
+impl pallet_proxy::Config for Test {
+	type RuntimeEvent = RuntimeEvent;
+	type RuntimeCall = RuntimeCall;
+	type Currency = Balances;
+	type ProxyType = ProxyType;
+	type ProxyDepositBase = ConstU64<1>;
+	type ProxyDepositFactor = ConstU64<1>;
+	type MaxProxies = ConstU32<4>;
+	type WeightInfo = ();
+	type CallHasher = BlakeTwo256;
+	type MaxPending = ConstU32<2>;
+	type AnnouncementDepositBase = ConstU64<1>;
+	type AnnouncementDepositFactor = ConstU64<1>;
+	type BlockNumberProvider = System;
+}
+
+const NATIVE: u32 = 0;
+const OTHER: u32 = 1;
+const DELEGATOR: u64 = 1;
+const DELEGATE: u64 = 2;
+const DEST: u64 = 3;
+
+parameter_types! {
+	pub const AllowanceProxyType: ProxyType = ProxyType::Allowance;
+}
+
+/// Meters the native transfers of the balances pallet and looks into the batches.
+pub struct TestSpending;
+impl InspectSpending<RuntimeCall, u64, u32, u64> for TestSpending {
+	fn spending(who: &u64, call: &RuntimeCall) -> Option<Vec<(u32, u64)>> {
+		match call {
+			RuntimeCall::Balances(
+				BalancesCall::transfer_allow_death { value, .. } |
+				BalancesCall::transfer_keep_alive { value, .. },
+			) => Some(vec![(NATIVE, *value)]),
+			RuntimeCall::Balances(BalancesCall::transfer_all { keep_alive, .. }) => {
+				let preservation =
+					if *keep_alive { Preservation::Preserve } else { Preservation::Expendable };
+				Some(vec![(NATIVE, Balances::reducible_balance(who, preservation, Polite))])
+			},
+			_ => None,
+		}
+	}
+
+	fn spending_weight() -> Weight {
+		Weight::zero()
+	}
+
+	fn nested_calls(call: &RuntimeCall) -> Option<&[RuntimeCall]> {
+		match call {
+			RuntimeCall::Utility(
+				UtilityCall::batch { calls } |
+				UtilityCall::batch_all { calls } |
+				UtilityCall::force_batch { calls },
+			) => Some(calls),
+			_ => None,
+		}
+	}
+
+	fn nested_calls_mut(call: &mut RuntimeCall) -> Option<&mut Vec<RuntimeCall>> {
+		match call {
+			RuntimeCall::Utility(
+				UtilityCall::batch { calls } |
+				UtilityCall::batch_all { calls } |
+				UtilityCall::force_batch { calls },
+			) => Some(calls),
+			_ => None,
+		}
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct TestBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<u64, u32, RuntimeCall> for TestBenchmarkHelper {
+	fn asset(index: u32) -> u32 {
+		index
+	}
+
+	fn spending_call() -> RuntimeCall {
+		transfer(1)
+	}
+
+	fn fund(who: &u64) {
+		Balances::make_free_balance_be(who, 1_000);
+	}
+}
+
+impl Config for Test {
+	type AssetKind = u32;
+	type Balance = u64;
+	type AllowanceProxyType = AllowanceProxyType;
+	type Spending = TestSpending;
+	type MaxAllowances = ConstU32<2>;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type AllowanceDeposit = ConstU64<1>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = TestBenchmarkHelper;
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(DELEGATOR, 100), (DELEGATE, 10), (DEST, 10)],
+		dev_accounts: None,
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn transfer(value: u64) -> RuntimeCall {
+	RuntimeCall::Balances(BalancesCall::transfer_allow_death { dest: DEST, value })
+}
+
+fn batch(calls: Vec<RuntimeCall>) -> RuntimeCall {
+	RuntimeCall::Utility(UtilityCall::batch { calls })
+}
+
+fn set_allowance(limit: u64, period: u64) {
+	assert_ok!(ProxyAllowances::set_allowance(
+		RuntimeOrigin::signed(DELEGATOR),
+		DELEGATE,
+		NATIVE,
+		limit,
+		period
+	));
+}
+
+fn spent() -> u64 {
+	Allowances::<Test>::get(DELEGATOR, DELEGATE)
+		.0
+		.iter()
+		.find(|a| a.asset == NATIVE)
+		.map(|a| a.spent)
+		.unwrap_or_default()
+}
+
+/// Dispatches `call` through the allowance proxy and returns the result of the call.
+fn proxy(call: RuntimeCall) -> DispatchResult {
+	assert_ok!(ProxyAllowances::proxy(RuntimeOrigin::signed(DELEGATE), DELEGATOR, Box::new(call)));
+	System::events()
+		.into_iter()
+		.rev()
+		.find_map(|record| match record.event {
+			RuntimeEvent::ProxyAllowances(Event::ProxyExecuted { result }) => Some(result),
+			_ => None,
+		})
+		.expect("`proxy` always emits `ProxyExecuted`; qed")
+}
+
+fn call_filtered() -> DispatchResult {
+	Err(frame_system::Error::<Test>::CallFiltered.into())
+}
+
+fn rejected(error: Error<Test>) -> DispatchResult {
+	Err(error.into())
+}
+
+fn is_allowance_proxy() -> bool {
+	Proxy::find_proxy(&DELEGATOR, &DELEGATE, Some(ProxyType::Allowance)).is_ok()
+}
+
+fn deposit() -> u64 {
+	Balances::balance_on_hold(&HoldReason::AllowanceDeposit.into(), &DELEGATOR)
+}
+
+#[test]
+fn set_allowance_adds_allowance_proxy() {
+	new_test_ext().execute_with(|| {
+		// Other proxy types don't qualify.
+		assert_ok!(Proxy::add_proxy(RuntimeOrigin::signed(DELEGATOR), DELEGATE, ProxyType::Any, 0));
+		set_allowance(10, 5);
+		assert!(is_allowance_proxy());
+		System::assert_has_event(
+			pallet_proxy::Event::ProxyAdded {
+				delegator: DELEGATOR,
+				delegatee: DELEGATE,
+				proxy_type: ProxyType::Allowance,
+				delay: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn set_allowance_requires_unannounced_proxy() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_proxy(
+			RuntimeOrigin::signed(DELEGATOR),
+			DELEGATE,
+			ProxyType::Allowance,
+			1
+		));
+		assert_noop!(
+			ProxyAllowances::set_allowance(
+				RuntimeOrigin::signed(DELEGATOR),
+				DELEGATE,
+				NATIVE,
+				10,
+				5
+			),
+			Error::<Test>::Unannounced
+		);
+	});
+}
+
+#[test]
+fn set_allowance_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ProxyAllowances::set_allowance(
+				RuntimeOrigin::signed(DELEGATOR),
+				DELEGATE,
+				NATIVE,
+				10,
+				0
+			),
+			Error::<Test>::ZeroPeriod
+		);
+
+		set_allowance(10, 5);
+		assert_eq!(deposit(), 1);
+		System::assert_last_event(
+			Event::AllowanceSet {
+				delegator: DELEGATOR,
+				delegate: DELEGATE,
+				asset: NATIVE,
+				limit: 10,
+				period: 5,
+			}
+			.into(),
+		);
+
+		// Setting it again replaces the allowance and starts a new period.
+		assert_eq!(proxy(transfer(4)), Ok(()));
+		System::set_block_number(3);
+		set_allowance(20, 5);
+		assert_eq!(
+			Allowances::<Test>::get(DELEGATOR, DELEGATE).0.into_inner(),
+			vec![Allowance { asset: NATIVE, limit: 20, period: 5, period_start: 3, spent: 0 }]
+		);
+		assert_eq!(deposit(), 1);
+
+		assert_ok!(ProxyAllowances::set_allowance(
+			RuntimeOrigin::signed(DELEGATOR),
+			DELEGATE,
+			OTHER,
+			10,
+			5
+		));
+		assert_eq!(deposit(), 2);
+		assert_noop!(
+			ProxyAllowances::set_allowance(RuntimeOrigin::signed(DELEGATOR), DELEGATE, 2, 10, 5),
+			Error::<Test>::TooManyAllowances
+		);
+	});
+}
+
+#[test]
+fn spending_is_limited_by_the_allowance() {
+	new_test_ext().execute_with(|| {
+		set_allowance(10, 5);
+
+		assert_eq!(proxy(transfer(6)), Ok(()));
+		System::assert_has_event(
+			Event::Spent {
+				delegator: DELEGATOR,
+				delegate: DELEGATE,
+				asset: NATIVE,
+				amount: 6,
+				remaining: 4,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(DEST), 16);
+
+		assert_eq!(proxy(transfer(5)), rejected(Error::<Test>::AllowanceExceeded));
+		assert_eq!(Balances::free_balance(DEST), 16);
+		assert_eq!(spent(), 6);
+
+		assert_eq!(proxy(transfer(4)), Ok(()));
+		assert_eq!(Balances::free_balance(DEST), 20);
+		assert_eq!(spent(), 10);
+	});
+}
+
+#[test]
+fn failed_calls_do_not_spend() {
+	new_test_ext().execute_with(|| {
+		set_allowance(1_000, 5);
+
+		assert!(proxy(transfer(500)).is_err());
+		assert_eq!(spent(), 0);
+		assert!(Metering::<Test>::get().is_none());
+	});
+}
+
+#[test]
+fn failed_calls_in_batch_do_not_spend() {
+	new_test_ext().execute_with(|| {
+		set_allowance(1_000, 5);
+
+		assert_eq!(proxy(batch(vec![transfer(30), transfer(500), transfer(30)])), Ok(()));
+		System::assert_has_event(
+			pallet_utility::Event::BatchInterrupted {
+				index: 1,
+				error: TokenError::FundsUnavailable.into(),
+			}
+			.into(),
+		);
+		assert_eq!(spent(), 30);
+		assert_eq!(Balances::free_balance(DEST), 40);
+	});
+}
+
+#[test]
+fn failed_calls_in_force_batch_do_not_spend() {
+	new_test_ext().execute_with(|| {
+		set_allowance(1_000, 5);
+
+		let force_batch = RuntimeCall::Utility(UtilityCall::force_batch {
+			calls: vec![transfer(30), transfer(500), transfer(30)],
+		});
+		assert_eq!(proxy(force_batch), Ok(()));
+		System::assert_has_event(
+			pallet_utility::Event::ItemFailed { error: TokenError::FundsUnavailable.into() }.into(),
+		);
+		assert_eq!(spent(), 60);
+		assert_eq!(Balances::free_balance(DEST), 70);
+	});
+}
+
+#[test]
+fn dispatch_metered_only_works_within_proxy() {
+	new_test_ext().execute_with(|| {
+		set_allowance(10, 5);
+
+		for who in [DELEGATOR, DELEGATE] {
+			assert_noop!(
+				ProxyAllowances::dispatch_metered(
+					RuntimeOrigin::signed(who),
+					Box::new(transfer(1))
+				),
+				Error::<Test>::NotMetered
+			);
+		}
+
+		// Nor can it be nested to spend twice.
+		let metered =
+			RuntimeCall::ProxyAllowances(Call::dispatch_metered { call: Box::new(transfer(1)) });
+		assert_eq!(proxy(metered), rejected(Error::<Test>::CallNotAllowed));
+		assert_eq!(spent(), 0);
+	});
+}
+
+#[test]
+fn transfer_all_is_metered() {
+	new_test_ext().execute_with(|| {
+		set_allowance(10, 5);
+		let transfer_all =
+			RuntimeCall::Balances(BalancesCall::transfer_all { dest: DEST, keep_alive: true });
+
+		assert_eq!(proxy(transfer_all.clone()), rejected(Error::<Test>::AllowanceExceeded));
+
+		let all = Balances::reducible_balance(&DELEGATOR, Preservation::Preserve, Polite);
+		set_allowance(all, 5);
+		assert_eq!(proxy(transfer_all), Ok(()));
+		assert_eq!(spent(), all);
+		assert_eq!(Balances::reducible_balance(&DELEGATOR, Preservation::Preserve, Polite), 0);
+	});
+}
+
+#[test]
+fn allowance_resets_every_period() {
+	new_test_ext().execute_with(|| {
+		set_allowance(10, 10);
+		assert_eq!(proxy(transfer(10)), Ok(()));
+
+		System::set_block_number(10);
+		assert_eq!(proxy(transfer(1)), rejected(Error::<Test>::AllowanceExceeded));
+
+		System::set_block_number(11);
+		assert_eq!(proxy(transfer(10)), Ok(()));
+
+		// Periods stay aligned to the first one.
+		System::set_block_number(25);
+		assert_eq!(proxy(transfer(1)), Ok(()));
+		assert_eq!(Allowances::<Test>::get(DELEGATOR, DELEGATE).0[0].period_start, 21);
+		assert_eq!(spent(), 1);
+	});
+}
+
+#[test]
+fn nested_batches_are_metered() {
+	new_test_ext().execute_with(|| {
+		set_allowance(10, 5);
+
+		assert_eq!(proxy(batch(vec![transfer(3), batch(vec![transfer(3), transfer(3)])])), Ok(()));
+		assert_eq!(spent(), 9);
+		assert_eq!(Balances::free_balance(DEST), 19);
+
+		// The batches are interrupted by the first call exceeding the allowance.
+		assert_eq!(proxy(batch(vec![batch(vec![transfer(1), transfer(1)]), transfer(1)])), Ok(()));
+		System::assert_has_event(
+			pallet_utility::Event::BatchInterrupted {
+				index: 1,
+				error: Error::<Test>::AllowanceExceeded.into(),
+			}
+			.into(),
+		);
+		assert_eq!(spent(), 10);
+		assert_eq!(Balances::free_balance(DEST), 20);
+	});
+}
+
+#[test]
+fn every_nested_call_is_weighed() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ProxyAllowances::metered_calls(&transfer(1)), 1);
+		assert_eq!(
+			ProxyAllowances::metered_calls(&batch(vec![
+				transfer(1),
+				batch(vec![transfer(1), transfer(1)]),
+				batch(vec![]),
+			])),
+			3
+		);
+	});
+}
+
+#[test]
+fn failed_batch_all_does_not_spend() {
+	new_test_ext().execute_with(|| {
+		set_allowance(10, 5);
+
+		let batch_all = RuntimeCall::Utility(UtilityCall::batch_all {
+			calls: vec![transfer(4), batch(vec![transfer(4)]), transfer(4)],
+		});
+		assert!(proxy(batch_all).is_err());
+		assert_eq!(spent(), 0);
+		assert_eq!(Balances::free_balance(DEST), 10);
+	});
+}
+
+#[test]
+fn other_calls_are_rejected() {
+	new_test_ext().execute_with(|| {
+		set_allowance(10, 5);
+
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		assert_eq!(proxy(remark.clone()), rejected(Error::<Test>::CallNotAllowed));
+		assert_eq!(proxy(batch(vec![remark.clone()])), Ok(()));
+		System::assert_has_event(
+			pallet_utility::Event::BatchInterrupted {
+				index: 0,
+				error: Error::<Test>::CallNotAllowed.into(),
+			}
+			.into(),
+		);
+
+		// Escaping the metering through the proxy pallet doesn't work.
+		let proxied = RuntimeCall::Proxy(pallet_proxy::Call::proxy {
+			real: DELEGATOR,
+			force_proxy_type: None,
+			call: Box::new(transfer(20)),
+		});
+		assert_eq!(proxy(proxied), rejected(Error::<Test>::CallNotAllowed));
+		assert_ok!(Proxy::proxy(
+			RuntimeOrigin::signed(DELEGATE),
+			DELEGATOR,
+			None,
+			Box::new(transfer(20))
+		));
+		System::assert_last_event(
+			pallet_proxy::Event::ProxyExecuted { result: call_filtered() }.into(),
+		);
+		assert_eq!(Balances::free_balance(DEST), 10);
+
+		// Only allowance proxies can spend.
+		assert_noop!(
+			ProxyAllowances::proxy(RuntimeOrigin::signed(DEST), DELEGATOR, Box::new(transfer(1))),
+			Error::<Test>::NotAllowanceProxy
+		);
+	});
+}
+
+#[test]
+fn spending_assets_without_allowance_is_rejected() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(ProxyAllowances::set_allowance(
+			RuntimeOrigin::signed(DELEGATOR),
+			DELEGATE,
+			OTHER,
+			10,
+			5
+		));
+
+		assert_eq!(proxy(transfer(1)), rejected(Error::<Test>::NoAllowance));
+		assert_eq!(
+			ProxyAllowances::spend(&DELEGATOR, &DELEGATE, vec![(OTHER, 1), (NATIVE, 1)]),
+			Err(Error::<Test>::NoAllowance.into())
+		);
+		// Nothing is spent unless everything can be.
+		assert_eq!(Allowances::<Test>::get(DELEGATOR, DELEGATE).0[0].spent, 0);
+	});
+}
+
+#[test]
+fn remove_allowance_works() {
+	new_test_ext().execute_with(|| {
+		set_allowance(10, 5);
+		assert_ok!(ProxyAllowances::set_allowance(
+			RuntimeOrigin::signed(DELEGATOR),
+			DELEGATE,
+			OTHER,
+			10,
+			5
+		));
+
+		assert_ok!(ProxyAllowances::remove_allowance(
+			RuntimeOrigin::signed(DELEGATOR),
+			DELEGATE,
+			OTHER
+		));
+		assert_eq!(deposit(), 1);
+		assert_eq!(proxy(transfer(1)), Ok(()));
+
+		// Removing the last allowance also removes the proxy.
+		assert_ok!(ProxyAllowances::remove_allowance(
+			RuntimeOrigin::signed(DELEGATOR),
+			DELEGATE,
+			NATIVE
+		));
+		System::assert_has_event(
+			Event::AllowanceRemoved { delegator: DELEGATOR, delegate: DELEGATE, asset: NATIVE }
+				.into(),
+		);
+		assert!(!Allowances::<Test>::contains_key(DELEGATOR, DELEGATE));
+		assert_eq!(deposit(), 0);
+		assert!(!is_allowance_proxy());
+		assert_noop!(
+			ProxyAllowances::proxy(
+				RuntimeOrigin::signed(DELEGATE),
+				DELEGATOR,
+				Box::new(transfer(1))
+			),
+			Error::<Test>::NotAllowanceProxy
+		);
+
+		assert_noop!(
+			ProxyAllowances::remove_allowance(RuntimeOrigin::signed(DELEGATOR), DELEGATE, NATIVE),
+			Error::<Test>::NoAllowance
+		);
+	});
+}
+
+#[test]
+fn allowances_of_removed_proxies_are_not_revived() {
+	new_test_ext().execute_with(|| {
+		set_allowance(10, 5);
+		assert_eq!(proxy(transfer(6)), Ok(()));
+
+		assert_ok!(Proxy::remove_proxy(
+			RuntimeOrigin::signed(DELEGATOR),
+			DELEGATE,
+			ProxyType::Allowance,
+			0
+		));
+		assert_noop!(
+			ProxyAllowances::proxy(
+				RuntimeOrigin::signed(DELEGATE),
+				DELEGATOR,
+				Box::new(transfer(1))
+			),
+			Error::<Test>::NotAllowanceProxy
+		);
+
+		// Adding the proxy again starts over.
+		System::set_block_number(2);
+		assert_ok!(ProxyAllowances::set_allowance(
+			RuntimeOrigin::signed(DELEGATOR),
+			DELEGATE,
+			OTHER,
+			10,
+			5
+		));
+		System::assert_has_event(
+			Event::AllowancesCleared { delegator: DELEGATOR, delegate: DELEGATE }.into(),
+		);
+		assert_eq!(
+			Allowances::<Test>::get(DELEGATOR, DELEGATE).0.into_inner(),
+			vec![Allowance { asset: OTHER, limit: 10, period: 5, period_start: 2, spent: 0 }]
+		);
+		assert_eq!(deposit(), 1);
+		assert_eq!(proxy(transfer(1)), rejected(Error::<Test>::NoAllowance));
+	});
+}
+
+#[test]
+fn clear_allowances_works() {
+	new_test_ext().execute_with(|| {
+		set_allowance(10, 5);
+		assert_noop!(
+			ProxyAllowances::clear_allowances(RuntimeOrigin::signed(DEST), DELEGATOR, DELEGATE),
+			Error::<Test>::StillAllowanceProxy
+		);
+
+		assert_ok!(Proxy::remove_proxies(RuntimeOrigin::signed(DELEGATOR)));
+		assert_ok!(ProxyAllowances::clear_allowances(
+			RuntimeOrigin::signed(DEST),
+			DELEGATOR,
+			DELEGATE
+		));
+		System::assert_last_event(
+			Event::AllowancesCleared { delegator: DELEGATOR, delegate: DELEGATE }.into(),
+		);
+		assert!(!Allowances::<Test>::contains_key(DELEGATOR, DELEGATE));
+		assert_eq!(deposit(), 0);
+
+		assert_noop!(
+			ProxyAllowances::clear_allowances(RuntimeOrigin::signed(DEST), DELEGATOR, DELEGATE),
+			Error::<Test>::NoAllowance
+		);
+	});
+}
+
+#[test]
+fn allowance_proxies_are_not_added_through_the_proxy_pallet() {
+	new_test_ext().execute_with(|| {
+		let add_proxy = |proxy_type| {
+			RuntimeCall::Proxy(pallet_proxy::Call::add_proxy {
+				delegate: DELEGATE,
+				proxy_type,
+				delay: 0,
+			})
+		};
+		let create_pure = |proxy_type| {
+			RuntimeCall::Proxy(pallet_proxy::Call::create_pure { proxy_type, delay: 0, index: 0 })
+		};
+
+		assert!(!ExcludeAllowanceProxies::<Test>::contains(&add_proxy(ProxyType::Allowance)));
+		assert!(!ExcludeAllowanceProxies::<Test>::contains(&create_pure(ProxyType::Allowance)));
+		assert!(ExcludeAllowanceProxies::<Test>::contains(&add_proxy(ProxyType::Any)));
+		assert!(ExcludeAllowanceProxies::<Test>::contains(&create_pure(ProxyType::Any)));
+		assert!(ExcludeAllowanceProxies::<Test>::contains(&transfer(1)));
+	});
+}
@@ -0,0 +1,44 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use frame_support::weights::Weight;
+
+/// Weight functions needed for `pallet_proxy_allowances`.
+pub trait WeightInfo {
+	fn set_allowance() -> Weight;
+	fn remove_allowance() -> Weight;
+	fn proxy() -> Weight;
+	fn dispatch_metered() -> Weight;
+	fn clear_allowances() -> Weight;
+}
+
+impl WeightInfo for () {
+	fn set_allowance() -> Weight {
+		Weight::MAX
+	}
+	fn remove_allowance() -> Weight {
+		Weight::MAX
+	}
+	fn proxy() -> Weight {
+		Weight::MAX
+	}
+	fn dispatch_metered() -> Weight {
+		Weight::MAX
+	}
+	fn clear_allowances() -> Weight {
+		Weight::MAX
+	}
+}
@@ -0,0 +1,63 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as RemoteIdentity;
+use alloc::vec;
+use frame_benchmarking::v2::{
+	account, impl_test_function, instance_benchmarks, whitelisted_caller,
+};
+use frame_system::RawOrigin;
+use sp_runtime::{traits::StaticLookup, BoundedVec};
+
+const SEED: u32 = 0;
+
+#[instance_benchmarks]
+mod benchmarks {
+	use super::*;
+	use frame_benchmarking::BenchmarkError;
+
+	// The padding takes up to half of the proof bounds, the other half is left for the proof
+	// created by the runtime.
+	#[benchmark]
+	fn prove_identity(
+		n: Linear<1, { T::MaxProofNodes::get() / 2 }>,
+		p: Linear<0, { T::MaxProofSize::get() / 2 }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let who: T::AccountId = account("who", 0, SEED);
+		let who_lookup = T::Lookup::unlookup(who.clone());
+		let (proof, block_number, storage_root) =
+			T::RemoteIdentity::create_remote_identity_proof(&who);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		// Unused nodes still need to be hashed when verifying the proof.
+		let proof = match proof {
+			RemoteIdentityProof::ParaHead { block, relay_proof, mut para_proof } => {
+				para_proof.extend((0..n).map(|_| vec![0u8; (p / n) as usize]));
+				RemoteIdentityProof::ParaHead { block, relay_proof, para_proof }
+			},
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), who_lookup, proof);
+
+		assert!(JudgedIdentities::<T, I>::contains_key(&who));
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(RemoteIdentity, crate::tests::new_test_ext(), crate::tests::Test);
+}
@@ -0,0 +1,615 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Remote identity pallet
+//!
+//! The pallet makes the identity judgements of a remote parachain (usually the People chain)
+//! available on the local chain. The exact remote location depends on the
+//! [`RemoteIdentityInterface`] implementation provided to this pallet. Judgements are proven by
+//! a two step storage proof: the first proof proves the head of the remote parachain in the state
+//! of the relay chain, the second proof proves the `Identity::IdentityOf` entry of an account in
+//! the state of the remote parachain. The relay chain storage roots are extracted from the
+//! [`PersistedValidationData`] in the same way as done by `pallet-remote-proxy`.
+//!
+//! Successfully proven judgements are cached in [`JudgedIdentities`] and can be checked by other
+//! pallets through the [`HasReasonableJudgement`] filter or the [`EnsureReasonableJudgement`]
+//! origin.
+//!
+//! ## Functions
+//!
+//! The pallet provides the following functions:
+//!
+//! - [`Pallet::prove_identity`]: Prove the current identity judgements of an account. A proof with
+//!   a reasonable judgement caches it, a proof without a reasonable judgement removes a previously
+//!   cached one.
+//!
+//! ## Security considerations
+//!
+//! The security of the judgements depends on the remote location and the registrars trusted by
+//! it. A cached judgement reflects the remote state at the time of the proof. When a judgement is
+//! removed at the remote location, the cached judgement stays valid for
+//! [`MaxJudgementAge`](Config::MaxJudgementAge) or until anyone proves the removal by calling
+//! [`Pallet::prove_identity`] with a more recent proof.
+//!
+//! The removal of a judgement is remembered in [`LastProofBlock`] as long as the storage root of
+//! the block it was proven at is kept, so an older proof can not restore the judgement. The size
+//! of the proofs is bounded by [`MaxProofNodes`](Config::MaxProofNodes) and
+//! [`MaxProofSize`](Config::MaxProofSize).
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use alloc::vec::Vec;
+use codec::{Encode, MaxEncodedLen};
+use core::{fmt::Debug, marker::PhantomData};
+use cumulus_primitives_core::ParaId;
+use frame_support::{
+	storage::storage_prefix,
+	traits::{Contains, EnsureOrigin},
+	Parameter, StorageHasher, Twox64Concat,
+};
+use sp_core::Hasher;
+use sp_runtime::traits::{Header as HeaderT, Saturating};
+
+pub use cumulus_primitives_core::PersistedValidationData;
+pub use pallet::*;
+pub use pallet_identity::{Judgement, RegistrarIndex};
+pub use weight::WeightInfo;
+
+/// The remote identity interface.
+pub trait RemoteIdentityInterface<AccountId> {
+	/// The remote account id.
+	type RemoteAccountId: Parameter + MaxEncodedLen;
+	/// The balance type used by the remote identity pallet.
+	type RemoteBalance: Encode
+		+ codec::Decode
+		+ MaxEncodedLen
+		+ Copy
+		+ Clone
+		+ Debug
+		+ Eq
+		+ PartialEq;
+	/// The relay chain block number.
+	type RelayBlockNumber: Parameter
+		+ Saturating
+		+ MaxEncodedLen
+		+ Default
+		+ PartialOrd
+		+ Ord
+		+ From<u32>;
+	/// The hash type used by the relay chain.
+	type RelayHash: Parameter + MaxEncodedLen;
+	/// The hasher used by the relay chain.
+	type RelayHasher: Hasher<Out = Self::RelayHash>;
+	/// The header of the remote parachain.
+	type RemoteHeader: HeaderT;
+	/// The hasher used by the remote parachain.
+	type RemoteHasher: Hasher<Out = <Self::RemoteHeader as HeaderT>::Hash>;
+
+	/// Get the latest relay chain block to storage root mapping.
+	fn block_to_storage_root(
+		validation_data: &PersistedValidationData,
+	) -> Option<(Self::RelayBlockNumber, <Self::RelayHasher as Hasher>::Out)>;
+
+	/// The id of the remote parachain.
+	fn remote_para_id() -> ParaId;
+
+	/// The storage key where to find the head of the remote parachain in the relay chain.
+	fn para_head_storage_key() -> Vec<u8> {
+		let mut key = storage_prefix(b"Paras", b"Heads").to_vec();
+		Self::remote_para_id().using_encoded(|p| {
+			key.extend(Twox64Concat::hash(p));
+		});
+		key
+	}
+
+	/// The storage key where to find the identity registration for the given account in the
+	/// remote parachain.
+	fn identity_storage_key(who: &Self::RemoteAccountId) -> Vec<u8> {
+		let mut key = storage_prefix(b"Identity", b"IdentityOf").to_vec();
+		who.using_encoded(|w| {
+			key.extend(Twox64Concat::hash(w));
+		});
+		key
+	}
+
+	/// Convert the local account id to the remote account id.
+	///
+	/// If the conversion is not possible, return `None`.
+	fn local_to_remote_account_id(local: &AccountId) -> Option<Self::RemoteAccountId>;
+
+	/// Create a remote identity proof with a reasonable judgement to be used in benchmarking.
+	///
+	/// Returns the `proof`, `block_number` and `storage_root`. The later are required to validate
+	/// the `proof`.
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_remote_identity_proof(
+		who: &AccountId,
+	) -> (RemoteIdentityProof<Self::RelayBlockNumber>, Self::RelayBlockNumber, Self::RelayHash) {
+		use sp_trie::TrieMut;
+
+		let who = Self::local_to_remote_account_id(who)
+			.expect("Benchmark account must be convertible to a remote account");
+
+		// Only the judgements are read from the registration, thus we can skip the other fields.
+		let judgements =
+			alloc::vec![(0 as RegistrarIndex, Judgement::<Self::RemoteBalance>::Reasonable)];
+
+		let (mut para_db, mut para_root) =
+			sp_trie::MemoryDB::<Self::RemoteHasher>::default_with_root();
+		let mut trie =
+			sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut para_db, &mut para_root)
+				.build();
+		trie.insert(&Self::identity_storage_key(&who), &judgements.encode()).unwrap();
+		drop(trie);
+
+		let header = Self::RemoteHeader::new(
+			Default::default(),
+			Default::default(),
+			para_root,
+			Default::default(),
+			Default::default(),
+		);
+
+		let (mut relay_db, mut relay_root) =
+			sp_trie::MemoryDB::<Self::RelayHasher>::default_with_root();
+		let mut trie =
+			sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut relay_db, &mut relay_root)
+				.build();
+		trie.insert(&Self::para_head_storage_key(), &header.encode().encode()).unwrap();
+		drop(trie);
+
+		(
+			RemoteIdentityProof::ParaHead {
+				block: 1u32.into(),
+				relay_proof: relay_db.drain().into_values().map(|d| d.0).collect(),
+				para_proof: para_db.drain().into_values().map(|d| d.0).collect(),
+			},
+			1u32.into(),
+			relay_root,
+		)
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use cumulus_pallet_parachain_system::OnSystemEvent;
+	use cumulus_primitives_core::PersistedValidationData;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::StaticLookup;
+
+	type AccountIdLookupOf<T> = <<T as frame_system::Config>::Lookup as StaticLookup>::Source;
+
+	pub(crate) type RelayBlockNumberOf<T, I> =
+		<<T as Config<I>>::RemoteIdentity as RemoteIdentityInterface<
+			<T as frame_system::Config>::AccountId,
+		>>::RelayBlockNumber;
+	type RelayHashOf<T, I> = <<T as Config<I>>::RemoteIdentity as RemoteIdentityInterface<
+		<T as frame_system::Config>::AccountId,
+	>>::RelayHash;
+	type RelayHasherOf<T, I> = <<T as Config<I>>::RemoteIdentity as RemoteIdentityInterface<
+		<T as frame_system::Config>::AccountId,
+	>>::RelayHasher;
+	type RemoteHeaderOf<T, I> = <<T as Config<I>>::RemoteIdentity as RemoteIdentityInterface<
+		<T as frame_system::Config>::AccountId,
+	>>::RemoteHeader;
+	type RemoteHasherOf<T, I> = <<T as Config<I>>::RemoteIdentity as RemoteIdentityInterface<
+		<T as frame_system::Config>::AccountId,
+	>>::RemoteHasher;
+	type RemoteBalanceOf<T, I> = <<T as Config<I>>::RemoteIdentity as RemoteIdentityInterface<
+		<T as frame_system::Config>::AccountId,
+	>>::RemoteBalance;
+	type WeightInfoOf<T, I> = <T as Config<I>>::WeightInfo;
+
+	#[pallet::pallet]
+	pub struct Pallet<T, I = ()>(_);
+
+	/// Stores the last [`Config::MaxStorageRootsToKeep`] block to storage root mappings of the
+	/// relay chain.
+	#[pallet::storage]
+	pub type BlockToRoot<T: Config<I>, I: 'static = ()> = StorageValue<
+		_,
+		BoundedVec<(RelayBlockNumberOf<T, I>, RelayHashOf<T, I>), T::MaxStorageRootsToKeep>,
+		ValueQuery,
+	>;
+
+	/// Accounts with a proven reasonable judgement on the remote chain.
+	///
+	/// Maps to the relay chain block at which the judgement was proven.
+	#[pallet::storage]
+	pub type JudgedIdentities<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, RelayBlockNumberOf<T, I>, OptionQuery>;
+
+	/// The relay chain block at which the removal of the judgement of an account was proven.
+	///
+	/// Proofs anchored at an older block are rejected, so a stale proof can not restore a
+	/// judgement whose removal was already proven. The entry is pruned together with the storage
+	/// root of the block, as no older proof can be verified afterwards.
+	#[pallet::storage]
+	pub type LastProofBlock<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, RelayBlockNumberOf<T, I>, OptionQuery>;
+
+	/// The accounts whose removal of a judgement was proven at the given relay chain block.
+	///
+	/// Used to prune [`LastProofBlock`] when the storage root of the block is removed.
+	#[pallet::storage]
+	pub type ProofBlocksToPrune<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		RelayBlockNumberOf<T, I>,
+		BoundedVec<T::AccountId, T::MaxRemovalsPerBlock>,
+		ValueQuery,
+	>;
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>:
+		frame_system::Config<RuntimeEvent: From<Event<Self, I>>>
+	{
+		/// The maximum number of storage roots to keep.
+		///
+		/// The storage roots are used to validate the remote proofs. The more we keep in storage,
+		/// the older the proof can be. This is not only seen as a maximum number, but also as the
+		/// maximum difference between the latest and the oldest storage root stored. This means
+		/// that if the chain for example did not progress for `MaxStorageRootsToKeep` blocks, only
+		/// the latest added storage root will be available for validating proofs.
+		type MaxStorageRootsToKeep: Get<u32>;
+
+		/// The number of relay chain blocks a proven judgement stays valid.
+		///
+		/// After this time the judgement needs to be proven again.
+		type MaxJudgementAge: Get<u32>;
+
+		/// The maximum number of nodes of the relay chain and the remote proof together.
+		type MaxProofNodes: Get<u32>;
+
+		/// The maximum size in bytes of the nodes of the relay chain and the remote proof
+		/// together.
+		type MaxProofSize: Get<u32>;
+
+		/// The maximum number of judgement removals that can be proven at the same relay chain
+		/// block.
+		///
+		/// Bounds the removals that need to be pruned with the storage root of a block.
+		type MaxRemovalsPerBlock: Get<u32>;
+
+		/// The interface for reading the remote identities.
+		type RemoteIdentity: RemoteIdentityInterface<Self::AccountId>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	impl<T: Config<I>, I: 'static> OnSystemEvent for Pallet<T, I> {
+		fn on_validation_data(validation_data: &PersistedValidationData) {
+			let Some((block, hash)) = T::RemoteIdentity::block_to_storage_root(validation_data)
+			else {
+				return;
+			};
+
+			// Update the block to root mappings.
+			BlockToRoot::<T, I>::mutate(|roots| {
+				let delete_up_to =
+					block.clone().saturating_sub(T::MaxStorageRootsToKeep::get().into());
+
+				while roots.first().is_some_and(|f| f.0 <= delete_up_to) {
+					let (pruned, _) = roots.remove(0);
+					Self::prune_proof_blocks(pruned);
+				}
+
+				// We always remove all the old items before, thus there should always be space in
+				// the vector.
+				let _res = roots.try_push((block, hash));
+				debug_assert!(_res.is_ok());
+			});
+		}
+
+		fn on_validation_code_applied() {}
+		fn on_relay_state_proof(
+			_relay_state_proof: &cumulus_pallet_parachain_system::relay_state_snapshot::RelayChainStateProof,
+		) -> frame_support::weights::Weight {
+			Default::default()
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// A reasonable judgement of `who` was proven at the relay chain `block`.
+		JudgementProven { who: T::AccountId, block: RelayBlockNumberOf<T, I> },
+		/// The cached judgement of `who` was removed as the relay chain `block` proves that the
+		/// account has no reasonable judgement anymore.
+		JudgementRemoved { who: T::AccountId, block: RelayBlockNumberOf<T, I> },
+	}
+
+	#[pallet::error]
+	#[derive(PartialEq)]
+	pub enum Error<T, I = ()> {
+		/// The local account id could not converted to the remote account id.
+		CouldNotConvertLocalToRemoteAccountId,
+		/// The anchor block of the remote proof is unknown.
+		UnknownProofAnchorBlock,
+		/// The para head could not be found in the relay chain proof.
+		InvalidParaHeadProof,
+		/// Failed to decode the para head from the relay chain proof.
+		ParaHeadDecodingFailed,
+		/// The identity proof is incomplete.
+		InvalidIdentityProof,
+		/// Failed to decode the identity registration from the proof.
+		IdentityDecodingFailed,
+		/// The account has no reasonable judgement and no cached judgement to remove.
+		NoReasonableJudgement,
+		/// A proof anchored at a more recent block than the given proof was already applied.
+		OutdatedProof,
+		/// The proof has more than [`Config::MaxProofNodes`] nodes or is larger than
+		/// [`Config::MaxProofSize`].
+		ProofTooLarge,
+		/// More than [`Config::MaxRemovalsPerBlock`] removals were proven at the anchor block of
+		/// the proof. A proof anchored at a more recent block needs to be used.
+		TooManyRemovals,
+	}
+
+	/// The remote identity proof to prove the judgements of an account.
+	#[derive(
+		core::fmt::Debug, Clone, Decode, DecodeWithMemTracking, Encode, TypeInfo, PartialEq, Eq,
+	)]
+	pub enum RemoteIdentityProof<RelayBlockNumber> {
+		/// Proves the para head in the relay chain and the identity in the remote parachain.
+		///
+		/// Assumes the default paras and identity storage layout.
+		ParaHead { block: RelayBlockNumber, relay_proof: Vec<Vec<u8>>, para_proof: Vec<Vec<u8>> },
+	}
+
+	impl<RelayBlockNumber> RemoteIdentityProof<RelayBlockNumber> {
+		/// The number of proof nodes.
+		pub fn proof_nodes(&self) -> u32 {
+			match self {
+				Self::ParaHead { relay_proof, para_proof, .. } =>
+					relay_proof.len().saturating_add(para_proof.len()) as u32,
+			}
+		}
+
+		/// The size of the proof nodes in bytes.
+		pub fn proof_size(&self) -> u32 {
+			match self {
+				Self::ParaHead { relay_proof, para_proof, .. } => relay_proof
+					.iter()
+					.chain(para_proof.iter())
+					.map(|node| node.len() as u32)
+					.fold(0, u32::saturating_add),
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Prove the current identity judgements of `who` on the remote chain.
+		///
+		/// If the proof contains a reasonable judgement, it is cached for
+		/// [`Config::MaxJudgementAge`]. If the proof shows that `who` has no reasonable judgement
+		/// anymore, a previously cached judgement is removed. Everybody is allowed to submit
+		/// proofs for any account, so removed judgements can be propagated quickly.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `who`: The account whose judgements are proven.
+		/// - `proof`: The proof from the remote chain about the identity of `who`.
+		#[pallet::call_index(0)]
+		#[pallet::weight(
+			WeightInfoOf::<T, I>::prove_identity(proof.proof_nodes(), proof.proof_size())
+			// Pruning a proven removal together with the storage root of its anchor block.
+			.saturating_add(T::DbWeight::get().reads_writes(1, 1))
+		)]
+		pub fn prove_identity(
+			origin: OriginFor<T>,
+			who: AccountIdLookupOf<T>,
+			proof: RemoteIdentityProof<RelayBlockNumberOf<T, I>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			let who = T::Lookup::lookup(who)?;
+			ensure!(
+				proof.proof_nodes() <= T::MaxProofNodes::get() &&
+					proof.proof_size() <= T::MaxProofSize::get(),
+				Error::<T, I>::ProofTooLarge
+			);
+
+			let (block, judged) = Self::verify_identity_proof(&who, proof)?;
+
+			let cached = JudgedIdentities::<T, I>::get(&who);
+			ensure!(
+				cached
+					.clone()
+					.or_else(|| LastProofBlock::<T, I>::get(&who))
+					.is_none_or(|last| last <= block),
+				Error::<T, I>::OutdatedProof
+			);
+
+			if judged {
+				JudgedIdentities::<T, I>::insert(&who, block.clone());
+				LastProofBlock::<T, I>::remove(&who);
+				Self::deposit_event(Event::JudgementProven { who, block });
+			} else if cached.is_some() {
+				JudgedIdentities::<T, I>::remove(&who);
+				ProofBlocksToPrune::<T, I>::try_mutate(&block, |accounts| {
+					accounts.try_push(who.clone()).map_err(|_| Error::<T, I>::TooManyRemovals)
+				})?;
+				LastProofBlock::<T, I>::insert(&who, block.clone());
+				Self::deposit_event(Event::JudgementRemoved { who, block });
+			} else {
+				return Err(Error::<T, I>::NoReasonableJudgement.into());
+			}
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Returns `true` if `who` has a proven reasonable judgement that is not yet expired.
+		pub fn has_reasonable_judgement(who: &T::AccountId) -> bool {
+			let Some(proven_at) = JudgedIdentities::<T, I>::get(who) else { return false };
+
+			BlockToRoot::<T, I>::get().last().is_some_and(|(latest, _)| {
+				proven_at >= latest.clone().saturating_sub(T::MaxJudgementAge::get().into())
+			})
+		}
+
+		/// Prune the proven removals anchored at `block`, whose storage root was removed.
+		///
+		/// A removal proven again at a more recent block is kept.
+		fn prune_proof_blocks(block: RelayBlockNumberOf<T, I>) {
+			for who in ProofBlocksToPrune::<T, I>::take(&block) {
+				LastProofBlock::<T, I>::mutate_exists(&who, |last| {
+					if last.as_ref().is_some_and(|last| *last <= block) {
+						*last = None;
+					}
+				});
+			}
+		}
+
+		/// Verify the given `proof` for `who`.
+		///
+		/// Returns the relay chain block the proof is anchored at and if `who` has a reasonable
+		/// judgement at this block.
+		fn verify_identity_proof(
+			who: &T::AccountId,
+			proof: RemoteIdentityProof<RelayBlockNumberOf<T, I>>,
+		) -> Result<(RelayBlockNumberOf<T, I>, bool), DispatchError> {
+			let Some(who_remote) = T::RemoteIdentity::local_to_remote_account_id(who) else {
+				return Err(Error::<T, I>::CouldNotConvertLocalToRemoteAccountId.into());
+			};
+
+			match proof {
+				RemoteIdentityProof::ParaHead { block, relay_proof, para_proof } => {
+					let roots = BlockToRoot::<T, I>::get();
+
+					let Ok(relay_root) = roots
+						.binary_search_by(|(b, _)| b.cmp(&block))
+						.map(|pos| roots[pos].1.clone())
+					else {
+						return Err(Error::<T, I>::UnknownProofAnchorBlock.into());
+					};
+
+					let db = sp_trie::StorageProof::new(relay_proof)
+						.into_memory_db::<RelayHasherOf<T, I>>();
+					let head = sp_trie::read_trie_value::<sp_trie::LayoutV1<_>, _>(
+						&db,
+						&relay_root,
+						&T::RemoteIdentity::para_head_storage_key(),
+						None,
+						None,
+					)
+					.ok()
+					.flatten()
+					.ok_or(Error::<T, I>::InvalidParaHeadProof)?;
+
+					// The para head is stored as `HeadData`, which wraps the encoded header.
+					let header = Vec::<u8>::decode(&mut &head[..])
+						.ok()
+						.and_then(|h| <RemoteHeaderOf<T, I>>::decode(&mut &h[..]).ok())
+						.ok_or(Error::<T, I>::ParaHeadDecodingFailed)?;
+
+					let db = sp_trie::StorageProof::new(para_proof)
+						.into_memory_db::<RemoteHasherOf<T, I>>();
+					// A proven absence of the key means that the account has no identity.
+					let Some(registration) = sp_trie::read_trie_value::<sp_trie::LayoutV1<_>, _>(
+						&db,
+						header.state_root(),
+						&T::RemoteIdentity::identity_storage_key(&who_remote),
+						None,
+						None,
+					)
+					.map_err(|_| Error::<T, I>::InvalidIdentityProof)?
+					else {
+						return Ok((block, false));
+					};
+
+					// The judgements are the first field of the registration, so we only decode
+					// them and ignore the rest of the registration.
+					let judgements =
+						Vec::<(RegistrarIndex, Judgement<RemoteBalanceOf<T, I>>)>::decode(
+							&mut &registration[..],
+						)
+						.map_err(|_| Error::<T, I>::IdentityDecodingFailed)?;
+
+					let judged = judgements
+						.iter()
+						.any(|(_, j)| matches!(j, Judgement::Reasonable | Judgement::KnownGood));
+
+					Ok((block, judged))
+				},
+			}
+		}
+	}
+}
+
+/// Filter for accounts with a proven reasonable judgement on the remote chain.
+///
+/// A judgement is reasonable if at least one registrar judged the identity as
+/// [`Judgement::Reasonable`] or [`Judgement::KnownGood`].
+pub struct HasReasonableJudgement<T, I = ()>(PhantomData<(T, I)>);
+
+impl<T: Config<I>, I: 'static> Contains<T::AccountId> for HasReasonableJudgement<T, I> {
+	fn contains(who: &T::AccountId) -> bool {
+		Pallet::<T, I>::has_reasonable_judgement(who)
+	}
+}
+
+/// Ensure that the origin is a signed account with a proven reasonable judgement on the remote
+/// chain.
+///
+/// Returns the signing account on success.
+pub struct EnsureReasonableJudgement<T, I = ()>(PhantomData<(T, I)>);
+
+impl<
+		O: Into<Result<frame_system::RawOrigin<T::AccountId>, O>>
+			+ From<frame_system::RawOrigin<T::AccountId>>,
+		T: Config<I>,
+		I: 'static,
+	> EnsureOrigin<O> for EnsureReasonableJudgement<T, I>
+{
+	type Success = T::AccountId;
+
+	fn try_origin(o: O) -> Result<Self::Success, O> {
+		o.into().and_then(|o| match o {
+			frame_system::RawOrigin::Signed(who)
+				if Pallet::<T, I>::has_reasonable_judgement(&who) =>
+				Ok(who),
+			r => Err(O::from(r)),
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<O, ()> {
+		let who: T::AccountId = frame_benchmarking::account("judged", 0, 0);
+		let (_, block, root) = T::RemoteIdentity::create_remote_identity_proof(&who);
+		BlockToRoot::<T, I>::set(frame_support::BoundedVec::truncate_from(alloc::vec![(
+			block.clone(),
+			root
+		)]));
+		JudgedIdentities::<T, I>::insert(&who, block);
+		Ok(O::from(frame_system::RawOrigin::Signed(who)))
+	}
+}
@@ -0,0 +1,420 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for Remote Identity Pallet
+
+use super::*;
+use crate as remote_identity;
+use cumulus_pallet_parachain_system::OnSystemEvent;
+use frame_support::{assert_err, assert_ok, construct_runtime, derive_impl};
+use sp_core::{ConstU32, H256};
+use sp_io::TestExternalities;
+use sp_runtime::{generic::Header, traits::BlakeTwo256, BuildStorage};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		RemoteIdentity: remote_identity,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+pub struct RemoteIdentityImpl;
+
+impl crate::RemoteIdentityInterface<u64> for RemoteIdentityImpl {
+	type RemoteAccountId = u64;
+	type RemoteBalance = u64;
+	type RelayBlockNumber = u64;
+	type RelayHash = H256;
+	type RelayHasher = BlakeTwo256;
+	type RemoteHeader = Header<u64, BlakeTwo256>;
+	type RemoteHasher = BlakeTwo256;
+
+	fn block_to_storage_root(
+		validation_data: &PersistedValidationData,
+	) -> Option<(Self::RelayBlockNumber, <Self::RelayHasher as Hasher>::Out)> {
+		Some((validation_data.relay_parent_number as _, validation_data.relay_parent_storage_root))
+	}
+
+	fn remote_para_id() -> ParaId {
+		1004.into()
+	}
+
+	fn local_to_remote_account_id(local: &u64) -> Option<Self::RemoteAccountId> {
+		Some(*local)
+	}
+}
+
+impl Config for Test {
+	type MaxStorageRootsToKeep = ConstU32<10>;
+	type MaxJudgementAge = ConstU32<5>;
+	type MaxProofNodes = ConstU32<16>;
+	type MaxProofSize = ConstU32<{ 16 * 1024 }>;
+	type MaxRemovalsPerBlock = ConstU32<1>;
+	type RemoteIdentity = RemoteIdentityImpl;
+	type WeightInfo = ();
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+type Judgements = Vec<(RegistrarIndex, Judgement<u64>)>;
+
+/// Create a proof for `who` anchored at the relay chain `block`.
+///
+/// The remote chain contains the given `identities`. Returns the proof and the relay chain storage
+/// root to validate it.
+fn create_proof(
+	who: u64,
+	identities: &[(u64, Judgements)],
+	block: u64,
+) -> (RemoteIdentityProof<u64>, H256) {
+	let mut remote = TestExternalities::default();
+	remote.execute_with(|| {
+		for (account, judgements) in identities {
+			// Mimic the registration layout: judgements, deposit and the identity info.
+			let registration = (judgements, 10u64, b"info".to_vec());
+			sp_io::storage::set(
+				&RemoteIdentityImpl::identity_storage_key(account),
+				&registration.encode(),
+			);
+		}
+	});
+	let para_proof = sp_state_machine::prove_read(
+		remote.as_backend(),
+		[RemoteIdentityImpl::identity_storage_key(&who)],
+	)
+	.unwrap();
+	let header = Header::<u64, BlakeTwo256>::new(
+		5,
+		Default::default(),
+		*remote.as_backend().root(),
+		Default::default(),
+		Default::default(),
+	);
+
+	let mut relay = TestExternalities::default();
+	relay.execute_with(|| {
+		sp_io::storage::set(
+			&RemoteIdentityImpl::para_head_storage_key(),
+			&header.encode().encode(),
+		);
+	});
+	let relay_proof = sp_state_machine::prove_read(
+		relay.as_backend(),
+		[RemoteIdentityImpl::para_head_storage_key()],
+	)
+	.unwrap();
+
+	(
+		RemoteIdentityProof::ParaHead {
+			block,
+			relay_proof: relay_proof.into_iter_nodes().collect(),
+			para_proof: para_proof.into_iter_nodes().collect(),
+		},
+		*relay.as_backend().root(),
+	)
+}
+
+fn add_storage_root(block: u64, root: H256) {
+	RemoteIdentity::on_validation_data(&PersistedValidationData {
+		parent_head: vec![].into(),
+		relay_parent_number: block as _,
+		relay_parent_storage_root: root,
+		max_pov_size: 5000000,
+	});
+}
+
+#[test]
+fn prove_identity_works() {
+	new_test_ext().execute_with(|| {
+		let (proof, root) = create_proof(
+			1,
+			&[(1, vec![(0, Judgement::FeePaid(5)), (1, Judgement::Reasonable)]), (2, vec![])],
+			1,
+		);
+		add_storage_root(1, root);
+
+		assert!(!HasReasonableJudgement::<Test>::contains(&1));
+		assert_ok!(RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 1, proof));
+
+		System::assert_last_event(Event::JudgementProven { who: 1, block: 1 }.into());
+		assert_eq!(JudgedIdentities::<Test>::get(1), Some(1));
+		assert!(HasReasonableJudgement::<Test>::contains(&1));
+		assert!(!HasReasonableJudgement::<Test>::contains(&2));
+
+		assert_eq!(
+			EnsureReasonableJudgement::<Test>::try_origin(RuntimeOrigin::signed(1)).ok(),
+			Some(1)
+		);
+		assert!(EnsureReasonableJudgement::<Test>::try_origin(RuntimeOrigin::signed(2)).is_err());
+		assert!(EnsureReasonableJudgement::<Test>::try_origin(RuntimeOrigin::root()).is_err());
+	});
+}
+
+#[test]
+fn prove_identity_fails_without_reasonable_judgement() {
+	new_test_ext().execute_with(|| {
+		let identities = [
+			(1, vec![(0, Judgement::FeePaid(5)), (1, Judgement::LowQuality)]),
+			(2, vec![(0, Judgement::Erroneous)]),
+		];
+
+		for who in [1, 2, 3] {
+			let (proof, root) = create_proof(who, &identities, who);
+			add_storage_root(who, root);
+
+			assert_err!(
+				RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), who, proof),
+				Error::<Test>::NoReasonableJudgement
+			);
+			assert!(!HasReasonableJudgement::<Test>::contains(&who));
+		}
+	});
+}
+
+#[test]
+fn prove_identity_rejects_invalid_proofs() {
+	new_test_ext().execute_with(|| {
+		let (proof, root) = create_proof(
+			1,
+			&[(1, vec![(0, Judgement::KnownGood)]), (2, vec![(0, Judgement::Reasonable)])],
+			1,
+		);
+		add_storage_root(1, root);
+
+		let RemoteIdentityProof::ParaHead { relay_proof, para_proof, .. } = proof.clone();
+
+		assert_err!(
+			RemoteIdentity::prove_identity(
+				RuntimeOrigin::signed(3),
+				1,
+				RemoteIdentityProof::ParaHead {
+					block: 2,
+					relay_proof: relay_proof.clone(),
+					para_proof: para_proof.clone(),
+				}
+			),
+			Error::<Test>::UnknownProofAnchorBlock
+		);
+
+		assert_err!(
+			RemoteIdentity::prove_identity(
+				RuntimeOrigin::signed(3),
+				1,
+				RemoteIdentityProof::ParaHead {
+					block: 1,
+					relay_proof: Vec::new(),
+					para_proof: para_proof.clone(),
+				}
+			),
+			Error::<Test>::InvalidParaHeadProof
+		);
+
+		assert_err!(
+			RemoteIdentity::prove_identity(
+				RuntimeOrigin::signed(3),
+				1,
+				RemoteIdentityProof::ParaHead { block: 1, relay_proof, para_proof: Vec::new() }
+			),
+			Error::<Test>::InvalidIdentityProof
+		);
+
+		// The proof is only valid for the account it was created for.
+		assert_err!(
+			RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 2, proof.clone()),
+			Error::<Test>::InvalidIdentityProof
+		);
+
+		assert_ok!(RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 1, proof));
+	});
+}
+
+#[test]
+fn prove_identity_rejects_too_large_proofs() {
+	new_test_ext().execute_with(|| {
+		let (proof, root) = create_proof(1, &[(1, vec![(0, Judgement::Reasonable)])], 1);
+		add_storage_root(1, root);
+
+		let RemoteIdentityProof::ParaHead { relay_proof, para_proof, .. } = proof;
+
+		let mut too_many_nodes = para_proof.clone();
+		too_many_nodes.extend((0..16).map(|i| vec![i]));
+		assert_err!(
+			RemoteIdentity::prove_identity(
+				RuntimeOrigin::signed(3),
+				1,
+				RemoteIdentityProof::ParaHead {
+					block: 1,
+					relay_proof: relay_proof.clone(),
+					para_proof: too_many_nodes,
+				}
+			),
+			Error::<Test>::ProofTooLarge
+		);
+
+		let mut too_large = para_proof;
+		too_large.push(vec![0; 16 * 1024]);
+		assert_err!(
+			RemoteIdentity::prove_identity(
+				RuntimeOrigin::signed(3),
+				1,
+				RemoteIdentityProof::ParaHead { block: 1, relay_proof, para_proof: too_large }
+			),
+			Error::<Test>::ProofTooLarge
+		);
+	});
+}
+
+#[test]
+fn removed_judgement_can_be_proven() {
+	new_test_ext().execute_with(|| {
+		let (judged_proof, root) = create_proof(1, &[(1, vec![(0, Judgement::Reasonable)])], 1);
+		add_storage_root(1, root);
+		assert_ok!(RemoteIdentity::prove_identity(
+			RuntimeOrigin::signed(3),
+			1,
+			judged_proof.clone()
+		));
+
+		let (cleared_proof, root) = create_proof(1, &[(2, vec![(0, Judgement::Reasonable)])], 2);
+		add_storage_root(2, root);
+		assert_ok!(RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 1, cleared_proof));
+
+		System::assert_last_event(Event::JudgementRemoved { who: 1, block: 2 }.into());
+		assert_eq!(JudgedIdentities::<Test>::get(1), None);
+		assert!(!HasReasonableJudgement::<Test>::contains(&1));
+
+		// Proving the judgement again at a later block works.
+		let (judged_again_proof, root) =
+			create_proof(1, &[(1, vec![(0, Judgement::Reasonable)])], 3);
+		add_storage_root(3, root);
+		assert_ok!(RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 1, judged_again_proof));
+		assert_eq!(JudgedIdentities::<Test>::get(1), Some(3));
+
+		// An older proof can not override a more recent one.
+		assert_err!(
+			RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 1, judged_proof),
+			Error::<Test>::OutdatedProof
+		);
+	});
+}
+
+#[test]
+fn stale_proof_can_not_restore_removed_judgement() {
+	new_test_ext().execute_with(|| {
+		let (judged_proof, root) = create_proof(1, &[(1, vec![(0, Judgement::Reasonable)])], 1);
+		add_storage_root(1, root);
+		assert_ok!(RemoteIdentity::prove_identity(
+			RuntimeOrigin::signed(3),
+			1,
+			judged_proof.clone()
+		));
+
+		let (cleared_proof, root) = create_proof(1, &[], 2);
+		add_storage_root(2, root);
+		assert_ok!(RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 1, cleared_proof));
+		assert_eq!(LastProofBlock::<Test>::get(1), Some(2));
+
+		// The storage root of the judged proof is still known, but the removal is more recent.
+		assert_err!(
+			RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 1, judged_proof),
+			Error::<Test>::OutdatedProof
+		);
+		assert!(!HasReasonableJudgement::<Test>::contains(&1));
+	});
+}
+
+#[test]
+fn proven_removal_is_pruned_with_its_storage_root() {
+	new_test_ext().execute_with(|| {
+		let identities =
+			[(1, vec![(0, Judgement::Reasonable)]), (2, vec![(0, Judgement::Reasonable)])];
+		for who in [1, 2] {
+			let (proof, root) = create_proof(who, &identities, who);
+			add_storage_root(who, root);
+			assert_ok!(RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), who, proof));
+		}
+
+		let (cleared_proof, root) = create_proof(1, &[], 3);
+		add_storage_root(3, root);
+		assert_ok!(RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 1, cleared_proof));
+		assert_eq!(LastProofBlock::<Test>::get(1), Some(3));
+		assert_eq!(ProofBlocksToPrune::<Test>::get(3).into_inner(), vec![1]);
+
+		// Only one removal can be proven per anchor block.
+		let (cleared_proof, _) = create_proof(2, &[], 3);
+		assert_err!(
+			RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 2, cleared_proof),
+			Error::<Test>::TooManyRemovals
+		);
+		assert!(JudgedIdentities::<Test>::contains_key(2));
+
+		// The storage root of block 3 is still known.
+		add_storage_root(12, H256::zero());
+		assert_eq!(LastProofBlock::<Test>::get(1), Some(3));
+
+		// No proof anchored at or before block 3 can be verified anymore.
+		add_storage_root(13, H256::zero());
+		assert_eq!(LastProofBlock::<Test>::get(1), None);
+		assert!(!ProofBlocksToPrune::<Test>::contains_key(3));
+	});
+}
+
+#[test]
+fn proving_the_judgement_again_removes_the_proven_removal() {
+	new_test_ext().execute_with(|| {
+		let (cleared_proof, root) = create_proof(1, &[], 2);
+		let (judged_proof, judged_root) =
+			create_proof(1, &[(1, vec![(0, Judgement::Reasonable)])], 1);
+		add_storage_root(1, judged_root);
+		add_storage_root(2, root);
+		assert_ok!(RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 1, judged_proof));
+		assert_ok!(RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 1, cleared_proof));
+
+		let (judged_proof, root) = create_proof(1, &[(1, vec![(0, Judgement::Reasonable)])], 3);
+		add_storage_root(3, root);
+		assert_ok!(RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 1, judged_proof));
+		assert_eq!(LastProofBlock::<Test>::get(1), None);
+		assert_eq!(JudgedIdentities::<Test>::get(1), Some(3));
+	});
+}
+
+#[test]
+fn proven_judgement_expires() {
+	new_test_ext().execute_with(|| {
+		let (proof, root) = create_proof(1, &[(1, vec![(0, Judgement::Reasonable)])], 1);
+		add_storage_root(1, root);
+		assert_ok!(RemoteIdentity::prove_identity(RuntimeOrigin::signed(3), 1, proof));
+
+		add_storage_root(6, H256::zero());
+		assert!(HasReasonableJudgement::<Test>::contains(&1));
+
+		add_storage_root(7, H256::zero());
+		assert!(!HasReasonableJudgement::<Test>::contains(&1));
+		assert!(EnsureReasonableJudgement::<Test>::try_origin(RuntimeOrigin::signed(1)).is_err());
+	});
+}
@@ -17,7 +17,7 @@
 
 use super::*;
 use crate::Pallet as RemoteProxy;
-use alloc::{boxed::Box, vec};
+use alloc::{boxed::Box, vec, vec::Vec};
 use frame_benchmarking::v2::{
 	account, impl_test_function, instance_benchmarks, whitelisted_caller,
 };
@@ -132,5 +132,35 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn register_remote_proxies_proof(
+		a: Linear<1, { T::MaxRegisteredProxies::get() }>,
+		p: Linear<0, { 1024 * 1024 }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = account("target", 0, SEED);
+		let reals: Vec<T::AccountId> = (0..a).map(|i| account("real", i, SEED)).collect();
+		let proxies: Vec<_> = reals.iter().map(|real| (caller.clone(), real.clone())).collect();
+		let (proof, block_number, storage_root) =
+			T::RemoteProxy::create_remote_proxies_proof(&proxies);
+		BlockToRoot::<T, I>::set(BoundedVec::truncate_from(vec![(block_number, storage_root)]));
+		// Unused nodes still need to be hashed when verifying the proof.
+		let proof = match proof {
+			RemoteProxyProof::RelayChain { mut proof, block } => {
+				proof.push(vec![0u8; p as usize]);
+				RemoteProxyProof::RelayChain { proof, block }
+			},
+		};
+		let reals_lookup = reals.iter().cloned().map(T::Lookup::unlookup).collect();
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), reals_lookup, proof);
+
+		for real in reals {
+			assert!(RegisteredProxies::<T, I>::contains_key(real));
+		}
+
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(RemoteProxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
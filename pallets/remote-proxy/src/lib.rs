@@ -20,9 +20,10 @@
 //! location of the proxy depends on the [`RemoteProxyInterface`] implementation provided to this
 //! pallet. The underlying implementation works by verifying proofs from the remote location that
 //! prove the existence of a proxy. The remote proof is verified against a storage root from the
-//! remote location. These storage roots are extracted from the relay chain. So, the security
-//! of the proxy depends on the remote location. This means that the remote location should be a
-//! trusted chain that for example doesn't create fake proxies.
+//! remote location. These storage roots are extracted from the relay chain, or on the relay chain
+//! itself from the head of the remote parachain. So, the security of the proxy depends on the
+//! remote location. This means that the remote location should be a trusted chain that for example
+//! doesn't create fake proxies.
 //!
 //! ## Functions
 //!
@@ -36,6 +37,9 @@
 //! - [`Pallet::remote_proxy_with_registered_proof`]: Use a previously registered `proof` to
 //!   dispatch the wrapped call.
 //!
+//! - [`Pallet::register_remote_proxies_proof`]: Register the proxies of several accounts, proven by
+//!   one `proof`, for the rest of the block.
+//!
 //! ## Security considerations
 //!
 //! As explained above the security of the proxy depends on the remote location. So, if the remote
@@ -93,6 +97,14 @@ pub trait RemoteProxyInterface<AccountId, ProxyType, BlockNumber> {
 		validation_data: &PersistedValidationData,
 	) -> Option<(Self::RemoteBlockNumber, <Self::RemoteHasher as Hasher>::Out)>;
 
+	/// Get the latest block to storage root mapping from the local state.
+	///
+	/// This is for chains that don't receive the [`PersistedValidationData`], like the relay chain
+	/// reading the head of the remote parachain. It is checked at the start of every block.
+	fn latest_storage_root() -> Option<(Self::RemoteBlockNumber, Self::RemoteHash)> {
+		None
+	}
+
 	/// The storage key where to find the [`ProxyDefinition`] for the given proxy account in the
 	/// remote chain.
 	fn proxy_definition_storage_key(proxy: &Self::RemoteAccountId) -> Vec<u8> {
@@ -127,6 +139,18 @@ pub trait RemoteProxyInterface<AccountId, ProxyType, BlockNumber> {
 	fn create_remote_proxy_proof(
 		caller: &AccountId,
 		proxy: &AccountId,
+	) -> (RemoteProxyProof<Self::RemoteBlockNumber>, Self::RemoteBlockNumber, Self::RemoteHash) {
+		Self::create_remote_proxies_proof(&[(caller.clone(), proxy.clone())])
+	}
+
+	/// Create a remote proxy proof of several proxies to be used in benchmarking.
+	///
+	/// Every `(caller, proxy)` pair is a proxy definition of `proxy` with `caller` as delegate.
+	/// Returns the `proof`, `block_number` and `storage_root` like
+	/// [`Self::create_remote_proxy_proof`].
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_remote_proxies_proof(
+		proxies: &[(AccountId, AccountId)],
 	) -> (RemoteProxyProof<Self::RemoteBlockNumber>, Self::RemoteBlockNumber, Self::RemoteHash);
 }
 
@@ -168,6 +192,11 @@ pub mod pallet {
 		BlockNumberFor<T>,
 	>>::RemoteProxyType;
 	type WeightInfoOf<T, I> = <T as Config<I>>::WeightInfo;
+	type ProxyDefinitionOf<T> = ProxyDefinition<
+		<T as frame_system::Config>::AccountId,
+		<T as pallet_proxy::Config>::ProxyType,
+		BlockNumberFor<T>,
+	>;
 
 	#[pallet::pallet]
 	pub struct Pallet<T, I = ()>(_);
@@ -181,6 +210,19 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// The proxy definitions registered by [`Pallet::register_remote_proxies_proof`] for the
+	/// current block.
+	///
+	/// Cleared at the end of every block.
+	#[pallet::storage]
+	pub type RegisteredProxies<T: Config<I>, I: 'static = ()> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		BoundedVec<ProxyDefinitionOf<T>, <T as pallet_proxy::Config>::MaxProxies>,
+		OptionQuery,
+	>;
+
 	/// Configuration trait.
 	#[pallet::config]
 	pub trait Config<I: 'static = ()>: frame_system::Config + pallet_proxy::Config {
@@ -193,6 +235,11 @@ pub mod pallet {
 		/// the latest added storage root will be available for validating proofs.
 		type MaxStorageRootsToKeep: Get<u32>;
 
+		/// The maximum number of accounts whose proxies can be registered with one proof by
+		/// [`Pallet::register_remote_proxies_proof`].
+		#[pallet::constant]
+		type MaxRegisteredProxies: Get<u32>;
+
 		/// The interface for interacting with the remote proxy.
 		type RemoteProxy: RemoteProxyInterface<
 			Self::AccountId,
@@ -204,26 +251,37 @@ pub mod pallet {
 		type WeightInfo: WeightInfo;
 	}
 
+	#[pallet::hooks]
+	impl<T: Config<I>, I: 'static> Hooks<BlockNumberFor<T>> for Pallet<T, I> {
+		fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+			// Clearing `RegisteredProxies` in `on_finalize`, the removals are paid by
+			// `register_remote_proxies_proof`.
+			let on_finalize = T::DbWeight::get().reads(1);
+
+			let Some((block, hash)) = T::RemoteProxy::latest_storage_root() else {
+				return on_finalize;
+			};
+
+			// The remote head is not updated in every block.
+			if BlockToRoot::<T, I>::get().last().is_none_or(|(last, _)| *last < block) {
+				Self::note_storage_root(block, hash);
+			}
+
+			T::DbWeight::get().reads_writes(2, 1).saturating_add(on_finalize)
+		}
+
+		fn on_finalize(_n: BlockNumberFor<T>) {
+			let _ = RegisteredProxies::<T, I>::clear(u32::MAX, None);
+		}
+	}
+
 	impl<T: Config<I>, I: 'static> OnSystemEvent for Pallet<T, I> {
 		fn on_validation_data(validation_data: &PersistedValidationData) {
 			let Some((block, hash)) = T::RemoteProxy::block_to_storage_root(validation_data) else {
 				return;
 			};
 
-			// Update the block to root mappings.
-			BlockToRoot::<T, I>::mutate(|roots| {
-				let delete_up_to =
-					block.clone().saturating_sub(T::MaxStorageRootsToKeep::get().into());
-
-				while roots.first().is_some_and(|f| f.0 <= delete_up_to) {
-					roots.remove(0);
-				}
-
-				// We always remove all the old items before, thus there should always be space in
-				// the vector.
-				let _res = roots.try_push((block, hash));
-				debug_assert!(_res.is_ok());
-			});
+			Self::note_storage_root(block, hash);
 		}
 
 		fn on_validation_code_applied() {}
@@ -251,6 +309,10 @@ pub mod pallet {
 		DidNotFindMatchingProxyDefinition,
 		/// Proxy proof not registered.
 		ProxyProofNotRegistered,
+		/// More than [`Config::MaxRegisteredProxies`] accounts to register.
+		TooManyProxies,
+		/// An account has more proxies than allowed by `pallet_proxy`.
+		TooManyProxyDefinitions,
 	}
 
 	/// The remote proxy proof to prove the existence of a proxy account.
@@ -262,6 +324,16 @@ pub mod pallet {
 		RelayChain { proof: Vec<Vec<u8>>, block: RemoteBlockNumber },
 	}
 
+	impl<RemoteBlockNumber> RemoteProxyProof<RemoteBlockNumber> {
+		/// The size of the proof nodes in bytes.
+		pub fn proof_size(&self) -> u32 {
+			match self {
+				Self::RelayChain { proof, .. } =>
+					proof.iter().map(|node| node.len() as u32).fold(0, u32::saturating_add),
+			}
+		}
+	}
+
 	/// The dispatch context to keep track of registered proofs.
 	#[derive(Default)]
 	pub(crate) struct RemoteProxyContext<RemoteBlockNumber> {
@@ -355,7 +427,9 @@ pub mod pallet {
 		///
 		/// The dispatch origin for this call must be _Signed_. The difference to
 		/// [`Self::remote_proxy`] is that the proof nees to registered before using
-		/// [`Self::register_remote_proxy_proof`] (see for more information).
+		/// [`Self::register_remote_proxy_proof`] (see for more information). Without a proof
+		/// registered in the current transaction, the proxies registered for `real` in the current
+		/// block by [`Self::register_remote_proxies_proof`] are used.
 		///
 		/// Parameters:
 		/// - `real`: The account that the proxy will make a call on behalf of.
@@ -383,14 +457,149 @@ pub mod pallet {
 				RemoteProxyContext<RemoteBlockNumberOf<T, I>>,
 				_,
 			>(|context| context.or_default().proofs.pop())
-			.flatten()
-			.ok_or(Error::<T, I>::ProxyProofNotRegistered)?;
+			.flatten();
+
+			match proof {
+				Some(proof) => Self::do_remote_proxy(who, real, force_proxy_type, *call, proof),
+				None => {
+					let definitions = RegisteredProxies::<T, I>::get(&real)
+						.ok_or(Error::<T, I>::ProxyProofNotRegistered)?;
+					Self::proxy_with_definitions(
+						who,
+						real,
+						force_proxy_type,
+						*call,
+						definitions.into_inner(),
+					)
+				},
+			}
+		}
 
-			Self::do_remote_proxy(who, real, force_proxy_type, *call, proof)
+		/// Register the proxies of several accounts, proven by one `proof`, for the rest of the
+		/// block.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// The `proof` is verified once against the storage root of its anchor block and needs to
+		/// contain the proxy definitions of all `reals`. Until the end of the block,
+		/// [`Self::remote_proxy_with_registered_proof`] can use these definitions for any of the
+		/// `reals`, in this and in any later transaction. This way a `utility::batch` of calls for
+		/// different accounts needs a single proof.
+		///
+		/// Registering an account again replaces its proxy definitions.
+		///
+		/// Parameters:
+		/// - `reals`: The accounts whose proxies are registered.
+		/// - `proof`: The proof from the remote chain about the proxies of all `reals`.
+		#[pallet::call_index(3)]
+		#[pallet::weight(
+			WeightInfoOf::<T, I>::register_remote_proxies_proof(
+				reals.len() as u32,
+				proof.proof_size(),
+			)
+			// Removing the registered proxies at the end of the block.
+			.saturating_add(T::DbWeight::get().writes(reals.len() as u64))
+		)]
+		pub fn register_remote_proxies_proof(
+			origin: OriginFor<T>,
+			reals: Vec<AccountIdLookupOf<T>>,
+			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(
+				reals.len() as u32 <= T::MaxRegisteredProxies::get(),
+				Error::<T, I>::TooManyProxies
+			);
+
+			let RemoteProxyProof::RelayChain { proof, block } = proof;
+			let (storage_root, db) = Self::proof_db(&block, proof)?;
+
+			for real in reals {
+				let real = T::Lookup::lookup(real)?;
+				let definitions = Self::proven_proxy_definitions(&db, &storage_root, &real)?;
+				let definitions = BoundedVec::try_from(definitions)
+					.map_err(|_| Error::<T, I>::TooManyProxyDefinitions)?;
+				RegisteredProxies::<T, I>::insert(real, definitions);
+			}
+
+			Ok(())
 		}
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Update the block to root mappings with the given storage root of the remote chain.
+		fn note_storage_root(block: RemoteBlockNumberOf<T, I>, hash: RemoteHashOf<T, I>) {
+			BlockToRoot::<T, I>::mutate(|roots| {
+				let delete_up_to =
+					block.clone().saturating_sub(T::MaxStorageRootsToKeep::get().into());
+
+				while roots.first().is_some_and(|f| f.0 <= delete_up_to) {
+					roots.remove(0);
+				}
+
+				// We always remove all the old items before, thus there should always be space in
+				// the vector.
+				let _res = roots.try_push((block, hash));
+				debug_assert!(_res.is_ok());
+			});
+		}
+
+		/// The storage root of the remote chain at `block` and the database of `proof`.
+		fn proof_db(
+			block: &RemoteBlockNumberOf<T, I>,
+			proof: Vec<Vec<u8>>,
+		) -> Result<(RemoteHashOf<T, I>, sp_trie::MemoryDB<RemoteHasherOf<T, I>>), DispatchError>
+		{
+			let roots = BlockToRoot::<T, I>::get();
+
+			let Ok(storage_root) =
+				roots.binary_search_by(|(b, _)| b.cmp(block)).map(|pos| roots[pos].1.clone())
+			else {
+				return Err(Error::<T, I>::UnknownProofAnchorBlock.into());
+			};
+
+			let db = sp_trie::StorageProof::new(proof).into_memory_db::<RemoteHasherOf<T, I>>();
+			Ok((storage_root, db))
+		}
+
+		/// The local proxy definitions of `real` at the remote chain, read from the proof `db`.
+		fn proven_proxy_definitions(
+			db: &sp_trie::MemoryDB<RemoteHasherOf<T, I>>,
+			storage_root: &RemoteHashOf<T, I>,
+			real: &T::AccountId,
+		) -> Result<Vec<ProxyDefinitionOf<T>>, DispatchError> {
+			let Some(real_remote) = T::RemoteProxy::local_to_remote_account_id(real) else {
+				return Err(Error::<T, I>::CouldNotConvertLocalToRemoteAccountId.into());
+			};
+
+			let key = T::RemoteProxy::proxy_definition_storage_key(&real_remote);
+
+			let value = sp_trie::read_trie_value::<sp_trie::LayoutV1<_>, _>(
+				db,
+				storage_root,
+				&key,
+				None,
+				None,
+			)
+			.ok()
+			.flatten()
+			.ok_or(Error::<T, I>::InvalidProof)?;
+
+			let proxy_definitions = alloc::vec::Vec::<
+				ProxyDefinition<
+					RemoteAccountIdOf<T, I>,
+					RemoteProxyTypeOf<T, I>,
+					RemoteBlockNumberOf<T, I>,
+				>,
+			>::decode(&mut &value[..])
+			.map_err(|_| Error::<T, I>::ProxyDefinitionDecodingFailed)?;
+
+			Ok(proxy_definitions
+				.into_iter()
+				.filter_map(T::RemoteProxy::remote_to_local_proxy_defintion)
+				.collect())
+		}
+
 		fn do_remote_proxy(
 			who: T::AccountId,
 			real: T::AccountId,
@@ -398,63 +607,32 @@ pub mod pallet {
 			call: <T as pallet_proxy::Config>::RuntimeCall,
 			proof: RemoteProxyProof<RemoteBlockNumberOf<T, I>>,
 		) -> DispatchResult {
-			let Some(real_remote) = T::RemoteProxy::local_to_remote_account_id(&real) else {
-				return Err(Error::<T, I>::CouldNotConvertLocalToRemoteAccountId.into());
-			};
-
-			let def = match proof {
+			let definitions = match proof {
 				RemoteProxyProof::RelayChain { proof, block } => {
-					let roots = BlockToRoot::<T, I>::get();
-
-					let Ok(storage_root) = roots
-						.binary_search_by(|(b, _)| b.cmp(&block))
-						.map(|pos| roots[pos].1.clone())
-					else {
-						return Err(Error::<T, I>::UnknownProofAnchorBlock.into());
-					};
-
-					let key = T::RemoteProxy::proxy_definition_storage_key(&real_remote);
-
-					let db =
-						sp_trie::StorageProof::new(proof).into_memory_db::<RemoteHasherOf<T, I>>();
-					let value = sp_trie::read_trie_value::<sp_trie::LayoutV1<_>, _>(
-						&db,
-						&storage_root,
-						&key,
-						None,
-						None,
-					)
-					.ok()
-					.flatten()
-					.ok_or(Error::<T, I>::InvalidProof)?;
-
-					let proxy_definitions = alloc::vec::Vec::<
-						ProxyDefinition<
-							RemoteAccountIdOf<T, I>,
-							RemoteProxyTypeOf<T, I>,
-							RemoteBlockNumberOf<T, I>,
-						>,
-					>::decode(&mut &value[..])
-					.map_err(|_| Error::<T, I>::ProxyDefinitionDecodingFailed)?;
-
-					let f = |x: &ProxyDefinition<
-						T::AccountId,
-						T::ProxyType,
-						BlockNumberFor<T>,
-					>|
-					 -> bool {
-						x.delegate == who &&
-							force_proxy_type.as_ref().is_none_or(|y| &x.proxy_type == y)
-					};
-
-					proxy_definitions
-						.into_iter()
-						.filter_map(T::RemoteProxy::remote_to_local_proxy_defintion)
-						.find(f)
-						.ok_or(Error::<T, I>::DidNotFindMatchingProxyDefinition)?
+					let (storage_root, db) = Self::proof_db(&block, proof)?;
+					Self::proven_proxy_definitions(&db, &storage_root, &real)?
 				},
 			};
 
+			Self::proxy_with_definitions(who, real, force_proxy_type, call, definitions)
+		}
+
+		/// Dispatch `call` from `real` if one of its `definitions` allows `who` to do it.
+		fn proxy_with_definitions(
+			who: T::AccountId,
+			real: T::AccountId,
+			force_proxy_type: Option<T::ProxyType>,
+			call: <T as pallet_proxy::Config>::RuntimeCall,
+			definitions: Vec<ProxyDefinitionOf<T>>,
+		) -> DispatchResult {
+			let def = definitions
+				.into_iter()
+				.find(|x| {
+					x.delegate == who &&
+						force_proxy_type.as_ref().is_none_or(|y| &x.proxy_type == y)
+				})
+				.ok_or(Error::<T, I>::DidNotFindMatchingProxyDefinition)?;
+
 			ensure!(def.delay.is_zero(), Error::<T, I>::Unannounced);
 
 			Self::do_proxy(def, real, call);
@@ -21,8 +21,8 @@ use crate as remote_proxy;
 use codec::{Decode, DecodeWithMemTracking};
 use cumulus_pallet_parachain_system::OnSystemEvent;
 use frame_support::{
-	assert_err, assert_ok, construct_runtime, derive_impl,
-	traits::{Contains, Currency},
+	assert_err, assert_ok, construct_runtime, derive_impl, parameter_types,
+	traits::{Contains, Currency, Hooks},
 };
 use frame_system::Call as SystemCall;
 use pallet_balances::Call as BalancesCall;
@@ -133,6 +133,10 @@ impl pallet_proxy::Config for Test {
 	type BlockNumberProvider = System;
 }
 
+parameter_types! {
+	pub static LatestStorageRoot: Option<(u64, H256)> = None;
+}
+
 pub struct RemoteProxyImpl;
 
 impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
@@ -148,6 +152,10 @@ impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
 		Some((validation_data.relay_parent_number as _, validation_data.relay_parent_storage_root))
 	}
 
+	fn latest_storage_root() -> Option<(Self::RemoteBlockNumber, Self::RemoteHash)> {
+		LatestStorageRoot::get()
+	}
+
 	fn local_to_remote_account_id(local: &u64) -> Option<Self::RemoteAccountId> {
 		Some(*local)
 	}
@@ -163,9 +171,8 @@ impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
-	fn create_remote_proxy_proof(
-		caller: &u64,
-		proxy: &u64,
+	fn create_remote_proxies_proof(
+		proxies: &[(u64, u64)],
 	) -> (RemoteProxyProof<Self::RemoteBlockNumber>, u64, H256) {
 		use sp_trie::TrieMut;
 
@@ -173,14 +180,16 @@ impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
 		let mut trie =
 			sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut db, &mut root).build();
 
-		let proxy_definition = vec![ProxyDefinition::<u64, ProxyType, u64> {
-			delegate: *caller,
-			proxy_type: ProxyType::default(),
-			delay: 0,
-		}];
+		for (caller, proxy) in proxies {
+			let proxy_definition = vec![ProxyDefinition::<u64, ProxyType, u64> {
+				delegate: *caller,
+				proxy_type: ProxyType::default(),
+				delay: 0,
+			}];
 
-		trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
-			.unwrap();
+			trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
+				.unwrap();
+		}
 		drop(trie);
 
 		(
@@ -196,6 +205,7 @@ impl crate::RemoteProxyInterface<u64, ProxyType, u64> for RemoteProxyImpl {
 
 impl Config for Test {
 	type MaxStorageRootsToKeep = ConstU32<10>;
+	type MaxRegisteredProxies = ConstU32<3>;
 	type RemoteProxy = RemoteProxyImpl;
 	type WeightInfo = ();
 }
@@ -644,3 +654,172 @@ fn clean_up_works_and_old_blocks_are_rejected() {
 			.for_each(|(b, _)| assert!(*b >= 31 && *b <= 40));
 	});
 }
+
+#[test]
+fn storage_roots_from_local_state_are_noted() {
+	new_test_ext().execute_with(|| {
+		// Nothing is noted without a storage root.
+		RemoteProxy::on_initialize(1);
+		assert!(BlockToRoot::<Test>::get().is_empty());
+
+		LatestStorageRoot::set(Some((5, H256::repeat_byte(1))));
+		RemoteProxy::on_initialize(2);
+		assert_eq!(BlockToRoot::<Test>::get().into_inner(), vec![(5, H256::repeat_byte(1))]);
+
+		// The same remote block is only noted once.
+		RemoteProxy::on_initialize(3);
+		assert_eq!(BlockToRoot::<Test>::get().len(), 1);
+
+		LatestStorageRoot::set(Some((6, H256::repeat_byte(2))));
+		RemoteProxy::on_initialize(4);
+		assert_eq!(
+			BlockToRoot::<Test>::get().into_inner(),
+			vec![(5, H256::repeat_byte(1)), (6, H256::repeat_byte(2))]
+		);
+
+		// Old roots are cleaned up as with the validation data.
+		LatestStorageRoot::set(Some((20, H256::repeat_byte(3))));
+		RemoteProxy::on_initialize(5);
+		assert_eq!(BlockToRoot::<Test>::get().into_inner(), vec![(20, H256::repeat_byte(3))]);
+	});
+}
+
+#[test]
+fn registered_proofs_of_several_accounts_are_reused_within_the_block() {
+	let mut ext = new_test_ext();
+
+	let (anon, anon2) = ext.execute_with(|| {
+		Balances::make_free_balance_be(&1, 12); // An extra one for the ED.
+		assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), ProxyType::Any, 0, 0));
+		assert_ok!(Proxy::create_pure(RuntimeOrigin::signed(1), ProxyType::Any, 0, 1));
+		(
+			Proxy::pure_account(&1, &ProxyType::Any, 0, None),
+			Proxy::pure_account(&1, &ProxyType::Any, 1, None),
+		)
+	});
+
+	let proof = sp_state_machine::prove_read(
+		ext.as_backend(),
+		[
+			pallet_proxy::Proxies::<Test>::hashed_key_for(anon),
+			pallet_proxy::Proxies::<Test>::hashed_key_for(anon2),
+		],
+	)
+	.unwrap();
+	let root = *ext.as_backend().root();
+
+	new_test_ext().execute_with(|| {
+		let call = Box::new(call_transfer(6, 1));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(3), anon, 5));
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(4), anon2, 5));
+
+		RemoteProxy::on_validation_data(&PersistedValidationData {
+			parent_head: vec![].into(),
+			relay_parent_number: 1,
+			relay_parent_storage_root: root,
+			max_pov_size: 5000000,
+		});
+		assert_ok!(RemoteProxy::register_remote_proxies_proof(
+			RuntimeOrigin::signed(2),
+			vec![anon, anon2],
+			RemoteProxyProof::RelayChain {
+				proof: proof.clone().into_iter_nodes().collect(),
+				block: 1
+			}
+		));
+
+		// One proof serves a batch of calls for both accounts.
+		assert_ok!(RuntimeCall::from(UtilityCall::batch_all {
+			calls: vec![
+				crate::Call::remote_proxy_with_registered_proof {
+					real: anon,
+					force_proxy_type: None,
+					call: call.clone(),
+				}
+				.into(),
+				crate::Call::remote_proxy_with_registered_proof {
+					real: anon2,
+					force_proxy_type: None,
+					call: call.clone(),
+				}
+				.into(),
+			]
+		})
+		.dispatch(RuntimeOrigin::signed(1)));
+		assert_eq!(Balances::free_balance(6), 2);
+
+		// ... and later transactions of the same block.
+		assert_ok!(RemoteProxy::remote_proxy_with_registered_proof(
+			RuntimeOrigin::signed(1),
+			anon2,
+			None,
+			call.clone(),
+		));
+		assert_eq!(Balances::free_balance(6), 3);
+
+		// Only the delegates of the proof can use it.
+		assert_err!(
+			RemoteProxy::remote_proxy_with_registered_proof(
+				RuntimeOrigin::signed(2),
+				anon,
+				None,
+				call.clone(),
+			),
+			Error::<Test>::DidNotFindMatchingProxyDefinition
+		);
+
+		// The registrations end with the block.
+		RemoteProxy::on_finalize(1);
+		assert_err!(
+			RemoteProxy::remote_proxy_with_registered_proof(
+				RuntimeOrigin::signed(1),
+				anon,
+				None,
+				call.clone(),
+			),
+			Error::<Test>::ProxyProofNotRegistered
+		);
+
+		// All accounts need to be in the proof.
+		assert_err!(
+			RemoteProxy::register_remote_proxies_proof(
+				RuntimeOrigin::signed(1),
+				vec![anon, anon2, 7],
+				RemoteProxyProof::RelayChain {
+					proof: proof.clone().into_iter_nodes().collect(),
+					block: 1
+				}
+			),
+			Error::<Test>::InvalidProof
+		);
+		assert_err!(
+			RemoteProxy::register_remote_proxies_proof(
+				RuntimeOrigin::signed(1),
+				vec![anon, anon2, 7, 8],
+				RemoteProxyProof::RelayChain {
+					proof: proof.clone().into_iter_nodes().collect(),
+					block: 1
+				}
+			),
+			Error::<Test>::TooManyProxies
+		);
+
+		// Once the anchor block is pruned, the proof can't be registered anymore.
+		for i in 2u32..=11u32 {
+			RemoteProxy::on_validation_data(&PersistedValidationData {
+				parent_head: vec![].into(),
+				relay_parent_number: i,
+				relay_parent_storage_root: H256::zero(),
+				max_pov_size: 5000000,
+			});
+		}
+		assert_err!(
+			RemoteProxy::register_remote_proxies_proof(
+				RuntimeOrigin::signed(1),
+				vec![anon, anon2],
+				RemoteProxyProof::RelayChain { proof: proof.into_iter_nodes().collect(), block: 1 }
+			),
+			Error::<Test>::UnknownProofAnchorBlock
+		);
+	});
+}
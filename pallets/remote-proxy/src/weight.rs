@@ -21,6 +21,7 @@ pub trait WeightInfo {
 	fn remote_proxy_with_registered_proof() -> Weight;
 	fn register_remote_proxy_proof() -> Weight;
 	fn remote_proxy() -> Weight;
+	fn register_remote_proxies_proof(a: u32, p: u32) -> Weight;
 }
 
 impl WeightInfo for () {
@@ -35,4 +36,8 @@ impl WeightInfo for () {
 	fn remote_proxy() -> Weight {
 		Weight::MAX
 	}
+
+	fn register_remote_proxies_proof(_a: u32, _p: u32) -> Weight {
+		Weight::MAX
+	}
 }
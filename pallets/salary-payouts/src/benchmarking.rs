@@ -0,0 +1,57 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as SalaryPayouts;
+use frame_benchmarking::v2::*;
+
+#[instance_benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn set_preferences() -> Result<(), BenchmarkError> {
+		let origin =
+			T::MemberOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let who = T::MemberOrigin::ensure_origin(origin.clone())
+			.map_err(|_| BenchmarkError::Weightless)?;
+
+		// Split the salary evenly over the most assets, each of which is checked against the
+		// accepted assets.
+		let assets = T::BenchmarkHelper::setup_assets(T::MaxSplits::get());
+		let count = assets.len() as u32;
+		assert_eq!(count, T::MaxSplits::get());
+		let share = Permill::from_rational(1, count);
+		let last =
+			Permill::from_parts(Permill::one().deconstruct() - share.deconstruct() * (count - 1));
+		let shares: SharesOf<T, I> = BoundedVec::truncate_from(
+			assets
+				.into_iter()
+				.enumerate()
+				.map(|(index, asset)| (asset, if index as u32 + 1 == count { last } else { share }))
+				.collect(),
+		);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, shares.clone());
+
+		assert_eq!(Preferences::<T, I>::get(&who), Some(shares));
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(SalaryPayouts, crate::tests::new_test_ext(), crate::tests::Test);
+}
@@ -0,0 +1,547 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Salary payouts pallet
+//!
+//! `pallet_salary` pays every salary with one call to its `Paymaster`, in one asset. This pallet
+//! is a `Paymaster` for `pallet_salary` which pays a salary in several assets.
+//!
+//! ## Preferences
+//!
+//! Members choose how their salary is split with [`Pallet::set_preferences`], for example 70% in
+//! the reference asset and 30% in the native asset. The shares must add up to 100% and name
+//! accepted assets only. Without preferences the salary is paid in [`Config::ReferenceAsset`].
+//!
+//! ## Conversion and budgets
+//!
+//! Salaries are denominated in [`Config::ReferenceAsset`]. A share in another asset is converted
+//! through the native asset with [`Config::Converter`], which usually is the `AssetRate` pallet.
+//!
+//! [`Config::Assets`] lists the accepted assets with the budget of each asset for one salary
+//! cycle. A share whose asset has no conversion rate or not enough budget left in the cycle falls
+//! back to the first accepted asset that can pay it, in the order of [`Config::Assets`].
+//!
+//! ## Tracking
+//!
+//! A salary payout is split into one [`Leg`] per asset, each paid with [`Config::Paymaster`].
+//! The payout succeeds once all legs succeeded and fails once all legs concluded and at least one
+//! failed. `pallet_salary` lets the member retry a failed payout; the retry only pays the part of
+//! the salary that the successful legs did not pay and returns the failed legs to the budget.
+//!
+//! A paymaster like `PayOverXcm` reports the outcome of a payment only once, so the outcome of
+//! each leg is kept in its [`LegStatus`] the first time it is observed. A leg whose outcome is
+//! unknown may have been paid, so it is neither paid again nor returned to the budget.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use alloc::{vec, vec::Vec};
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	storage::with_storage_layer,
+	traits::{
+		tokens::{ConversionFromAssetBalance, ConversionToAssetBalance, Pay, PaymentStatus},
+		Get,
+	},
+	BoundedVec,
+};
+use scale_info::TypeInfo;
+use sp_arithmetic::{traits::Zero, Permill};
+use sp_runtime::{traits::Saturating, DispatchError};
+
+pub use pallet::*;
+pub use weight::WeightInfo;
+
+/// The identifier of a salary payout.
+pub type PayoutId = u64;
+
+/// The balance of the assets paid by [`Config::Paymaster`].
+pub type BalanceOf<T, I = ()> = <<T as Config<I>>::Paymaster as Pay>::Balance;
+
+/// The identifier of a payment of [`Config::Paymaster`].
+pub type PaymentIdOf<T, I = ()> = <<T as Config<I>>::Paymaster as Pay>::Id;
+
+/// The split of a salary: the share of the salary paid in each asset.
+pub type SharesOf<T, I = ()> =
+	BoundedVec<(<T as Config<I>>::AssetKind, Permill), <T as Config<I>>::MaxSplits>;
+
+/// A [`Leg`] of a payout of the runtime.
+pub type LegOf<T, I = ()> = Leg<<T as Config<I>>::AssetKind, BalanceOf<T, I>, PaymentIdOf<T, I>>;
+
+/// A [`Payout`] of the runtime.
+pub type PayoutOf<T, I = ()> = Payout<
+	<T as frame_system::Config>::AccountId,
+	BoundedVec<LegOf<T, I>, <T as Config<I>>::MaxSplits>,
+>;
+
+/// Sets up the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetKind> {
+	/// Make `count` distinct assets accepted and return them.
+	fn setup_assets(count: u32) -> Vec<AssetKind>;
+}
+
+/// The outcome of a [`Leg`], as observed from [`Config::Paymaster`].
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LegStatus {
+	/// The payment did not conclude yet, or was not checked since it did.
+	Pending,
+	/// The payment succeeded.
+	Paid,
+	/// The payment failed.
+	Failed,
+}
+
+/// The part of a payout paid in one asset.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct Leg<AssetKind, Balance, Id> {
+	/// The asset paid.
+	pub asset: AssetKind,
+	/// The amount of `asset` paid.
+	pub amount: Balance,
+	/// The part of the salary paid by this leg, in the reference asset.
+	pub value: Balance,
+	/// The payment of [`Config::Paymaster`].
+	pub id: Id,
+	/// The outcome of the payment, kept once it is observed.
+	pub status: LegStatus,
+}
+
+/// A salary payout.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct Payout<AccountId, Legs> {
+	/// The member paid.
+	pub beneficiary: AccountId,
+	/// The salary cycle of the payout.
+	pub cycle: u32,
+	/// The payments of the payout, one per asset.
+	pub legs: Legs,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T, I = ()>(PhantomData<(T, I)>);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config<I: 'static = ()>:
+		frame_system::Config<RuntimeEvent: From<Event<Self, I>>>
+	{
+		/// The kind of assets the salaries are paid in.
+		type AssetKind: Parameter + MaxEncodedLen;
+
+		/// Pays one asset of a salary.
+		type Paymaster: Pay<Beneficiary = Self::AccountId, AssetKind = Self::AssetKind>;
+
+		/// Converts the balances of the assets from and to the native asset.
+		type Converter: ConversionFromAssetBalance<BalanceOf<Self, I>, Self::AssetKind, BalanceOf<Self, I>>
+			+ ConversionToAssetBalance<BalanceOf<Self, I>, Self::AssetKind, BalanceOf<Self, I>>;
+
+		/// The asset the salaries are denominated in.
+		type ReferenceAsset: Get<Self::AssetKind>;
+
+		/// The accepted assets with their budget for one salary cycle, in the order in which they
+		/// are used when a preferred asset can not pay its share.
+		type Assets: Get<Vec<(Self::AssetKind, BalanceOf<Self, I>)>>;
+
+		/// The index of the current salary cycle.
+		type Cycle: Get<u32>;
+
+		/// The origin of the members who can set their preferences.
+		type MemberOrigin: EnsureOrigin<Self::RuntimeOrigin, Success = Self::AccountId>;
+
+		/// The maximum number of assets a salary can be split into.
+		#[pallet::constant]
+		type MaxSplits: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Sets up the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetKind>;
+	}
+
+	/// The split of the salary chosen by a member.
+	#[pallet::storage]
+	pub type Preferences<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, SharesOf<T, I>, OptionQuery>;
+
+	/// The amount of each asset paid in a salary cycle.
+	#[pallet::storage]
+	pub type Spent<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::AssetKind, (u32, BalanceOf<T, I>), OptionQuery>;
+
+	/// The part of the salary of a member paid in a salary cycle, in the reference asset.
+	///
+	/// Only updated when a failed payout is retried.
+	#[pallet::storage]
+	pub type Paid<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, (u32, BalanceOf<T, I>), OptionQuery>;
+
+	/// The latest payout of a member.
+	#[pallet::storage]
+	pub type LastPayout<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, T::AccountId, PayoutId, OptionQuery>;
+
+	/// The payouts, until they are replaced by the next payout of the member.
+	#[pallet::storage]
+	pub type Payouts<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Twox64Concat, PayoutId, PayoutOf<T, I>, OptionQuery>;
+
+	/// The identifier of the next payout.
+	#[pallet::storage]
+	pub type NextPayoutId<T: Config<I>, I: 'static = ()> = StorageValue<_, PayoutId, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config<I>, I: 'static = ()> {
+		/// `who` set the split of their salary, empty `shares` pay it in the reference asset.
+		PreferencesSet { who: T::AccountId, shares: SharesOf<T, I> },
+		/// `amount` of `asset` of the payout `id` was sent to `who`.
+		LegPaid { who: T::AccountId, id: PayoutId, asset: T::AssetKind, amount: BalanceOf<T, I> },
+		/// A share of the salary of `who` is paid in `to`, as `from` could not pay it.
+		FellBack { who: T::AccountId, from: T::AssetKind, to: T::AssetKind },
+	}
+
+	#[pallet::error]
+	pub enum Error<T, I = ()> {
+		/// The shares do not add up to 100%.
+		InvalidShares,
+		/// The asset is not accepted.
+		UnknownAsset,
+		/// The asset is named more than once.
+		DuplicateAsset,
+		/// No accepted asset can pay a share of the salary.
+		NoBudget,
+		/// The payment of an asset failed.
+		PaymentFailed,
+	}
+
+	#[pallet::call]
+	impl<T: Config<I>, I: 'static> Pallet<T, I> {
+		/// Set how the salary of the caller is split into assets.
+		///
+		/// The dispatch origin for this call must be [`Config::MemberOrigin`].
+		///
+		/// Parameters:
+		/// - `shares`: The share of the salary paid in each asset. The shares must add up to 100%
+		///   and name each accepted asset at most once. Empty shares pay the salary in the
+		///   reference asset.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::set_preferences())]
+		pub fn set_preferences(origin: OriginFor<T>, shares: SharesOf<T, I>) -> DispatchResult {
+			let who = T::MemberOrigin::ensure_origin(origin)?;
+
+			Self::check_shares(&shares)?;
+			if shares.is_empty() {
+				Preferences::<T, I>::remove(&who);
+			} else {
+				Preferences::<T, I>::insert(&who, &shares);
+			}
+			Self::deposit_event(Event::PreferencesSet { who, shares });
+
+			Ok(())
+		}
+	}
+}
+
+impl<T: Config<I>, I: 'static> Pallet<T, I> {
+	/// Check that `shares` add up to 100% and only name accepted assets, each at most once.
+	fn check_shares(shares: &SharesOf<T, I>) -> Result<(), Error<T, I>> {
+		if shares.is_empty() {
+			return Ok(());
+		}
+
+		let accepted = T::Assets::get();
+		let mut total: u32 = 0;
+		for (index, (asset, share)) in shares.iter().enumerate() {
+			if !accepted.iter().any(|(accepted, _)| accepted == asset) {
+				return Err(Error::UnknownAsset);
+			}
+			if shares.iter().take(index).any(|(other, _)| other == asset) {
+				return Err(Error::DuplicateAsset);
+			}
+			total = total.saturating_add(share.deconstruct());
+		}
+
+		if total != Permill::one().deconstruct() {
+			return Err(Error::InvalidShares);
+		}
+		Ok(())
+	}
+
+	/// Pay the salary `amount` of `who` according to their preferences.
+	pub(crate) fn do_pay(
+		who: &T::AccountId,
+		amount: BalanceOf<T, I>,
+	) -> Result<PayoutId, DispatchError> {
+		let cycle = T::Cycle::get();
+		Self::settle(who, cycle);
+
+		let due = amount.saturating_sub(Self::paid(who, cycle));
+
+		let shares = Preferences::<T, I>::get(who)
+			.map(|shares| shares.into_inner())
+			.unwrap_or_else(|| vec![(T::ReferenceAsset::get(), Permill::one())]);
+		let assets = T::Assets::get();
+
+		// The asset, the amount of the asset and the value in the reference asset of each leg.
+		let mut splits: Vec<(T::AssetKind, BalanceOf<T, I>, BalanceOf<T, I>)> = Vec::new();
+		let mut remaining = due;
+		for (index, (preferred, share)) in shares.iter().enumerate() {
+			// The last share takes the rounding remainder.
+			let value = if index + 1 == shares.len() {
+				remaining
+			} else {
+				share.mul_floor(due).min(remaining)
+			};
+			remaining = remaining.saturating_sub(value);
+			if value.is_zero() {
+				continue;
+			}
+
+			let (asset, amount) = Self::pick_asset(preferred, value, cycle, &assets)
+				.ok_or(Error::<T, I>::NoBudget)?;
+			Spent::<T, I>::insert(
+				&asset,
+				(cycle, Self::spent(&asset, cycle).saturating_add(amount)),
+			);
+			if asset != *preferred {
+				Self::deposit_event(Event::FellBack {
+					who: who.clone(),
+					from: preferred.clone(),
+					to: asset.clone(),
+				});
+			}
+
+			match splits.iter_mut().find(|(split_asset, ..)| *split_asset == asset) {
+				Some((_, split_amount, split_value)) => {
+					split_amount.saturating_accrue(amount);
+					split_value.saturating_accrue(value);
+				},
+				None => splits.push((asset, amount, value)),
+			}
+		}
+
+		let id = NextPayoutId::<T, I>::mutate(|next| {
+			let id = *next;
+			next.saturating_inc();
+			id
+		});
+
+		let mut legs = Vec::with_capacity(splits.len());
+		for (asset, amount, value) in splits {
+			let payment = T::Paymaster::pay(who, asset.clone(), amount).map_err(|error| {
+				log::debug!(target: LOG_TARGET, "Failed to pay {asset:?} to {who:?}: {error:?}");
+				Error::<T, I>::PaymentFailed
+			})?;
+			Self::deposit_event(Event::LegPaid {
+				who: who.clone(),
+				id,
+				asset: asset.clone(),
+				amount,
+			});
+			legs.push(Leg { asset, amount, value, id: payment, status: LegStatus::Pending });
+		}
+
+		let payout = Payout {
+			beneficiary: who.clone(),
+			cycle,
+			// One leg per share at most, so the bound of the shares holds.
+			legs: BoundedVec::truncate_from(legs),
+		};
+		Payouts::<T, I>::insert(id, payout);
+		LastPayout::<T, I>::insert(who, id);
+
+		Ok(id)
+	}
+
+	/// The asset and the amount of it to pay `value` of the salary with in `cycle`.
+	///
+	/// `preferred` is used if it can pay, otherwise the first accepted asset that can.
+	fn pick_asset(
+		preferred: &T::AssetKind,
+		value: BalanceOf<T, I>,
+		cycle: u32,
+		assets: &[(T::AssetKind, BalanceOf<T, I>)],
+	) -> Option<(T::AssetKind, BalanceOf<T, I>)> {
+		let (preferred_first, others): (Vec<_>, Vec<_>) =
+			assets.iter().partition(|(asset, _)| asset == preferred);
+
+		preferred_first.into_iter().chain(others).find_map(|(asset, budget)| {
+			let amount = Self::convert(value, asset)?;
+			let spent = Self::spent(asset, cycle).saturating_add(amount);
+			(spent <= *budget).then(|| (asset.clone(), amount))
+		})
+	}
+
+	/// Convert `value` of the reference asset to `asset`.
+	fn convert(value: BalanceOf<T, I>, asset: &T::AssetKind) -> Option<BalanceOf<T, I>> {
+		let reference = T::ReferenceAsset::get();
+		if *asset == reference {
+			return Some(value);
+		}
+
+		let native = T::Converter::from_asset_balance(value, reference).ok()?;
+		T::Converter::to_asset_balance(native, asset.clone()).ok()
+	}
+
+	/// The amount of `asset` paid in `cycle`.
+	fn spent(asset: &T::AssetKind, cycle: u32) -> BalanceOf<T, I> {
+		Spent::<T, I>::get(asset)
+			.filter(|(spent_cycle, _)| *spent_cycle == cycle)
+			.map_or(Zero::zero(), |(_, spent)| spent)
+	}
+
+	/// The part of the salary of `who` paid in `cycle`.
+	fn paid(who: &T::AccountId, cycle: u32) -> BalanceOf<T, I> {
+		Paid::<T, I>::get(who)
+			.filter(|(paid_cycle, _)| *paid_cycle == cycle)
+			.map_or(Zero::zero(), |(_, paid)| paid)
+	}
+
+	/// Account for the previous payout of `who` before paying them again in `cycle`.
+	///
+	/// If the previous payout is from `cycle`, it failed and is retried: its failed legs are
+	/// returned to the budget and its other legs are added to [`Paid`].
+	fn settle(who: &T::AccountId, cycle: u32) {
+		let Some(payout) = LastPayout::<T, I>::take(who).and_then(Payouts::<T, I>::take) else {
+			return;
+		};
+		if payout.cycle != cycle {
+			return;
+		}
+
+		for mut leg in payout.legs {
+			match Self::leg_status(&mut leg) {
+				PaymentStatus::Failure => {
+					Spent::<T, I>::mutate(&leg.asset, |spent| {
+						if let Some((spent_cycle, spent)) = spent {
+							if *spent_cycle == cycle {
+								spent.saturating_reduce(leg.amount);
+							}
+						}
+					});
+				},
+				// A leg still in progress or with an unknown outcome may have paid, it is not paid
+				// again.
+				PaymentStatus::Success | PaymentStatus::InProgress | PaymentStatus::Unknown => {
+					let paid = Self::paid(who, cycle).saturating_add(leg.value);
+					Paid::<T, I>::insert(who, (cycle, paid));
+				},
+			}
+		}
+	}
+
+	/// The status of `leg`, checked with [`Config::Paymaster`] and kept in the leg once it
+	/// concluded.
+	fn leg_status(leg: &mut LegOf<T, I>) -> PaymentStatus {
+		match leg.status {
+			LegStatus::Paid => return PaymentStatus::Success,
+			LegStatus::Failed => return PaymentStatus::Failure,
+			LegStatus::Pending => {},
+		}
+
+		let status = T::Paymaster::check_payment(leg.id);
+		match status {
+			PaymentStatus::Success => leg.status = LegStatus::Paid,
+			PaymentStatus::Failure => leg.status = LegStatus::Failed,
+			PaymentStatus::InProgress | PaymentStatus::Unknown => {},
+		}
+		status
+	}
+
+	/// The status of `payout` from the status of its legs, keeping the legs that concluded.
+	///
+	/// All legs are checked, so that none of their outcomes is lost.
+	fn payout_status(payout: &mut PayoutOf<T, I>) -> PaymentStatus {
+		let (mut in_progress, mut failed, mut unknown) = (false, false, false);
+		for leg in payout.legs.iter_mut() {
+			match Self::leg_status(leg) {
+				PaymentStatus::Success => {},
+				PaymentStatus::InProgress => in_progress = true,
+				PaymentStatus::Failure => failed = true,
+				PaymentStatus::Unknown => unknown = true,
+			}
+		}
+
+		if in_progress {
+			PaymentStatus::InProgress
+		} else if failed {
+			PaymentStatus::Failure
+		} else if unknown {
+			PaymentStatus::Unknown
+		} else {
+			PaymentStatus::Success
+		}
+	}
+}
+
+const LOG_TARGET: &str = "runtime::salary-payouts";
+
+impl<T: Config<I>, I: 'static> Pay for Pallet<T, I> {
+	type Balance = BalanceOf<T, I>;
+	type Beneficiary = T::AccountId;
+	type AssetKind = ();
+	type Id = PayoutId;
+	type Error = DispatchError;
+
+	fn pay(
+		who: &Self::Beneficiary,
+		_asset_kind: Self::AssetKind,
+		amount: Self::Balance,
+	) -> Result<Self::Id, Self::Error> {
+		with_storage_layer(|| Self::do_pay(who, amount))
+	}
+
+	fn check_payment(id: Self::Id) -> PaymentStatus {
+		Payouts::<T, I>::mutate(id, |payout| {
+			payout.as_mut().map_or(PaymentStatus::Unknown, Self::payout_status)
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(who: &Self::Beneficiary, _: Self::AssetKind, amount: Self::Balance) {
+		for (asset, _) in T::Assets::get() {
+			Spent::<T, I>::remove(&asset);
+			<T::Converter as ConversionFromAssetBalance<_, _, _>>::ensure_successful(asset.clone());
+			T::Paymaster::ensure_successful(who, asset, amount);
+		}
+		Paid::<T, I>::remove(who);
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_concluded(id: Self::Id) {
+		if let Some(payout) = Payouts::<T, I>::get(id) {
+			for leg in payout.legs {
+				T::Paymaster::ensure_concluded(leg.id);
+			}
+		}
+	}
+}
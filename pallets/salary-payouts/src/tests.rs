@@ -0,0 +1,385 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for the Salary Payouts Pallet
+
+use super::*;
+use crate as salary_payouts;
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, derive_impl, parameter_types, traits::ConstU32,
+};
+use frame_system::EnsureSigned;
+use sp_io::TestExternalities;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		SalaryPayouts: salary_payouts,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+const USD: u32 = 0;
+const DOT: u32 = 1;
+const USDC: u32 = 2;
+const UNPRICED: u32 = 3;
+
+const ALICE: u64 = 1;
+
+parameter_types! {
+	pub const ReferenceAsset: u32 = USD;
+	pub static Assets: Vec<(u32, u128)> = vec![(USD, 1_000), (DOT, 100), (USDC, 1_000)];
+	pub static Cycle: u32 = 0;
+	pub static Payments: Vec<(u64, u32, u128)> = vec![];
+	pub static Statuses: Vec<(u64, PaymentStatus)> = vec![];
+	pub static PayFails: bool = false;
+}
+
+/// Records the payments, which stay in progress until [`set_status`] concludes them. Like
+/// `PayOverXcm`, a concluded payment is reported once, later checks report it as unknown.
+pub struct TestPay;
+impl Pay for TestPay {
+	type Balance = u128;
+	type Beneficiary = u64;
+	type AssetKind = u32;
+	type Id = u64;
+	type Error = ();
+
+	fn pay(who: &u64, asset_kind: u32, amount: u128) -> Result<u64, ()> {
+		if PayFails::get() {
+			return Err(());
+		}
+		Payments::mutate(|payments| {
+			payments.push((*who, asset_kind, amount));
+			Ok(payments.len() as u64 - 1)
+		})
+	}
+
+	fn check_payment(id: u64) -> PaymentStatus {
+		Statuses::mutate(|statuses| {
+			statuses
+				.iter_mut()
+				.find(|(payment, _)| *payment == id)
+				.map_or(PaymentStatus::InProgress, |(_, status)| {
+					core::mem::replace(status, PaymentStatus::Unknown)
+				})
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(_: &u64, _: u32, _: u128) {}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_concluded(_: u64) {}
+}
+
+/// The native value of one unit of each asset.
+fn rate(asset: u32) -> Result<u128, ()> {
+	match asset {
+		USD | USDC => Ok(2),
+		DOT => Ok(10),
+		_ => Err(()),
+	}
+}
+
+pub struct TestConverter;
+impl ConversionFromAssetBalance<u128, u32, u128> for TestConverter {
+	type Error = ();
+
+	fn from_asset_balance(balance: u128, asset_id: u32) -> Result<u128, ()> {
+		rate(asset_id).map(|rate| balance * rate)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(_: u32) {}
+}
+
+impl ConversionToAssetBalance<u128, u32, u128> for TestConverter {
+	type Error = ();
+
+	fn to_asset_balance(balance: u128, asset_id: u32) -> Result<u128, ()> {
+		rate(asset_id).map(|rate| balance / rate)
+	}
+}
+
+impl Config for Test {
+	type AssetKind = u32;
+	type Paymaster = TestPay;
+	type Converter = TestConverter;
+	type ReferenceAsset = ReferenceAsset;
+	type Assets = Assets;
+	type Cycle = Cycle;
+	type MemberOrigin = EnsureSigned<u64>;
+	type MaxSplits = ConstU32<3>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = SalaryPayoutsBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SalaryPayoutsBenchmarkHelper;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper<u32> for SalaryPayoutsBenchmarkHelper {
+	fn setup_assets(count: u32) -> Vec<u32> {
+		let assets: Vec<u32> = (0..count).collect();
+		Assets::set(assets.iter().map(|asset| (*asset, 1_000)).collect());
+		assets
+	}
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		Assets::set(vec![(USD, 1_000), (DOT, 100), (USDC, 1_000)]);
+		Cycle::set(0);
+		Payments::take();
+		Statuses::take();
+		PayFails::set(false);
+	});
+	ext
+}
+
+fn shares(shares: &[(u32, u32)]) -> SharesOf<Test> {
+	shares
+		.iter()
+		.map(|(asset, percent)| (*asset, Permill::from_percent(*percent)))
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+fn prefer(who: u64, split: &[(u32, u32)]) {
+	assert_ok!(SalaryPayouts::set_preferences(RuntimeOrigin::signed(who), shares(split)));
+}
+
+fn set_status(payment: u64, status: PaymentStatus) {
+	Statuses::mutate(|statuses| statuses.push((payment, status)));
+}
+
+fn spent(asset: u32) -> Option<(u32, u128)> {
+	Spent::<Test>::get(asset)
+}
+
+#[test]
+fn set_preferences_checks_shares() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SalaryPayouts::set_preferences(
+				RuntimeOrigin::signed(ALICE),
+				shares(&[(USD, 70), (DOT, 20)])
+			),
+			Error::<Test>::InvalidShares
+		);
+		assert_noop!(
+			SalaryPayouts::set_preferences(
+				RuntimeOrigin::signed(ALICE),
+				shares(&[(USD, 70), (UNPRICED, 30)])
+			),
+			Error::<Test>::UnknownAsset
+		);
+		assert_noop!(
+			SalaryPayouts::set_preferences(
+				RuntimeOrigin::signed(ALICE),
+				shares(&[(USD, 50), (USD, 50)])
+			),
+			Error::<Test>::DuplicateAsset
+		);
+
+		prefer(ALICE, &[(USD, 70), (DOT, 30)]);
+		assert_eq!(Preferences::<Test>::get(ALICE), Some(shares(&[(USD, 70), (DOT, 30)])));
+		System::assert_last_event(
+			Event::PreferencesSet { who: ALICE, shares: shares(&[(USD, 70), (DOT, 30)]) }.into(),
+		);
+
+		// Empty shares go back to the reference asset.
+		prefer(ALICE, &[]);
+		assert_eq!(Preferences::<Test>::get(ALICE), None);
+	});
+}
+
+#[test]
+fn pays_reference_asset_without_preferences() {
+	new_test_ext().execute_with(|| {
+		let id = SalaryPayouts::pay(&ALICE, (), 100).unwrap();
+
+		assert_eq!(Payments::get(), vec![(ALICE, USD, 100)]);
+		assert_eq!(spent(USD), Some((0, 100)));
+		assert_eq!(SalaryPayouts::check_payment(id), PaymentStatus::InProgress);
+
+		set_status(0, PaymentStatus::Success);
+		assert_eq!(SalaryPayouts::check_payment(id), PaymentStatus::Success);
+		assert_eq!(SalaryPayouts::check_payment(id + 1), PaymentStatus::Unknown);
+	});
+}
+
+#[test]
+fn splits_and_converts_salary() {
+	new_test_ext().execute_with(|| {
+		prefer(ALICE, &[(USD, 70), (DOT, 30)]);
+
+		let id = SalaryPayouts::pay(&ALICE, (), 1_000).unwrap();
+
+		// 300 USD are worth 600 native, which is 60 DOT.
+		assert_eq!(Payments::get(), vec![(ALICE, USD, 700), (ALICE, DOT, 60)]);
+		assert_eq!(spent(USD), Some((0, 700)));
+		assert_eq!(spent(DOT), Some((0, 60)));
+		System::assert_has_event(Event::LegPaid { who: ALICE, id, asset: DOT, amount: 60 }.into());
+
+		// The payout only succeeds once all legs did.
+		set_status(0, PaymentStatus::Success);
+		assert_eq!(SalaryPayouts::check_payment(id), PaymentStatus::InProgress);
+		set_status(1, PaymentStatus::Success);
+		assert_eq!(SalaryPayouts::check_payment(id), PaymentStatus::Success);
+	});
+}
+
+#[test]
+fn falls_back_when_an_asset_can_not_pay() {
+	new_test_ext().execute_with(|| {
+		// 60 DOT are over the DOT budget, the share is paid with the first accepted asset.
+		Assets::set(vec![(USDC, 1_000), (DOT, 50), (USD, 1_000)]);
+		prefer(ALICE, &[(USD, 70), (DOT, 30)]);
+
+		assert_ok!(SalaryPayouts::pay(&ALICE, (), 1_000));
+
+		assert_eq!(Payments::get(), vec![(ALICE, USD, 700), (ALICE, USDC, 300)]);
+		assert_eq!(spent(DOT), None);
+		System::assert_has_event(Event::FellBack { who: ALICE, from: DOT, to: USDC }.into());
+
+		// An asset without a rate falls back as well.
+		Assets::set(vec![(UNPRICED, 1_000), (USD, 10_000)]);
+		prefer(2, &[(UNPRICED, 100)]);
+		assert_ok!(SalaryPayouts::pay(&2, (), 1_000));
+		assert_eq!(Payments::get().last(), Some(&(2, USD, 1_000)));
+	});
+}
+
+#[test]
+fn fails_without_budget() {
+	new_test_ext().execute_with(|| {
+		Assets::set(vec![(USD, 500), (DOT, 10)]);
+
+		assert_noop!(SalaryPayouts::pay(&ALICE, (), 1_000), Error::<Test>::NoBudget);
+
+		PayFails::set(true);
+		assert_noop!(SalaryPayouts::pay(&ALICE, (), 100), Error::<Test>::PaymentFailed);
+	});
+}
+
+#[test]
+fn retry_pays_the_unpaid_part() {
+	new_test_ext().execute_with(|| {
+		prefer(ALICE, &[(USD, 70), (DOT, 30)]);
+		let id = SalaryPayouts::pay(&ALICE, (), 1_000).unwrap();
+
+		set_status(0, PaymentStatus::Success);
+		set_status(1, PaymentStatus::Failure);
+		assert_eq!(SalaryPayouts::check_payment(id), PaymentStatus::Failure);
+
+		// The 700 USD paid are kept, the remaining 300 USD are split again.
+		let retry = SalaryPayouts::pay(&ALICE, (), 1_000).unwrap();
+		assert_eq!(&Payments::get()[2..], &[(ALICE, USD, 210), (ALICE, DOT, 18)]);
+		assert_eq!(Paid::<Test>::get(ALICE), Some((0, 700)));
+		assert_eq!(spent(USD), Some((0, 910)));
+		assert_eq!(spent(DOT), Some((0, 18)));
+
+		// The failed payout is replaced by the retry.
+		assert_eq!(Payouts::<Test>::get(id), None);
+		assert_eq!(LastPayout::<Test>::get(ALICE), Some(retry));
+	});
+}
+
+#[test]
+fn new_cycle_resets_budgets() {
+	new_test_ext().execute_with(|| {
+		Assets::set(vec![(USD, 1_000)]);
+		let id = SalaryPayouts::pay(&ALICE, (), 1_000).unwrap();
+		set_status(0, PaymentStatus::Success);
+		assert_eq!(SalaryPayouts::check_payment(id), PaymentStatus::Success);
+
+		// The USD budget is used up in this cycle.
+		assert_noop!(SalaryPayouts::pay(&2, (), 1_000), Error::<Test>::NoBudget);
+
+		Cycle::set(1);
+		assert_ok!(SalaryPayouts::pay(&ALICE, (), 1_000));
+		assert_eq!(Payments::get().last(), Some(&(ALICE, USD, 1_000)));
+		assert_eq!(spent(USD), Some((1, 1_000)));
+		assert_eq!(Paid::<Test>::get(ALICE), None);
+	});
+}
+
+#[test]
+fn concluded_legs_are_kept_once_observed() {
+	new_test_ext().execute_with(|| {
+		prefer(ALICE, &[(USD, 70), (DOT, 30)]);
+		let id = SalaryPayouts::pay(&ALICE, (), 1_000).unwrap();
+
+		// The outcome of the first leg is taken from the paymaster while the payout is in progress.
+		set_status(0, PaymentStatus::Success);
+		assert_eq!(SalaryPayouts::check_payment(id), PaymentStatus::InProgress);
+		assert_eq!(TestPay::check_payment(0), PaymentStatus::Unknown);
+		assert_eq!(Payouts::<Test>::get(id).unwrap().legs[0].status, LegStatus::Paid);
+
+		set_status(1, PaymentStatus::Failure);
+		assert_eq!(SalaryPayouts::check_payment(id), PaymentStatus::Failure);
+		assert_eq!(SalaryPayouts::check_payment(id), PaymentStatus::Failure);
+
+		// The successful leg is not paid again.
+		assert_ok!(SalaryPayouts::pay(&ALICE, (), 1_000));
+		assert_eq!(&Payments::get()[2..], &[(ALICE, USD, 210), (ALICE, DOT, 18)]);
+		assert_eq!(Paid::<Test>::get(ALICE), Some((0, 700)));
+	});
+}
+
+#[test]
+fn unknown_legs_are_not_refunded() {
+	new_test_ext().execute_with(|| {
+		prefer(ALICE, &[(USD, 70), (DOT, 30)]);
+		let id = SalaryPayouts::pay(&ALICE, (), 1_000).unwrap();
+
+		// The outcome of the first leg is taken by someone else and never observed.
+		set_status(0, PaymentStatus::Success);
+		assert_eq!(TestPay::check_payment(0), PaymentStatus::Success);
+		set_status(1, PaymentStatus::Failure);
+		assert_eq!(SalaryPayouts::check_payment(id), PaymentStatus::Failure);
+
+		// The unknown leg may have paid: it stays spent and is not paid again.
+		assert_ok!(SalaryPayouts::pay(&ALICE, (), 1_000));
+		assert_eq!(&Payments::get()[2..], &[(ALICE, USD, 210), (ALICE, DOT, 18)]);
+		assert_eq!(Paid::<Test>::get(ALICE), Some((0, 700)));
+		assert_eq!(spent(USD), Some((0, 910)));
+	});
+}
+
+#[test]
+fn payout_with_unknown_legs_is_unknown() {
+	new_test_ext().execute_with(|| {
+		let id = SalaryPayouts::pay(&ALICE, (), 100).unwrap();
+
+		set_status(0, PaymentStatus::Success);
+		assert_eq!(TestPay::check_payment(0), PaymentStatus::Success);
+		assert_eq!(SalaryPayouts::check_payment(id), PaymentStatus::Unknown);
+	});
+}
@@ -0,0 +1,59 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as StakingBridgeMonitor;
+use frame_benchmarking::v2::*;
+use frame_support::traits::Hooks;
+use frame_system::pallet_prelude::BlockNumberFor;
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	/// Every message was received long ago and failed to be sent, so that all degradations that
+	/// do not depend on the client are reported at once.
+	#[benchmark]
+	fn on_finalize() {
+		let now: BlockNumberFor<T> = 1_000_000u32.into();
+		frame_system::Pallet::<T>::set_block_number(now);
+		for message in Message::ALL {
+			Sent::<T>::insert(
+				message,
+				Traffic { count: 1, last: Some(1u32.into()), consecutive_failures: u32::MAX },
+			);
+			Received::<T>::insert(
+				message,
+				Traffic { count: 1, last: Some(1u32.into()), consecutive_failures: 0 },
+			);
+		}
+		LastValidatorSet::<T>::put(ValidatorSetInfo { id: 1, at: 1u32.into() });
+		LatestReceived::<T>::put((Message::ValidatorSet, u32::MAX));
+
+		#[block]
+		{
+			StakingBridgeMonitor::<T>::on_finalize(now);
+		}
+
+		assert!(Degraded::<T>::get().contains(&Degradation::SendFailing));
+	}
+
+	impl_benchmark_test_suite!(
+		StakingBridgeMonitor,
+		crate::tests::new_test_ext(),
+		crate::tests::Test
+	);
+}
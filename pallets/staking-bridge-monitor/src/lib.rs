@@ -0,0 +1,498 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Staking bridge monitor pallet
+//!
+//! The staking of the relay chain and Asset Hub is connected by XCM messages. The relay chain
+//! sends session reports and offences with `pallet_staking_async_ah_client`, Asset Hub sends
+//! validator sets and session key changes with `pallet_staking_async_rc_client`. Both client
+//! pallets retry or fall back on their own, but it is not visible from the outside whether a
+//! message is stuck, being retried or whether the relay chain has fallen back to its own staking.
+//!
+//! This pallet records the messages one side of the bridge sends and receives, and exposes them
+//! together with the state of the client pallet through the
+//! [`StakingBridgeHealthApi`](runtime_api::StakingBridgeHealthApi).
+//!
+//! The runtime reports its sent messages with the `note_*_sent` functions of [`Pallet`] and
+//! provides the state of its client pallet through [`StakingBridgeClient`]. Received messages are
+//! picked up from the client state at the end of every block. At the same time the bridge is
+//! checked for the [`Degradation`]s, which are reported with [`Event::Degraded`] and
+//! [`Event::Recovered`] when they start or end.
+//!
+//! Activated validator sets are taken from the session reports of the relay chain: the relay chain
+//! notes them when it sends a report, Asset Hub when it receives one through
+//! [`NoteSessionReports`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use alloc::vec::Vec;
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::{traits::Get, weights::Weight};
+use pallet_staking_async_rc_client::{AHStakingInterface, Offence, SessionReport};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, BoundedVec};
+use sp_staking::SessionIndex;
+
+pub use pallet::*;
+pub use weight::WeightInfo;
+
+/// The staking messages between the relay chain and Asset Hub.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub enum Message {
+	/// A session report, sent by the relay chain.
+	SessionReport,
+	/// A page of offences, sent by the relay chain.
+	Offences,
+	/// A validator set, sent by Asset Hub.
+	ValidatorSet,
+	/// A session key change, sent by Asset Hub.
+	Keys,
+}
+
+impl Message {
+	/// All messages.
+	pub const ALL: [Message; 4] =
+		[Message::SessionReport, Message::Offences, Message::ValidatorSet, Message::Keys];
+}
+
+/// The operating mode of `pallet_staking_async_ah_client` on the relay chain.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub enum ClientMode {
+	/// The relay chain runs its own staking and ignores Asset Hub.
+	Passive,
+	/// The relay chain buffers offences and waits for Asset Hub.
+	Buffered,
+	/// The relay chain takes its validators from Asset Hub.
+	Active,
+}
+
+/// A message that the client pallet failed to send and is retrying.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct Outgoing {
+	/// The retried message.
+	pub message: Message,
+	/// The end index of the session report or the id of the validator set.
+	pub id: u32,
+	/// The number of retries left before the message is dropped.
+	pub retries_left: u32,
+}
+
+/// The number of messages of one kind that were sent or received.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug, Default)]
+pub struct Traffic<BlockNumber> {
+	/// The number of messages.
+	pub count: u32,
+	/// The block of the last message.
+	pub last: Option<BlockNumber>,
+	/// The number of failed sends since the last successful one.
+	pub consecutive_failures: u32,
+}
+
+/// A validator set, sent by Asset Hub.
+#[derive(Encode, Decode, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct ValidatorSetInfo<BlockNumber> {
+	/// The id of the validator set.
+	pub id: u32,
+	/// The block at which the validator set was sent (Asset Hub) or received (relay chain).
+	pub at: BlockNumber,
+}
+
+/// Why the staking bridge is degraded.
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	TypeInfo,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub enum Degradation {
+	/// The client pallet on the relay chain is not in [`ClientMode::Active`].
+	NotActive,
+	/// At least [`Config::MaxSendFailures`] consecutive sends of a message failed.
+	SendFailing,
+	/// The client pallet is retrying a message.
+	Retrying,
+	/// No message was received from the other side for [`Config::SilenceThreshold`] blocks.
+	Silent,
+}
+
+/// The maximum number of degradations at the same time.
+pub const MAX_DEGRADATIONS: u32 = 4;
+
+/// The health of the staking bridge as seen by one side.
+#[derive(Encode, Decode, TypeInfo, Clone, PartialEq, Eq, Debug)]
+pub struct StakingBridgeHealth<BlockNumber> {
+	/// The operating mode of the client pallet on the relay chain, `None` on Asset Hub.
+	pub mode: Option<ClientMode>,
+	/// The number of offences waiting to be sent to Asset Hub.
+	pub queued_offences: u32,
+	/// The message that is being retried.
+	pub outgoing: Option<Outgoing>,
+	/// The messages sent to the other side.
+	pub sent: Vec<(Message, Traffic<BlockNumber>)>,
+	/// The messages received from the other side.
+	pub received: Vec<(Message, Traffic<BlockNumber>)>,
+	/// The last validator set sent by Asset Hub.
+	pub last_validator_set: Option<ValidatorSetInfo<BlockNumber>>,
+	/// The number of key changes sent since the last session report was received.
+	///
+	/// The relay chain applies key changes when it receives them, thus this is always zero on the
+	/// relay chain.
+	pub pending_key_changes: u32,
+	/// The blocks between the last activated validator set being sent (Asset Hub) or received
+	/// (relay chain) and its activation being reported.
+	pub round_trip: Option<BlockNumber>,
+	/// The current degradations.
+	pub degraded: Vec<Degradation>,
+}
+
+/// The state of the staking client pallet on this chain.
+///
+/// The default implementations fit a chain whose client pallet does not have the information.
+pub trait StakingBridgeClient {
+	/// The operating mode of the client pallet.
+	fn mode() -> Option<ClientMode> {
+		None
+	}
+
+	/// The number of offences waiting to be sent.
+	fn queued_offences() -> u32 {
+		0
+	}
+
+	/// The message that is being retried.
+	fn outgoing() -> Option<Outgoing> {
+		None
+	}
+
+	/// The latest message received from the other side with its id, as far as it is still in the
+	/// state of the client pallet.
+	///
+	/// The id is the end index of a session report or the id of a validator set.
+	fn latest_received() -> Option<(Message, u32)> {
+		None
+	}
+}
+
+impl StakingBridgeClient for () {}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+		/// The state of the staking client pallet on this chain.
+		type Client: StakingBridgeClient;
+
+		/// The number of consecutive failed sends of a message after which the bridge is degraded.
+		#[pallet::constant]
+		type MaxSendFailures: Get<u32>;
+
+		/// The number of blocks without a message from the other side after which the bridge is
+		/// degraded.
+		///
+		/// Should be well above the interval of the least frequent message, i.e. an era on the
+		/// relay chain and a session on Asset Hub.
+		#[pallet::constant]
+		type SilenceThreshold: Get<BlockNumberFor<Self>>;
+
+		/// Weight information for the hooks of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	/// The messages sent to the other side.
+	#[pallet::storage]
+	pub type Sent<T: Config> =
+		StorageMap<_, Twox64Concat, Message, Traffic<BlockNumberFor<T>>, ValueQuery>;
+
+	/// The messages received from the other side.
+	#[pallet::storage]
+	pub type Received<T: Config> =
+		StorageMap<_, Twox64Concat, Message, Traffic<BlockNumberFor<T>>, ValueQuery>;
+
+	/// The latest message received from the other side, as last seen in the client state.
+	#[pallet::storage]
+	pub type LatestReceived<T: Config> = StorageValue<_, (Message, u32), OptionQuery>;
+
+	/// The last validator set sent by Asset Hub.
+	#[pallet::storage]
+	pub type LastValidatorSet<T: Config> =
+		StorageValue<_, ValidatorSetInfo<BlockNumberFor<T>>, OptionQuery>;
+
+	/// The id of the last activated validator set.
+	#[pallet::storage]
+	pub type LastActivated<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+	/// The round trip of the last activated validator set, see
+	/// [`StakingBridgeHealth::round_trip`].
+	#[pallet::storage]
+	pub type RoundTrip<T: Config> = StorageValue<_, BlockNumberFor<T>, OptionQuery>;
+
+	/// The number of key changes sent since the last session report was received.
+	#[pallet::storage]
+	pub type PendingKeyChanges<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+	/// The current degradations.
+	#[pallet::storage]
+	pub type Degraded<T: Config> =
+		StorageValue<_, BoundedVec<Degradation, ConstU32<MAX_DEGRADATIONS>>, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The staking bridge became degraded.
+		Degraded { reason: Degradation },
+		/// The staking bridge recovered from a degradation.
+		Recovered { reason: Degradation },
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_: BlockNumberFor<T>) -> Weight {
+			T::WeightInfo::on_finalize()
+		}
+
+		fn on_finalize(now: BlockNumberFor<T>) {
+			Self::observe(now);
+		}
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Note a session report that was sent to Asset Hub.
+	///
+	/// `activated` is the id of the validator set whose activation is reported.
+	pub fn note_session_report_sent(activated: Option<u32>, sent: bool) {
+		Self::note_sent(Message::SessionReport, sent);
+		if let (true, Some(id)) = (sent, activated) {
+			Self::note_activated(id);
+		}
+	}
+
+	/// Note a session report that was received from the relay chain.
+	///
+	/// `activated` is the id of the validator set whose activation is reported.
+	pub fn note_session_report_received(activated: Option<u32>) {
+		if let Some(id) = activated {
+			Self::note_activated(id);
+		}
+	}
+
+	/// Note a page of offences that was sent to Asset Hub.
+	pub fn note_offences_sent(sent: bool) {
+		Self::note_sent(Message::Offences, sent);
+	}
+
+	/// Note a validator set that was sent to the relay chain.
+	pub fn note_validator_set_sent(id: u32, sent: bool) {
+		Self::note_sent(Message::ValidatorSet, sent);
+		if sent {
+			let at = frame_system::Pallet::<T>::block_number();
+			LastValidatorSet::<T>::put(ValidatorSetInfo { id, at });
+		}
+	}
+
+	/// Note a session key change that was sent to the relay chain.
+	pub fn note_keys_sent(sent: bool) {
+		Self::note_sent(Message::Keys, sent);
+		if sent {
+			PendingKeyChanges::<T>::mutate(|pending| pending.saturating_inc());
+		}
+	}
+
+	/// The health of the staking bridge.
+	pub fn health() -> StakingBridgeHealth<BlockNumberFor<T>> {
+		StakingBridgeHealth {
+			mode: T::Client::mode(),
+			queued_offences: T::Client::queued_offences(),
+			outgoing: T::Client::outgoing(),
+			sent: Message::ALL.into_iter().map(|m| (m, Sent::<T>::get(m))).collect(),
+			received: Message::ALL.into_iter().map(|m| (m, Received::<T>::get(m))).collect(),
+			last_validator_set: LastValidatorSet::<T>::get(),
+			pending_key_changes: PendingKeyChanges::<T>::get(),
+			round_trip: RoundTrip::<T>::get(),
+			degraded: Degraded::<T>::get().into_inner(),
+		}
+	}
+
+	/// The degradations of the staking bridge at block `now`.
+	pub fn degradations(now: BlockNumberFor<T>) -> Vec<Degradation> {
+		let mut degraded = Vec::new();
+		if T::Client::mode().is_some_and(|mode| mode != ClientMode::Active) {
+			degraded.push(Degradation::NotActive);
+		}
+		if Message::ALL
+			.into_iter()
+			.any(|m| Sent::<T>::get(m).consecutive_failures >= T::MaxSendFailures::get())
+		{
+			degraded.push(Degradation::SendFailing);
+		}
+		if T::Client::outgoing().is_some() {
+			degraded.push(Degradation::Retrying);
+		}
+		// A bridge that never received anything is not silent, but not yet in use.
+		let last_received =
+			Message::ALL.into_iter().filter_map(|m| Received::<T>::get(m).last).max();
+		if last_received.is_some_and(|last| now.saturating_sub(last) > T::SilenceThreshold::get()) {
+			degraded.push(Degradation::Silent);
+		}
+		degraded
+	}
+
+	fn note_sent(message: Message, sent: bool) {
+		let now = frame_system::Pallet::<T>::block_number();
+		Sent::<T>::mutate(message, |traffic| {
+			if sent {
+				traffic.count.saturating_inc();
+				traffic.last = Some(now);
+				traffic.consecutive_failures = 0;
+			} else {
+				traffic.consecutive_failures.saturating_inc();
+			}
+		});
+	}
+
+	fn note_received(message: Message, id: u32, now: BlockNumberFor<T>) {
+		Received::<T>::mutate(message, |traffic| {
+			traffic.count.saturating_inc();
+			traffic.last = Some(now);
+		});
+		match message {
+			Message::ValidatorSet => LastValidatorSet::<T>::put(ValidatorSetInfo { id, at: now }),
+			Message::SessionReport => PendingKeyChanges::<T>::kill(),
+			Message::Offences | Message::Keys => {},
+		}
+	}
+
+	fn note_activated(id: u32) {
+		if LastActivated::<T>::get() == Some(id) {
+			return;
+		}
+		LastActivated::<T>::put(id);
+		if let Some(set) = LastValidatorSet::<T>::get().filter(|set| set.id == id) {
+			let now = frame_system::Pallet::<T>::block_number();
+			RoundTrip::<T>::put(now.saturating_sub(set.at));
+		}
+	}
+
+	/// Pick up the received messages from the client state and check the degradations.
+	fn observe(now: BlockNumberFor<T>) {
+		if let Some(latest) = T::Client::latest_received() {
+			if LatestReceived::<T>::get() != Some(latest) {
+				LatestReceived::<T>::put(latest);
+				Self::note_received(latest.0, latest.1, now);
+			}
+		}
+
+		let degraded = Self::degradations(now);
+		let previous = Degraded::<T>::get();
+		for reason in degraded.iter().filter(|d| !previous.contains(d)) {
+			Self::deposit_event(Event::Degraded { reason: *reason });
+		}
+		for reason in previous.iter().filter(|d| !degraded.contains(d)) {
+			Self::deposit_event(Event::Recovered { reason: *reason });
+		}
+		Degraded::<T>::put(BoundedVec::truncate_from(degraded));
+	}
+}
+
+/// Wraps the staking of Asset Hub to note the validator sets whose activation the session reports
+/// of the relay chain report.
+pub struct NoteSessionReports<T, Staking>(core::marker::PhantomData<(T, Staking)>);
+impl<T: Config, Staking: AHStakingInterface> AHStakingInterface for NoteSessionReports<T, Staking> {
+	type AccountId = Staking::AccountId;
+	type MaxValidatorSet = Staking::MaxValidatorSet;
+
+	fn on_relay_session_report(report: SessionReport<Self::AccountId>) -> Weight {
+		Pallet::<T>::note_session_report_received(report.activation_timestamp.map(|(_, id)| id));
+		Staking::on_relay_session_report(report)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
+
+	fn weigh_on_relay_session_report(report: &SessionReport<Self::AccountId>) -> Weight {
+		Staking::weigh_on_relay_session_report(report)
+			.saturating_add(T::DbWeight::get().reads_writes(2, 2))
+	}
+
+	fn on_new_offences(
+		slash_session: SessionIndex,
+		offences: Vec<Offence<Self::AccountId>>,
+	) -> Weight {
+		Staking::on_new_offences(slash_session, offences)
+	}
+
+	fn weigh_on_new_offences(offence_count: u32) -> Weight {
+		Staking::weigh_on_new_offences(offence_count)
+	}
+
+	fn active_era_start_session_index() -> SessionIndex {
+		Staking::active_era_start_session_index()
+	}
+}
+
+pub mod runtime_api {
+	use super::*;
+
+	sp_api::decl_runtime_apis! {
+		/// API to query the health of the staking messages between the relay chain and Asset Hub.
+		pub trait StakingBridgeHealthApi<BlockNumber> where BlockNumber: codec::Codec {
+			/// Returns the health of the staking bridge as seen by this chain.
+			fn staking_bridge_health() -> StakingBridgeHealth<BlockNumber>;
+		}
+	}
+}
@@ -0,0 +1,270 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for the Staking Bridge Monitor Pallet
+
+use super::*;
+use crate as staking_bridge_monitor;
+use frame_support::{
+	construct_runtime, derive_impl, parameter_types,
+	traits::{ConstU32, ConstU64, Hooks},
+};
+use sp_io::TestExternalities;
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		StakingBridgeMonitor: staking_bridge_monitor,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+}
+
+parameter_types! {
+	pub static Mode: Option<ClientMode> = Some(ClientMode::Active);
+	pub static QueuedOffences: u32 = 0;
+	pub static Retrying: Option<Outgoing> = None;
+	pub static LatestMessage: Option<(Message, u32)> = None;
+}
+
+pub struct MockClient;
+
+impl StakingBridgeClient for MockClient {
+	fn mode() -> Option<ClientMode> {
+		Mode::get()
+	}
+
+	fn queued_offences() -> u32 {
+		QueuedOffences::get()
+	}
+
+	fn outgoing() -> Option<Outgoing> {
+		Retrying::get()
+	}
+
+	fn latest_received() -> Option<(Message, u32)> {
+		LatestMessage::get()
+	}
+}
+
+impl Config for Test {
+	type Client = MockClient;
+	type MaxSendFailures = ConstU32<3>;
+	type SilenceThreshold = ConstU64<100>;
+	type WeightInfo = ();
+}
+
+pub(crate) fn new_test_ext() -> TestExternalities {
+	let t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+/// Finalize the current block and start block `n`.
+fn run_to_block(n: u64) {
+	while System::block_number() < n {
+		StakingBridgeMonitor::on_finalize(System::block_number());
+		System::set_block_number(System::block_number() + 1);
+	}
+}
+
+fn monitor_events() -> Vec<Event<Test>> {
+	System::events()
+		.into_iter()
+		.filter_map(|record| match record.event {
+			RuntimeEvent::StakingBridgeMonitor(event) => Some(event),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn healthy_bridge_is_not_degraded() {
+	new_test_ext().execute_with(|| {
+		StakingBridgeMonitor::note_session_report_sent(None, true);
+		LatestMessage::set(Some((Message::ValidatorSet, 1)));
+		run_to_block(50);
+
+		assert!(monitor_events().is_empty());
+		assert!(Degraded::<Test>::get().is_empty());
+	});
+}
+
+#[test]
+fn failing_sends_degrade_until_a_send_succeeds() {
+	new_test_ext().execute_with(|| {
+		StakingBridgeMonitor::note_offences_sent(false);
+		StakingBridgeMonitor::note_offences_sent(false);
+		run_to_block(2);
+		assert!(monitor_events().is_empty());
+
+		StakingBridgeMonitor::note_offences_sent(false);
+		run_to_block(3);
+		assert_eq!(monitor_events(), vec![Event::Degraded { reason: Degradation::SendFailing }]);
+		assert_eq!(Sent::<Test>::get(Message::Offences).consecutive_failures, 3);
+
+		StakingBridgeMonitor::note_offences_sent(true);
+		run_to_block(4);
+		assert_eq!(
+			monitor_events(),
+			vec![
+				Event::Degraded { reason: Degradation::SendFailing },
+				Event::Recovered { reason: Degradation::SendFailing },
+			]
+		);
+		assert_eq!(
+			Sent::<Test>::get(Message::Offences),
+			Traffic { count: 1, last: Some(3), consecutive_failures: 0 }
+		);
+	});
+}
+
+#[test]
+fn fallback_of_the_client_degrades() {
+	new_test_ext().execute_with(|| {
+		Mode::set(Some(ClientMode::Passive));
+		run_to_block(2);
+		assert_eq!(monitor_events(), vec![Event::Degraded { reason: Degradation::NotActive }]);
+
+		// Staying in the fallback does not report again.
+		Mode::set(Some(ClientMode::Buffered));
+		run_to_block(3);
+		assert_eq!(monitor_events().len(), 1);
+
+		Mode::set(Some(ClientMode::Active));
+		run_to_block(4);
+		assert_eq!(monitor_events()[1], Event::Recovered { reason: Degradation::NotActive });
+	});
+}
+
+#[test]
+fn retried_message_degrades() {
+	new_test_ext().execute_with(|| {
+		let outgoing = Outgoing { message: Message::SessionReport, id: 12, retries_left: 63 };
+		Retrying::set(Some(outgoing.clone()));
+		run_to_block(2);
+		assert_eq!(monitor_events(), vec![Event::Degraded { reason: Degradation::Retrying }]);
+		assert_eq!(StakingBridgeMonitor::health().outgoing, Some(outgoing));
+
+		Retrying::set(None);
+		run_to_block(3);
+		assert_eq!(monitor_events()[1], Event::Recovered { reason: Degradation::Retrying });
+	});
+}
+
+#[test]
+fn silence_degrades_after_threshold() {
+	new_test_ext().execute_with(|| {
+		// Nothing received yet, the bridge is not silent.
+		run_to_block(200);
+		assert!(monitor_events().is_empty());
+
+		LatestMessage::set(Some((Message::SessionReport, 10)));
+		run_to_block(301);
+		assert!(monitor_events().is_empty());
+		assert_eq!(Received::<Test>::get(Message::SessionReport).last, Some(200));
+
+		run_to_block(302);
+		assert_eq!(monitor_events(), vec![Event::Degraded { reason: Degradation::Silent }]);
+
+		LatestMessage::set(Some((Message::SessionReport, 11)));
+		run_to_block(303);
+		assert_eq!(monitor_events()[1], Event::Recovered { reason: Degradation::Silent });
+		assert_eq!(Received::<Test>::get(Message::SessionReport).count, 2);
+	});
+}
+
+#[test]
+fn round_trip_of_sent_validator_set() {
+	new_test_ext().execute_with(|| {
+		run_to_block(10);
+		StakingBridgeMonitor::note_validator_set_sent(5, true);
+		assert_eq!(LastValidatorSet::<Test>::get(), Some(ValidatorSetInfo { id: 5, at: 10 }));
+
+		// The relay chain reports the activation of another validator set.
+		run_to_block(20);
+		StakingBridgeMonitor::note_session_report_received(Some(4));
+		assert_eq!(RoundTrip::<Test>::get(), None);
+
+		run_to_block(25);
+		StakingBridgeMonitor::note_session_report_received(None);
+		assert_eq!(LastActivated::<Test>::get(), Some(4));
+
+		run_to_block(30);
+		StakingBridgeMonitor::note_session_report_received(Some(5));
+		assert_eq!(RoundTrip::<Test>::get(), Some(20));
+		assert_eq!(LastActivated::<Test>::get(), Some(5));
+	});
+}
+
+#[test]
+fn round_trip_of_received_validator_set() {
+	new_test_ext().execute_with(|| {
+		LatestMessage::set(Some((Message::ValidatorSet, 7)));
+		run_to_block(5);
+		assert_eq!(LastValidatorSet::<Test>::get(), Some(ValidatorSetInfo { id: 7, at: 1 }));
+
+		// A failed report does not count as activation.
+		StakingBridgeMonitor::note_session_report_sent(Some(7), false);
+		assert_eq!(RoundTrip::<Test>::get(), None);
+
+		run_to_block(12);
+		StakingBridgeMonitor::note_session_report_sent(Some(7), true);
+		assert_eq!(RoundTrip::<Test>::get(), Some(11));
+		assert_eq!(LastActivated::<Test>::get(), Some(7));
+	});
+}
+
+#[test]
+fn key_changes_are_pending_until_session_report() {
+	new_test_ext().execute_with(|| {
+		StakingBridgeMonitor::note_keys_sent(true);
+		StakingBridgeMonitor::note_keys_sent(false);
+		StakingBridgeMonitor::note_keys_sent(true);
+		run_to_block(2);
+		assert_eq!(StakingBridgeMonitor::health().pending_key_changes, 2);
+
+		LatestMessage::set(Some((Message::SessionReport, 3)));
+		run_to_block(3);
+		assert_eq!(StakingBridgeMonitor::health().pending_key_changes, 0);
+	});
+}
+
+#[test]
+fn health_reports_client_state() {
+	new_test_ext().execute_with(|| {
+		QueuedOffences::set(3);
+		StakingBridgeMonitor::note_offences_sent(true);
+		run_to_block(2);
+
+		let health = StakingBridgeMonitor::health();
+		assert_eq!(health.mode, Some(ClientMode::Active));
+		assert_eq!(health.queued_offences, 3);
+		assert_eq!(health.sent.len(), Message::ALL.len());
+		assert_eq!(
+			health.sent[1],
+			(Message::Offences, Traffic { count: 1, last: Some(1), consecutive_failures: 0 })
+		);
+		assert!(health.degraded.is_empty());
+	});
+}
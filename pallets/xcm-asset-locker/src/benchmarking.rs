@@ -0,0 +1,130 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+use super::*;
+use crate::Pallet as XcmAssetLocker;
+use alloc::{boxed::Box, vec::Vec};
+use frame_benchmarking::v2::*;
+use frame_support::{traits::Get, BoundedVec};
+
+fn chain(index: u32) -> Location {
+	Location::new(1, [Parachain(index), GeneralIndex(u128::MAX)])
+}
+
+/// Fill [`AllowedChains`] up to `count` chains.
+fn allow_chains<T: Config>(count: u32) {
+	let chains = (0..count).map(|i| VersionedLocation::from(chain(i))).collect::<Vec<_>>();
+	AllowedChains::<T>::put(BoundedVec::try_from(chains).expect("at most `MaxChains`; qed"));
+}
+
+/// Lock all of `asset` of `owner` for the first `count` chains.
+fn set_locks<T: Config>(owner: &Location, asset: &Asset, count: u32) -> Result<(), BenchmarkError> {
+	let (who, asset, amount) = XcmAssetLocker::<T>::local(asset, owner)
+		.map_err(|_| BenchmarkError::Stop("not lockable"))?;
+	let locks = (0..count)
+		.map(|i| (VersionedLocation::from(chain(i)), amount))
+		.collect::<Vec<_>>();
+	let locks = BoundedVec::try_from(locks).expect("at most `MaxLocks`; qed");
+	XcmAssetLocker::<T>::set_locks(&who, &asset, locks)
+		.map_err(|_| BenchmarkError::Stop("freeze failed"))?;
+	Ok(())
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	// The worst case checks all the other chains.
+	#[benchmark]
+	fn allow_chain() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let max = T::MaxChains::get();
+		allow_chains::<T>(max.saturating_sub(1));
+		let new = chain(max);
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Box::new(new.into()));
+
+		assert_eq!(AllowedChains::<T>::get().len() as u32, max);
+
+		Ok(())
+	}
+
+	// The worst case removes the last chain.
+	#[benchmark]
+	fn disallow_chain() -> Result<(), BenchmarkError> {
+		let origin =
+			T::AdminOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)?;
+		let max = T::MaxChains::get();
+		allow_chains::<T>(max);
+		let last = chain(max.saturating_sub(1));
+
+		#[extrinsic_call]
+		_(origin as T::RuntimeOrigin, Box::new(last.into()));
+
+		assert_eq!(AllowedChains::<T>::get().len() as u32, max.saturating_sub(1));
+
+		Ok(())
+	}
+
+	// The worst case checks all the allowed chains and adds the last lock.
+	#[benchmark]
+	fn lock_asset() -> Result<(), BenchmarkError> {
+		let max = T::MaxChains::get();
+		allow_chains::<T>(max);
+		let (owner, asset) = T::BenchmarkHelper::lockable();
+		set_locks::<T>(&owner, &asset, T::MaxLocks::get().saturating_sub(1))?;
+		let unlocker = chain(max.saturating_sub(1));
+
+		#[block]
+		{
+			XcmAssetLocker::<T>::prepare_lock(unlocker, asset.clone(), owner.clone())
+				.and_then(|ticket| ticket.enact())
+				.map_err(|_| BenchmarkError::Stop("lock failed"))?;
+		}
+
+		let (who, asset, _) = XcmAssetLocker::<T>::local(&asset, &owner)
+			.map_err(|_| BenchmarkError::Stop("not lockable"))?;
+		assert_eq!(XcmAssetLocker::<T>::locks(&who, &asset).len() as u32, T::MaxLocks::get());
+
+		Ok(())
+	}
+
+	// The worst case removes the last lock.
+	#[benchmark]
+	fn unlock_asset() -> Result<(), BenchmarkError> {
+		let (owner, asset) = T::BenchmarkHelper::lockable();
+		let max = T::MaxLocks::get();
+		set_locks::<T>(&owner, &asset, max)?;
+		let unlocker = chain(max.saturating_sub(1));
+
+		#[block]
+		{
+			XcmAssetLocker::<T>::prepare_unlock(unlocker, asset.clone(), owner.clone())
+				.and_then(|ticket| ticket.enact())
+				.map_err(|_| BenchmarkError::Stop("unlock failed"))?;
+		}
+
+		let (who, asset, _) = XcmAssetLocker::<T>::local(&asset, &owner)
+			.map_err(|_| BenchmarkError::Stop("not lockable"))?;
+		assert_eq!(XcmAssetLocker::<T>::locks(&who, &asset).len() as u32, max.saturating_sub(1));
+
+		Ok(())
+	}
+
+	impl_benchmark_test_suite!(XcmAssetLocker, crate::tests::new_test_ext(), crate::tests::Test);
+}
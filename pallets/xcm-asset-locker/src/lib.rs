@@ -0,0 +1,452 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! XCM asset locker pallet
+//!
+//! The XCM `LockAsset` instruction locks an asset of the origin on this chain on behalf of an
+//! unlocker, usually another chain, which is told about the lock with `NoteUnlockable`. The funds
+//! stay with their owner but can't be moved until the unlocker sends `UnlockAsset`. Other chains
+//! can then use the locked funds as collateral, for voting or for bonds without taking custody.
+//!
+//! This pallet implements the locker side of [`AssetLock`] for the native token and the assets of
+//! a `pallet_assets` instance:
+//!
+//! - Native balances are frozen with [`FreezeReason::XcmLock`].
+//! - Asset balances are frozen through the [`FrozenBalance`] hook of `pallet_assets`, which must be
+//!   configured with this pallet as its `Freezer`.
+//!
+//! Only the chains in [`AllowedChains`], managed by [`Config::AdminOrigin`], can be unlockers.
+//! Locks of several unlockers on the same asset overlap, the frozen amount is the largest of them.
+//! Chains removed from the list can still unlock what was locked for them.
+//!
+//! The unlocker side (`NoteUnlockable` and `RequestUnlock`) is handled by
+//! [`Config::RemoteLocks`], which is usually `pallet_xcm`.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+mod tests;
+mod weight;
+
+use codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
+use frame_support::traits::{fungible, fungibles};
+use pallet_assets::FrozenBalance;
+use scale_info::TypeInfo;
+use sp_runtime::traits::Zero;
+use xcm::{latest::prelude::*, VersionedLocation};
+use xcm_executor::traits::{
+	AssetLock, ConvertLocation, Enact, LockError, MatchesFungible, MatchesFungibles,
+};
+
+pub use pallet::*;
+pub use weight::WeightInfo;
+
+/// Provides the arguments for the benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper {
+	/// An owner and an asset they hold, which can be locked.
+	fn lockable() -> (Location, Asset);
+}
+
+/// An asset that can be locked.
+#[derive(
+	Encode, Decode, DecodeWithMemTracking, MaxEncodedLen, TypeInfo, Clone, PartialEq, Eq, Debug,
+)]
+pub enum LockedAsset<AssetId> {
+	/// The native token.
+	Native,
+	/// An asset of [`Config::Assets`].
+	Asset(AssetId),
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use alloc::boxed::Box;
+	use frame_support::{pallet_prelude::*, traits::fungible::MutateFreeze};
+	use frame_system::pallet_prelude::*;
+
+	pub(crate) type BalanceOf<T> =
+		<<T as Config>::NativeBalance as fungible::Inspect<AccountIdOf<T>>>::Balance;
+	pub(crate) type AssetIdOf<T> =
+		<<T as Config>::Assets as fungibles::Inspect<AccountIdOf<T>>>::AssetId;
+	pub(crate) type AccountIdOf<T> = <T as frame_system::Config>::AccountId;
+	/// The locks of an account on an asset, by unlocker.
+	pub type LocksOf<T> = BoundedVec<(VersionedLocation, BalanceOf<T>), <T as Config>::MaxLocks>;
+
+	#[pallet::pallet]
+	pub struct Pallet<T>(_);
+
+	/// Configuration trait.
+	#[pallet::config]
+	pub trait Config: frame_system::Config<RuntimeEvent: From<Event<Self>>> {
+		/// The overarching freeze reason.
+		type RuntimeFreezeReason: From<FreezeReason>;
+
+		/// The native token.
+		type NativeBalance: fungible::MutateFreeze<Self::AccountId, Id = Self::RuntimeFreezeReason>;
+
+		/// Matches the native token in XCM assets.
+		type NativeMatcher: MatchesFungible<BalanceOf<Self>>;
+
+		/// The assets that can be locked, which must use this pallet as their `Freezer`.
+		type Assets: fungibles::Inspect<Self::AccountId, Balance = BalanceOf<Self>>;
+
+		/// Matches the assets of [`Self::Assets`] in XCM assets.
+		type AssetsMatcher: MatchesFungibles<AssetIdOf<Self>, BalanceOf<Self>>;
+
+		/// Converts the owners of locked assets to local accounts.
+		type LocationToAccountId: ConvertLocation<Self::AccountId>;
+
+		/// Handles the assets locked for this chain on other chains.
+		type RemoteLocks: AssetLock;
+
+		/// The origin which manages [`AllowedChains`].
+		type AdminOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of chains in [`AllowedChains`].
+		#[pallet::constant]
+		type MaxChains: Get<u32>;
+
+		/// The maximum number of unlockers of an asset of an account.
+		#[pallet::constant]
+		type MaxLocks: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+
+		/// Helper for the benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type BenchmarkHelper: BenchmarkHelper;
+	}
+
+	/// A reason for freezing funds.
+	#[pallet::composite_enum]
+	pub enum FreezeReason {
+		/// Funds locked through XCM on behalf of another chain.
+		#[codec(index = 0)]
+		XcmLock,
+	}
+
+	/// The chains which can request locks, including their interior locations.
+	#[pallet::storage]
+	pub type AllowedChains<T: Config> =
+		StorageValue<_, BoundedVec<VersionedLocation, T::MaxChains>, ValueQuery>;
+
+	/// The locks of the native token.
+	#[pallet::storage]
+	pub type NativeLocks<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, LocksOf<T>, ValueQuery>;
+
+	/// The locks of the assets.
+	#[pallet::storage]
+	pub type AssetLocks<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		AssetIdOf<T>,
+		Blake2_128Concat,
+		T::AccountId,
+		LocksOf<T>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A chain was allowed to request locks.
+		ChainAllowed { chain: Location },
+		/// A chain is no longer allowed to request locks.
+		ChainDisallowed { chain: Location },
+		/// An asset of `owner` was locked for `unlocker`.
+		Locked {
+			owner: T::AccountId,
+			asset: LockedAsset<AssetIdOf<T>>,
+			unlocker: Location,
+			amount: BalanceOf<T>,
+		},
+		/// An asset of `owner` was unlocked by `unlocker`.
+		Unlocked {
+			owner: T::AccountId,
+			asset: LockedAsset<AssetIdOf<T>>,
+			unlocker: Location,
+			amount: BalanceOf<T>,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The location can not be converted to the current XCM version.
+		BadLocation,
+		/// The chain is already allowed.
+		AlreadyAllowed,
+		/// The chain is not allowed.
+		NotAllowed,
+		/// There are already [`Config::MaxChains`] allowed chains.
+		TooManyChains,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Allow a chain to request locks.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`].
+		///
+		/// Parameters:
+		/// - `chain`: The chain, whose interior locations are allowed too.
+		#[pallet::call_index(0)]
+		#[pallet::weight(T::WeightInfo::allow_chain())]
+		pub fn allow_chain(origin: OriginFor<T>, chain: Box<VersionedLocation>) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let chain = Location::try_from(*chain).map_err(|_| Error::<T>::BadLocation)?;
+
+			AllowedChains::<T>::try_mutate(|chains| {
+				ensure!(Self::position_of(chains, &chain).is_none(), Error::<T>::AlreadyAllowed);
+				chains
+					.try_push(VersionedLocation::from(chain.clone()))
+					.map_err(|_| Error::<T>::TooManyChains)?;
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::ChainAllowed { chain });
+			Ok(())
+		}
+
+		/// Stop allowing a chain to request locks.
+		///
+		/// The dispatch origin for this call must be [`Config::AdminOrigin`]. The existing locks of
+		/// the chain are kept and can still be unlocked by it.
+		///
+		/// Parameters:
+		/// - `chain`: The chain, as it was allowed.
+		#[pallet::call_index(1)]
+		#[pallet::weight(T::WeightInfo::disallow_chain())]
+		pub fn disallow_chain(
+			origin: OriginFor<T>,
+			chain: Box<VersionedLocation>,
+		) -> DispatchResult {
+			T::AdminOrigin::ensure_origin(origin)?;
+			let chain = Location::try_from(*chain).map_err(|_| Error::<T>::BadLocation)?;
+
+			AllowedChains::<T>::try_mutate(|chains| {
+				let position = Self::position_of(chains, &chain).ok_or(Error::<T>::NotAllowed)?;
+				chains.remove(position);
+				Ok::<_, DispatchError>(())
+			})?;
+
+			Self::deposit_event(Event::ChainDisallowed { chain });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn position_of(chains: &[VersionedLocation], chain: &Location) -> Option<usize> {
+			chains.iter().position(|c| Location::try_from(c.clone()).as_ref() == Ok(chain))
+		}
+
+		/// Whether `unlocker` is an allowed chain or one of its interior locations.
+		pub fn is_allowed(unlocker: &Location) -> bool {
+			AllowedChains::<T>::get().into_iter().any(|chain| {
+				Location::try_from(chain).is_ok_and(|chain| unlocker.starts_with(&chain))
+			})
+		}
+
+		/// The locks of `owner` on `asset`.
+		pub fn locks(owner: &T::AccountId, asset: &LockedAsset<AssetIdOf<T>>) -> LocksOf<T> {
+			match asset {
+				LockedAsset::Native => NativeLocks::<T>::get(owner),
+				LockedAsset::Asset(id) => AssetLocks::<T>::get(id, owner),
+			}
+		}
+
+		/// The amount of `asset` which `owner` holds.
+		pub(crate) fn balance(
+			owner: &T::AccountId,
+			asset: &LockedAsset<AssetIdOf<T>>,
+		) -> BalanceOf<T> {
+			match asset {
+				LockedAsset::Native => <T::NativeBalance as fungible::Inspect<_>>::balance(owner),
+				LockedAsset::Asset(id) =>
+					<T::Assets as fungibles::Inspect<_>>::balance(id.clone(), owner),
+			}
+		}
+
+		/// Store the locks of `owner` on `asset` and update the frozen amount.
+		pub(crate) fn set_locks(
+			owner: &T::AccountId,
+			asset: &LockedAsset<AssetIdOf<T>>,
+			locks: LocksOf<T>,
+		) -> Result<(), LockError> {
+			let frozen = locks.iter().map(|(_, amount)| *amount).max().unwrap_or_default();
+			match asset {
+				LockedAsset::Native => {
+					let reason = FreezeReason::XcmLock.into();
+					let result = if frozen.is_zero() {
+						T::NativeBalance::thaw(&reason, owner)
+					} else {
+						T::NativeBalance::set_freeze(&reason, owner, frozen)
+					};
+					result.map_err(|_| LockError::UnexpectedState)?;
+					if locks.is_empty() {
+						NativeLocks::<T>::remove(owner);
+					} else {
+						NativeLocks::<T>::insert(owner, locks);
+					}
+				},
+				// Read by `pallet_assets` through `FrozenBalance`.
+				LockedAsset::Asset(id) =>
+					if locks.is_empty() {
+						AssetLocks::<T>::remove(id, owner);
+					} else {
+						AssetLocks::<T>::insert(id, owner, locks);
+					},
+			}
+			Ok(())
+		}
+
+		/// Convert an XCM asset and its owner to their local counterparts.
+		pub(crate) fn local(
+			asset: &Asset,
+			owner: &Location,
+		) -> Result<(T::AccountId, LockedAsset<AssetIdOf<T>>, BalanceOf<T>), LockError> {
+			let (asset, amount) = match T::NativeMatcher::matches_fungible(asset) {
+				Some(amount) => (LockedAsset::Native, amount),
+				None => {
+					let (id, amount) = T::AssetsMatcher::matches_fungibles(asset)
+						.map_err(|_| LockError::UnknownAsset)?;
+					(LockedAsset::Asset(id), amount)
+				},
+			};
+			let owner =
+				T::LocationToAccountId::convert_location(owner).ok_or(LockError::BadOwner)?;
+			Ok((owner, asset, amount))
+		}
+	}
+}
+
+/// A lock or unlock prepared by [`AssetLock`], enacted later by the XCM executor.
+pub struct LockTicket<T: Config> {
+	owner: T::AccountId,
+	asset: LockedAsset<AssetIdOf<T>>,
+	unlocker: Location,
+	locks: LocksOf<T>,
+	amount: BalanceOf<T>,
+	lock: bool,
+}
+
+impl<T: Config> Enact for LockTicket<T> {
+	fn enact(self) -> Result<(), LockError> {
+		let LockTicket { owner, asset, unlocker, locks, amount, lock } = self;
+		Pallet::<T>::set_locks(&owner, &asset, locks)?;
+		let event = if lock {
+			Event::Locked { owner, asset, unlocker, amount }
+		} else {
+			Event::Unlocked { owner, asset, unlocker, amount }
+		};
+		Pallet::<T>::deposit_event(event);
+		Ok(())
+	}
+}
+
+impl<T: Config> AssetLock for Pallet<T> {
+	type LockTicket = LockTicket<T>;
+	type UnlockTicket = LockTicket<T>;
+	type ReduceTicket = <T::RemoteLocks as AssetLock>::ReduceTicket;
+
+	fn prepare_lock(
+		unlocker: Location,
+		asset: Asset,
+		owner: Location,
+	) -> Result<Self::LockTicket, LockError> {
+		ensure_allowed::<T>(&unlocker)?;
+		let (owner, asset, amount) = Self::local(&asset, &owner)?;
+
+		let mut locks = Self::locks(&owner, &asset);
+		let versioned = VersionedLocation::from(unlocker.clone());
+		// Like the locks of `pallet_xcm`, a new lock for the same unlocker can only increase it.
+		match locks.iter_mut().find(|(u, _)| *u == versioned) {
+			Some((_, locked)) => *locked = (*locked).max(amount),
+			None => locks.try_push((versioned, amount)).map_err(|_| LockError::NoResources)?,
+		}
+		let frozen = locks.iter().map(|(_, amount)| *amount).max().unwrap_or_default();
+		if Self::balance(&owner, &asset) < frozen {
+			return Err(LockError::AssetNotOwned);
+		}
+
+		Ok(LockTicket { owner, asset, unlocker, locks, amount, lock: true })
+	}
+
+	fn prepare_unlock(
+		unlocker: Location,
+		asset: Asset,
+		owner: Location,
+	) -> Result<Self::UnlockTicket, LockError> {
+		let (owner, asset, amount) = Self::local(&asset, &owner)?;
+
+		let mut locks = Self::locks(&owner, &asset);
+		let versioned = VersionedLocation::from(unlocker.clone());
+		let position =
+			locks.iter().position(|(u, _)| *u == versioned).ok_or(LockError::NotLocked)?;
+		let locked = &mut locks[position].1;
+		if *locked < amount {
+			return Err(LockError::NotEnoughLocked);
+		}
+		*locked -= amount;
+		if locked.is_zero() {
+			locks.remove(position);
+		}
+
+		Ok(LockTicket { owner, asset, unlocker, locks, amount, lock: false })
+	}
+
+	fn note_unlockable(locker: Location, asset: Asset, owner: Location) -> Result<(), LockError> {
+		T::RemoteLocks::note_unlockable(locker, asset, owner)
+	}
+
+	fn prepare_reduce_unlockable(
+		locker: Location,
+		asset: Asset,
+		owner: Location,
+	) -> Result<Self::ReduceTicket, LockError> {
+		T::RemoteLocks::prepare_reduce_unlockable(locker, asset, owner)
+	}
+}
+
+fn ensure_allowed<T: Config>(unlocker: &Location) -> Result<(), LockError> {
+	if Pallet::<T>::is_allowed(unlocker) {
+		Ok(())
+	} else {
+		Err(LockError::NotTrusted)
+	}
+}
+
+impl<T: Config> FrozenBalance<AssetIdOf<T>, T::AccountId, BalanceOf<T>> for Pallet<T> {
+	fn frozen_balance(asset: AssetIdOf<T>, who: &T::AccountId) -> Option<BalanceOf<T>> {
+		AssetLocks::<T>::get(asset, who).iter().map(|(_, amount)| *amount).max()
+	}
+
+	fn died(asset: AssetIdOf<T>, who: &T::AccountId) {
+		// A frozen balance keeps the account alive, this only happens if the asset is destroyed.
+		AssetLocks::<T>::remove(asset, who);
+	}
+
+	fn contains_freezes(asset: AssetIdOf<T>) -> bool {
+		AssetLocks::<T>::contains_prefix(asset)
+	}
+}
@@ -0,0 +1,343 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+// Tests for the XCM Asset Locker Pallet
+
+use super::*;
+use crate as xcm_asset_locker;
+use frame_support::{
+	assert_noop, assert_ok, construct_runtime, derive_impl, parameter_types,
+	traits::{
+		fungible::InspectFreeze,
+		fungibles::Inspect as _,
+		tokens::{Fortitude::Polite, Preservation::Expendable},
+		AsEnsureOriginWithArg, ConstU32,
+	},
+};
+use frame_system::{EnsureRoot, EnsureSigned};
+use sp_io::TestExternalities;
+use sp_runtime::{traits::TryConvertInto, BuildStorage, DispatchError, TokenError};
+use xcm_builder::{AsPrefixedGeneralIndex, ConvertedConcreteId, IsConcrete};
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub struct Test {
+		System: frame_system,
+		Balances: pallet_balances,
+		Assets: pallet_assets,
+		XcmAssetLocker: xcm_asset_locker,
+	}
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+	type Block = Block;
+	type AccountData = pallet_balances::AccountData<u64>;
+}
+
+#[derive_impl(pallet_balances::config_preludes::TestDefaultConfig)]
+impl pallet_balances::Config for Test {
+	type AccountStore = System;
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<1>;
+}
+
+#[derive_impl(pallet_assets::config_preludes::TestDefaultConfig)]
+impl pallet_assets::Config for Test {
+	type Currency = Balances;
+	type CreateOrigin = AsEnsureOriginWithArg<EnsureSigned<u64>>;
+	type ForceOrigin = EnsureRoot<u64>;
+	type Freezer = XcmAssetLocker;
+}
+
+const ASSET: u32 = 1;
+const OWNER: u64 = 1;
+const OTHER: u64 = 2;
+
+parameter_types! {
+	pub AssetsPalletLocation: Location = PalletInstance(2).into();
+}
+
+/// Converts `AccountIndex64` junctions to accounts.
+pub struct IndexToAccount;
+impl ConvertLocation<u64> for IndexToAccount {
+	fn convert_location(location: &Location) -> Option<u64> {
+		match location.unpack() {
+			(0, [AccountIndex64 { index, .. }]) => Some(*index),
+			_ => None,
+		}
+	}
+}
+
+impl Config for Test {
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type NativeBalance = Balances;
+	type NativeMatcher = IsConcrete<Here>;
+	type Assets = Assets;
+	type AssetsMatcher = ConvertedConcreteId<
+		u32,
+		u64,
+		AsPrefixedGeneralIndex<AssetsPalletLocation, u32, TryConvertInto>,
+		TryConvertInto,
+	>;
+	type LocationToAccountId = IndexToAccount;
+	type RemoteLocks = ();
+	type AdminOrigin = EnsureRoot<u64>;
+	type MaxChains = ConstU32<2>;
+	type MaxLocks = ConstU32<2>;
+	type WeightInfo = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = LockableNative;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct LockableNative;
+#[cfg(feature = "runtime-benchmarks")]
+impl BenchmarkHelper for LockableNative {
+	fn lockable() -> (Location, Asset) {
+		(owner(), native(50))
+	}
+}
+
+pub fn new_test_ext() -> TestExternalities {
+	let mut t = frame_system::GenesisConfig::<Test>::default().build_storage().unwrap();
+	pallet_balances::GenesisConfig::<Test> {
+		balances: vec![(OWNER, 100), (OTHER, 100)],
+		dev_accounts: None,
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	pallet_assets::GenesisConfig::<Test> {
+		assets: vec![(ASSET, OTHER, true, 1)],
+		metadata: vec![],
+		accounts: vec![(ASSET, OWNER, 100)],
+		next_asset_id: None,
+		reserves: vec![],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext = TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
+
+fn para(id: u32) -> Location {
+	Location::new(1, [Parachain(id)])
+}
+
+fn owner() -> Location {
+	AccountIndex64 { network: None, index: OWNER }.into()
+}
+
+fn native(amount: u64) -> Asset {
+	(Here, amount as u128).into()
+}
+
+fn asset(amount: u64) -> Asset {
+	(AssetsPalletLocation::get().appended_with(GeneralIndex(ASSET.into())).unwrap(), amount as u128)
+		.into()
+}
+
+fn allow(chain: Location) {
+	assert_ok!(XcmAssetLocker::allow_chain(RuntimeOrigin::root(), Box::new(chain.into())));
+}
+
+fn lock(unlocker: Location, asset: Asset) -> Result<(), LockError> {
+	XcmAssetLocker::prepare_lock(unlocker, asset, owner())?.enact()
+}
+
+fn unlock(unlocker: Location, asset: Asset) -> Result<(), LockError> {
+	XcmAssetLocker::prepare_unlock(unlocker, asset, owner())?.enact()
+}
+
+fn frozen_native() -> u64 {
+	Balances::balance_frozen(&FreezeReason::XcmLock.into(), &OWNER)
+}
+
+#[test]
+fn allow_and_disallow_chains_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			XcmAssetLocker::allow_chain(RuntimeOrigin::signed(OWNER), Box::new(para(2000).into())),
+			DispatchError::BadOrigin
+		);
+
+		allow(para(2000));
+		System::assert_last_event(Event::ChainAllowed { chain: para(2000) }.into());
+		assert_noop!(
+			XcmAssetLocker::allow_chain(RuntimeOrigin::root(), Box::new(para(2000).into())),
+			Error::<Test>::AlreadyAllowed
+		);
+		allow(para(2001));
+		assert_noop!(
+			XcmAssetLocker::allow_chain(RuntimeOrigin::root(), Box::new(para(2002).into())),
+			Error::<Test>::TooManyChains
+		);
+
+		assert_ok!(XcmAssetLocker::disallow_chain(
+			RuntimeOrigin::root(),
+			Box::new(para(2000).into())
+		));
+		System::assert_last_event(Event::ChainDisallowed { chain: para(2000) }.into());
+		assert_noop!(
+			XcmAssetLocker::disallow_chain(RuntimeOrigin::root(), Box::new(para(2000).into())),
+			Error::<Test>::NotAllowed
+		);
+		assert!(!XcmAssetLocker::is_allowed(&para(2000)));
+		assert!(XcmAssetLocker::is_allowed(&para(2001)));
+	});
+}
+
+#[test]
+fn only_allowed_chains_can_be_unlockers() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(lock(para(2000), native(10)).err(), Some(LockError::NotTrusted));
+
+		allow(para(2000));
+		assert_ok!(lock(para(2000), native(10)));
+		// Interior locations of the chain are allowed too.
+		let pallet = para(2000).appended_with(PalletInstance(42)).unwrap();
+		assert_ok!(lock(pallet, native(10)));
+		assert_eq!(lock(para(2001), native(10)).err(), Some(LockError::NotTrusted));
+	});
+}
+
+#[test]
+fn lock_and_unlock_native_works() {
+	new_test_ext().execute_with(|| {
+		allow(para(2000));
+
+		assert_ok!(lock(para(2000), native(60)));
+		System::assert_last_event(
+			Event::Locked {
+				owner: OWNER,
+				asset: LockedAsset::Native,
+				unlocker: para(2000),
+				amount: 60,
+			}
+			.into(),
+		);
+		assert_eq!(frozen_native(), 60);
+		assert_noop!(
+			Balances::transfer_allow_death(RuntimeOrigin::signed(OWNER), OTHER, 50),
+			TokenError::Frozen
+		);
+		assert_ok!(Balances::transfer_allow_death(RuntimeOrigin::signed(OWNER), OTHER, 40));
+
+		assert_ok!(unlock(para(2000), native(20)));
+		System::assert_last_event(
+			Event::Unlocked {
+				owner: OWNER,
+				asset: LockedAsset::Native,
+				unlocker: para(2000),
+				amount: 20,
+			}
+			.into(),
+		);
+		assert_eq!(frozen_native(), 40);
+
+		assert_ok!(unlock(para(2000), native(40)));
+		assert_eq!(frozen_native(), 0);
+		assert!(!NativeLocks::<Test>::contains_key(OWNER));
+	});
+}
+
+#[test]
+fn locking_more_than_owned_fails() {
+	new_test_ext().execute_with(|| {
+		allow(para(2000));
+
+		assert_eq!(lock(para(2000), native(101)).err(), Some(LockError::AssetNotOwned));
+		assert_eq!(lock(para(2000), asset(101)).err(), Some(LockError::AssetNotOwned));
+		let unknown: Asset = (Parent, 1u128).into();
+		assert_eq!(lock(para(2000), unknown).err(), Some(LockError::UnknownAsset));
+	});
+}
+
+#[test]
+fn locks_of_several_unlockers_overlap() {
+	new_test_ext().execute_with(|| {
+		allow(para(2000));
+		allow(para(2001));
+
+		assert_ok!(lock(para(2000), native(40)));
+		assert_ok!(lock(para(2001), native(60)));
+		assert_eq!(frozen_native(), 60);
+
+		// A smaller lock of the same unlocker doesn't reduce it.
+		assert_ok!(lock(para(2001), native(30)));
+		assert_eq!(frozen_native(), 60);
+
+		assert_ok!(unlock(para(2001), native(60)));
+		assert_eq!(frozen_native(), 40);
+
+		// A third unlocker doesn't fit.
+		let pallet = para(2000).appended_with(PalletInstance(42)).unwrap();
+		assert_ok!(lock(pallet, native(10)));
+		assert_eq!(lock(para(2001), native(10)).err(), Some(LockError::NoResources));
+	});
+}
+
+#[test]
+fn unlocking_checks_the_locks() {
+	new_test_ext().execute_with(|| {
+		allow(para(2000));
+		allow(para(2001));
+		assert_ok!(lock(para(2000), native(40)));
+
+		assert_eq!(unlock(para(2001), native(10)).err(), Some(LockError::NotLocked));
+		assert_eq!(unlock(para(2000), native(41)).err(), Some(LockError::NotEnoughLocked));
+		assert_eq!(unlock(para(2000), asset(10)).err(), Some(LockError::NotLocked));
+
+		// Disallowed chains can still unlock.
+		assert_ok!(XcmAssetLocker::disallow_chain(
+			RuntimeOrigin::root(),
+			Box::new(para(2000).into())
+		));
+		assert_ok!(unlock(para(2000), native(40)));
+		assert_eq!(frozen_native(), 0);
+	});
+}
+
+#[test]
+fn lock_and_unlock_assets_works() {
+	new_test_ext().execute_with(|| {
+		allow(para(2000));
+
+		assert_ok!(lock(para(2000), asset(70)));
+		System::assert_last_event(
+			Event::Locked {
+				owner: OWNER,
+				asset: LockedAsset::Asset(ASSET),
+				unlocker: para(2000),
+				amount: 70,
+			}
+			.into(),
+		);
+		assert!(Assets::reducible_balance(ASSET, &OWNER, Expendable, Polite) <= 30);
+		assert!(XcmAssetLocker::contains_freezes(ASSET));
+		assert!(Assets::transfer(RuntimeOrigin::signed(OWNER), ASSET.into(), OTHER, 50).is_err());
+		assert_ok!(Assets::transfer(RuntimeOrigin::signed(OWNER), ASSET.into(), OTHER, 20));
+		// The native token is not affected.
+		assert_eq!(frozen_native(), 0);
+
+		assert_ok!(unlock(para(2000), asset(70)));
+		assert!(!XcmAssetLocker::contains_freezes(ASSET));
+		assert_eq!(Assets::reducible_balance(ASSET, &OWNER, Expendable, Polite), 80);
+	});
+}
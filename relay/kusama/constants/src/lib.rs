@@ -119,6 +119,8 @@ pub mod system_parachain {
 	pub const ENCOINTER_ID: u32 = 1001;
 	/// Bridge Hub parachain ID.
 	pub const BRIDGE_HUB_ID: u32 = 1002;
+	/// Collectives parachain ID.
+	pub const COLLECTIVES_ID: u32 = 1003;
 	/// People parachain ID.
 	pub const PEOPLE_ID: u32 = 1004;
 	/// Coretime parachain ID.
@@ -233,9 +235,8 @@ pub mod proxy {
 		}
 
 		#[cfg(feature = "runtime-benchmarks")]
-		fn create_remote_proxy_proof(
-			caller: &AccountId,
-			proxy: &AccountId,
+		fn create_remote_proxies_proof(
+			proxies: &[(AccountId, AccountId)],
 		) -> (pallet_remote_proxy::RemoteProxyProof<Self::RemoteBlockNumber>, BlockNumber, Hash) {
 			use codec::Encode;
 			use sp_trie::TrieMut;
@@ -244,15 +245,17 @@ pub mod proxy {
 			let mut trie =
 				sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut db, &mut root).build();
 
-			let proxy_definition =
-				alloc::vec![ProxyDefinition::<AccountId, ProxyType, BlockNumber> {
-					delegate: caller.clone(),
-					proxy_type: ProxyType::default(),
-					delay: 0,
-				}];
-
-			trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
-				.unwrap();
+			for (caller, proxy) in proxies {
+				let proxy_definition =
+					alloc::vec![ProxyDefinition::<AccountId, ProxyType, BlockNumber> {
+						delegate: caller.clone(),
+						proxy_type: ProxyType::default(),
+						delay: 0,
+					}];
+
+				trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
+					.unwrap();
+			}
 			drop(trie);
 
 			(
@@ -0,0 +1,244 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Moving the Kusama Fellowship from the Relay Chain to Collectives Kusama.
+//!
+//! The move happens in three steps:
+//!
+//! 1. [`MigrateFellowshipToCollectives`] sends the members of `FellowshipCollective` to Collectives
+//!    Kusama. Each member is added and promoted to its rank with the typed calls of the ranked
+//!    collective pallet there, dispatched by a `Superuser` XCM `Transact`.
+//! 2. In the same step the referenda in flight are moved. A referendum whose proposal sends an XCM
+//!    message with the voice of the Fellows or Architects is submitted again on Collectives Kusama,
+//!    where the same voice sends the same message, and cancelled here. Votes are not moved, the
+//!    members vote again on Collectives Kusama. On success the migration sets
+//!    [`FellowshipMigrated`].
+//! 3. From then on [`InFlightOnly`] limits the Fellowship calls on the Relay Chain. New referenda
+//!    and membership changes are rejected, while the referenda that could not be moved can still be
+//!    voted on and run to completion with the frozen member set.
+//!
+//! Members `import` themselves into `FellowshipCore` on Collectives Kusama to take part in the
+//! rank-retention and salary cycles.
+//!
+//! The migration waits for Collectives Kusama to be onboarded as a parachain. Until then it does
+//! nothing and stays in the migrations of the next release.
+
+use super::*;
+use alloc::{vec, vec::Vec};
+use frame_support::traits::{
+	schedule::DispatchTime, OnRuntimeUpgrade, QueryPreimage, RankedMembers,
+};
+use kusama_runtime_constants::system_parachain::COLLECTIVES_ID;
+use pallet_referenda::{ReferendumInfo, ReferendumStatusOf};
+use remote_calls::{
+	kusama::{CollectivesCall, CollectivesOrigin, FellowshipOrigin},
+	AccountLookup, BoundedCall, PreimageCall, RankedCollectiveCall, ReferendaCall, UtilityCall,
+	XcmCall,
+};
+
+/// The maximum encoded size of the calls sent with one message.
+pub const MAX_PAGE_SIZE: usize = 32 * 1024;
+
+/// The maximum length of a proposal that is not noted as a preimage first.
+const MAX_INLINE_LEN: usize = 128;
+
+/// Whether the members were sent to Collectives Kusama.
+#[frame_support::storage_alias]
+pub type FellowshipMigrated = StorageValue<FellowshipMigration, bool, ValueQuery>;
+
+type FellowshipReferendum = ReferendumStatusOf<Runtime, FellowshipReferendaInstance>;
+
+/// The calls adding each member of the Fellowship to Collectives Kusama with its rank.
+pub fn member_calls() -> Vec<Vec<CollectivesCall>> {
+	pallet_ranked_collective::Members::<Runtime, FellowshipCollectiveInstance>::iter_keys()
+		.map(|who| {
+			let rank = <FellowshipCollective as RankedMembers>::rank_of(&who).unwrap_or_default();
+			let mut calls =
+				vec![CollectivesCall::FellowshipCollective(RankedCollectiveCall::AddMember {
+					who: AccountLookup::Id(who.clone()),
+				})];
+			calls.extend((0..rank).map(|_| {
+				CollectivesCall::FellowshipCollective(RankedCollectiveCall::PromoteMember {
+					who: AccountLookup::Id(who.clone()),
+				})
+			}));
+			calls
+		})
+		.collect()
+}
+
+/// The calls submitting `referendum` again on Collectives Kusama.
+///
+/// Only referenda sending an XCM message with the voice of the Fellows or Architects can be moved,
+/// since these are the voices that send messages on Collectives Kusama.
+pub fn referendum_calls(referendum: &FellowshipReferendum) -> Option<Vec<CollectivesCall>> {
+	let origin = match referendum.track {
+		3 => FellowshipOrigin::Fellows,
+		4 => FellowshipOrigin::Architects,
+		_ => return None,
+	};
+	let (call, _) = <Preimage as QueryPreimage>::peek(&referendum.proposal).ok()?;
+	let RuntimeCall::XcmPallet(pallet_xcm::Call::send { dest, message }) = call else {
+		return None
+	};
+	let dest = Location::try_from(*dest)
+		.ok()?
+		.reanchored(
+			&Location::new(0, [Parachain(COLLECTIVES_ID)]),
+			&xcm_config::UniversalLocation::get(),
+		)
+		.ok()?;
+	let proposal =
+		CollectivesCall::PolkadotXcm(XcmCall::Send { dest: dest.into(), message: *message })
+			.encode();
+
+	let mut calls = Vec::new();
+	let proposal = if proposal.len() <= MAX_INLINE_LEN {
+		BoundedCall::Inline(proposal)
+	} else {
+		let hash = sp_io::hashing::blake2_256(&proposal).into();
+		let len = proposal.len() as u32;
+		calls.push(CollectivesCall::Preimage(PreimageCall::NotePreimage { bytes: proposal }));
+		BoundedCall::Lookup { hash, len }
+	};
+	let enactment_moment = match referendum.enactment {
+		DispatchTime::At(block) => remote_calls::DispatchTime::After(
+			block.saturating_sub(frame_system::Pallet::<Runtime>::block_number()),
+		),
+		DispatchTime::After(delay) => remote_calls::DispatchTime::After(delay),
+	};
+	calls.push(CollectivesCall::FellowshipReferenda(ReferendaCall::Submit {
+		proposal_origin: CollectivesOrigin::FellowshipOrigins(origin),
+		proposal,
+		enactment_moment,
+	}));
+	Some(calls)
+}
+
+/// Splits `items` into pages whose encoded size stays below `max_size`.
+///
+/// A single item larger than `max_size` gets a page of its own.
+pub fn paginate<T: Encode>(items: Vec<T>, max_size: usize) -> Vec<Vec<T>> {
+	let mut pages = Vec::new();
+	let mut page = Vec::new();
+	let mut size = 0;
+	for item in items {
+		let item_size = item.encoded_size();
+		if !page.is_empty() && size + item_size > max_size {
+			pages.push(core::mem::take(&mut page));
+			size = 0;
+		}
+		size += item_size;
+		page.push(item);
+	}
+	if !page.is_empty() {
+		pages.push(page);
+	}
+	pages
+}
+
+/// Sends `calls` to Collectives Kusama as one batch dispatched by the Relay Chain.
+fn send_batch(calls: Vec<CollectivesCall>) -> Result<(), SendError> {
+	let message = remote_calls::unpaid_transact(
+		OriginKind::Superuser,
+		&CollectivesCall::Utility(UtilityCall::BatchAll { calls }),
+	);
+	send_xcm::<xcm_config::XcmRouter>(Location::new(0, [Parachain(COLLECTIVES_ID)]), message)
+		.map(|_| ())
+}
+
+/// Sends the members and the referenda in flight of the Fellowship to Collectives Kusama, see the
+/// [module docs](self).
+pub struct MigrateFellowshipToCollectives;
+impl OnRuntimeUpgrade for MigrateFellowshipToCollectives {
+	fn on_runtime_upgrade() -> Weight {
+		let db_weight = <Runtime as frame_system::Config>::DbWeight::get();
+		if FellowshipMigrated::get() {
+			log::info!(target: "runtime::fellowship", "Fellowship already migrated, skipping");
+			return db_weight.reads(1)
+		}
+		if !Paras::is_parachain(COLLECTIVES_ID.into()) {
+			log::info!(target: "runtime::fellowship", "Collectives Kusama not onboarded, skipping");
+			return db_weight.reads(2)
+		}
+
+		let members = member_calls();
+		let mut reads = 2 + 2 * members.len() as u64;
+		for (index, page) in paginate(members, MAX_PAGE_SIZE).into_iter().enumerate() {
+			if let Err(error) = send_batch(page.concat()) {
+				// Pages sent before are harmless: adding a member twice fails its whole batch.
+				log::error!(
+					target: "runtime::fellowship",
+					"Failed to send page {index} of the Fellowship members: {error:?}",
+				);
+				return db_weight.reads(reads)
+			}
+		}
+
+		let mut writes = 1;
+		for (index, info) in
+			pallet_referenda::ReferendumInfoFor::<Runtime, FellowshipReferendaInstance>::iter()
+		{
+			reads += 2;
+			let ReferendumInfo::Ongoing(referendum) = info else { continue };
+			let Some(calls) = referendum_calls(&referendum) else { continue };
+			if let Err(error) = send_batch(calls) {
+				log::error!(
+					target: "runtime::fellowship",
+					"Failed to move Fellowship referendum {index}: {error:?}",
+				);
+				continue
+			}
+			let cancelled =
+				pallet_referenda::Pallet::<Runtime, FellowshipReferendaInstance>::cancel(
+					origins::Origin::FellowshipExperts.into(),
+					index,
+				);
+			debug_assert!(cancelled.is_ok(), "ongoing referendum can be cancelled");
+			writes += 3;
+		}
+
+		FellowshipMigrated::put(true);
+		log::info!(target: "runtime::fellowship", "Fellowship sent to Collectives Kusama");
+		db_weight.reads_writes(reads, writes)
+	}
+}
+
+/// Fellowship calls that stay available on the Relay Chain once [`FellowshipMigrated`] is set.
+///
+/// Only the referenda already in flight are served: votes, deposits and the bookkeeping needed
+/// to close them.
+pub struct InFlightOnly;
+impl Contains<RuntimeCall> for InFlightOnly {
+	fn contains(call: &RuntimeCall) -> bool {
+		matches!(
+			call,
+			RuntimeCall::FellowshipCollective(
+				pallet_ranked_collective::Call::vote { .. } |
+					pallet_ranked_collective::Call::cleanup_poll { .. }
+			) | RuntimeCall::FellowshipReferenda(
+				pallet_referenda::Call::place_decision_deposit { .. } |
+					pallet_referenda::Call::refund_decision_deposit { .. } |
+					pallet_referenda::Call::refund_submission_deposit { .. } |
+					pallet_referenda::Call::cancel { .. } |
+					pallet_referenda::Call::kill { .. } |
+					pallet_referenda::Call::nudge_referendum { .. } |
+					pallet_referenda::Call::one_fewer_deciding { .. } |
+					pallet_referenda::Call::set_metadata { .. }
+			)
+		)
+	}
+}
@@ -33,6 +33,7 @@ mod tracks;
 pub use tracks::TracksInfo;
 mod fellowship;
 pub use fellowship::{FellowshipCollectiveInstance, FellowshipReferendaInstance};
+pub mod fellowship_migration;
 
 parameter_types! {
 	pub const VoteLockingPeriod: BlockNumber = 7 * DAYS;
@@ -246,6 +246,12 @@ impl Contains<RuntimeCall> for PostAhmFilter {
 			// Coretime: request_revenue_at is allowed, rest handled by catch-all.
 			Coretime(coretime::Call::<Runtime>::request_revenue_at { .. }) => true,
 
+			// Once the Fellowship moved to Collectives Kusama, only the referenda in flight are
+			// served.
+			FellowshipCollective(..) | FellowshipReferenda(..)
+				if governance::fellowship_migration::FellowshipMigrated::get() =>
+				governance::fellowship_migration::InFlightOnly::contains(call),
+
 			// Fellowship and its preimages explicitly allowed.
 			FellowshipCollective(..) | FellowshipReferenda(..) | Preimage(..) => true,
 
@@ -1633,6 +1639,63 @@ impl coretime::Config for Runtime {
 	>;
 }
 
+/// The Coretime Chain, dispatching calls with `OriginKind::Native`.
+pub struct EnsureBroker;
+impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureBroker {
+	type Success = ();
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		match <RuntimeOrigin as Into<Result<parachains_origin::Origin, RuntimeOrigin>>>::into(
+			o.clone(),
+		) {
+			Ok(parachains_origin::Origin::Parachain(id)) if id == BrokerId::get().into() => Ok(()),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(parachains_origin::Origin::Parachain(BrokerId::get().into()).into())
+	}
+}
+
+/// Applies the core assignments received in chunks like `coretime::assign_core`.
+pub struct CoretimeAssignCore;
+impl pallet_chunked_core_assignment::AssignCore<BlockNumber> for CoretimeAssignCore {
+	fn assign_core(
+		core: pallet_chunked_core_assignment::CoreIndex,
+		begin: BlockNumber,
+		assignment: Vec<(
+			pallet_chunked_core_assignment::CoreAssignment,
+			pallet_chunked_core_assignment::PartsOf57600,
+		)>,
+		end_hint: Option<BlockNumber>,
+	) -> sp_runtime::DispatchResult {
+		coretime::Pallet::<Runtime>::assign_core(
+			RuntimeOrigin::root(),
+			core,
+			begin,
+			assignment,
+			end_hint,
+		)
+	}
+
+	fn assign_core_weight(assignments: u32) -> Weight {
+		<weights::runtime_parachains_coretime::WeightInfo<Runtime> as coretime::WeightInfo>::assign_core(
+			assignments,
+		)
+	}
+}
+
+impl pallet_chunked_core_assignment::Config for Runtime {
+	type BrokerOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureBroker>;
+	type AssignCore = CoretimeAssignCore;
+	type MaxChunkAssignments = ConstU32<{ remote_calls::MAX_ASSIGNMENTS_PER_CALL }>;
+	// One assignment for each bit of the core mask of the broker, and the idle rest.
+	type MaxAssignments = ConstU32<81>;
+	type MaxPendingCores = ConstU32<256>;
+	type WeightInfo = weights::pallet_chunked_core_assignment::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const OnDemandTrafficDefaultValue: FixedU128 = FixedU128::from_u32(1);
 	pub const MaxHistoricalRevenue: BlockNumber = 2 * TIMESLICE_PERIOD;
@@ -1828,58 +1891,27 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureAssetHub {
 	}
 }
 
-#[derive(Encode, Decode)]
-enum AssetHubRuntimePallets<AccountId> {
-	// index of the rc-client pallet in ksm-ah.
-	#[codec(index = 84)]
-	RcClient(RcClientCalls<AccountId>),
-}
-
-#[derive(Encode, Decode)]
-enum RcClientCalls<AccountId> {
-	#[codec(index = 0)]
-	RelaySessionReport(rc_client::SessionReport<AccountId>),
-	#[codec(index = 1)]
-	RelayNewOffencePaged(Vec<(SessionIndex, rc_client::Offence<AccountId>)>),
-}
-
 pub struct SessionReportToXcm;
 impl Convert<rc_client::SessionReport<AccountId>, Xcm<()>> for SessionReportToXcm {
-	fn convert(a: rc_client::SessionReport<AccountId>) -> Xcm<()> {
-		Xcm(vec![
-			Instruction::UnpaidExecution {
-				weight_limit: WeightLimit::Unlimited,
-				check_origin: None,
-			},
-			Instruction::Transact {
-				origin_kind: OriginKind::Superuser,
-				fallback_max_weight: None,
-				call: AssetHubRuntimePallets::RcClient(RcClientCalls::RelaySessionReport(a))
-					.encode()
-					.into(),
-			},
-		])
+	fn convert(report: rc_client::SessionReport<AccountId>) -> Xcm<()> {
+		remote_calls::unpaid_transact(
+			OriginKind::Superuser,
+			&remote_calls::kusama::AssetHubCall::StakingRcClient(
+				remote_calls::RcClientCall::RelaySessionReport { report },
+			),
+		)
 	}
 }
 
 pub struct QueuedOffenceToXcm;
 impl Convert<Vec<ah_client::QueuedOffenceOf<Runtime>>, Xcm<()>> for QueuedOffenceToXcm {
 	fn convert(offences: Vec<ah_client::QueuedOffenceOf<Runtime>>) -> Xcm<()> {
-		Xcm(vec![
-			Instruction::UnpaidExecution {
-				weight_limit: WeightLimit::Unlimited,
-				check_origin: None,
-			},
-			Instruction::Transact {
-				origin_kind: OriginKind::Superuser,
-				fallback_max_weight: None,
-				call: AssetHubRuntimePallets::RcClient(RcClientCalls::RelayNewOffencePaged(
-					offences,
-				))
-				.encode()
-				.into(),
-			},
-		])
+		remote_calls::unpaid_transact(
+			OriginKind::Superuser,
+			&remote_calls::kusama::AssetHubCall::StakingRcClient(
+				remote_calls::RcClientCall::RelayNewOffencePaged { offences },
+			),
+		)
 	}
 }
 
@@ -1890,26 +1922,75 @@ impl pallet_staking_async_ah_client::SendToAssetHub for StakingXcmToAssetHub {
 	fn relay_session_report(
 		session_report: rc_client::SessionReport<Self::AccountId>,
 	) -> Result<(), ()> {
-		rc_client::XCMSender::<
+		let activated = session_report.activation_timestamp.map(|(_, id)| id);
+		let result = rc_client::XCMSender::<
 			xcm_config::XcmRouter,
 			AssetHubLocation,
 			rc_client::SessionReport<AccountId>,
 			SessionReportToXcm,
-		>::send(session_report)
+		>::send(session_report);
+		StakingBridgeMonitor::note_session_report_sent(activated, result.is_ok());
+		result
 	}
 
 	fn relay_new_offence_paged(
 		offences: Vec<ah_client::QueuedOffenceOf<Runtime>>,
 	) -> Result<(), ()> {
-		rc_client::XCMSender::<
+		let result = rc_client::XCMSender::<
 			xcm_config::XcmRouter,
 			AssetHubLocation,
 			Vec<ah_client::QueuedOffenceOf<Runtime>>,
 			QueuedOffenceToXcm,
-		>::send(offences)
+		>::send(offences);
+		StakingBridgeMonitor::note_offences_sent(result.is_ok());
+		result
 	}
 }
 
+/// The view of [`StakingBridgeMonitor`] on the state of [`StakingAhClient`].
+pub struct AhClientState;
+impl pallet_staking_bridge_monitor::StakingBridgeClient for AhClientState {
+	fn mode() -> Option<pallet_staking_bridge_monitor::ClientMode> {
+		use pallet_staking_bridge_monitor::ClientMode;
+		Some(match ah_client::Mode::<Runtime>::get() {
+			ah_client::OperatingMode::Passive => ClientMode::Passive,
+			ah_client::OperatingMode::Buffered => ClientMode::Buffered,
+			ah_client::OperatingMode::Active => ClientMode::Active,
+		})
+	}
+
+	fn queued_offences() -> u32 {
+		ah_client::OffenceSendQueue::<Runtime>::count()
+	}
+
+	fn outgoing() -> Option<pallet_staking_bridge_monitor::Outgoing> {
+		ah_client::OutgoingSessionReport::<Runtime>::get().map(|(report, retries_left)| {
+			pallet_staking_bridge_monitor::Outgoing {
+				message: pallet_staking_bridge_monitor::Message::SessionReport,
+				id: report.end_index,
+				retries_left,
+			}
+		})
+	}
+
+	fn latest_received() -> Option<(pallet_staking_bridge_monitor::Message, u32)> {
+		ah_client::ValidatorSet::<Runtime>::get()
+			.map(|(id, _)| (pallet_staking_bridge_monitor::Message::ValidatorSet, id))
+	}
+}
+
+parameter_types! {
+	/// Two eras without a validator set from Asset Hub.
+	pub const StakingBridgeSilenceThreshold: BlockNumber = 12 * HOURS;
+}
+
+impl pallet_staking_bridge_monitor::Config for Runtime {
+	type Client = AhClientState;
+	type MaxSendFailures = ConstU32<3>;
+	type SilenceThreshold = StakingBridgeSilenceThreshold;
+	type WeightInfo = weights::pallet_staking_bridge_monitor::WeightInfo<Runtime>;
+}
+
 /// The [frame_support::traits::tokens::ConversionFromAssetBalance] implementation provided by the
 /// `AssetRate` pallet instance.
 ///
@@ -2031,6 +2112,7 @@ construct_runtime! {
 
 		// staking client to communicate with AH.
 		StakingAhClient: pallet_staking_async_ah_client = 48,
+		StakingBridgeMonitor: pallet_staking_bridge_monitor = 49,
 
 		// Parachains pallets. Start indices at 50 to leave room.
 		ParachainsOrigin: parachains_origin = 50,
@@ -2054,6 +2136,7 @@ construct_runtime! {
 		Auctions: auctions = 72,
 		Crowdloan: crowdloan = 73,
 		Coretime: coretime = 74,
+		ChunkedCoreAssignment: pallet_chunked_core_assignment = 75,
 
 		// Pallet for sending XCM.
 		XcmPallet: pallet_xcm = 99,
@@ -2132,6 +2215,8 @@ pub mod migrations {
 		parachains_configuration::migration::v13::MigrateToV13<Runtime>,
 		parachains_shared::migration::MigrateToV2<Runtime>,
 		RemoveRecoveryPallet,
+		// Does nothing until Collectives Kusama is onboarded, keep until it ran.
+		governance::fellowship_migration::MigrateFellowshipToCollectives,
 	);
 
 	/// Migrations/checks that do not need to be versioned and can run on every update.
@@ -2184,6 +2269,7 @@ mod benches {
 		[frame_benchmarking::baseline, Baseline::<Runtime>]
 		[pallet_bounties, Bounties]
 		[pallet_child_bounties, ChildBounties]
+		[pallet_chunked_core_assignment, ChunkedCoreAssignment]
 		[pallet_conviction_voting, ConvictionVoting]
 		[pallet_election_provider_multi_phase, ElectionProviderMultiPhase]
 		[frame_election_provider_support, ElectionProviderBench::<Runtime>]
@@ -2202,6 +2288,7 @@ mod benches {
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_society, Society]
 		[pallet_staking, Staking]
+		[pallet_staking_bridge_monitor, StakingBridgeMonitor]
 		[frame_system, SystemBench::<Runtime>]
 		[frame_system_extensions, SystemExtensionsBench::<Runtime>]
 		[pallet_timestamp, Timestamp]
@@ -3067,6 +3154,12 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_staking_bridge_monitor::runtime_api::StakingBridgeHealthApi<Block, BlockNumber> for Runtime {
+		fn staking_bridge_health() -> pallet_staking_bridge_monitor::StakingBridgeHealth<BlockNumber> {
+			StakingBridgeMonitor::health()
+		}
+	}
+
 	impl sp_genesis_builder::GenesisBuilder<Block> for Runtime {
 		fn build_state(config: Vec<u8>) -> sp_genesis_builder::Result {
 			build_state::<RuntimeGenesisConfig>(config)
@@ -176,3 +176,206 @@ fn check_treasury_pallet_id() {
 		kusama_runtime_constants::TREASURY_PALLET_ID
 	);
 }
+
+#[test]
+fn remote_calls_compatibility() {
+	// if this fails, make sure `remote_calls::kusama::RelayCall` has valid encoding
+	use remote_calls::{kusama::RelayCall, AhClientCall, CoreAssignment, CoretimeCall, HrmpCall};
+
+	let stash = AccountId::from([1; 32]);
+	let report = rc_client::ValidatorSetReport {
+		new_validator_set: vec![stash.clone()],
+		id: 42,
+		prune_up_to: Some(7),
+		leftover: false,
+	};
+	let assignment = vec![(CoreAssignment::Task(1_000), 57_600)];
+
+	for (remote, call) in [
+		(
+			RelayCall::StakingAhClient(AhClientCall::ValidatorSet { report: report.clone() }),
+			RuntimeCall::StakingAhClient(ah_client::Call::validator_set { report }),
+		),
+		(
+			RelayCall::StakingAhClient(AhClientCall::SetKeysFromAh {
+				stash: stash.clone(),
+				keys: vec![1, 2, 3],
+			}),
+			RuntimeCall::StakingAhClient(ah_client::Call::set_keys_from_ah {
+				stash: stash.clone(),
+				keys: vec![1, 2, 3],
+			}),
+		),
+		(
+			RelayCall::StakingAhClient(AhClientCall::PurgeKeysFromAh { stash: stash.clone() }),
+			RuntimeCall::StakingAhClient(ah_client::Call::purge_keys_from_ah {
+				stash: stash.clone(),
+			}),
+		),
+		(
+			RelayCall::Hrmp(HrmpCall::HrmpInitOpenChannel {
+				recipient: 2_000.into(),
+				proposed_max_capacity: 8,
+				proposed_max_message_size: 1_024,
+			}),
+			RuntimeCall::Hrmp(parachains_hrmp::Call::hrmp_init_open_channel {
+				recipient: 2_000.into(),
+				proposed_max_capacity: 8,
+				proposed_max_message_size: 1_024,
+			}),
+		),
+		(
+			RelayCall::Hrmp(HrmpCall::HrmpAcceptOpenChannel { sender: 2_000.into() }),
+			RuntimeCall::Hrmp(parachains_hrmp::Call::hrmp_accept_open_channel {
+				sender: 2_000.into(),
+			}),
+		),
+		(
+			RelayCall::Coretime(CoretimeCall::RequestCoreCount { count: 60 }),
+			RuntimeCall::Coretime(coretime::Call::request_core_count { count: 60 }),
+		),
+		(
+			RelayCall::Coretime(CoretimeCall::RequestRevenueAt { when: 1_000 }),
+			RuntimeCall::Coretime(coretime::Call::request_revenue_at { when: 1_000 }),
+		),
+		(
+			RelayCall::Coretime(CoretimeCall::CreditAccount { who: stash.clone(), amount: 10 }),
+			RuntimeCall::Coretime(coretime::Call::credit_account { who: stash, amount: 10 }),
+		),
+		(
+			RelayCall::Coretime(CoretimeCall::AssignCore {
+				core: 3,
+				begin: 1_000,
+				assignment: assignment.clone(),
+				end_hint: Some(2_000),
+			}),
+			RuntimeCall::Coretime(coretime::Call::assign_core {
+				core: 3,
+				begin: 1_000,
+				assignment,
+				end_hint: Some(2_000),
+			}),
+		),
+	] {
+		assert_eq!(remote.encode(), call.encode(), "{call:?}");
+	}
+}
+
+#[test]
+fn fellowship_migration_adds_and_promotes_members() {
+	use codec::Encode;
+	use frame_support::assert_ok;
+	use governance::{
+		fellowship_migration::{member_calls, paginate, MAX_PAGE_SIZE},
+		FellowshipCollectiveInstance,
+	};
+	use remote_calls::{kusama::CollectivesCall, RankedCollectiveCall};
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		let ranks = [0u16, 1, 3, 7];
+		for (i, rank) in ranks.into_iter().enumerate() {
+			assert_ok!(pallet_ranked_collective::Pallet::<
+				Runtime,
+				FellowshipCollectiveInstance,
+			>::do_add_member_to_rank(AccountId::from([i as u8; 32]), rank, false));
+		}
+
+		let members = member_calls();
+		assert_eq!(members.len(), ranks.len());
+		for calls in &members {
+			// The member is added first and then promoted once per rank.
+			assert!(matches!(
+				calls[0],
+				CollectivesCall::FellowshipCollective(RankedCollectiveCall::AddMember { .. })
+			));
+			assert!(calls[1..].iter().all(|call| matches!(
+				call,
+				CollectivesCall::FellowshipCollective(RankedCollectiveCall::PromoteMember { .. })
+			)));
+		}
+		let mut promotions: Vec<_> = members.iter().map(|calls| calls.len() as u16 - 1).collect();
+		promotions.sort();
+		assert_eq!(promotions, ranks);
+
+		// Every member makes it into exactly one page and pages stay below the limit.
+		let pages = paginate(members.clone(), 200);
+		assert!(pages.len() > 1);
+		assert!(pages.iter().all(|page| {
+			page.len() == 1 || page.iter().map(|calls| calls.encoded_size()).sum::<usize>() <= 200
+		}));
+		assert_eq!(pages.concat().encode(), members.encode());
+		assert_eq!(paginate(members.clone(), MAX_PAGE_SIZE).len(), 1);
+	});
+}
+
+#[test]
+fn fellowship_migration_moves_referenda_sending_xcm() {
+	use codec::Encode;
+	use frame_support::{
+		assert_ok,
+		traits::{fungible::Mutate, schedule::DispatchTime, StorePreimage},
+	};
+	use governance::{
+		fellowship_migration::referendum_calls, pallet_custom_origins::Origin,
+		FellowshipCollectiveInstance, FellowshipReferendaInstance,
+	};
+	use remote_calls::{
+		kusama::{CollectivesCall, CollectivesOrigin, FellowshipOrigin},
+		BoundedCall, ReferendaCall, XcmCall,
+	};
+
+	sp_io::TestExternalities::default().execute_with(|| {
+		let who = AccountId::from([1; 32]);
+		assert_ok!(pallet_ranked_collective::Pallet::<Runtime, FellowshipCollectiveInstance>::do_add_member_to_rank(
+			who.clone(),
+			3,
+			false
+		));
+		assert_ok!(Balances::mint_into(&who, 1_000 * UNITS));
+
+		let message = VersionedXcm::from(Xcm::<()>(vec![ClearOrigin]));
+		let send = RuntimeCall::XcmPallet(pallet_xcm::Call::send {
+			dest: Box::new(Location::new(0, [Parachain(1_000)]).into()),
+			message: Box::new(message.clone()),
+		});
+		let referendum = |origin: Origin, call: RuntimeCall| {
+			assert_ok!(FellowshipReferenda::submit(
+				RuntimeOrigin::signed(who.clone()),
+				Box::new(OriginCaller::Origins(origin)),
+				Preimage::bound(call).unwrap(),
+				DispatchTime::After(10),
+			));
+			let index =
+				pallet_referenda::ReferendumCount::<Runtime, FellowshipReferendaInstance>::get() - 1;
+			match pallet_referenda::ReferendumInfoFor::<Runtime, FellowshipReferendaInstance>::get(
+				index,
+			) {
+				Some(pallet_referenda::ReferendumInfo::Ongoing(status)) => status,
+				_ => panic!("referendum {index} is ongoing"),
+			}
+		};
+
+		// The destination is seen from Collectives Kusama.
+		let proposal = CollectivesCall::PolkadotXcm(XcmCall::Send {
+			dest: Location::new(1, [Parachain(1_000)]).into(),
+			message,
+		})
+		.encode();
+		let calls = referendum_calls(&referendum(Origin::Fellows, send.clone())).unwrap();
+		assert_eq!(
+			calls.encode(),
+			vec![CollectivesCall::FellowshipReferenda(ReferendaCall::Submit {
+				proposal_origin: CollectivesOrigin::FellowshipOrigins(FellowshipOrigin::Fellows),
+				proposal: BoundedCall::Inline(proposal),
+				enactment_moment: remote_calls::DispatchTime::After(10),
+			})]
+			.encode()
+		);
+
+		// The voice of other ranks can not send messages on Collectives Kusama.
+		assert!(referendum_calls(&referendum(Origin::FellowshipMasters, send)).is_none());
+		// Other proposals stay on the Relay Chain.
+		let remark = RuntimeCall::System(frame_system::Call::remark { remark: vec![] });
+		assert!(referendum_calls(&referendum(Origin::Fellows, remark)).is_none());
+	});
+}
@@ -24,6 +24,7 @@ pub mod pallet_balances_native;
 pub mod pallet_beefy_mmr;
 pub mod pallet_bounties;
 pub mod pallet_child_bounties;
+pub mod pallet_chunked_core_assignment;
 pub mod pallet_conviction_voting;
 pub mod pallet_election_provider_multi_phase;
 pub mod pallet_fast_unstake;
@@ -41,6 +42,7 @@ pub mod pallet_scheduler;
 pub mod pallet_session;
 pub mod pallet_society;
 pub mod pallet_staking;
+pub mod pallet_staking_bridge_monitor;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
 pub mod pallet_treasury;
@@ -0,0 +1,77 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_chunked_core_assignment`
+//!
+//! These weights are NOT generated by the benchmark CLI, they are estimates to be replaced by the
+//! output of:
+//!
+//! frame-omni-bencher v1 benchmark pallet --extrinsic=* --pallet=pallet_chunked_core_assignment
+//!
+//! The storage accesses are those of the worst case, with full `Pending` and `Deadlines` storage.
+//! The reference time is at least twice that of the generated `runtime_parachains_coretime`
+//! weights of this runtime with as many storage accesses, and the proof size is the sum of the
+//! maximum encoded sizes of the storage items accessed. Applying the assignment at the end is
+//! weighed separately by `AssignCore`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_chunked_core_assignment`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_chunked_core_assignment::WeightInfo for WeightInfo<T> {
+	/// Storage: `ChunkedCoreAssignment::Pending` (r:1 w:1)
+	/// Proof: `ChunkedCoreAssignment::Pending` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `ChunkedCoreAssignment::Deadlines` (r:1 w:1)
+	/// Proof: `ChunkedCoreAssignment::Deadlines` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
+	fn begin_assign_core() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5610))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `ChunkedCoreAssignment::Pending` (r:1 w:1)
+	/// Proof: `ChunkedCoreAssignment::Pending` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	fn continue_assign_core() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3065))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ChunkedCoreAssignment::Pending` (r:1 w:1)
+	/// Proof: `ChunkedCoreAssignment::Pending` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	/// Storage: `ChunkedCoreAssignment::Deadlines` (r:1 w:1)
+	/// Proof: `ChunkedCoreAssignment::Deadlines` (`max_values`: Some(1), `max_size`: Some(2050), added: 2545, mode: `MaxEncodedLen`)
+	fn end_assign_core() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 5610))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(2))
+	}
+	/// Storage: `ChunkedCoreAssignment::Pending` (r:1 w:1)
+	/// Proof: `ChunkedCoreAssignment::Pending` (`max_values`: None, `max_size`: Some(590), added: 3065, mode: `MaxEncodedLen`)
+	fn drop_incomplete() -> Weight {
+		Weight::from_parts(18_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3065))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -240,6 +240,42 @@ pub mod proxy {
 		ParaRegistration = 9,
 	}
 
+	/// The proxy types of Asset Hub Polkadot, as stored in its `Proxy::Proxies`.
+	///
+	/// Used by the relay chain to decode the proxies of Asset Hub in remote proxy proofs.
+	#[derive(
+		Copy,
+		Clone,
+		Eq,
+		PartialEq,
+		Ord,
+		PartialOrd,
+		codec::Encode,
+		codec::Decode,
+		codec::DecodeWithMemTracking,
+		core::fmt::Debug,
+		codec::MaxEncodedLen,
+		scale_info::TypeInfo,
+		Default,
+	)]
+	pub enum AssetHubProxyType {
+		#[default]
+		Any = 0,
+		NonTransfer = 1,
+		CancelProxy = 2,
+		Assets = 3,
+		AssetOwner = 4,
+		AssetManager = 5,
+		Collator = 6,
+		Governance = 7,
+		Staking = 8,
+		NominationPools = 9,
+		Auction = 10,
+		ParaRegistration = 11,
+		StakingOperator = 12,
+		SpendingAllowance = 13,
+	}
+
 	/// Remote proxy interface that uses the relay chain as remote location.
 	pub struct RemoteProxyInterface<LocalProxyType, ProxyDefinitionConverter>(
 		core::marker::PhantomData<(LocalProxyType, ProxyDefinitionConverter)>,
@@ -285,9 +321,8 @@ pub mod proxy {
 		}
 
 		#[cfg(feature = "runtime-benchmarks")]
-		fn create_remote_proxy_proof(
-			caller: &AccountId,
-			proxy: &AccountId,
+		fn create_remote_proxies_proof(
+			proxies: &[(AccountId, AccountId)],
 		) -> (pallet_remote_proxy::RemoteProxyProof<Self::RemoteBlockNumber>, BlockNumber, Hash) {
 			use codec::Encode;
 			use sp_trie::TrieMut;
@@ -296,15 +331,17 @@ pub mod proxy {
 			let mut trie =
 				sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut db, &mut root).build();
 
-			let proxy_definition =
-				alloc::vec![ProxyDefinition::<AccountId, ProxyType, BlockNumber> {
-					delegate: caller.clone(),
-					proxy_type: ProxyType::default(),
-					delay: 0,
-				}];
+			for (caller, proxy) in proxies {
+				let proxy_definition =
+					alloc::vec![ProxyDefinition::<AccountId, ProxyType, BlockNumber> {
+						delegate: caller.clone(),
+						proxy_type: ProxyType::default(),
+						delay: 0,
+					}];
 
-			trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
-				.unwrap();
+				trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
+					.unwrap();
+			}
 			drop(trie);
 
 			(
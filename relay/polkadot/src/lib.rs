@@ -61,6 +61,7 @@ use frame_system::EnsureRoot;
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_election_provider_multi_phase::{Call as EPMCall, GeometricDepositBase};
 use pallet_grandpa::{fg_primitives, AuthorityId as GrandpaId};
+use pallet_remote_proxy::{ProxyDefinition, RemoteProxyInterface};
 use pallet_session::historical as session_historical;
 use pallet_staking::UseValidatorsMap;
 pub use pallet_timestamp::Call as TimestampCall;
@@ -132,7 +133,12 @@ use xcm_runtime_apis::{
 
 /// Constant values used within the runtime.
 use polkadot_runtime_constants::{
-	currency::*, fee::*, proxy::ProxyType, system_parachain, time::*, TREASURY_PALLET_ID,
+	currency::*,
+	fee::*,
+	proxy::{AssetHubProxyType, ProxyType},
+	system_parachain::{self, AssetHubParaId},
+	time::*,
+	TREASURY_PALLET_ID,
 };
 
 // Weights used in the runtime.
@@ -1279,6 +1285,120 @@ impl pallet_proxy::Config for Runtime {
 	type BlockNumberProvider = System;
 }
 
+/// Remote proxy interface that uses Asset Hub as remote location.
+///
+/// The proxies of Asset Hub are proven against the state root of its latest head in
+/// `Paras::Heads`. Only the proxy types with a meaning on the relay chain are converted, the Asset
+/// Hub specific ones are ignored.
+pub struct AssetHubRemoteProxy;
+
+impl RemoteProxyInterface<AccountId, TransparentProxyType<ProxyType>, BlockNumber>
+	for AssetHubRemoteProxy
+{
+	type RemoteAccountId = AccountId;
+
+	type RemoteProxyType = AssetHubProxyType;
+
+	type RemoteBlockNumber = BlockNumber;
+
+	type RemoteHash = Hash;
+
+	type RemoteHasher = BlakeTwo256;
+
+	fn block_to_storage_root(
+		_validation_data: &polkadot_primitives::PersistedValidationData,
+	) -> Option<(Self::RemoteBlockNumber, <Self::RemoteHasher as sp_core::Hasher>::Out)> {
+		// The relay chain does not receive any validation data.
+		None
+	}
+
+	fn latest_storage_root() -> Option<(Self::RemoteBlockNumber, Self::RemoteHash)> {
+		let head = parachains_paras::Heads::<Runtime>::get(AssetHubParaId::get())?;
+		let header = polkadot_primitives::Header::decode(&mut &head.0[..]).ok()?;
+		Some((header.number, header.state_root))
+	}
+
+	fn local_to_remote_account_id(local: &AccountId) -> Option<Self::RemoteAccountId> {
+		Some(local.clone())
+	}
+
+	fn remote_to_local_proxy_defintion(
+		remote: ProxyDefinition<
+			Self::RemoteAccountId,
+			Self::RemoteProxyType,
+			Self::RemoteBlockNumber,
+		>,
+	) -> Option<ProxyDefinition<AccountId, TransparentProxyType<ProxyType>, BlockNumber>> {
+		let proxy_type = match remote.proxy_type {
+			AssetHubProxyType::Any => ProxyType::Any,
+			AssetHubProxyType::NonTransfer => ProxyType::NonTransfer,
+			AssetHubProxyType::CancelProxy => ProxyType::CancelProxy,
+			AssetHubProxyType::Governance => ProxyType::Governance,
+			AssetHubProxyType::Staking => ProxyType::Staking,
+			AssetHubProxyType::NominationPools => ProxyType::NominationPools,
+			AssetHubProxyType::Auction => ProxyType::Auction,
+			AssetHubProxyType::ParaRegistration => ProxyType::ParaRegistration,
+			// Proxy types that only exist on Asset Hub.
+			AssetHubProxyType::Assets |
+			AssetHubProxyType::AssetOwner |
+			AssetHubProxyType::AssetManager |
+			AssetHubProxyType::Collator |
+			AssetHubProxyType::StakingOperator |
+			AssetHubProxyType::SpendingAllowance => return None,
+		};
+
+		Some(ProxyDefinition {
+			delegate: remote.delegate,
+			proxy_type: TransparentProxyType(proxy_type),
+			// Delays are currently not supported by the remote proxy pallet, but should be
+			// converted in the future to the block time used by the local proxy pallet.
+			delay: remote.delay,
+		})
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn create_remote_proxies_proof(
+		proxies: &[(AccountId, AccountId)],
+	) -> (pallet_remote_proxy::RemoteProxyProof<Self::RemoteBlockNumber>, BlockNumber, Hash) {
+		use sp_trie::TrieMut;
+
+		let (mut db, mut root) = sp_trie::MemoryDB::<BlakeTwo256>::default_with_root();
+		let mut trie =
+			sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut db, &mut root).build();
+
+		for (caller, proxy) in proxies {
+			let proxy_definition =
+				vec![ProxyDefinition::<AccountId, AssetHubProxyType, BlockNumber> {
+					delegate: caller.clone(),
+					proxy_type: AssetHubProxyType::default(),
+					delay: 0,
+				}];
+
+			trie.insert(&Self::proxy_definition_storage_key(proxy), &proxy_definition.encode())
+				.unwrap();
+		}
+		drop(trie);
+
+		(
+			pallet_remote_proxy::RemoteProxyProof::RelayChain {
+				proof: db.drain().into_values().map(|d| d.0).collect(),
+				block: 1,
+			},
+			1,
+			root,
+		)
+	}
+}
+
+impl pallet_remote_proxy::Config for Runtime {
+	// The time between creating a proof and using the proof in a transaction, in Asset Hub
+	// blocks.
+	type MaxStorageRootsToKeep = ConstU32<{ MINUTES }>;
+	type MaxRegisteredProxies = ConstU32<32>;
+	type RemoteProxy = AssetHubRemoteProxy;
+	type WeightInfo = weights::pallet_remote_proxy::WeightInfo<Runtime>;
+}
+
 impl parachains_origin::Config for Runtime {}
 
 impl parachains_configuration::Config for Runtime {
@@ -1434,6 +1554,63 @@ impl coretime::Config for Runtime {
 	>;
 }
 
+/// The Coretime Chain, dispatching calls with `OriginKind::Native`.
+pub struct EnsureBroker;
+impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureBroker {
+	type Success = ();
+	fn try_origin(o: RuntimeOrigin) -> Result<Self::Success, RuntimeOrigin> {
+		match <RuntimeOrigin as Into<Result<parachains_origin::Origin, RuntimeOrigin>>>::into(
+			o.clone(),
+		) {
+			Ok(parachains_origin::Origin::Parachain(id)) if id == BrokerId::get().into() => Ok(()),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin() -> Result<RuntimeOrigin, ()> {
+		Ok(parachains_origin::Origin::Parachain(BrokerId::get().into()).into())
+	}
+}
+
+/// Applies the core assignments received in chunks like `coretime::assign_core`.
+pub struct CoretimeAssignCore;
+impl pallet_chunked_core_assignment::AssignCore<BlockNumber> for CoretimeAssignCore {
+	fn assign_core(
+		core: pallet_chunked_core_assignment::CoreIndex,
+		begin: BlockNumber,
+		assignment: Vec<(
+			pallet_chunked_core_assignment::CoreAssignment,
+			pallet_chunked_core_assignment::PartsOf57600,
+		)>,
+		end_hint: Option<BlockNumber>,
+	) -> sp_runtime::DispatchResult {
+		coretime::Pallet::<Runtime>::assign_core(
+			RuntimeOrigin::root(),
+			core,
+			begin,
+			assignment,
+			end_hint,
+		)
+	}
+
+	fn assign_core_weight(assignments: u32) -> Weight {
+		<weights::runtime_parachains_coretime::WeightInfo<Runtime> as coretime::WeightInfo>::assign_core(
+			assignments,
+		)
+	}
+}
+
+impl pallet_chunked_core_assignment::Config for Runtime {
+	type BrokerOrigin = EitherOfDiverse<EnsureRoot<AccountId>, EnsureBroker>;
+	type AssignCore = CoretimeAssignCore;
+	type MaxChunkAssignments = ConstU32<{ remote_calls::MAX_ASSIGNMENTS_PER_CALL }>;
+	// One assignment for each bit of the core mask of the broker, and the idle rest.
+	type MaxAssignments = ConstU32<81>;
+	type MaxPendingCores = ConstU32<256>;
+	type WeightInfo = weights::pallet_chunked_core_assignment::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const OnDemandTrafficDefaultValue: FixedU128 = FixedU128::from_u32(1);
 	pub const MaxHistoricalRevenue: BlockNumber = 2 * system_parachain::coretime::TIMESLICE_PERIOD;
@@ -1703,58 +1880,27 @@ impl frame_support::traits::EnsureOrigin<RuntimeOrigin> for EnsureAssetHub {
 	}
 }
 
-#[derive(Encode, Decode)]
-enum AssetHubRuntimePallets<AccountId> {
-	// Audit: `StakingRcClient` in asset-hub-polkadot
-	#[codec(index = 84)]
-	RcClient(RcClientCalls<AccountId>),
-}
-
-#[derive(Encode, Decode)]
-enum RcClientCalls<AccountId> {
-	#[codec(index = 0)]
-	RelaySessionReport(rc_client::SessionReport<AccountId>),
-	#[codec(index = 1)]
-	RelayNewOffencePaged(Vec<(SessionIndex, rc_client::Offence<AccountId>)>),
-}
-
 pub struct SessionReportToXcm;
 impl Convert<rc_client::SessionReport<AccountId>, Xcm<()>> for SessionReportToXcm {
-	fn convert(a: rc_client::SessionReport<AccountId>) -> Xcm<()> {
-		Xcm(vec![
-			Instruction::UnpaidExecution {
-				weight_limit: WeightLimit::Unlimited,
-				check_origin: None,
-			},
-			Instruction::Transact {
-				origin_kind: OriginKind::Superuser,
-				fallback_max_weight: None,
-				call: AssetHubRuntimePallets::RcClient(RcClientCalls::RelaySessionReport(a))
-					.encode()
-					.into(),
-			},
-		])
+	fn convert(report: rc_client::SessionReport<AccountId>) -> Xcm<()> {
+		remote_calls::unpaid_transact(
+			OriginKind::Superuser,
+			&remote_calls::polkadot::AssetHubCall::StakingRcClient(
+				remote_calls::RcClientCall::RelaySessionReport { report },
+			),
+		)
 	}
 }
 
 pub struct QueuedOffenceToXcm;
 impl Convert<Vec<ah_client::QueuedOffenceOf<Runtime>>, Xcm<()>> for QueuedOffenceToXcm {
 	fn convert(offences: Vec<ah_client::QueuedOffenceOf<Runtime>>) -> Xcm<()> {
-		Xcm(vec![
-			Instruction::UnpaidExecution {
-				weight_limit: WeightLimit::Unlimited,
-				check_origin: None,
-			},
-			Instruction::Transact {
-				origin_kind: OriginKind::Superuser,
-				fallback_max_weight: None,
-				call: AssetHubRuntimePallets::RcClient(RcClientCalls::RelayNewOffencePaged(
-					offences,
-				))
-				.encode()
-				.into(),
-			},
-		])
+		remote_calls::unpaid_transact(
+			OriginKind::Superuser,
+			&remote_calls::polkadot::AssetHubCall::StakingRcClient(
+				remote_calls::RcClientCall::RelayNewOffencePaged { offences },
+			),
+		)
 	}
 }
 
@@ -1765,26 +1911,75 @@ impl ah_client::SendToAssetHub for StakingXcmToAssetHub {
 	fn relay_session_report(
 		session_report: rc_client::SessionReport<Self::AccountId>,
 	) -> Result<(), ()> {
-		rc_client::XCMSender::<
+		let activated = session_report.activation_timestamp.map(|(_, id)| id);
+		let result = rc_client::XCMSender::<
 			xcm_config::XcmRouter,
 			AssetHubLocation,
 			rc_client::SessionReport<AccountId>,
 			SessionReportToXcm,
-		>::send(session_report)
+		>::send(session_report);
+		StakingBridgeMonitor::note_session_report_sent(activated, result.is_ok());
+		result
 	}
 
 	fn relay_new_offence_paged(
 		offences: Vec<ah_client::QueuedOffenceOf<Runtime>>,
 	) -> Result<(), ()> {
-		rc_client::XCMSender::<
+		let result = rc_client::XCMSender::<
 			xcm_config::XcmRouter,
 			AssetHubLocation,
 			Vec<ah_client::QueuedOffenceOf<Runtime>>,
 			QueuedOffenceToXcm,
-		>::send(offences)
+		>::send(offences);
+		StakingBridgeMonitor::note_offences_sent(result.is_ok());
+		result
+	}
+}
+
+/// The view of [`StakingBridgeMonitor`] on the state of [`StakingAhClient`].
+pub struct AhClientState;
+impl pallet_staking_bridge_monitor::StakingBridgeClient for AhClientState {
+	fn mode() -> Option<pallet_staking_bridge_monitor::ClientMode> {
+		use pallet_staking_bridge_monitor::ClientMode;
+		Some(match ah_client::Mode::<Runtime>::get() {
+			ah_client::OperatingMode::Passive => ClientMode::Passive,
+			ah_client::OperatingMode::Buffered => ClientMode::Buffered,
+			ah_client::OperatingMode::Active => ClientMode::Active,
+		})
+	}
+
+	fn queued_offences() -> u32 {
+		ah_client::OffenceSendQueue::<Runtime>::count()
+	}
+
+	fn outgoing() -> Option<pallet_staking_bridge_monitor::Outgoing> {
+		ah_client::OutgoingSessionReport::<Runtime>::get().map(|(report, retries_left)| {
+			pallet_staking_bridge_monitor::Outgoing {
+				message: pallet_staking_bridge_monitor::Message::SessionReport,
+				id: report.end_index,
+				retries_left,
+			}
+		})
+	}
+
+	fn latest_received() -> Option<(pallet_staking_bridge_monitor::Message, u32)> {
+		ah_client::ValidatorSet::<Runtime>::get()
+			.map(|(id, _)| (pallet_staking_bridge_monitor::Message::ValidatorSet, id))
 	}
 }
 
+parameter_types! {
+	/// Two eras without a validator set from Asset Hub.
+	pub const StakingBridgeSilenceThreshold: BlockNumber = 2 * DAYS;
+}
+
+impl pallet_staking_bridge_monitor::Config for Runtime {
+	type Client = AhClientState;
+	type MaxSendFailures = ConstU32<3>;
+	type SilenceThreshold = StakingBridgeSilenceThreshold;
+	type WeightInfo = weights::pallet_staking_bridge_monitor::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	// The deposit configuration for the singed migration. Specially if you want to allow any signed account to do the migration (see `SignedFilter`, these deposits should be high)
 	pub const MigrationSignedDepositPerItem: Balance = CENTS;
@@ -1906,10 +2101,14 @@ construct_runtime! {
 		FastUnstake: pallet_fast_unstake = 40,
 		DelegatedStaking: pallet_delegated_staking = 41,
 		StakingAhClient: pallet_staking_async_ah_client = 42,
+		StakingBridgeMonitor: pallet_staking_bridge_monitor = 43,
 
 		// Dynamic, configurable parameters.
 		Parameters: pallet_parameters = 46,
 
+		// Proxies defined on Asset Hub, usable on the relay chain.
+		RemoteProxyAssetHub: pallet_remote_proxy = 47,
+
 		// Parachains pallets. Start indices at 50 to leave room.
 		ParachainsOrigin: parachains_origin = 50,
 		Configuration: parachains_configuration = 51,
@@ -1933,6 +2132,7 @@ construct_runtime! {
 		Auctions: auctions = 72,
 		Crowdloan: crowdloan = 73,
 		Coretime: coretime = 74,
+		ChunkedCoreAssignment: pallet_chunked_core_assignment = 75,
 
 		// State trie migration pallet, only temporary.
 		StateTrieMigration: pallet_state_trie_migration = 98,
@@ -2057,6 +2257,7 @@ mod benches {
 		[frame_benchmarking::baseline, Baseline::<Runtime>]
 		[pallet_bounties, Bounties]
 		[pallet_child_bounties, ChildBounties]
+		[pallet_chunked_core_assignment, ChunkedCoreAssignment]
 		[pallet_election_provider_multi_phase, ElectionProviderMultiPhase]
 		[frame_election_provider_support, ElectionProviderBench::<Runtime>]
 		[pallet_fast_unstake, FastUnstake]
@@ -2068,9 +2269,11 @@ mod benches {
 		[pallet_parameters, Parameters]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
+		[pallet_remote_proxy, RemoteProxyAssetHub]
 		[pallet_scheduler, Scheduler]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_staking, Staking]
+		[pallet_staking_bridge_monitor, StakingBridgeMonitor]
 		[frame_system, SystemBench::<Runtime>]
 		[frame_system_extensions, SystemExtensionsBench::<Runtime>]
 		[pallet_timestamp, Timestamp]
@@ -2407,6 +2610,12 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_staking_bridge_monitor::runtime_api::StakingBridgeHealthApi<Block, BlockNumber> for Runtime {
+		fn staking_bridge_health() -> pallet_staking_bridge_monitor::StakingBridgeHealth<BlockNumber> {
+			StakingBridgeMonitor::health()
+		}
+	}
+
 	impl sp_transaction_pool::runtime_api::TaggedTransactionQueue<Block> for Runtime {
 		fn validate_transaction(
 			source: TransactionSource,
@@ -3710,3 +3919,139 @@ mod post_ahm_filter_tests {
 		});
 	}
 }
+
+#[cfg(test)]
+mod remote_calls_tests {
+	use super::*;
+
+	#[test]
+	fn remote_calls_compatibility() {
+		// if this fails, make sure `remote_calls::polkadot::RelayCall` has valid encoding
+		use remote_calls::{
+			polkadot::RelayCall, AhClientCall, ChunkedCoreAssignmentCall, CoreAssignment,
+			CoretimeCall, HrmpCall,
+		};
+
+		let stash = AccountId::from([1; 32]);
+		let report = rc_client::ValidatorSetReport {
+			new_validator_set: vec![stash.clone()],
+			id: 42,
+			prune_up_to: Some(7),
+			leftover: false,
+		};
+		let assignment = vec![(CoreAssignment::Task(1_000), 57_600)];
+
+		for (remote, call) in [
+			(
+				RelayCall::StakingAhClient(AhClientCall::ValidatorSet { report: report.clone() }),
+				RuntimeCall::StakingAhClient(ah_client::Call::validator_set { report }),
+			),
+			(
+				RelayCall::StakingAhClient(AhClientCall::SetKeysFromAh {
+					stash: stash.clone(),
+					keys: vec![1, 2, 3],
+				}),
+				RuntimeCall::StakingAhClient(ah_client::Call::set_keys_from_ah {
+					stash: stash.clone(),
+					keys: vec![1, 2, 3],
+				}),
+			),
+			(
+				RelayCall::StakingAhClient(AhClientCall::PurgeKeysFromAh { stash: stash.clone() }),
+				RuntimeCall::StakingAhClient(ah_client::Call::purge_keys_from_ah {
+					stash: stash.clone(),
+				}),
+			),
+			(
+				RelayCall::Hrmp(HrmpCall::HrmpInitOpenChannel {
+					recipient: 2_000.into(),
+					proposed_max_capacity: 8,
+					proposed_max_message_size: 1_024,
+				}),
+				RuntimeCall::Hrmp(parachains_hrmp::Call::hrmp_init_open_channel {
+					recipient: 2_000.into(),
+					proposed_max_capacity: 8,
+					proposed_max_message_size: 1_024,
+				}),
+			),
+			(
+				RelayCall::Hrmp(HrmpCall::HrmpAcceptOpenChannel { sender: 2_000.into() }),
+				RuntimeCall::Hrmp(parachains_hrmp::Call::hrmp_accept_open_channel {
+					sender: 2_000.into(),
+				}),
+			),
+			(
+				RelayCall::Coretime(CoretimeCall::RequestCoreCount { count: 60 }),
+				RuntimeCall::Coretime(coretime::Call::request_core_count { count: 60 }),
+			),
+			(
+				RelayCall::Coretime(CoretimeCall::RequestRevenueAt { when: 1_000 }),
+				RuntimeCall::Coretime(coretime::Call::request_revenue_at { when: 1_000 }),
+			),
+			(
+				RelayCall::Coretime(CoretimeCall::CreditAccount { who: stash.clone(), amount: 10 }),
+				RuntimeCall::Coretime(coretime::Call::credit_account { who: stash, amount: 10 }),
+			),
+			(
+				RelayCall::Coretime(CoretimeCall::AssignCore {
+					core: 3,
+					begin: 1_000,
+					assignment: assignment.clone(),
+					end_hint: Some(2_000),
+				}),
+				RuntimeCall::Coretime(coretime::Call::assign_core {
+					core: 3,
+					begin: 1_000,
+					assignment: assignment.clone(),
+					end_hint: Some(2_000),
+				}),
+			),
+			(
+				RelayCall::ChunkedCoreAssignment(ChunkedCoreAssignmentCall::BeginAssignCore {
+					core: 3,
+					begin: 1_000,
+					assignment: assignment.clone(),
+					end_hint: Some(2_000),
+				}),
+				RuntimeCall::ChunkedCoreAssignment(
+					pallet_chunked_core_assignment::Call::begin_assign_core {
+						core: 3,
+						begin: 1_000,
+						assignment: assignment.clone().try_into().unwrap(),
+						end_hint: Some(2_000),
+					},
+				),
+			),
+			(
+				RelayCall::ChunkedCoreAssignment(ChunkedCoreAssignmentCall::ContinueAssignCore {
+					core: 3,
+					begin: 1_000,
+					assignment: assignment.clone(),
+				}),
+				RuntimeCall::ChunkedCoreAssignment(
+					pallet_chunked_core_assignment::Call::continue_assign_core {
+						core: 3,
+						begin: 1_000,
+						assignment: assignment.clone().try_into().unwrap(),
+					},
+				),
+			),
+			(
+				RelayCall::ChunkedCoreAssignment(ChunkedCoreAssignmentCall::EndAssignCore {
+					core: 3,
+					begin: 1_000,
+					assignment: assignment.clone(),
+				}),
+				RuntimeCall::ChunkedCoreAssignment(
+					pallet_chunked_core_assignment::Call::end_assign_core {
+						core: 3,
+						begin: 1_000,
+						assignment: assignment.try_into().unwrap(),
+					},
+				),
+			),
+		] {
+			assert_eq!(remote.encode(), call.encode(), "{call:?}");
+		}
+	}
+}
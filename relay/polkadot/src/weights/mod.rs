@@ -24,6 +24,7 @@ pub mod pallet_balances;
 pub mod pallet_beefy_mmr;
 pub mod pallet_bounties;
 pub mod pallet_child_bounties;
+pub mod pallet_chunked_core_assignment;
 pub mod pallet_conviction_voting;
 pub mod pallet_election_provider_multi_phase;
 pub mod pallet_fast_unstake;
@@ -35,9 +36,11 @@ pub mod pallet_parameters;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
 pub mod pallet_referenda;
+pub mod pallet_remote_proxy;
 pub mod pallet_scheduler;
 pub mod pallet_session;
 pub mod pallet_staking;
+pub mod pallet_staking_bridge_monitor;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
 pub mod pallet_treasury;
@@ -0,0 +1,73 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_remote_proxy`
+//!
+//! These weights are NOT generated by the benchmark CLI, they are estimates to be replaced by the
+//! output of:
+//!
+//! frame-omni-bencher v1 benchmark pallet --extrinsic=* --pallet=pallet_remote_proxy
+//!
+//! `remote_proxy`, `register_remote_proxy_proof` and `remote_proxy_with_registered_proof` take
+//! the generated weights of the `RemoteProxyRelayChain` instance of Kusama Asset Hub, which keeps
+//! the same number of storage roots. `register_remote_proxies_proof` is about twice
+//! `remote_proxy` for building the proof database, twice the cost of a proof lookup per account
+//! and about twice the cost of hashing per byte of proof, with the proof size of `BlockToRoot`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_remote_proxy`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_remote_proxy::WeightInfo for WeightInfo<T> {
+	/// Storage: `RemoteProxyAssetHub::BlockToRoot` (r:1 w:0)
+	/// Proof: `RemoteProxyAssetHub::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn remote_proxy() -> Weight {
+		Weight::from_parts(17_310_000, 0)
+			.saturating_add(Weight::from_parts(0, 1846))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	fn register_remote_proxy_proof() -> Weight {
+		Weight::from_parts(2_980_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	/// Storage: `RemoteProxyAssetHub::BlockToRoot` (r:1 w:0)
+	/// Proof: `RemoteProxyAssetHub::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	fn remote_proxy_with_registered_proof() -> Weight {
+		Weight::from_parts(17_469_000, 0)
+			.saturating_add(Weight::from_parts(0, 1846))
+			.saturating_add(T::DbWeight::get().reads(1))
+	}
+	/// Storage: `RemoteProxyAssetHub::BlockToRoot` (r:1 w:0)
+	/// Proof: `RemoteProxyAssetHub::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `RemoteProxyAssetHub::RegisteredProxies` (r:0 w:32)
+	/// Proof: `RemoteProxyAssetHub::RegisteredProxies` (`max_values`: None, `max_size`: Some(1257), added: 3732, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[1, 32]`.
+	/// The range of component `p` is `[0, 1048576]`.
+	fn register_remote_proxies_proof(a: u32, p: u32, ) -> Weight {
+		Weight::from_parts(35_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 856))
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(4_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+	}
+}
@@ -0,0 +1,190 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Tests for the remote proxies of Asset Hub on the relay chain.
+
+use codec::Encode;
+use frame_support::{
+	assert_err, assert_ok,
+	traits::{fungible::Mutate, Hooks},
+};
+use pallet_remote_proxy::{Error, ProxyDefinition, RemoteProxyInterface, RemoteProxyProof};
+use polkadot_primitives::{AccountId, BlockNumber, HeadData, Header, Id as ParaId};
+use polkadot_runtime::{
+	AssetHubRemoteProxy, Balances, RemoteProxyAssetHub, Runtime, RuntimeCall, RuntimeEvent,
+	RuntimeOrigin, System,
+};
+use polkadot_runtime_common::paras_registrar;
+use polkadot_runtime_constants::{
+	currency::UNITS, proxy::AssetHubProxyType, system_parachain::ASSET_HUB_ID,
+};
+use sp_runtime::{BuildStorage, DispatchError};
+
+const REAL: [u8; 32] = [1u8; 32];
+const REGISTRAR: [u8; 32] = [2u8; 32];
+const ASSET_MANAGER: [u8; 32] = [3u8; 32];
+const ASSET_HUB_BLOCK: BlockNumber = 42;
+
+/// Builds the state of Asset Hub with the proxies of `REAL` and proves them.
+///
+/// Returns the proof and the head of Asset Hub committing to the state.
+fn asset_hub_proxies_proof() -> (Vec<Vec<u8>>, HeadData) {
+	let proxies = vec![
+		ProxyDefinition::<AccountId, AssetHubProxyType, BlockNumber> {
+			delegate: REGISTRAR.into(),
+			proxy_type: AssetHubProxyType::ParaRegistration,
+			delay: 0,
+		},
+		ProxyDefinition::<AccountId, AssetHubProxyType, BlockNumber> {
+			delegate: ASSET_MANAGER.into(),
+			proxy_type: AssetHubProxyType::AssetManager,
+			delay: 0,
+		},
+	];
+	// `Proxy::Proxies` stores the definitions with the deposit.
+	let key = AssetHubRemoteProxy::proxy_definition_storage_key(&REAL.into());
+
+	let mut asset_hub = sp_io::TestExternalities::default();
+	asset_hub.execute_with(|| {
+		sp_io::storage::set(&key, &(proxies, 2 * UNITS).encode());
+		// Some unrelated state, so that the proof is not the whole trie.
+		for i in 0u32..16 {
+			sp_io::storage::set(&i.encode(), &[i as u8; 64]);
+		}
+	});
+
+	let proof =
+		sp_state_machine::prove_read(asset_hub.as_backend(), [key]).expect("key was set; qed");
+	let header = Header::new(
+		ASSET_HUB_BLOCK,
+		Default::default(),
+		*asset_hub.as_backend().root(),
+		Default::default(),
+		Default::default(),
+	);
+
+	(proof.into_iter_nodes().collect(), header.encode().into())
+}
+
+fn new_test_ext(asset_hub_head: HeadData) -> sp_io::TestExternalities {
+	let storage = frame_system::GenesisConfig::<Runtime>::default().build_storage().unwrap();
+	let mut ext = sp_io::TestExternalities::new(storage);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		runtime_parachains::paras::Heads::<Runtime>::insert(
+			ParaId::from(ASSET_HUB_ID),
+			asset_hub_head,
+		);
+		RemoteProxyAssetHub::on_initialize(1);
+		assert_ok!(Balances::mint_into(&REAL.into(), 10_000 * UNITS));
+	});
+	ext
+}
+
+fn proxy_executed_result() -> Option<Result<(), DispatchError>> {
+	System::events().into_iter().rev().find_map(|record| match record.event {
+		RuntimeEvent::Proxy(pallet_proxy::Event::ProxyExecuted { result }) => Some(result),
+		_ => None,
+	})
+}
+
+#[test]
+fn asset_hub_head_is_noted() {
+	let (_, head) = asset_hub_proxies_proof();
+
+	new_test_ext(head).execute_with(|| {
+		let roots = pallet_remote_proxy::BlockToRoot::<Runtime>::get();
+		assert_eq!(roots.len(), 1);
+		assert_eq!(roots[0].0, ASSET_HUB_BLOCK);
+
+		// The same head is not noted twice.
+		RemoteProxyAssetHub::on_initialize(2);
+		assert_eq!(pallet_remote_proxy::BlockToRoot::<Runtime>::get().len(), 1);
+	});
+}
+
+#[test]
+fn para_registration_proxy_of_asset_hub_works_on_the_relay() {
+	let (proof, head) = asset_hub_proxies_proof();
+
+	new_test_ext(head).execute_with(|| {
+		assert_ok!(RemoteProxyAssetHub::remote_proxy(
+			RuntimeOrigin::signed(REGISTRAR.into()),
+			REAL.into(),
+			None,
+			Box::new(RuntimeCall::Registrar(paras_registrar::Call::reserve {})),
+			RemoteProxyProof::RelayChain { proof: proof.clone(), block: ASSET_HUB_BLOCK },
+		));
+		assert_eq!(proxy_executed_result(), Some(Ok(())));
+		assert!(System::events().iter().any(|record| matches!(
+			&record.event,
+			RuntimeEvent::Registrar(paras_registrar::Event::Reserved { who, .. })
+				if *who == AccountId::from(REAL)
+		)));
+
+		// Calls outside of the relay filter of the proxy type are rejected.
+		assert_ok!(RemoteProxyAssetHub::remote_proxy(
+			RuntimeOrigin::signed(REGISTRAR.into()),
+			REAL.into(),
+			None,
+			Box::new(RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+				dest: REGISTRAR.into(),
+				value: UNITS,
+			})),
+			RemoteProxyProof::RelayChain { proof, block: ASSET_HUB_BLOCK },
+		));
+		assert_eq!(
+			proxy_executed_result(),
+			Some(Err(frame_system::Error::<Runtime>::CallFiltered.into()))
+		);
+	});
+}
+
+#[test]
+fn asset_hub_only_proxy_types_are_ignored() {
+	let (proof, head) = asset_hub_proxies_proof();
+
+	new_test_ext(head).execute_with(|| {
+		assert_err!(
+			RemoteProxyAssetHub::remote_proxy(
+				RuntimeOrigin::signed(ASSET_MANAGER.into()),
+				REAL.into(),
+				None,
+				Box::new(RuntimeCall::System(frame_system::Call::remark { remark: vec![] })),
+				RemoteProxyProof::RelayChain { proof, block: ASSET_HUB_BLOCK },
+			),
+			Error::<Runtime>::DidNotFindMatchingProxyDefinition
+		);
+	});
+}
+
+#[test]
+fn proofs_against_unknown_heads_are_rejected() {
+	let (proof, head) = asset_hub_proxies_proof();
+
+	new_test_ext(head).execute_with(|| {
+		assert_err!(
+			RemoteProxyAssetHub::remote_proxy(
+				RuntimeOrigin::signed(REGISTRAR.into()),
+				REAL.into(),
+				None,
+				Box::new(RuntimeCall::Registrar(paras_registrar::Call::reserve {})),
+				RemoteProxyProof::RelayChain { proof, block: ASSET_HUB_BLOCK + 1 },
+			),
+			Error::<Runtime>::UnknownProofAnchorBlock
+		);
+	});
+}
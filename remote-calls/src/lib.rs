@@ -0,0 +1,338 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Typed encoding of the calls that the system runtimes dispatch on each other with XCM
+//! `Transact`.
+//!
+//! The sending runtime can not depend on the receiving runtime, so the `RuntimeCall` of the
+//! receiver is mirrored here for the calls that are actually sent. The mirror only has to encode
+//! the same as the real call. This is checked by the tests of every receiving runtime, which
+//! compare the encoding against their own `RuntimeCall`. Reordering a pallet or changing a call
+//! signature on the receiving side therefore fails CI instead of silently breaking the messages.
+//!
+//! The pallet indices differ per network and live in [`polkadot`] and [`kusama`]. The call enums
+//! of the pallets are shared.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::{vec, vec::Vec};
+pub use bp_xcm_bridge_hub_router::XcmBridgeHubRouterCall;
+use codec::{Decode, Encode};
+pub use pallet_broker::{CoreAssignment, CoreIndex, PartsOf57600};
+use pallet_staking_async_rc_client::{Offence, SessionReport, ValidatorSetReport};
+use polkadot_primitives::{AccountId, Balance, BlockNumber, Hash, Id as ParaId, SessionIndex};
+use xcm::{latest::prelude::*, VersionedLocation, VersionedXcm};
+
+/// Calls of `pallet_staking_async_rc_client` on Asset Hub, sent by the Relay Chain.
+#[derive(Encode, Decode, Clone)]
+pub enum RcClientCall {
+	#[codec(index = 0)]
+	RelaySessionReport { report: SessionReport<AccountId> },
+	#[codec(index = 1)]
+	RelayNewOffencePaged { offences: Vec<(SessionIndex, Offence<AccountId>)> },
+}
+
+/// Calls of `pallet_staking_async_ah_client` on the Relay Chain, sent by Asset Hub.
+#[derive(Encode, Decode, Clone)]
+pub enum AhClientCall {
+	#[codec(index = 0)]
+	ValidatorSet { report: ValidatorSetReport<AccountId> },
+	#[codec(index = 3)]
+	SetKeysFromAh { stash: AccountId, keys: Vec<u8> },
+	#[codec(index = 4)]
+	PurgeKeysFromAh { stash: AccountId },
+}
+
+/// Calls of the `coretime` pallet on the Relay Chain, sent by the Coretime Chain.
+#[derive(Encode, Decode, Clone)]
+pub enum CoretimeCall {
+	#[codec(index = 1)]
+	RequestCoreCount { count: CoreIndex },
+	#[codec(index = 2)]
+	RequestRevenueAt { when: BlockNumber },
+	#[codec(index = 3)]
+	CreditAccount { who: AccountId, amount: Balance },
+	#[codec(index = 4)]
+	AssignCore {
+		core: CoreIndex,
+		begin: BlockNumber,
+		assignment: Vec<(CoreAssignment, PartsOf57600)>,
+		end_hint: Option<BlockNumber>,
+	},
+}
+
+/// The maximum number of assignments of a core sent in one call to the Relay Chain.
+pub const MAX_ASSIGNMENTS_PER_CALL: u32 = 28;
+
+/// Calls of `pallet_chunked_core_assignment` on the Relay Chain, sent by the Coretime Chain.
+#[derive(Encode, Decode, Clone)]
+pub enum ChunkedCoreAssignmentCall {
+	#[codec(index = 0)]
+	BeginAssignCore {
+		core: CoreIndex,
+		begin: BlockNumber,
+		assignment: Vec<(CoreAssignment, PartsOf57600)>,
+		end_hint: Option<BlockNumber>,
+	},
+	#[codec(index = 1)]
+	ContinueAssignCore {
+		core: CoreIndex,
+		begin: BlockNumber,
+		assignment: Vec<(CoreAssignment, PartsOf57600)>,
+	},
+	#[codec(index = 2)]
+	EndAssignCore {
+		core: CoreIndex,
+		begin: BlockNumber,
+		assignment: Vec<(CoreAssignment, PartsOf57600)>,
+	},
+}
+
+impl ChunkedCoreAssignmentCall {
+	/// The calls sending `assignment` in chunks of at most [`MAX_ASSIGNMENTS_PER_CALL`], in order.
+	///
+	/// `assignment` must not fit a single chunk.
+	pub fn chunks(
+		core: CoreIndex,
+		begin: BlockNumber,
+		assignment: Vec<(CoreAssignment, PartsOf57600)>,
+		end_hint: Option<BlockNumber>,
+	) -> Vec<Self> {
+		let mut chunks = assignment.chunks(MAX_ASSIGNMENTS_PER_CALL as usize).map(|c| c.to_vec());
+		let first = chunks.next().unwrap_or_default();
+		let last = chunks.next_back().unwrap_or_default();
+		let mut calls = vec![Self::BeginAssignCore { core, begin, assignment: first, end_hint }];
+		calls.extend(chunks.map(|assignment| Self::ContinueAssignCore { core, begin, assignment }));
+		calls.push(Self::EndAssignCore { core, begin, assignment: last });
+		calls
+	}
+}
+
+/// Calls of the `hrmp` pallet on the Relay Chain, sent by a parachain for itself.
+#[derive(Encode, Decode, Clone)]
+pub enum HrmpCall {
+	#[codec(index = 0)]
+	HrmpInitOpenChannel {
+		recipient: ParaId,
+		proposed_max_capacity: u32,
+		proposed_max_message_size: u32,
+	},
+	#[codec(index = 1)]
+	HrmpAcceptOpenChannel { sender: ParaId },
+}
+
+/// Calls of `pallet_broker` on the Coretime Chain, sent by Asset Hub on behalf of a buyer.
+#[derive(Encode, Decode, Clone)]
+pub enum BrokerCall {
+	#[codec(index = 5)]
+	Purchase { price_limit: Balance },
+	#[codec(index = 6)]
+	Renew { core: CoreIndex },
+}
+
+/// Mirror of `sp_runtime::MultiAddress`, for the calls looking up an account.
+#[derive(Encode, Decode, Clone)]
+pub enum AccountLookup {
+	#[codec(index = 0)]
+	Id(AccountId),
+}
+
+/// Mirror of `assets_common::local_and_foreign_assets::ForeignAssetReserveData`.
+#[derive(Encode, Decode, Clone)]
+pub struct ForeignAssetReserve {
+	pub reserve: Location,
+	pub teleportable: bool,
+}
+
+/// Calls of the foreign assets instance of `pallet_assets` on Asset Hub, sent by the chain an
+/// asset originates from.
+#[derive(Encode, Decode, Clone)]
+pub enum ForeignAssetsCall {
+	#[codec(index = 0)]
+	Create { id: Location, admin: AccountLookup, min_balance: Balance },
+	#[codec(index = 33)]
+	SetReserves { id: Location, reserves: Vec<ForeignAssetReserve> },
+}
+
+/// Calls of `pallet_utility`.
+#[derive(Encode, Decode, Clone)]
+pub enum UtilityCall<Call> {
+	#[codec(index = 2)]
+	BatchAll { calls: Vec<Call> },
+}
+
+/// Calls of `pallet_preimage`.
+#[derive(Encode, Decode, Clone)]
+pub enum PreimageCall {
+	#[codec(index = 0)]
+	NotePreimage { bytes: Vec<u8> },
+}
+
+/// Calls of `pallet_xcm`.
+#[derive(Encode, Decode, Clone)]
+pub enum XcmCall {
+	#[codec(index = 0)]
+	Send { dest: VersionedLocation, message: VersionedXcm<()> },
+}
+
+/// Calls of `pallet_ranked_collective`, sent by the Relay Chain to move the Kusama Fellowship.
+#[derive(Encode, Decode, Clone)]
+pub enum RankedCollectiveCall {
+	#[codec(index = 0)]
+	AddMember { who: AccountLookup },
+	#[codec(index = 1)]
+	PromoteMember { who: AccountLookup },
+}
+
+/// Mirror of `frame_support::traits::Bounded`, for the proposal of a referendum.
+#[derive(Encode, Decode, Clone)]
+pub enum BoundedCall {
+	#[codec(index = 1)]
+	Inline(Vec<u8>),
+	#[codec(index = 2)]
+	Lookup { hash: Hash, len: u32 },
+}
+
+/// Mirror of `frame_support::traits::schedule::DispatchTime`.
+#[derive(Encode, Decode, Clone)]
+pub enum DispatchTime {
+	#[codec(index = 0)]
+	At(BlockNumber),
+	#[codec(index = 1)]
+	After(BlockNumber),
+}
+
+/// Calls of `pallet_referenda`, sent by the Relay Chain to move the Kusama Fellowship.
+#[derive(Encode, Decode, Clone)]
+pub enum ReferendaCall<Origin> {
+	#[codec(index = 0)]
+	Submit { proposal_origin: Origin, proposal: BoundedCall, enactment_moment: DispatchTime },
+}
+
+/// Build the message that dispatches `call` on a trusted destination without paying for it.
+pub fn unpaid_transact<Call: Encode, T>(origin_kind: OriginKind, call: &Call) -> Xcm<T> {
+	Xcm(vec![
+		UnpaidExecution { weight_limit: WeightLimit::Unlimited, check_origin: None },
+		Transact { origin_kind, fallback_max_weight: None, call: call.encode().into() },
+	])
+}
+
+/// Calls of the Polkadot system runtimes.
+pub mod polkadot {
+	use super::*;
+
+	/// Mirror of the Polkadot Relay Chain `RuntimeCall`.
+	#[derive(Encode, Decode, Clone)]
+	pub enum RelayCall {
+		#[codec(index = 42)]
+		StakingAhClient(AhClientCall),
+		#[codec(index = 60)]
+		Hrmp(HrmpCall),
+		#[codec(index = 74)]
+		Coretime(CoretimeCall),
+		#[codec(index = 75)]
+		ChunkedCoreAssignment(ChunkedCoreAssignmentCall),
+	}
+
+	/// Mirror of the Polkadot Asset Hub `RuntimeCall`.
+	#[allow(clippy::large_enum_variant)]
+	#[derive(Encode, Decode, Clone)]
+	pub enum AssetHubCall {
+		/// Sent by the Polkadot Bridge Hub to report the status of the bridge to Kusama.
+		#[codec(index = 34)]
+		ToKusamaXcmRouter(XcmBridgeHubRouterCall),
+		#[codec(index = 84)]
+		StakingRcClient(RcClientCall),
+	}
+
+	/// Mirror of the Polkadot Coretime Chain `RuntimeCall`.
+	#[derive(Encode, Decode, Clone)]
+	pub enum CoretimeChainCall {
+		#[codec(index = 50)]
+		Broker(BrokerCall),
+	}
+}
+
+/// Calls of the Kusama system runtimes.
+pub mod kusama {
+	use super::*;
+
+	/// Mirror of the Kusama Relay Chain `RuntimeCall`.
+	#[derive(Encode, Decode, Clone)]
+	pub enum RelayCall {
+		#[codec(index = 48)]
+		StakingAhClient(AhClientCall),
+		#[codec(index = 60)]
+		Hrmp(HrmpCall),
+		#[codec(index = 74)]
+		Coretime(CoretimeCall),
+		#[codec(index = 75)]
+		ChunkedCoreAssignment(ChunkedCoreAssignmentCall),
+	}
+
+	/// Mirror of the Kusama Asset Hub `RuntimeCall`.
+	#[allow(clippy::large_enum_variant)]
+	#[derive(Encode, Decode, Clone)]
+	pub enum AssetHubCall {
+		/// Sent by the Kusama Bridge Hub to report the status of the bridge to Polkadot.
+		#[codec(index = 34)]
+		ToPolkadotXcmRouter(XcmBridgeHubRouterCall),
+		#[codec(index = 53)]
+		ForeignAssets(ForeignAssetsCall),
+		#[codec(index = 84)]
+		StakingRcClient(RcClientCall),
+	}
+
+	/// Mirror of the Kusama Coretime Chain `RuntimeCall`.
+	#[derive(Encode, Decode, Clone)]
+	pub enum CoretimeChainCall {
+		#[codec(index = 50)]
+		Broker(BrokerCall),
+	}
+
+	/// Mirror of the Kusama Collectives `RuntimeCall`.
+	#[derive(Encode, Decode, Clone)]
+	pub enum CollectivesCall {
+		#[codec(index = 31)]
+		PolkadotXcm(XcmCall),
+		#[codec(index = 40)]
+		Utility(UtilityCall<CollectivesCall>),
+		#[codec(index = 43)]
+		Preimage(PreimageCall),
+		#[codec(index = 60)]
+		FellowshipCollective(RankedCollectiveCall),
+		#[codec(index = 61)]
+		FellowshipReferenda(ReferendaCall<CollectivesOrigin>),
+	}
+
+	/// Mirror of the Kusama Collectives `OriginCaller`.
+	#[derive(Encode, Decode, Clone)]
+	pub enum CollectivesOrigin {
+		#[codec(index = 62)]
+		FellowshipOrigins(FellowshipOrigin),
+	}
+
+	/// Mirror of the Fellowship origins of Kusama Collectives that send XCM.
+	#[derive(Encode, Decode, Clone)]
+	pub enum FellowshipOrigin {
+		#[codec(index = 2)]
+		Fellows,
+		#[codec(index = 3)]
+		Architects,
+	}
+}
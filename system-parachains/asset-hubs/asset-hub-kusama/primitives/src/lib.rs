@@ -20,30 +20,14 @@
 
 extern crate alloc;
 
-use codec::{Decode, Encode};
-use scale_info::TypeInfo;
+use codec::Encode;
 
-pub use bp_xcm_bridge_hub_router::XcmBridgeHubRouterCall;
+use remote_calls::kusama::AssetHubCall;
+pub use remote_calls::XcmBridgeHubRouterCall;
 use xcm::latest::prelude::*;
 
 use system_parachains_constants::kusama::currency::*;
 
-/// `AssetHubKusama` Runtime `Call` enum.
-///
-/// The enum represents a subset of possible `Call`s we can send to `AssetHubKusama` chain.
-/// Ideally this code would be auto-generated from metadata, because we want to
-/// avoid depending directly on the ENTIRE runtime just to get the encoding of `Dispatchable`s.
-///
-/// All entries here (like pretty much in the entire file) must be kept in sync with
-/// `AssetHubKusama` `construct_runtime`, so that we maintain SCALE-compatibility.
-#[allow(clippy::large_enum_variant)]
-#[derive(Encode, Decode, Debug, PartialEq, Eq, Clone, TypeInfo)]
-pub enum Call {
-	/// `ToPolkadotXcmRouter` bridge pallet.
-	#[codec(index = 34)]
-	ToPolkadotXcmRouter(XcmBridgeHubRouterCall),
-}
-
 frame_support::parameter_types! {
 	/// Some sane weight to execute `xcm::Transact(pallet-xcm-bridge-hub-router::Call::report_bridge_status)`.
 	pub const XcmBridgeHubRouterTransactCallMaxWeight: Weight = Weight::from_parts(200_000_000, 6144);
@@ -63,7 +47,7 @@ pub fn build_congestion_message<RuntimeCall>(
 		Transact {
 			origin_kind: OriginKind::Xcm,
 			fallback_max_weight: Some(XcmBridgeHubRouterTransactCallMaxWeight::get()),
-			call: Call::ToPolkadotXcmRouter(XcmBridgeHubRouterCall::report_bridge_status {
+			call: AssetHubCall::ToPolkadotXcmRouter(XcmBridgeHubRouterCall::report_bridge_status {
 				bridge_id,
 				is_congested,
 			})
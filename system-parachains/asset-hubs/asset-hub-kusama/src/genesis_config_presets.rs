@@ -34,13 +34,17 @@ fn asset_hub_kusama_genesis(
 	foreign_assets: Vec<(Location, AccountId, Balance)>,
 	foreign_assets_endowed_accounts: Vec<(Location, AccountId, Balance)>,
 ) -> serde_json::Value {
+	let mut balances: Vec<(AccountId, Balance)> = endowed_accounts
+		.iter()
+		.cloned()
+		.map(|k| (k, ASSET_HUB_KUSAMA_ED * 4096 * 4096))
+		.collect();
+	balances.push((Dap::buffer_account(), ASSET_HUB_KUSAMA_ED));
+	balances.push((Dap::staging_account(), ASSET_HUB_KUSAMA_ED));
+
 	serde_json::json!({
 		"balances": BalancesConfig {
-			balances: endowed_accounts
-				.iter()
-				.cloned()
-				.map(|k| (k, ASSET_HUB_KUSAMA_ED * 4096 * 4096))
-				.collect(),
+			balances,
 			dev_accounts: None,
 		},
 		"parachainInfo": ParachainInfoConfig {
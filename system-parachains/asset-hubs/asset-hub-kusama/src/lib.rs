@@ -32,6 +32,8 @@ extern crate alloc;
 pub mod genesis_config_presets;
 pub mod governance;
 pub mod migrations;
+pub mod nis;
+pub mod proxy_allowances;
 pub mod staking;
 pub mod treasury;
 mod weights;
@@ -60,8 +62,8 @@ use frame_support::{
 		fungible::{self, HoldConsideration},
 		fungibles,
 		tokens::imbalance::{ResolveAssetTo, ResolveTo},
-		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, EitherOf,
-		EitherOfDiverse, EnsureOrigin, EnsureOriginWithArg, Equals, Everything, InstanceFilter,
+		AsEnsureOriginWithArg, ConstBool, ConstU128, ConstU32, ConstU64, ConstU8, Contains,
+		EitherOf, EitherOfDiverse, EnsureOrigin, EnsureOriginWithArg, Equals, InstanceFilter,
 		LinearStoragePrice, PrivilegeCmp, TransformOrigin, WithdrawReasons,
 	},
 	weights::{ConstantMultiplier, Weight},
@@ -198,7 +200,7 @@ parameter_types! {
 
 // Configure FRAME pallets to include in runtime.
 impl frame_system::Config for Runtime {
-	type BaseCallFilter = Everything;
+	type BaseCallFilter = pallet_proxy_allowances::ExcludeAllowanceProxies<Runtime>;
 	type BlockWeights = RuntimeBlockWeights;
 	type BlockLength = RuntimeBlockLength;
 	type AccountId = AccountId;
@@ -363,7 +365,9 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ExistentialDeposit;
 	type StringLimit = AssetsStringLimit;
-	type Freezer = ();
+	// Assets locked for other chains through XCM. No migration is needed, nothing is frozen
+	// until the first `LockAsset`.
+	type Freezer = XcmAssetLocker;
 	type Holder = ();
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_local::WeightInfo<Runtime>;
@@ -524,6 +528,18 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type BenchmarkHelper = assets_common::benchmarks::LocationAssetsBenchmarkHelper;
 }
 
+/// Governance can manage the reserves of foreign assets besides their owners.
+pub type ForeignAssetReservesAdminOrigin =
+	EitherOf<EnsureRoot<AccountId>, EitherOf<FellowshipAdmin, GeneralAdmin>>;
+
+impl pallet_foreign_asset_reserves::Config<ForeignAssetsInstance> for Runtime {
+	type AdminOrigin = ForeignAssetReservesAdminOrigin;
+	type MaxReserves = ConstU32<5>;
+	type WeightInfo = weights::pallet_foreign_asset_reserves::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ForeignAssetReservesBenchmarkHelper;
+}
+
 parameter_types! {
 	// One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
 	pub const DepositBase: Balance = system_para_deposit(1, 88);
@@ -645,6 +661,11 @@ pub enum ProxyType {
 	/// Contains `Staking` (validate, chill, kick), `StakingRcClient` (set_keys, purge_keys),
 	/// and `Utility` batching calls (batch, batch_all, force_batch).
 	StakingOperator,
+	/// Can spend an allowance of assets per period, set by the proxied account.
+	///
+	/// This variant cannot do anything through `Proxy`. Its calls are dispatched and metered by
+	/// `ProxyAllowances`.
+	SpendingAllowance,
 }
 
 impl InstanceFilter<RuntimeCall> for ProxyType {
@@ -762,6 +783,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 					RuntimeCall::System(frame_system::Call::remark_with_event { .. })
 			),
 			ProxyType::ParaRegistration => false, // Only for remote proxy
+			ProxyType::SpendingAllowance => false, // Only for proxy allowances
 			// AH specific proxy types that are not on the Relay:
 			ProxyType::Assets => {
 				matches!(
@@ -857,7 +879,10 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(ProxyType::Staking, ProxyType::StakingOperator) => true,
 			(
 				ProxyType::NonTransfer,
-				ProxyType::Assets | ProxyType::AssetOwner | ProxyType::AssetManager,
+				ProxyType::Assets |
+				ProxyType::AssetOwner |
+				ProxyType::AssetManager |
+				ProxyType::SpendingAllowance,
 			) => false,
 			(ProxyType::NonTransfer, _) => true,
 			_ => false,
@@ -889,7 +914,7 @@ parameter_types! {
 
 impl cumulus_pallet_parachain_system::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnSystemEvent = RemoteProxyRelayChain;
+	type OnSystemEvent = (RemoteProxyRelayChain, RemoteIdentityPeople);
 	type SelfParaId = parachain_info::Pallet<Runtime>;
 	type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
 	type ReservedDmpWeight = ReservedDmpWeight;
@@ -980,6 +1005,111 @@ impl cumulus_pallet_xcmp_queue::migration::v5::V5Config for Runtime {
 	type ChannelList = ParachainSystem;
 }
 
+/// Encodes the channel calls of the relay chain `Hrmp` pallet.
+pub struct HrmpRelayCalls;
+impl pallet_hrmp_auto_accept::RelayHrmpCalls for HrmpRelayCalls {
+	fn init_open_channel(
+		recipient: ParaId,
+		proposed_max_capacity: u32,
+		proposed_max_message_size: u32,
+	) -> Vec<u8> {
+		remote_calls::kusama::RelayCall::Hrmp(remote_calls::HrmpCall::HrmpInitOpenChannel {
+			recipient,
+			proposed_max_capacity,
+			proposed_max_message_size,
+		})
+		.encode()
+	}
+
+	fn accept_open_channel(sender: ParaId) -> Vec<u8> {
+		remote_calls::kusama::RelayCall::Hrmp(remote_calls::HrmpCall::HrmpAcceptOpenChannel {
+			sender,
+		})
+		.encode()
+	}
+}
+
+/// The parachains with an open channel to Asset Hub, as last reported by the relay chain.
+pub struct IngressChannels;
+impl Contains<ParaId> for IngressChannels {
+	fn contains(para: &ParaId) -> bool {
+		cumulus_pallet_parachain_system::RelevantMessagingState::<Runtime>::get()
+			.is_some_and(|state| state.ingress_channels.iter().any(|(sender, _)| sender == para))
+	}
+}
+
+impl pallet_hrmp_auto_accept::Config for Runtime {
+	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, GeneralAdmin>;
+	type SelfParaId = ParachainInfo;
+	type RelayCalls = HrmpRelayCalls;
+	type XcmSender = xcm_config::XcmRouter;
+	type IngressChannels = IngressChannels;
+	type AcceptTimeout = ConstU32<{ 24 * HOURS }>;
+	type WeightInfo = weights::pallet_hrmp_auto_accept::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const XcmLockerMaxChains: u32 = 64;
+	pub const XcmLockerMaxLocks: u32 = 8;
+}
+
+impl pallet_xcm_asset_locker::Config for Runtime {
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type NativeBalance = Balances;
+	type NativeMatcher = xcm_builder::IsConcrete<xcm_config::KsmLocation>;
+	type Assets = Assets;
+	type AssetsMatcher = xcm_config::TrustBackedAssetsConvertedConcreteId;
+	type LocationToAccountId = xcm_config::LocationToAccountId;
+	type RemoteLocks = PolkadotXcm;
+	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, GeneralAdmin>;
+	type MaxChains = XcmLockerMaxChains;
+	type MaxLocks = XcmLockerMaxLocks;
+	type WeightInfo = weights::pallet_xcm_asset_locker::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = XcmAssetLockerBenchmarkHelper;
+}
+
+/// Encodes the coretime calls of the Coretime Chain `Broker` pallet.
+pub struct CoretimeBrokerCalls;
+impl pallet_coretime_purchase::BrokerCalls for CoretimeBrokerCalls {
+	fn purchase(price_limit: Balance) -> Vec<u8> {
+		remote_calls::kusama::CoretimeChainCall::Broker(remote_calls::BrokerCall::Purchase {
+			price_limit,
+		})
+		.encode()
+	}
+
+	fn renew(core: u16) -> Vec<u8> {
+		remote_calls::kusama::CoretimeChainCall::Broker(remote_calls::BrokerCall::Renew { core })
+			.encode()
+	}
+}
+
+parameter_types! {
+	pub CoretimeLocation: Location =
+		Location::new(1, Parachain(kusama_runtime_constants::system_parachain::BROKER_ID));
+	// Covers the execution of the order on the Coretime Chain and of a refund back to here.
+	pub const CoretimePurchaseRemoteFee: Balance = 10 * CENTS;
+	pub const CoretimePurchaseDeliveryFee: Balance = 5 * CENTS;
+}
+
+impl pallet_coretime_purchase::Config for Runtime {
+	type AssetKind = Location;
+	type NativeAsset = KsmLocation;
+	type Swap = AssetConversion;
+	type AccountIdToLocation =
+		xcm_builder::AliasesIntoAccountId32<xcm_config::RelayNetwork, AccountId>;
+	type XcmExecutor = xcm_executor::XcmExecutor<xcm_config::XcmConfig>;
+	type CoretimeLocation = CoretimeLocation;
+	type SelfLocation = system_parachains_constants::kusama::locations::AssetHubLocation;
+	type BrokerCalls = CoretimeBrokerCalls;
+	type RemoteFee = CoretimePurchaseRemoteFee;
+	type DeliveryFee = CoretimePurchaseDeliveryFee;
+	type WeightInfo = weights::pallet_coretime_purchase::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = CoretimePurchaseBenchmarkHelper;
+}
+
 parameter_types! {
 	pub const Period: u32 = 6 * HOURS;
 	pub const Offset: u32 = 0;
@@ -1218,6 +1348,7 @@ impl
 impl pallet_remote_proxy::Config for Runtime {
 	// The time between creating a proof and using the proof in a transaction.
 	type MaxStorageRootsToKeep = ConstU32<{ RC_MINUTES }>;
+	type MaxRegisteredProxies = ConstU32<32>;
 	type RemoteProxy = kusama_runtime_constants::proxy::RemoteProxyInterface<
 		ProxyType,
 		RelayChainToLocalProxyTypeConverter,
@@ -1225,6 +1356,22 @@ impl pallet_remote_proxy::Config for Runtime {
 	type WeightInfo = weights::pallet_remote_proxy::WeightInfo<Runtime>;
 }
 
+impl pallet_remote_identity::Config for Runtime {
+	// The time between creating a proof and using the proof in a transaction.
+	type MaxStorageRootsToKeep = ConstU32<{ RC_MINUTES }>;
+	// Judgements need to be proven again after a week to propagate removals on the People chain.
+	type MaxJudgementAge = ConstU32<{ 7 * RC_DAYS }>;
+	// Generous bounds, a relay chain proof of a para head has about ten nodes of at most 600
+	// bytes and the identity proof of the People chain is of similar size.
+	type MaxProofNodes = ConstU32<64>;
+	type MaxProofSize = ConstU32<{ 64 * 1024 }>;
+	type MaxRemovalsPerBlock = ConstU32<64>;
+	type RemoteIdentity = system_parachains_common::remote_identity::PeopleChainIdentityInterface<
+		kusama_runtime_constants::system_parachain::PeopleParaId,
+	>;
+	type WeightInfo = weights::pallet_remote_identity::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const DepositPerItem: Balance = system_para_deposit(1, 0);
 	pub const DepositPerChildTrieItem: Balance = system_para_deposit(1, 0) / 100;
@@ -1455,9 +1602,10 @@ impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParamet
 				EitherOf::<EnsureRoot<AccountId>, GeneralAdmin>::ensure_origin(origin.clone()),
 			StakingElection(_) =>
 				EitherOf::<EnsureRoot<AccountId>, StakingAdmin>::ensure_origin(origin.clone()),
-			Issuance(_) => <EnsureRoot<AccountId> as EnsureOrigin<RuntimeOrigin>>::ensure_origin(
-				origin.clone(),
-			),
+			Issuance(_) | Nis(_) =>
+				<EnsureRoot<AccountId> as EnsureOrigin<RuntimeOrigin>>::ensure_origin(
+					origin.clone(),
+				),
 			// technical params, can be controlled by the fellowship voice.
 			Scheduler(_) | MessageQueue(_) => EitherOfDiverse::<
 				EnsureRoot<AccountId>,
@@ -1593,6 +1741,19 @@ pub mod dynamic_params {
 		pub static MaxOnIdleWeight: Option<Weight> =
 			Some(Perbill::from_percent(50) * RuntimeBlockWeights::get().max_block);
 	}
+
+	/// Parameters about the NIS pallet.
+	#[dynamic_pallet_params]
+	#[codec(index = 5)]
+	pub mod nis {
+		/// The target proportion of the total issuance frozen in receipts.
+		#[codec(index = 0)]
+		pub static Target: Perquintill = Perquintill::from_percent(5);
+
+		/// The minimum amount of funds that may be placed in a bid.
+		#[codec(index = 1)]
+		pub static MinBid: Balance = 100 * QUID;
+	}
 }
 
 #[cfg(feature = "runtime-benchmarks")]
@@ -1656,6 +1817,7 @@ construct_runtime!(
 		AssetTxPayment: pallet_asset_conversion_tx_payment = 13,
 		Vesting: pallet_vesting = 14,
 		Claims: pallet_claims = 15,
+		Dap: pallet_dap = 16,
 
 		// Collator support. the order of these 5 are important and shall not change.
 		Authorship: pallet_authorship = 20,
@@ -1671,6 +1833,8 @@ construct_runtime!(
 		// DmpQueue = 33
 		ToPolkadotXcmRouter: pallet_xcm_bridge_hub_router::<Instance1> = 34,
 		MessageQueue: pallet_message_queue = 35,
+		HrmpAutoAccept: pallet_hrmp_auto_accept = 37,
+		XcmAssetLocker: pallet_xcm_asset_locker = 38,
 
 		// Handy utilities.
 		Utility: pallet_utility = 40,
@@ -1678,6 +1842,8 @@ construct_runtime!(
 		Proxy: pallet_proxy = 42,
 		RemoteProxyRelayChain: pallet_remote_proxy = 43,
 		Indices: pallet_indices = 44,
+		RemoteIdentityPeople: pallet_remote_identity = 45,
+		ProxyAllowances: pallet_proxy_allowances = 46,
 
 		// The main stage.
 		Assets: pallet_assets::<Instance1> = 50,
@@ -1690,18 +1856,24 @@ construct_runtime!(
 		AssetConversion: pallet_asset_conversion = 56,
 		Recovery: pallet_recovery = 57,
 		Society: pallet_society = 58,
+		CoretimePurchase: pallet_coretime_purchase = 59,
 
 		Revive: pallet_revive = 60,
 
 		AssetsPrecompiles: pallet_assets_precompiles::pallet = 61,
 		AssetsPrecompilesPermit: pallet_assets_precompiles::permit::pallet = 62,
 		VestingPrecompiles: pallet_vesting_precompiles::pallet = 63,
+		ForeignAssetReserves: pallet_foreign_asset_reserves::<Instance2> = 64,
 
 		// State trie migration pallet, only temporary.
 		StateTrieMigration: pallet_state_trie_migration = 70,
 
+		// Out of the 80s, which are full.
+		StakingBridgeMonitor: pallet_staking_bridge_monitor = 79,
+
 		// Staking in the 80s
 		NominationPools: pallet_nomination_pools = 80,
+		Nis: pallet_nis = 81,
 		VoterList: pallet_bags_list::<Instance1> = 82,
 		DelegatedStaking: pallet_delegated_staking = 83,
 		StakingRcClient: pallet_staking_async_rc_client = 84,
@@ -1863,6 +2035,52 @@ impl
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CoretimePurchaseBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_coretime_purchase::BenchmarkHelper<Location, AccountId>
+	for CoretimePurchaseBenchmarkHelper
+{
+	fn setup_buyer(who: &AccountId) -> Location {
+		use pallet_asset_conversion_tx_payment::BenchmarkHelperTrait;
+
+		let (asset_id, _) = AssetConversionTxHelper::create_asset_id_parameter(1);
+		AssetConversionTxHelper::setup_balances_and_pool(asset_id.clone(), who.clone());
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(
+			kusama_runtime_constants::system_parachain::BROKER_ID.into(),
+		);
+		asset_id
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ForeignAssetReservesBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_foreign_asset_reserves::BenchmarkHelper<ForeignAssetReserveData>
+	for ForeignAssetReservesBenchmarkHelper
+{
+	fn reserve(index: u32) -> ForeignAssetReserveData {
+		(Location::new(1, Parachain(2_000 + index)), false).into()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct XcmAssetLockerBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_xcm_asset_locker::BenchmarkHelper for XcmAssetLockerBenchmarkHelper {
+	fn lockable() -> (Location, Asset) {
+		use frame_support::traits::fungible::Mutate;
+
+		let who = AccountId::from([42u8; 32]);
+		Balances::set_balance(&who, 1_000 * UNITS);
+		let owner = AccountId32 { network: None, id: who.into() }.into();
+		(owner, (xcm_config::KsmLocation::get(), 100 * UNITS).into())
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 type StakingRcClientBench<T> = pallet_staking_async_rc_client::benchmarking::Pallet<T>;
 
@@ -1880,11 +2098,14 @@ mod benches {
 		[pallet_assets, Local]
 		[pallet_assets, Foreign]
 		[pallet_assets, Pool]
+		[pallet_foreign_asset_reserves, ForeignAssetReserves]
 		[pallet_assets_precompiles, AssetsPrecompiles]
 		[pallet_asset_conversion, AssetConversion]
 		// TODO: Somehow, benchmarks for this pallet are not visible outside the pallet
 		[pallet_asset_conversion_tx_payment, AssetTxPayment]
 		[pallet_balances, Balances]
+		[pallet_coretime_purchase, CoretimePurchase]
+		[pallet_hrmp_auto_accept, HrmpAutoAccept]
 		[pallet_indices, Indices]
 		[pallet_message_queue, MessageQueue]
 		[pallet_migrations, MultiBlockMigrations]
@@ -1894,7 +2115,9 @@ mod benches {
 		[pallet_parameters, Parameters]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
+		[pallet_proxy_allowances, ProxyAllowances]
 		[pallet_remote_proxy, RemoteProxyRelayChain]
+		[pallet_remote_identity, RemoteIdentityPeople]
 		[pallet_scheduler, Scheduler]
 		[pallet_revive, Revive]
 		[pallet_session, SessionBench::<Runtime>]
@@ -1923,6 +2146,7 @@ mod benches {
 		[pallet_society, Society]
 		// XCM
 		[pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
+		[pallet_xcm_asset_locker, XcmAssetLocker]
 		// Bridges
 		[pallet_xcm_bridge_hub_router, ToPolkadot]
 		// NOTE: Make sure you point to the individual modules below.
@@ -1932,7 +2156,10 @@ mod benches {
 		// Staking
 		[pallet_staking_async, Staking]
 		[pallet_staking_async_rc_client, StakingRcClientBench::<Runtime>]
+		[pallet_staking_bridge_monitor, StakingBridgeMonitor]
 		[pallet_bags_list, VoterList]
+		[pallet_dap, Dap]
+		[pallet_nis, Nis]
 		// DelegatedStaking has no calls
 		[pallet_election_provider_multi_block, MultiBlockElection]
 		[pallet_election_provider_multi_block::verifier, MultiBlockElectionVerifier]
@@ -2814,6 +3041,12 @@ pallet_revive::impl_runtime_apis_plus_revive_traits!(
 		}
 	}
 
+	impl pallet_staking_bridge_monitor::runtime_api::StakingBridgeHealthApi<Block, BlockNumber> for Runtime {
+		fn staking_bridge_health() -> pallet_staking_bridge_monitor::StakingBridgeHealth<BlockNumber> {
+			StakingBridgeMonitor::health()
+		}
+	}
+
 	impl system_parachains_common::apis::Inflation<Block> for Runtime {
 		fn experimental_issuance_prediction_info() -> system_parachains_common::apis::InflationInfo {
 			crate::staking::EraPayout::impl_experimental_inflation_info()
@@ -2994,11 +3227,13 @@ mod tests {
 		// Assets IS supertype of AssetOwner and AssetManager
 		assert!(ProxyType::Assets.is_superset(&ProxyType::AssetOwner));
 		assert!(ProxyType::Assets.is_superset(&ProxyType::AssetManager));
-		// NonTransfer is NOT supertype of Any, Assets, AssetOwner and AssetManager
+		// NonTransfer is NOT supertype of Any, Assets, AssetOwner, AssetManager and
+		// SpendingAllowance
 		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::Any));
 		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::Assets));
 		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::AssetOwner));
 		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::AssetManager));
+		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::SpendingAllowance));
 		// NonTransfer is supertype of remaining stuff
 		assert!(ProxyType::NonTransfer.is_superset(&ProxyType::CancelProxy));
 		assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Collator));
@@ -21,6 +21,17 @@ pub type Unreleased = (
 	cumulus_pallet_xcmp_queue::migration::v6::MigrateV5ToV6<crate::Runtime>,
 	MigrateBountyAccountAssets,
 	cumulus_pallet_parachain_system::migration::Migration<crate::Runtime>,
+	// DAP: bring the freshly added pallet to V1, so that the upstream V1->V2 migration can seed
+	// `BudgetAllocation` and `LastIssuanceTimestamp` and credit the one-shot catch-up drip for the
+	// staking pots. Required when moving staking to non-minting mode.
+	InitializeDap,
+	pallet_dap::migrations::MigrateV1ToV2<
+		crate::Runtime,
+		DapLastIssuanceTimestamp,
+		DefaultDapBudget,
+		crate::dynamic_params::staking_election::MaxEraDuration,
+	>,
+	CreateNisCounterpart,
 );
 
 /// Migrations/checks that do not need to be versioned and can run on every update.
@@ -32,6 +43,13 @@ pub type SingleBlockMigrations = (Unreleased, Permanent);
 frame_support::parameter_types! {
 	pub const AhMigratorPalletName: &'static str = "AhMigrator";
 
+	/// The share of the emission that `DefaultDapBudget` allocates to the NIS pot.
+	///
+	/// This is a share of the emission, unrelated to the NIS `Target`, which is the share of the
+	/// total issuance frozen in receipts. It only seeds the budget of `pallet_dap`, which
+	/// governance adjusts from then on.
+	pub const NisEmissionShare: sp_runtime::Perbill = sp_runtime::Perbill::from_percent(5);
+
 	/// Assets that the multi-asset bounty migration must sweep from the old
 	/// derivation to the new one. Extends `treasury::BountyRelevantAssets`
 	/// (the legacy bounties' sweep set: KSM, USDT) with DOT, since
@@ -131,6 +149,164 @@ impl frame_support::traits::OnRuntimeUpgrade for MigrateBountyAccountAssets {
 	}
 }
 
+/// Provides the initial `LastIssuanceTimestamp` for the DAP V1->V2 migration.
+///
+/// Uses the start of the active era (ms since unix epoch) so the catch-up drip covers
+/// the gap between the last era boundary and the migration. Falls back to 0 (no catch-up)
+/// if no era is active.
+pub struct DapLastIssuanceTimestamp;
+impl frame_support::traits::Get<u64> for DapLastIssuanceTimestamp {
+	fn get() -> u64 {
+		pallet_staking_async::ActiveEra::<crate::Runtime>::get()
+			.and_then(|era| era.start)
+			.unwrap_or(0)
+	}
+}
+
+/// Default DAP budget allocation: the legacy `EraPayout` split at the time of the migration,
+/// 0% validator incentive.
+///
+/// Kusama used to split each era payout between stakers and the treasury depending on the
+/// staked ratio. DAP allocates a fixed share instead, so we seed it with the split that the
+/// active era would have received. The NIS pot receives [`NisEmissionShare`] of the emission out
+/// of the treasury share, the treasury keeps the rest and nothing is left in the DAP buffer.
+pub struct DefaultDapBudget;
+impl frame_support::traits::Get<pallet_dap::BudgetAllocationMap> for DefaultDapBudget {
+	fn get() -> pallet_dap::BudgetAllocationMap {
+		use crate::staking::EraPayout;
+		use sp_runtime::Perbill;
+		use sp_staking::budget::BudgetRecipientList;
+
+		// We assume un-delayed 6h eras; the split does not depend on the duration.
+		let era_duration = 6 * 60 * 60 * 1000;
+		let (stakers, rest) = EraPayout::split_payout(
+			EraPayout::active_era_stake(),
+			pallet_balances::Pallet::<crate::Runtime>::total_issuance(),
+			era_duration,
+		);
+		let stakers = Perbill::from_rational(stakers, stakers.saturating_add(rest));
+		let nis = NisEmissionShare::get().min(stakers.left_from_one());
+		let treasury = stakers.left_from_one().saturating_sub(nis);
+
+		let recipients = <crate::Runtime as pallet_dap::Config>::BudgetRecipients::recipients();
+		// Order matches `pallet_dap::Config::BudgetRecipients`:
+		// [dap (buffer), StakerRewardRecipient, ValidatorIncentiveRecipient,
+		// TreasuryBudgetRecipient, NisBudgetRecipient]
+		let percentages =
+			[Perbill::from_percent(0), stakers, Perbill::from_percent(0), treasury, nis];
+
+		let mut map = pallet_dap::BudgetAllocationMap::new();
+		for ((key, _), perbill) in recipients.into_iter().zip(percentages) {
+			let _ = map.try_insert(key, perbill);
+		}
+		map
+	}
+}
+
+/// Prepares `pallet-dap`, which is newly added to Asset Hub Kusama, for the upstream V1->V2
+/// migration.
+///
+/// Asset Hub Polkadot ran DAP V1 before moving to V2, Kusama did not. V1 had no storage of its
+/// own, so it is enough to make sure the buffer and staging accounts exist and to note the
+/// storage version. No-op if the pallet is already beyond V0.
+pub struct InitializeDap;
+impl frame_support::traits::OnRuntimeUpgrade for InitializeDap {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+		let db_weight = <crate::Runtime as frame_system::Config>::DbWeight::get();
+		if crate::Dap::on_chain_storage_version() != StorageVersion::new(0) {
+			return db_weight.reads(1);
+		}
+
+		for account in [crate::Dap::buffer_account(), crate::Dap::staging_account()] {
+			if !frame_system::Pallet::<crate::Runtime>::account_exists(&account) {
+				let _ = frame_system::Pallet::<crate::Runtime>::inc_providers(&account);
+			}
+		}
+		StorageVersion::new(1).put::<crate::Dap>();
+
+		db_weight.reads_writes(3, 3)
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use frame_support::traits::{GetStorageVersion, StorageVersion};
+
+		frame_support::ensure!(
+			crate::Dap::on_chain_storage_version() >= StorageVersion::new(1),
+			"DAP should be at least at V1"
+		);
+		frame_support::ensure!(
+			frame_system::Pallet::<crate::Runtime>::account_exists(&crate::Dap::buffer_account()),
+			"DAP buffer account should exist"
+		);
+		Ok(())
+	}
+}
+
+/// Creates the trust-backed asset that represents communal NIS receipts at
+/// [`crate::nis::NIS_COUNTERPART_ASSET_ID`].
+///
+/// No-op if the asset was already created. Leaves an asset it did not create alone and logs an
+/// error, as it must not become the counterpart.
+pub struct CreateNisCounterpart;
+impl frame_support::traits::OnRuntimeUpgrade for CreateNisCounterpart {
+	fn on_runtime_upgrade() -> frame_support::weights::Weight {
+		let db_weight = <crate::Runtime as frame_system::Config>::DbWeight::get();
+		let exists = crate::nis::counterpart_asset_exists();
+
+		if let Err(e) = crate::nis::create_counterpart_asset() {
+			log::error!(
+				target: "runtime::nis",
+				"Failed to create the NIS counterpart asset {}: {e:?}",
+				crate::nis::NIS_COUNTERPART_ASSET_ID,
+			);
+		}
+
+		if exists {
+			db_weight.reads(2)
+		} else {
+			db_weight.reads_writes(3, 5)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<alloc::vec::Vec<u8>, sp_runtime::TryRuntimeError> {
+		// The id must be free, unless it already is the counterpart. For an existing asset,
+		// `create_counterpart_asset` only checks it.
+		frame_support::ensure!(
+			!crate::nis::counterpart_asset_exists() ||
+				crate::nis::create_counterpart_asset().is_ok(),
+			"NIS counterpart asset id is taken by another asset"
+		);
+		Ok(alloc::vec::Vec::new())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(_state: alloc::vec::Vec<u8>) -> Result<(), sp_runtime::TryRuntimeError> {
+		use sp_runtime::traits::AccountIdConversion;
+
+		let details =
+			pallet_assets::Asset::<crate::Runtime, crate::TrustBackedAssetsInstance>::get(
+				crate::nis::NIS_COUNTERPART_ASSET_ID,
+			)
+			.ok_or("NIS counterpart asset should exist")?;
+		let nis: crate::AccountId = crate::nis::NisPalletId::get().into_account_truncating();
+		frame_support::ensure!(
+			[&details.owner, &details.issuer, &details.admin, &details.freezer]
+				.into_iter()
+				.all(|account| *account == nis),
+			"NIS counterpart asset should be controlled by the NIS pot only"
+		);
+		frame_support::ensure!(
+			details.supply == 0,
+			"NIS counterpart asset should not be minted yet"
+		);
+		Ok(())
+	}
+}
+
 #[cfg(not(feature = "runtime-benchmarks"))]
 pub use multiblock_migrations::MbmMigrations;
 
@@ -0,0 +1,399 @@
+// Copyright (C) Polkadot Fellows.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Non-interactive staking (NIS): fixed-term, transferable receipts for frozen KSM.
+//!
+//! This replaces `Nis` and `NisCounterpartBalances` that were removed from the Kusama relay chain.
+//! Communal receipts are represented by a trust-backed asset, so they can be traded in
+//! `AssetConversion` pools. Receipt yields are paid out of the `nis` budget of `pallet_dap`, which
+//! drips its share of the issuance curve straight into the NIS pot.
+
+use super::*;
+use frame_support::traits::{fungible::ItemOf, NeverEnsureOrigin};
+use pallet_nis::WithMaximumOf;
+use sp_runtime::{traits::Zero, DispatchError, DispatchResult};
+use system_parachains_constants::async_backing::{DAYS, MINUTES};
+
+/// The trust-backed asset minted for communal receipts.
+///
+/// A fixed id below the first id handed out by `AutoIncAssetId`, so that it never collides with
+/// assets created through `Assets::create` since. Assets created before `AutoIncAssetId` chose
+/// their ids, so [`create_counterpart_asset`] refuses to adopt an asset it did not create.
+pub const NIS_COUNTERPART_ASSET_ID: AssetIdForTrustBackedAssets = 49_999_999;
+
+parameter_types! {
+	pub const NisPalletId: PalletId = PalletId(*b"py/nis  ");
+	pub const NisBasePeriod: BlockNumber = 7 * DAYS;
+	pub MinReceipt: Perquintill = Perquintill::from_rational(1u64, 10_000_000u64);
+	pub const IntakePeriod: BlockNumber = 5 * MINUTES;
+	pub MaxIntakeWeight: Weight = Perbill::from_percent(10) * RuntimeBlockWeights::get().max_block;
+	pub const ThawThrottle: (Perquintill, BlockNumber) = (Perquintill::from_percent(25), 5);
+	pub const NisCounterpartAsset: AssetIdForTrustBackedAssets = NIS_COUNTERPART_ASSET_ID;
+}
+
+/// Whether the counterpart asset was created.
+pub fn counterpart_asset_exists() -> bool {
+	pallet_assets::Asset::<Runtime, TrustBackedAssetsInstance>::contains_key(
+		NIS_COUNTERPART_ASSET_ID,
+	)
+}
+
+/// Create the trust-backed asset used as counterpart of communal receipts.
+///
+/// The asset is owned by the NIS pallet account, which is the only account able to mint it. Does
+/// nothing if the asset was already created and nothing was minted yet. Fails if another asset
+/// took the id: its owner could mint receipts and thaw the KSM frozen by others.
+pub fn create_counterpart_asset() -> DispatchResult {
+	let owner: AccountId = NisPalletId::get().into_account_truncating();
+	if let Some(details) =
+		pallet_assets::Asset::<Runtime, TrustBackedAssetsInstance>::get(NIS_COUNTERPART_ASSET_ID)
+	{
+		let controlled_by_nis = [&details.owner, &details.issuer, &details.admin, &details.freezer]
+			.into_iter()
+			.all(|account| *account == owner);
+		return if controlled_by_nis && details.supply.is_zero() {
+			Ok(())
+		} else {
+			Err(DispatchError::Other("NisCounterpartAssetIdTaken"))
+		};
+	}
+
+	Assets::force_create(
+		RuntimeOrigin::root(),
+		NIS_COUNTERPART_ASSET_ID.into(),
+		owner.into(),
+		true,
+		ExistentialDeposit::get(),
+	)?;
+	Assets::force_set_metadata(
+		RuntimeOrigin::root(),
+		NIS_COUNTERPART_ASSET_ID.into(),
+		b"Kusama NIS Receipt".to_vec(),
+		b"nKSM".to_vec(),
+		12,
+		false,
+	)
+}
+
+/// Receives the share of the issuance that funds the yield of the receipts.
+///
+/// The budget is paid into the NIS pot, so it is part of the holdings `pallet_nis` accounts for
+/// when thawing receipts.
+pub struct NisBudgetRecipient;
+impl sp_staking::budget::BudgetRecipient<AccountId> for NisBudgetRecipient {
+	fn budget_key() -> sp_staking::budget::BudgetKey {
+		sp_staking::budget::BudgetKey::truncate_from(b"nis".to_vec())
+	}
+	fn pot_account() -> AccountId {
+		NisPalletId::get().into_account_truncating()
+	}
+}
+
+impl pallet_nis::Config for Runtime {
+	type WeightInfo = weights::pallet_nis::WeightInfo<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type Currency = Balances;
+	type CurrencyBalance = Balance;
+	// The pot is funded by `NisBudgetRecipient`, the deficit must not be minted on top of the
+	// issuance curve.
+	type FundOrigin = NeverEnsureOrigin<()>;
+	type Counterpart = ItemOf<Assets, NisCounterpartAsset, AccountId>;
+	type CounterpartAmount = WithMaximumOf<ConstU128<21_000_000_000_000_000_000u128>>;
+	type Deficit = ();
+	type IgnoredIssuance = ();
+	type Target = dynamic_params::nis::Target;
+	type PalletId = NisPalletId;
+	type QueueCount = ConstU32<500>;
+	type MaxQueueLen = ConstU32<1000>;
+	type FifoQueueLen = ConstU32<250>;
+	type BasePeriod = NisBasePeriod;
+	type MinBid = dynamic_params::nis::MinBid;
+	type MinReceipt = MinReceipt;
+	type IntakePeriod = IntakePeriod;
+	type MaxIntakeWeight = MaxIntakeWeight;
+	type ThawThrottle = ThawThrottle;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkSetup = NisBenchmarkSetup;
+}
+
+/// Creates the counterpart asset for the `pallet_nis` benchmarks.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct NisBenchmarkSetup;
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_nis::BenchmarkSetup for NisBenchmarkSetup {
+	fn create_counterpart_asset() {
+		create_counterpart_asset().expect("Counterpart asset can be created");
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::{
+		assert_noop, assert_ok,
+		traits::{fungible::Mutate as _, Hooks},
+	};
+
+	const ALICE: AccountId = AccountId::new([1u8; 32]);
+	const BOB: AccountId = AccountId::new([2u8; 32]);
+	const CHARLIE: AccountId = AccountId::new([3u8; 32]);
+
+	const COUNTERPART_ID: AssetIdForTrustBackedAssets = NIS_COUNTERPART_ASSET_ID;
+
+	fn new_test_ext() -> sp_io::TestExternalities {
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			pallet_assets::NextAssetId::<Runtime, TrustBackedAssetsInstance>::put(50_000_000);
+			assert_ok!(create_counterpart_asset());
+
+			// Most of the issuance is not frozen in receipts.
+			assert_ok!(Balances::mint_into(&CHARLIE, 1_000_000 * UNITS));
+			assert_ok!(Balances::mint_into(&ALICE, 10_000 * UNITS));
+			assert_ok!(Balances::mint_into(&BOB, 10 * UNITS));
+		});
+		ext
+	}
+
+	/// Jump to the next intake period and issue receipts for the queued bids.
+	fn run_intake() {
+		let next = (System::block_number() / IntakePeriod::get() + 1) * IntakePeriod::get();
+		System::set_block_number(next);
+		Nis::on_initialize(next);
+	}
+
+	fn run_to_expiry(index: pallet_nis::ReceiptIndex) {
+		let expiry = pallet_nis::Receipts::<Runtime>::get(index).unwrap().expiry;
+		System::set_block_number(expiry);
+	}
+
+	fn counterpart_location() -> Location {
+		Location::new(0, [PalletInstance(50), GeneralIndex(COUNTERPART_ID.into())])
+	}
+
+	#[test]
+	fn counterpart_asset_is_created_once() {
+		new_test_ext().execute_with(|| {
+			assert!(counterpart_asset_exists());
+			assert_eq!(
+				pallet_assets::NextAssetId::<Runtime, TrustBackedAssetsInstance>::get(),
+				Some(50_000_000)
+			);
+
+			assert_ok!(create_counterpart_asset());
+			assert_eq!(Assets::total_supply(COUNTERPART_ID), 0);
+		});
+	}
+
+	#[test]
+	fn counterpart_asset_id_taken_by_another_asset_is_not_adopted() {
+		let mut ext = sp_io::TestExternalities::new_empty();
+		ext.execute_with(|| {
+			System::set_block_number(1);
+			assert_ok!(Assets::force_create(
+				RuntimeOrigin::root(),
+				COUNTERPART_ID.into(),
+				ALICE.into(),
+				true,
+				1,
+			));
+
+			assert_noop!(
+				create_counterpart_asset(),
+				DispatchError::Other("NisCounterpartAssetIdTaken")
+			);
+		});
+	}
+
+	#[test]
+	fn counterpart_asset_with_supply_is_not_adopted() {
+		new_test_ext().execute_with(|| {
+			let nis: AccountId = NisPalletId::get().into_account_truncating();
+			assert_ok!(Assets::mint(
+				RuntimeOrigin::signed(nis),
+				COUNTERPART_ID.into(),
+				ALICE.into(),
+				UNITS,
+			));
+
+			assert_noop!(
+				create_counterpart_asset(),
+				DispatchError::Other("NisCounterpartAssetIdTaken")
+			);
+		});
+	}
+
+	#[test]
+	fn target_is_active_by_default() {
+		new_test_ext().execute_with(|| {
+			assert!(dynamic_params::nis::Target::get() > Perquintill::zero());
+		});
+	}
+
+	#[test]
+	fn target_is_controlled_by_root() {
+		new_test_ext().execute_with(|| {
+			let set_target = RuntimeParameters::Nis(dynamic_params::nis::Parameters::Target(
+				dynamic_params::nis::Target,
+				Some(Perquintill::from_percent(20)),
+			));
+			assert!(Parameters::set_parameter(RuntimeOrigin::signed(ALICE), set_target.clone())
+				.is_err());
+			assert_ok!(Parameters::set_parameter(RuntimeOrigin::root(), set_target));
+			assert_eq!(dynamic_params::nis::Target::get(), Perquintill::from_percent(20));
+		});
+	}
+
+	#[test]
+	fn place_bid_and_thaw_works() {
+		new_test_ext().execute_with(|| {
+			assert_noop!(
+				Nis::place_bid(
+					RuntimeOrigin::signed(ALICE),
+					dynamic_params::nis::MinBid::get() - 1,
+					1
+				),
+				pallet_nis::Error::<Runtime>::AmountTooSmall
+			);
+			assert_ok!(Nis::place_bid(RuntimeOrigin::signed(ALICE), 100 * UNITS, 1));
+			assert_eq!(Balances::free_balance(&ALICE), 9_900 * UNITS);
+
+			run_intake();
+			let receipt = pallet_nis::Receipts::<Runtime>::get(0).unwrap();
+			assert_eq!(receipt.owner, Some((ALICE, 100 * UNITS)));
+			assert_eq!(receipt.expiry, System::block_number() + NisBasePeriod::get());
+
+			// Thawing is only possible after the receipt expired.
+			assert_noop!(
+				Nis::thaw_private(RuntimeOrigin::signed(ALICE), 0, None),
+				pallet_nis::Error::<Runtime>::NotExpired
+			);
+
+			// The issuance grows by 10%, so does the value of the receipt. The NIS budget of the
+			// DAP drips its share into the pot.
+			assert_ok!(Balances::mint_into(&CHARLIE, 101_000 * UNITS));
+			assert_ok!(Balances::mint_into(
+				&<NisBudgetRecipient as sp_staking::budget::BudgetRecipient<_>>::pot_account(),
+				11 * UNITS,
+			));
+
+			run_to_expiry(0);
+			let issuance = Balances::total_issuance();
+			assert_ok!(Nis::thaw_private(RuntimeOrigin::signed(ALICE), 0, None));
+			assert!(pallet_nis::Receipts::<Runtime>::get(0).is_none());
+			assert!(Balances::free_balance(&ALICE) > 10_010 * UNITS);
+			assert_eq!(Balances::total_issuance(), issuance);
+		});
+	}
+
+	#[test]
+	fn deficit_can_not_be_minted() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Nis::place_bid(RuntimeOrigin::signed(ALICE), 100 * UNITS, 1));
+			run_intake();
+			assert_ok!(Balances::mint_into(&CHARLIE, 100_000 * UNITS));
+
+			let issuance = Balances::total_issuance();
+			assert_noop!(
+				Nis::fund_deficit(RuntimeOrigin::signed(BOB)),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_noop!(
+				Nis::fund_deficit(RuntimeOrigin::root()),
+				sp_runtime::DispatchError::BadOrigin
+			);
+			assert_eq!(Balances::total_issuance(), issuance);
+		});
+	}
+
+	#[test]
+	fn private_receipt_transfer_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Nis::place_bid(RuntimeOrigin::signed(ALICE), 100 * UNITS, 1));
+			run_intake();
+
+			assert_ok!(<Nis as frame_support::traits::nonfungible::Transfer<AccountId>>::transfer(
+				&0, &BOB
+			));
+			let receipt = pallet_nis::Receipts::<Runtime>::get(0).unwrap();
+			assert_eq!(receipt.owner, Some((BOB, 100 * UNITS)));
+
+			run_to_expiry(0);
+			assert_noop!(
+				Nis::thaw_private(RuntimeOrigin::signed(ALICE), 0, None),
+				pallet_nis::Error::<Runtime>::NotOwner
+			);
+			assert_ok!(Nis::thaw_private(RuntimeOrigin::signed(BOB), 0, None));
+			assert_eq!(Balances::free_balance(&ALICE), 9_900 * UNITS);
+			assert!(Balances::free_balance(&BOB) > 109 * UNITS);
+		});
+	}
+
+	#[test]
+	fn communify_and_counterpart_transfer_works() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Nis::place_bid(RuntimeOrigin::signed(ALICE), 100 * UNITS, 1));
+			run_intake();
+
+			assert_ok!(Nis::communify(RuntimeOrigin::signed(ALICE), 0));
+			assert_eq!(pallet_nis::Receipts::<Runtime>::get(0).unwrap().owner, None);
+			let counterpart = Assets::balance(COUNTERPART_ID, ALICE);
+			assert!(counterpart > 0);
+
+			// The counterpart is a regular asset and can be transferred.
+			assert_ok!(Assets::transfer(
+				RuntimeOrigin::signed(ALICE),
+				COUNTERPART_ID.into(),
+				BOB.into(),
+				counterpart,
+			));
+			assert_eq!(Assets::balance(COUNTERPART_ID, ALICE), 0);
+
+			run_to_expiry(0);
+			assert!(Nis::thaw_communal(RuntimeOrigin::signed(ALICE), 0).is_err());
+			assert_ok!(Nis::thaw_communal(RuntimeOrigin::signed(BOB), 0));
+			assert_eq!(Assets::balance(COUNTERPART_ID, BOB), 0);
+			assert!(Balances::free_balance(&BOB) > 109 * UNITS);
+		});
+	}
+
+	#[test]
+	fn counterpart_can_be_pooled() {
+		new_test_ext().execute_with(|| {
+			assert_ok!(Nis::place_bid(RuntimeOrigin::signed(ALICE), 1_000 * UNITS, 1));
+			run_intake();
+			assert_ok!(Nis::communify(RuntimeOrigin::signed(ALICE), 0));
+			let counterpart = Assets::balance(COUNTERPART_ID, ALICE);
+
+			assert_ok!(AssetConversion::create_pool(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(xcm_config::KsmLocation::get()),
+				Box::new(counterpart_location()),
+			));
+			assert_ok!(AssetConversion::add_liquidity(
+				RuntimeOrigin::signed(ALICE),
+				Box::new(xcm_config::KsmLocation::get()),
+				Box::new(counterpart_location()),
+				100 * UNITS,
+				counterpart / 2,
+				1,
+				1,
+				ALICE,
+			));
+		});
+	}
+}
@@ -28,6 +28,7 @@ use frame_support::traits::tokens::imbalance::ResolveTo;
 use pallet_election_provider_multi_block::{self as multi_block, SolutionAccuracyOf};
 use pallet_staking_async::UseValidatorsMap;
 use pallet_staking_async_rc_client as rc_client;
+use remote_calls::AhClientCall;
 use sp_runtime::{
 	generic, traits::OpaqueKeys, transaction_validity::TransactionPriority, Perquintill,
 };
@@ -120,10 +121,17 @@ impl pallet_bags_list::Config<VoterBagsListInstance> for Runtime {
 parameter_types! {
 	pub const DelegatedStakingPalletId: PalletId = PalletId(*b"py/dlstk");
 	pub const SlashRewardFraction: Perbill = Perbill::from_percent(1);
-	/// Seed pallet-id for `RewardPots`. Required to compile in legacy minting mode but
-	/// never actually consulted because `DisableMinting = false` means era pots are not
-	/// created or funded. Distinct 8-byte tag to avoid collision with any other pot.
-	pub const UnusedStakingPotsPalletId: PalletId = PalletId(*b"ks/stkng");
+	pub const DapPalletId: PalletId = sp_dap::DAP_PALLET_ID;
+	pub DapStagingAccount: AccountId = pallet_dap::Pallet::<Runtime>::staging_account();
+	/// Seed pallet-id for the era reward pots of `pallet-staking-async`.
+	///
+	/// Kept at the tag used while Kusama was in legacy minting mode, when no pot was ever
+	/// created or funded under it.
+	pub const StakingPotsPalletId: PalletId = PalletId(*b"ks/stkng");
+	/// Minimum time (ms) between issuance drips. 60s = drip at most once per minute.
+	pub const DapIssuanceCadence: u64 = 60_000;
+	/// Safety ceiling (ms) for elapsed time in a single drip. Prevents over-minting after stalls.
+	pub const DapMaxElapsedPerDrip: u64 = 600_000;
 }
 
 impl pallet_delegated_staking::Config for Runtime {
@@ -137,6 +145,40 @@ impl pallet_delegated_staking::Config for Runtime {
 	type CoreStaking = Staking;
 }
 
+/// Receives the share of the issuance which used to go to the treasury as `RewardRemainder` of
+/// the legacy `EraPayout`.
+pub struct TreasuryBudgetRecipient;
+impl sp_staking::budget::BudgetRecipient<AccountId> for TreasuryBudgetRecipient {
+	fn budget_key() -> sp_staking::budget::BudgetKey {
+		sp_staking::budget::BudgetKey::truncate_from(b"treasury".to_vec())
+	}
+	fn pot_account() -> AccountId {
+		xcm_config::TreasuryAccount::get()
+	}
+}
+
+impl pallet_dap::Config for Runtime {
+	type Currency = Balances;
+	type PalletId = DapPalletId;
+	type IssuanceCurve = EraPayout;
+	type BudgetRecipients = (
+		pallet_dap::Pallet<Runtime>,
+		pallet_staking_async::StakerRewardRecipient<
+			pallet_staking_async::Seed<StakingPotsPalletId>,
+		>,
+		pallet_staking_async::ValidatorIncentiveRecipient<
+			pallet_staking_async::Seed<StakingPotsPalletId>,
+		>,
+		TreasuryBudgetRecipient,
+		crate::nis::NisBudgetRecipient,
+	);
+	type Time = pallet_timestamp::Pallet<Runtime>;
+	type IssuanceCadence = DapIssuanceCadence;
+	type MaxElapsedPerDrip = DapMaxElapsedPerDrip;
+	type BudgetOrigin = EnsureRoot<AccountId>;
+	type WeightInfo = weights::pallet_dap::WeightInfo<Runtime>;
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 parameter_types! {
 	pub BenchElectionBounds: frame_election_provider_support::bounds::ElectionBounds =
@@ -294,16 +336,23 @@ impl multi_block::unsigned::miner::MinerConfig for Runtime {
 	type TargetSnapshotPerBlock = <Runtime as multi_block::Config>::TargetSnapshotPerBlock;
 }
 
-// AUDIT: This is the inflation formula of Kusama prior to AHM. Source:
-// https://github.com/polkadot-fellows/runtimes/blob/18cbc8b3004f3cff44f6de053bb4220a9f85a7b1/relay/kusama/src/lib.rs#L793-L823
 pub struct EraPayout;
-impl pallet_staking_async::EraPayout<Balance> for EraPayout {
-	fn era_payout(
+
+impl EraPayout {
+	const MILLISECONDS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
+
+	/// The `(stakers, rest)` split of the emission over `elapsed_millis`.
+	///
+	/// AUDIT: This is the inflation formula of Kusama prior to AHM. Source:
+	/// https://github.com/polkadot-fellows/runtimes/blob/18cbc8b3004f3cff44f6de053bb4220a9f85a7b1/relay/kusama/src/lib.rs#L793-L823
+	///
+	/// The sum of both parts is always `MaxInflation` of `total_issuance` pro rata of the period,
+	/// the staked ratio only decides how it is split.
+	pub fn split_payout(
 		total_staked: Balance,
 		total_issuance: Balance,
-		era_duration_millis: u64,
+		elapsed_millis: u64,
 	) -> (Balance, Balance) {
-		const MILLISECONDS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
 		use crate::dynamic_params;
 
 		let params = polkadot_runtime_common::impls::EraPayoutParams {
@@ -313,7 +362,10 @@ impl pallet_staking_async::EraPayout<Balance> for EraPayout {
 			max_annual_inflation: dynamic_params::issuance::MaxInflation::get(),
 			min_annual_inflation: dynamic_params::issuance::MinInflation::get(),
 			falloff: dynamic_params::issuance::Falloff::get(),
-			period_fraction: Perquintill::from_rational(era_duration_millis, MILLISECONDS_PER_YEAR),
+			period_fraction: Perquintill::from_rational(
+				elapsed_millis,
+				Self::MILLISECONDS_PER_YEAR,
+			),
 			// Note: Kusama RC had the code for reserving a subset of its "ideal-staked-ratio" to be
 			// allocated to parachain auctions. Yet, this code was buggy in the RC, and was actually
 			// not doing this. Even if otherwise, in the absence of auctions, this code made no
@@ -324,29 +376,39 @@ impl pallet_staking_async::EraPayout<Balance> for EraPayout {
 		};
 		polkadot_runtime_common::impls::relay_era_payout(params)
 	}
-}
 
-impl EraPayout {
-	pub(crate) fn impl_experimental_inflation_info() -> InflationInfo {
+	/// Total stake backing the active era, as used for the staker/rest split.
+	pub fn active_era_stake() -> Balance {
 		use pallet_staking_async::{ActiveEra, ActiveEraInfo, ErasTotalStake};
-		let staked = ActiveEra::<Runtime>::get()
+		ActiveEra::<Runtime>::get()
 			.map(|ActiveEraInfo { index, .. }| ErasTotalStake::<Runtime>::get(index))
-			.unwrap_or(0);
+			.unwrap_or(0)
+	}
+
+	pub(crate) fn impl_experimental_inflation_info() -> InflationInfo {
 		let ti = pallet_balances::Pallet::<Runtime>::total_issuance();
 
 		// We assume un-delayed 6h eras.
 		let era_duration = 6 * 60 * 60 * 1000;
-		let next_mint = <Self as pallet_staking_async::EraPayout<Balance>>::era_payout(
-			staked,
-			ti,
-			era_duration,
-		);
-		let total = next_mint.0 + next_mint.1;
+		let era_emission =
+			<Self as sp_staking::budget::IssuanceCurve<Balance>>::issue(ti, era_duration);
 		const NUM_ERAS_PER_DAY: u128 = 4;
-		let annual_issuance = total * 36525 * NUM_ERAS_PER_DAY / 100;
+		let annual_issuance = era_emission * 36525 * NUM_ERAS_PER_DAY / 100;
 		let issuance = Perquintill::from_rational(annual_issuance, ti);
 
-		InflationInfo { issuance, next_mint }
+		InflationInfo { issuance, next_mint: (era_emission, 0) }
+	}
+}
+
+/// DAP issuance curve: total emission for a given elapsed period.
+///
+/// Same computation as the legacy Kusama `EraPayout`, but returns the combined emission. The
+/// staker/rest split is handled by pallet-dap via `BudgetAllocation`.
+impl sp_staking::budget::IssuanceCurve<Balance> for EraPayout {
+	fn issue(total_issuance: Balance, elapsed_millis: u64) -> Balance {
+		// The stake only decides the split, the total is the maximum inflation for the period.
+		let (stakers, rest) = Self::split_payout(0, total_issuance, elapsed_millis);
+		stakers.saturating_add(rest)
 	}
 }
 
@@ -392,14 +454,19 @@ impl pallet_staking_async::Config for Runtime {
 	// `U128CurrencyToVote`, the presence of one whale with more than u64::MAX will cause everyone's
 	// staking election vote to be downscaled by two.
 	type CurrencyToVote = sp_staking::currency_to_vote::SaturatingCurrencyToVote;
-	type RewardRemainder = ResolveTo<xcm_config::TreasuryAccount, Balances>;
+	// Non-minting mode: `RewardRemainder` is unused (kept for compile / legacy path).
+	type RewardRemainder = ResolveTo<DapStagingAccount, Balances>;
+	// Unlike Polkadot Asset Hub, which sends slashes to the DAP buffer, Kusama keeps paying them to
+	// the treasury, which also keeps its share of the issuance through `TreasuryBudgetRecipient`.
 	type Slash = ResolveTo<xcm_config::TreasuryAccount, Balances>;
 	type Reward = ();
 	type SessionsPerEra = SessionsPerEra;
 	type BondingDuration = BondingDuration;
 	type SlashDeferDuration = SlashDeferDuration;
 	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, StakingAdmin>;
-	type EraPayout = EraPayout;
+	// Non-minting mode: `EraPayout` is unused. Inflation is driven by pallet-dap via
+	// `IssuanceCurve` (implemented on `EraPayout` above).
+	type EraPayout = ();
 	type MaxExposurePageSize = MaxExposurePageSize;
 	type ElectionProvider = MultiBlockElection;
 	type VoterList = VoterList;
@@ -413,13 +480,11 @@ impl pallet_staking_async::Config for Runtime {
 	// This will start election for the next era as soon as an era starts.
 	type PlanningEraOffset = ConstU32<6>;
 	type RcClientInterface = StakingRcClient;
+	// Non-minting mode: `MaxEraDuration` is unused (legacy path only). Kept for compile.
 	type MaxEraDuration = MaxEraDuration;
-	// Legacy minting mode: Kusama keeps on-the-fly inflation via `EraPayout`.
-	type DisableMinting = ConstBool<false>;
-	// Unused in legacy minting mode (no era pots are created), but required to compile.
-	type UnclaimedRewardHandler = ();
-	// Unused in legacy minting mode
-	type RewardPots = pallet_staking_async::Seed<UnusedStakingPotsPalletId>;
+	type DisableMinting = ConstBool<true>;
+	type UnclaimedRewardHandler = Dap;
+	type RewardPots = pallet_staking_async::Seed<StakingPotsPalletId>;
 	type StakerRewardCalculator =
 		pallet_staking_async::reward::DefaultStakerRewardCalculator<Runtime>;
 	type WeightInfo = weights::pallet_staking_async::WeightInfo<Runtime>;
@@ -453,7 +518,7 @@ parameter_types! {
 
 impl pallet_staking_async_rc_client::Config for Runtime {
 	type RelayChainOrigin = EnsureRoot<AccountId>;
-	type AHStakingInterface = Staking;
+	type AHStakingInterface = pallet_staking_bridge_monitor::NoteSessionReports<Runtime, Staking>;
 	type SendToRelayChain = StakingXcmToRelayChain;
 	type MaxValidatorSetRetries = ConstU32<64>;
 	type ValidatorSetExportSession = ValidatorSetExportSession;
@@ -467,34 +532,14 @@ impl pallet_staking_async_rc_client::Config for Runtime {
 	type WeightInfo = weights::pallet_staking_async_rc_client::WeightInfo<Runtime>;
 }
 
-#[derive(Encode, Decode)]
-// Call indices taken from westend-next runtime.
-pub enum RelayChainRuntimePallets {
-	// Audit: index of `StakingAhClient` on the Relay Chain.
-	#[codec(index = 48)]
-	AhClient(AhClientCalls),
-}
-
-#[derive(Encode, Decode)]
-pub enum AhClientCalls {
-	// index of `fn validator_set` in `staking-async-ah-client`.
-	#[codec(index = 0)]
-	ValidatorSet(rc_client::ValidatorSetReport<AccountId>),
-	// index of `fn set_keys_from_ah` in `staking-async-ah-client`.
-	#[codec(index = 3)]
-	SetKeys { stash: AccountId, keys: Vec<u8> },
-	// index of `fn purge_keys_from_ah` in `staking-async-ah-client`.
-	#[codec(index = 4)]
-	PurgeKeys { stash: AccountId },
-}
-
 pub struct ValidatorSetToXcm;
 impl sp_runtime::traits::Convert<rc_client::ValidatorSetReport<AccountId>, Xcm<()>>
 	for ValidatorSetToXcm
 {
 	fn convert(report: rc_client::ValidatorSetReport<AccountId>) -> Xcm<()> {
 		rc_client::build_transact_xcm(
-			RelayChainRuntimePallets::AhClient(AhClientCalls::ValidatorSet(report)).encode(),
+			remote_calls::kusama::RelayCall::StakingAhClient(AhClientCall::ValidatorSet { report })
+				.encode(),
 		)
 	}
 }
@@ -502,13 +547,14 @@ impl sp_runtime::traits::Convert<rc_client::ValidatorSetReport<AccountId>, Xcm<(
 pub struct KeysMessageToXcm;
 impl sp_runtime::traits::Convert<rc_client::KeysMessage<AccountId>, Xcm<()>> for KeysMessageToXcm {
 	fn convert(msg: rc_client::KeysMessage<AccountId>) -> Xcm<()> {
-		let encoded_call = match msg {
+		let call = match msg {
 			rc_client::KeysMessage::SetKeys { stash, keys } =>
-				RelayChainRuntimePallets::AhClient(AhClientCalls::SetKeys { stash, keys }).encode(),
-			rc_client::KeysMessage::PurgeKeys { stash } =>
-				RelayChainRuntimePallets::AhClient(AhClientCalls::PurgeKeys { stash }).encode(),
+				AhClientCall::SetKeysFromAh { stash, keys },
+			rc_client::KeysMessage::PurgeKeys { stash } => AhClientCall::PurgeKeysFromAh { stash },
 		};
-		rc_client::build_transact_xcm(encoded_call)
+		rc_client::build_transact_xcm(
+			remote_calls::kusama::RelayCall::StakingAhClient(call).encode(),
+		)
 	}
 }
 
@@ -526,12 +572,15 @@ impl rc_client::SendToRelayChain for StakingXcmToRelayChain {
 	type Balance = Balance;
 
 	fn validator_set(report: rc_client::ValidatorSetReport<Self::AccountId>) -> Result<(), ()> {
-		rc_client::XCMSender::<
+		let id = report.id;
+		let result = rc_client::XCMSender::<
 			xcm_config::XcmRouter,
 			RelayLocation,
 			rc_client::ValidatorSetReport<Self::AccountId>,
 			ValidatorSetToXcm,
-		>::send(report)
+		>::send(report);
+		StakingBridgeMonitor::note_validator_set_sent(id, result.is_ok());
+		result
 	}
 
 	fn set_keys(
@@ -544,7 +593,7 @@ impl rc_client::SendToRelayChain for StakingXcmToRelayChain {
 				&RemoteKeysExecutionWeight::get(),
 			);
 
-		rc_client::XCMSender::<
+		let result = rc_client::XCMSender::<
 			xcm_config::XcmRouter,
 			RelayLocation,
 			rc_client::KeysMessage<Self::AccountId>,
@@ -560,7 +609,9 @@ impl rc_client::SendToRelayChain for StakingXcmToRelayChain {
 			stash,
 			max_delivery_and_remote_execution_fee,
 			execution_cost,
-		)
+		);
+		StakingBridgeMonitor::note_keys_sent(result.is_ok());
+		result
 	}
 
 	fn purge_keys(
@@ -572,7 +623,7 @@ impl rc_client::SendToRelayChain for StakingXcmToRelayChain {
 				&RemoteKeysExecutionWeight::get(),
 			);
 
-		rc_client::XCMSender::<
+		let result = rc_client::XCMSender::<
 			xcm_config::XcmRouter,
 			RelayLocation,
 			rc_client::KeysMessage<Self::AccountId>,
@@ -588,8 +639,45 @@ impl rc_client::SendToRelayChain for StakingXcmToRelayChain {
 			stash,
 			max_delivery_and_remote_execution_fee,
 			execution_cost,
-		)
+		);
+		StakingBridgeMonitor::note_keys_sent(result.is_ok());
+		result
+	}
+}
+
+/// The view of [`StakingBridgeMonitor`] on the state of [`StakingRcClient`].
+pub struct RcClientState;
+impl pallet_staking_bridge_monitor::StakingBridgeClient for RcClientState {
+	fn outgoing() -> Option<pallet_staking_bridge_monitor::Outgoing> {
+		rc_client::OutgoingValidatorSet::<Runtime>::get().map(|(report, retries_left)| {
+			pallet_staking_bridge_monitor::Outgoing {
+				message: pallet_staking_bridge_monitor::Message::ValidatorSet,
+				id: report.id,
+				retries_left,
+			}
+		})
 	}
+
+	fn latest_received() -> Option<(pallet_staking_bridge_monitor::Message, u32)> {
+		rc_client::LastSessionReportEndingIndex::<Runtime>::get()
+			.map(|index| (pallet_staking_bridge_monitor::Message::SessionReport, index))
+	}
+}
+
+parameter_types! {
+	/// Three relay chain sessions without a session report.
+	///
+	/// `RelaySessionDuration` is in relay chain blocks, the threshold is in Asset Hub blocks.
+	pub StakingBridgeSilenceThreshold: BlockNumber = (3 * RelaySessionDuration::get() as u64 *
+		kusama_runtime_constants::time::MILLISECS_PER_BLOCK /
+		system_parachains_constants::async_backing::MILLISECS_PER_BLOCK) as BlockNumber;
+}
+
+impl pallet_staking_bridge_monitor::Config for Runtime {
+	type Client = RcClientState;
+	type MaxSendFailures = ConstU32<3>;
+	type SilenceThreshold = StakingBridgeSilenceThreshold;
+	type WeightInfo = weights::pallet_staking_bridge_monitor::WeightInfo<Runtime>;
 }
 
 impl<C> frame_system::offchain::CreateTransactionBase<C> for Runtime
@@ -670,11 +758,27 @@ impl frame_support::traits::OnRuntimeUpgrade for InitiateStakingAsync {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use approx::assert_relative_eq;
 	use frame_election_provider_support::ElectionProvider;
-	use pallet_staking_async::EraPayout;
 	use sp_runtime::Percent;
+	use sp_staking::budget::IssuanceCurve as _;
 	use sp_weights::constants::{WEIGHT_PROOF_SIZE_PER_KB, WEIGHT_REF_TIME_PER_MILLIS};
 
+	const MILLISECONDS_PER_YEAR: u64 = 1000 * 3600 * 24 * 36525 / 100;
+	const ERA_DURATION_MILLIS: u64 = 6 * 60 * 60 * 1000;
+
+	// values taken from a recent Kusama snapshot.
+	const TOTAL_STAKED: Balance = 8085567183241128549;
+	const TOTAL_ISSUANCE: Balance = 17016510054564053390;
+
+	fn set_active_era_stake(staked: Balance) {
+		pallet_staking_async::ActiveEra::<Runtime>::put(pallet_staking_async::ActiveEraInfo {
+			index: 777,
+			start: None,
+		});
+		pallet_staking_async::ErasTotalStake::<Runtime>::insert(777, staked);
+	}
+
 	#[test]
 	fn inflation_sanity_check() {
 		// values taken from a recent Kusama snapshot:
@@ -685,27 +789,19 @@ mod tests {
 		// recent era paid: https://kusama.subscan.io/event/30011049-0
 		// 835 KSM / 291 KSM
 		sp_io::TestExternalities::new_empty().execute_with(|| {
-			let average_era_duration_millis = 6 * 60 * 60 * 1000; // 6h
-			let (staking, treasury) = super::EraPayout::era_payout(
-				8085567183241128549,
-				17016510054564053390,
-				average_era_duration_millis,
-			);
+			let (staking, treasury) =
+				EraPayout::split_payout(TOTAL_STAKED, TOTAL_ISSUANCE, ERA_DURATION_MILLIS);
 			assert_eq!(staking, 844_606070970705);
 			assert_eq!(treasury, 320_110565207524);
 
-			pallet_balances::TotalIssuance::<Runtime>::put(17016510054564053390u128);
-			pallet_staking_async::ActiveEra::<Runtime>::put(pallet_staking_async::ActiveEraInfo {
-				index: 777,
-				start: None,
-			});
-			pallet_staking_async::ErasTotalStake::<Runtime>::insert(777, 8085567183241128549u128);
+			pallet_balances::TotalIssuance::<Runtime>::put(TOTAL_ISSUANCE);
+			set_active_era_stake(TOTAL_STAKED);
 			let expected_issuance_parts = 99999999999999249;
 			assert_eq!(
-				super::EraPayout::impl_experimental_inflation_info(),
+				EraPayout::impl_experimental_inflation_info(),
 				InflationInfo {
 					issuance: Perquintill::from_parts(99999999999999249),
-					next_mint: (staking, treasury),
+					next_mint: (staking + treasury, 0),
 				}
 			);
 			// around 9% now
@@ -713,6 +809,88 @@ mod tests {
 		});
 	}
 
+	// A single era worth of DAP issuance is exactly what the legacy era payout minted.
+	#[test]
+	fn dap_era_emission_matches_legacy_era_payout() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			set_active_era_stake(TOTAL_STAKED);
+
+			let (staking, treasury) =
+				EraPayout::split_payout(TOTAL_STAKED, TOTAL_ISSUANCE, ERA_DURATION_MILLIS);
+			assert_eq!(EraPayout::issue(TOTAL_ISSUANCE, ERA_DURATION_MILLIS), staking + treasury);
+		});
+	}
+
+	// Minute-level drips over a year emit the same amount as the legacy 6h eras did.
+	#[test]
+	fn dap_yearly_emission_matches_legacy_era_payout() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			set_active_era_stake(TOTAL_STAKED);
+
+			let eras_per_year = MILLISECONDS_PER_YEAR / ERA_DURATION_MILLIS;
+			let legacy_yearly: Balance = (0..eras_per_year)
+				.map(|_| {
+					let (staking, treasury) =
+						EraPayout::split_payout(TOTAL_STAKED, TOTAL_ISSUANCE, ERA_DURATION_MILLIS);
+					staking + treasury
+				})
+				.sum();
+
+			let cadence = DapIssuanceCadence::get();
+			let drips_per_year = MILLISECONDS_PER_YEAR / cadence;
+			let dap_yearly: Balance =
+				(0..drips_per_year).map(|_| EraPayout::issue(TOTAL_ISSUANCE, cadence)).sum();
+
+			assert_relative_eq!(legacy_yearly as f64, dap_yearly as f64, max_relative = 0.000001);
+
+			// and both are `MaxInflation` of the total issuance.
+			let max_inflation = crate::dynamic_params::issuance::MaxInflation::get();
+			assert_relative_eq!(
+				dap_yearly as f64,
+				(max_inflation * TOTAL_ISSUANCE) as f64,
+				max_relative = 0.000001
+			);
+		});
+	}
+
+	// The staked ratio only changes the split of the legacy payout, never the total emission.
+	#[test]
+	fn dap_emission_independent_of_staked_ratio() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let emission_at = |staked: Balance| {
+				set_active_era_stake(staked);
+				EraPayout::issue(TOTAL_ISSUANCE, ERA_DURATION_MILLIS)
+			};
+
+			let reference = emission_at(TOTAL_STAKED);
+			assert_eq!(emission_at(0), reference);
+			assert_eq!(emission_at(TOTAL_ISSUANCE * 3 / 4), reference);
+			assert_eq!(emission_at(TOTAL_ISSUANCE), reference);
+		});
+	}
+
+	// `issue` takes the total of `split_payout` at zero stake, which is the same at any stake.
+	#[test]
+	fn split_payout_total_independent_of_stake() {
+		sp_io::TestExternalities::new_empty().execute_with(|| {
+			let emission = EraPayout::issue(TOTAL_ISSUANCE, ERA_DURATION_MILLIS);
+			let ideal = crate::dynamic_params::issuance::IdealStake::get() * TOTAL_ISSUANCE;
+
+			let splits =
+				[0, TOTAL_STAKED / 10, TOTAL_STAKED, ideal, TOTAL_ISSUANCE * 3 / 4, TOTAL_ISSUANCE]
+					.map(|staked| {
+						EraPayout::split_payout(staked, TOTAL_ISSUANCE, ERA_DURATION_MILLIS)
+					});
+			for (stakers, rest) in splits {
+				assert_eq!(stakers + rest, emission);
+			}
+
+			// while the split itself does depend on the stake.
+			assert!(splits[0].0 < splits[2].0);
+			assert!(splits[3].0 > splits[5].0);
+		});
+	}
+
 	#[test]
 	fn election_duration_less_than_session() {
 		// parameters of kusama are such that the election is intended to kick of at the start of
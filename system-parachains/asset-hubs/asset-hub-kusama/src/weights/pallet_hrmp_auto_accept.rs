@@ -0,0 +1,110 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_hrmp_auto_accept`
+//!
+//! These weights are NOT generated by the benchmark CLI, they are estimates to be replaced by the
+//! output of:
+//!
+//! frame-omni-bencher v1 benchmark pallet --extrinsic=* --pallet=pallet_hrmp_auto_accept
+//!
+//! The storage accesses are those of the calls and handlers, with the sending of the channel calls
+//! to the relay chain taken as the `ParachainSystem` upward message path. The reference time is a
+//! rough estimate of the execution, doubled as margin, and the proof size is the sum of the maximum
+//! encoded sizes of the storage items read, with 1000 bytes for the items without a maximum size.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_hrmp_auto_accept`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_hrmp_auto_accept::WeightInfo for WeightInfo<T> {
+	/// Storage: `HrmpAutoAccept::Policy` (r:0 w:1)
+	/// Proof: `HrmpAutoAccept::Policy` (`max_values`: Some(1), `max_size`: Some(54), added: 549, mode: `MaxEncodedLen`)
+	fn set_policy() -> Weight {
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `HrmpAutoAccept::Listings` (r:0 w:1)
+	/// Proof: `HrmpAutoAccept::Listings` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn set_listing() -> Weight {
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `HrmpAutoAccept::PendingAccepts` (r:1 w:1)
+	/// Proof: `HrmpAutoAccept::PendingAccepts` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `HrmpAutoAccept::Channels` (r:1 w:1)
+	/// Proof: `HrmpAutoAccept::Channels` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `HrmpAutoAccept::ChannelCount` (r:1 w:1)
+	/// Proof: `HrmpAutoAccept::ChannelCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn confirm_channel() -> Weight {
+		Weight::from_parts(40_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6481))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `HrmpAutoAccept::Policy` (r:1 w:0)
+	/// Proof: `HrmpAutoAccept::Policy` (`max_values`: Some(1), `max_size`: Some(54), added: 549, mode: `MaxEncodedLen`)
+	/// Storage: `HrmpAutoAccept::Channels` (r:2 w:1)
+	/// Proof: `HrmpAutoAccept::Channels` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `HrmpAutoAccept::Listings` (r:1 w:0)
+	/// Proof: `HrmpAutoAccept::Listings` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `HrmpAutoAccept::ChannelCount` (r:1 w:1)
+	/// Proof: `HrmpAutoAccept::ChannelCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::HostConfiguration` (r:1 w:0)
+	/// Proof: `ParachainSystem::HostConfiguration` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::PendingUpwardMessages` (r:1 w:1)
+	/// Proof: `ParachainSystem::PendingUpwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::UpwardDeliveryFeeFactor` (r:1 w:0)
+	/// Proof: `ParachainSystem::UpwardDeliveryFeeFactor` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `HrmpAutoAccept::PendingAccepts` (r:0 w:1)
+	/// Proof: `HrmpAutoAccept::PendingAccepts` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn on_open_request() -> Weight {
+		Weight::from_parts(94_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 11522))
+			.saturating_add(T::DbWeight::get().reads(8))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+	/// Storage: `HrmpAutoAccept::Channels` (r:1 w:1)
+	/// Proof: `HrmpAutoAccept::Channels` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn on_channel_accepted() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2491))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `ParachainInfo::ParachainId` (r:1 w:0)
+	/// Proof: `ParachainInfo::ParachainId` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `HrmpAutoAccept::Channels` (r:1 w:1)
+	/// Proof: `HrmpAutoAccept::Channels` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `HrmpAutoAccept::ChannelCount` (r:1 w:1)
+	/// Proof: `HrmpAutoAccept::ChannelCount` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `HrmpAutoAccept::PendingAccepts` (r:0 w:1)
+	/// Proof: `HrmpAutoAccept::PendingAccepts` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	fn on_channel_closing() -> Weight {
+		Weight::from_parts(32_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3489))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
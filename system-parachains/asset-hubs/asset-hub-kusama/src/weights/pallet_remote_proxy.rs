@@ -81,4 +81,23 @@ impl<T: frame_system::Config> pallet_remote_proxy::WeightInfo for WeightInfo<T>
 			.saturating_add(Weight::from_parts(0, 1846))
 			.saturating_add(T::DbWeight::get().reads(1))
 	}
+	/// Storage: `RemoteProxyRelayChain::BlockToRoot` (r:1 w:0)
+	/// Proof: `RemoteProxyRelayChain::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `RemoteProxyRelayChain::RegisteredProxies` (r:0 w:32)
+	/// Proof: `RemoteProxyRelayChain::RegisteredProxies` (`max_values`: None, `max_size`: Some(1257), added: 3732, mode: `MaxEncodedLen`)
+	/// The range of component `a` is `[1, 32]`.
+	/// The range of component `p` is `[0, 1048576]`.
+	///
+	/// NOT generated by the benchmark CLI, an estimate until the next run: twice
+	/// `remote_proxy` for building the proof database, twice the cost of a proof lookup per
+	/// account and about twice the cost of hashing per byte of proof. The proof size is that of
+	/// `BlockToRoot`.
+	fn register_remote_proxies_proof(a: u32, p: u32, ) -> Weight {
+		Weight::from_parts(35_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 856))
+			.saturating_add(Weight::from_parts(20_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(Weight::from_parts(4_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+	}
 }
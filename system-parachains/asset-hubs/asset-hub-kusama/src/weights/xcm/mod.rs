@@ -17,9 +17,18 @@
 mod pallet_xcm_benchmarks_fungible;
 mod pallet_xcm_benchmarks_generic;
 
-use crate::{xcm_config::MaxAssetsIntoHolding, Runtime};
+use crate::{
+	weights::{
+		pallet_hrmp_auto_accept::WeightInfo as HrmpAutoAcceptWeight,
+		pallet_xcm_asset_locker::WeightInfo as XcmAssetLockerWeight,
+	},
+	xcm_config::MaxAssetsIntoHolding,
+	Runtime,
+};
 use alloc::vec::Vec;
 use frame_support::BoundedVec;
+use pallet_hrmp_auto_accept::WeightInfo as _;
+use pallet_xcm_asset_locker::WeightInfo as _;
 use pallet_xcm_benchmarks_fungible::WeightInfo as XcmFungibleWeight;
 use pallet_xcm_benchmarks_generic::WeightInfo as XcmGeneric;
 use xcm::{
@@ -97,16 +106,13 @@ impl<Call> XcmWeightInfo<Call> for AssetHubKusamaXcmWeight<Call> {
 		_max_message_size: &u32,
 		_max_capacity: &u32,
 	) -> Weight {
-		// XCM Executor does not currently support HRMP channel operations
-		Weight::MAX
+		HrmpAutoAcceptWeight::<Runtime>::on_open_request()
 	}
 	fn hrmp_channel_accepted(_recipient: &u32) -> Weight {
-		// XCM Executor does not currently support HRMP channel operations
-		Weight::MAX
+		HrmpAutoAcceptWeight::<Runtime>::on_channel_accepted()
 	}
 	fn hrmp_channel_closing(_initiator: &u32, _sender: &u32, _recipient: &u32) -> Weight {
-		// XCM Executor does not currently support HRMP channel operations
-		Weight::MAX
+		HrmpAutoAcceptWeight::<Runtime>::on_channel_closing()
 	}
 	fn clear_origin() -> Weight {
 		XcmGeneric::<Runtime>::clear_origin()
@@ -210,10 +216,10 @@ impl<Call> XcmWeightInfo<Call> for AssetHubKusamaXcmWeight<Call> {
 		Weight::MAX
 	}
 	fn lock_asset(_: &Asset, _: &Location) -> Weight {
-		Weight::MAX
+		XcmAssetLockerWeight::<Runtime>::lock_asset()
 	}
 	fn unlock_asset(_: &Asset, _: &Location) -> Weight {
-		Weight::MAX
+		XcmAssetLockerWeight::<Runtime>::unlock_asset()
 	}
 	fn note_unlockable(_: &Asset, _: &Location) -> Weight {
 		Weight::MAX
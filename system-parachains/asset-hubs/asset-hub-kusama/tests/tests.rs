@@ -520,7 +520,7 @@ fn report_bridge_status_from_xcm_bridge_router_for_polkadot_works() {
 
 #[test]
 fn test_report_bridge_status_call_compatibility() {
-	// if this test fails, make sure `bp_asset_hub_polkadot` has valid encoding
+	// if this test fails, make sure `remote_calls::kusama::AssetHubCall` has valid encoding
 	assert_eq!(
 		RuntimeCall::ToPolkadotXcmRouter(
 			pallet_xcm_bridge_hub_router::Call::report_bridge_status {
@@ -529,8 +529,8 @@ fn test_report_bridge_status_call_compatibility() {
 			}
 		)
 		.encode(),
-		bp_asset_hub_kusama::Call::ToPolkadotXcmRouter(
-			bp_asset_hub_kusama::XcmBridgeHubRouterCall::report_bridge_status {
+		remote_calls::kusama::AssetHubCall::ToPolkadotXcmRouter(
+			remote_calls::XcmBridgeHubRouterCall::report_bridge_status {
 				bridge_id: Default::default(),
 				is_congested: true,
 			}
@@ -539,6 +539,88 @@ fn test_report_bridge_status_call_compatibility() {
 	)
 }
 
+#[test]
+fn remote_rc_client_calls_compatibility() {
+	// if this test fails, make sure `remote_calls::kusama::AssetHubCall` has valid encoding
+	use pallet_staking_async_rc_client as rc_client;
+	use remote_calls::{kusama::AssetHubCall, RcClientCall};
+
+	let report = rc_client::SessionReport {
+		end_index: 42,
+		validator_points: vec![(AccountId::from([1; 32]), 10)],
+		activation_timestamp: Some((1_000, 7)),
+		leftover: false,
+	};
+	assert_eq!(
+		RuntimeCall::StakingRcClient(rc_client::Call::relay_session_report {
+			report: report.clone()
+		})
+		.encode(),
+		AssetHubCall::StakingRcClient(RcClientCall::RelaySessionReport { report }).encode()
+	);
+
+	let offences = vec![(
+		42,
+		rc_client::Offence {
+			offender: AccountId::from([1; 32]),
+			reporters: vec![AccountId::from([2; 32])],
+			slash_fraction: sp_runtime::Perbill::from_percent(10),
+		},
+	)];
+	assert_eq!(
+		RuntimeCall::StakingRcClient(rc_client::Call::relay_new_offence_paged {
+			offences: offences.clone()
+		})
+		.encode(),
+		AssetHubCall::StakingRcClient(RcClientCall::RelayNewOffencePaged { offences }).encode()
+	);
+}
+
+#[test]
+fn remote_foreign_assets_calls_compatibility() {
+	// if this test fails, make sure `remote_calls::kusama::AssetHubCall` has valid encoding
+	use remote_calls::{
+		kusama::AssetHubCall, AccountLookup, ForeignAssetReserve, ForeignAssetsCall,
+	};
+
+	let id = Location::new(
+		1,
+		[Parachain(1001), PalletInstance(63), GeneralKey { length: 9, data: [1; 32] }],
+	);
+	let admin = AccountId::from([1; 32]);
+	assert_eq!(
+		RuntimeCall::ForeignAssets(pallet_assets::Call::create {
+			id: id.clone(),
+			admin: admin.clone().into(),
+			min_balance: 42,
+		})
+		.encode(),
+		AssetHubCall::ForeignAssets(ForeignAssetsCall::Create {
+			id: id.clone(),
+			admin: AccountLookup::Id(admin),
+			min_balance: 42,
+		})
+		.encode()
+	);
+
+	let reserve = Location::new(1, [Parachain(1001)]);
+	assert_eq!(
+		RuntimeCall::ForeignAssets(pallet_assets::Call::set_reserves {
+			id: id.clone(),
+			reserves: vec![ForeignAssetReserveData {
+				reserve: reserve.clone(),
+				teleportable: false
+			}],
+		})
+		.encode(),
+		AssetHubCall::ForeignAssets(ForeignAssetsCall::SetReserves {
+			id,
+			reserves: vec![ForeignAssetReserve { reserve, teleportable: false }],
+		})
+		.encode()
+	);
+}
+
 #[test]
 fn check_sane_weight_report_bridge_status() {
 	use pallet_xcm_bridge_hub_router::WeightInfo;
@@ -1311,3 +1393,159 @@ fn session_keys_are_compatible_between_ah_and_rc() {
 		"Session key type IDs must match between AssetHub and Kusama"
 	);
 }
+
+#[test]
+fn governance_reserves_of_foreign_assets_are_used_by_xcm_filters() {
+	use asset_hub_kusama_runtime::ForeignAssetReserves;
+	type IsReserve = <XcmConfig as xcm_executor::Config>::IsReserve;
+	type IsTeleporter = <XcmConfig as xcm_executor::Config>::IsTeleporter;
+
+	ExtBuilder::<Runtime>::default()
+		.with_collators(vec![AccountId::from(ALICE)])
+		.with_session_keys(vec![(
+			AccountId::from(ALICE),
+			AccountId::from(ALICE),
+			SessionKeys { aura: AuraId::from(sp_core::sr25519::Public::from_raw(ALICE)) },
+		)])
+		.build()
+		.execute_with(|| {
+			let sibling = Location::new(1, [Parachain(2_000)]);
+			let foreign_asset_id = Location::new(1, [Parachain(2_000), GeneralIndex(42)]);
+			let asset: Asset = (foreign_asset_id.clone(), 1_000).into();
+			assert_ok!(ForeignAssets::force_create(
+				RuntimeHelper::root_origin(),
+				foreign_asset_id.clone(),
+				AccountId::from(SOME_ASSET_ADMIN).into(),
+				false,
+				1
+			));
+			assert!(!IsReserve::contains(&asset, &sibling));
+			assert!(!IsTeleporter::contains(&asset, &sibling));
+
+			// Only governance can manage the reserves.
+			let reserve = ForeignAssetReserveData { reserve: sibling.clone(), teleportable: false };
+			assert!(ForeignAssetReserves::add_reserve(
+				RuntimeHelper::origin_of(AccountId::from(ALICE)),
+				foreign_asset_id.clone(),
+				reserve.clone(),
+			)
+			.is_err());
+
+			// Reserve transfers are accepted as soon as the reserve is added.
+			assert_ok!(ForeignAssetReserves::add_reserve(
+				RuntimeHelper::root_origin(),
+				foreign_asset_id.clone(),
+				reserve.clone(),
+			));
+			assert!(IsReserve::contains(&asset, &sibling));
+			assert!(!IsTeleporter::contains(&asset, &sibling));
+			assert!(!IsReserve::contains(&asset, &Location::new(1, [Parachain(2_001)])));
+
+			// Making the reserve teleportable switches to teleports.
+			let teleportable =
+				ForeignAssetReserveData { reserve: sibling.clone(), teleportable: true };
+			assert_ok!(ForeignAssetReserves::set_reserves(
+				RuntimeHelper::root_origin(),
+				foreign_asset_id.clone(),
+				vec![teleportable.clone()].try_into().unwrap(),
+			));
+			assert!(!IsReserve::contains(&asset, &sibling));
+			assert!(IsTeleporter::contains(&asset, &sibling));
+			assert!(frame_system::Pallet::<Runtime>::events().iter().any(|record| {
+				record.event ==
+					RuntimeEvent::ForeignAssetReserves(
+						pallet_foreign_asset_reserves::Event::ReservesSet {
+							asset_id: foreign_asset_id.clone(),
+							reserves: vec![teleportable.clone()],
+						},
+					)
+			}));
+
+			// Removing it stops both.
+			assert_ok!(ForeignAssetReserves::remove_reserve(
+				RuntimeHelper::root_origin(),
+				foreign_asset_id.clone(),
+				teleportable,
+			));
+			assert!(!IsReserve::contains(&asset, &sibling));
+			assert!(!IsTeleporter::contains(&asset, &sibling));
+		})
+}
+
+#[test]
+fn trust_backed_assets_are_frozen_by_xcm_locks() {
+	use asset_hub_kusama_runtime::XcmAssetLocker;
+	use pallet_assets::FrozenBalance;
+	use xcm_executor::traits::{AssetLock, Enact, LockError};
+
+	ExtBuilder::<Runtime>::default().build().execute_with(|| {
+		let asset_id: AssetIdForTrustBackedAssets = 1;
+		let alice = AccountId::from(ALICE);
+		let bob = AccountId::from(SOME_ASSET_ADMIN);
+		let unlocker = Location::new(1, [Parachain(2000)]);
+		let asset = |amount: Balance| -> Asset {
+			let location = TrustBackedAssetsPalletLocation::get()
+				.appended_with(GeneralIndex(asset_id.into()))
+				.unwrap();
+			(location, amount).into()
+		};
+		let owner: Location = AccountId32 { network: None, id: ALICE }.into();
+
+		assert_ok!(Assets::force_create(
+			RuntimeHelper::root_origin(),
+			asset_id.into(),
+			alice.clone().into(),
+			true,
+			1
+		));
+		assert_ok!(Assets::mint(
+			RuntimeHelper::origin_of(alice.clone()),
+			asset_id.into(),
+			alice.clone().into(),
+			1_000
+		));
+		// the existing balances are not frozen by the new `Freezer`.
+		assert_eq!(XcmAssetLocker::frozen_balance(asset_id, &alice), None);
+		assert!(!XcmAssetLocker::contains_freezes(asset_id));
+
+		// only allowed chains can lock assets.
+		assert_eq!(
+			XcmAssetLocker::prepare_lock(unlocker.clone(), asset(600), owner.clone()).err(),
+			Some(LockError::NotTrusted)
+		);
+		assert_ok!(XcmAssetLocker::allow_chain(
+			RuntimeOrigin::root(),
+			Box::new(unlocker.clone().into())
+		));
+		assert_ok!(XcmAssetLocker::prepare_lock(unlocker.clone(), asset(600), owner.clone())
+			.unwrap()
+			.enact());
+
+		// the locked balance can't be moved, and the asset can't be destroyed.
+		assert!(Assets::transfer(
+			RuntimeHelper::origin_of(alice.clone()),
+			asset_id.into(),
+			bob.clone().into(),
+			401
+		)
+		.is_err());
+		assert_ok!(Assets::transfer(
+			RuntimeHelper::origin_of(alice.clone()),
+			asset_id.into(),
+			bob.clone().into(),
+			400
+		));
+		assert!(Assets::start_destroy(RuntimeHelper::root_origin(), asset_id.into()).is_err());
+
+		// once unlocked, it is free again.
+		assert_ok!(XcmAssetLocker::prepare_unlock(unlocker, asset(600), owner).unwrap().enact());
+		assert_eq!(XcmAssetLocker::frozen_balance(asset_id, &alice), None);
+		assert_ok!(Assets::transfer(
+			RuntimeHelper::origin_of(alice),
+			asset_id.into(),
+			bob.into(),
+			600
+		));
+		assert_ok!(Assets::start_destroy(RuntimeHelper::root_origin(), asset_id.into()));
+	});
+}
@@ -21,30 +21,14 @@
 extern crate alloc;
 
 use alloc::vec::Vec;
-use codec::{Decode, Encode};
-use scale_info::TypeInfo;
+use codec::Encode;
 
-pub use bp_xcm_bridge_hub_router::XcmBridgeHubRouterCall;
+use remote_calls::polkadot::AssetHubCall;
+pub use remote_calls::XcmBridgeHubRouterCall;
 use xcm::latest::prelude::*;
 
 use system_parachains_constants::polkadot::currency::*;
 
-/// `AssetHubPolkadot` Runtime `Call` enum.
-///
-/// The enum represents a subset of possible `Call`s we can send to `AssetHubPolkadot` chain.
-/// Ideally this code would be auto-generated from metadata, because we want to
-/// avoid depending directly on the ENTIRE runtime just to get the encoding of `Dispatchable`s.
-///
-/// All entries here (like pretty much in the entire file) must be kept in sync with
-/// `AssetHubPolkadot` `construct_runtime`, so that we maintain SCALE-compatibility.
-#[allow(clippy::large_enum_variant)]
-#[derive(Encode, Decode, Debug, PartialEq, Eq, Clone, TypeInfo)]
-pub enum Call {
-	/// `ToKusamaXcmRouter` bridge pallet.
-	#[codec(index = 34)]
-	ToKusamaXcmRouter(XcmBridgeHubRouterCall),
-}
-
 frame_support::parameter_types! {
 	/// Some sane weight to execute `xcm::Transact(pallet-xcm-bridge-hub-router::Call::report_bridge_status)`.
 	pub const XcmBridgeHubRouterTransactCallMaxWeight: Weight = Weight::from_parts(200_000_000, 6144);
@@ -66,7 +50,7 @@ pub fn build_congestion_message<RuntimeCall>(
 		Transact {
 			origin_kind: OriginKind::Xcm,
 			fallback_max_weight: Some(XcmBridgeHubRouterTransactCallMaxWeight::get()),
-			call: Call::ToKusamaXcmRouter(XcmBridgeHubRouterCall::report_bridge_status {
+			call: AssetHubCall::ToKusamaXcmRouter(XcmBridgeHubRouterCall::report_bridge_status {
 				bridge_id,
 				is_congested,
 			})
@@ -68,6 +68,7 @@ pub mod bridge_to_ethereum_config;
 pub mod genesis_config_presets;
 pub mod governance;
 pub mod migrations;
+pub mod proxy_allowances;
 #[cfg(all(test, feature = "try-runtime"))]
 mod remote_tests;
 pub mod staking;
@@ -125,7 +126,7 @@ use frame_support::{
 		fungibles,
 		tokens::imbalance::{ResolveAssetTo, ResolveTo},
 		AsEnsureOriginWithArg, ConstBool, ConstU32, ConstU64, ConstU8, Contains, EitherOf,
-		EitherOfDiverse, Equals, InstanceFilter, LinearStoragePrice, NeverEnsureOrigin,
+		EitherOfDiverse, Equals, InsideBoth, InstanceFilter, LinearStoragePrice, NeverEnsureOrigin,
 		PrivilegeCmp, TransformOrigin, WithdrawReasons,
 	},
 	weights::{ConstantMultiplier, Weight},
@@ -262,7 +263,8 @@ impl Contains<RuntimeCall> for AllExceptReapStash {
 
 // Configure FRAME pallets to include in runtime.
 impl frame_system::Config for Runtime {
-	type BaseCallFilter = AllExceptReapStash;
+	type BaseCallFilter =
+		InsideBoth<AllExceptReapStash, pallet_proxy_allowances::ExcludeAllowanceProxies<Runtime>>;
 	type BlockWeights = RuntimeBlockWeights;
 	type BlockLength = RuntimeBlockLength;
 	type AccountId = AccountId;
@@ -423,7 +425,9 @@ impl pallet_assets::Config<TrustBackedAssetsInstance> for Runtime {
 	type MetadataDepositPerByte = MetadataDepositPerByte;
 	type ApprovalDeposit = ExistentialDeposit;
 	type StringLimit = AssetsStringLimit;
-	type Freezer = ();
+	// Assets locked for other chains through XCM. No migration is needed, nothing is frozen
+	// until the first `LockAsset`.
+	type Freezer = XcmAssetLocker;
 	type Holder = ();
 	type Extra = ();
 	type WeightInfo = weights::pallet_assets_local::WeightInfo<Runtime>;
@@ -489,6 +493,18 @@ impl pallet_assets::Config<ForeignAssetsInstance> for Runtime {
 	type BenchmarkHelper = assets_common::benchmarks::LocationAssetsBenchmarkHelper;
 }
 
+/// Governance can manage the reserves of foreign assets besides their owners.
+pub type ForeignAssetReservesAdminOrigin =
+	EitherOf<EnsureRoot<AccountId>, EitherOf<FellowshipAdmin, GeneralAdmin>>;
+
+impl pallet_foreign_asset_reserves::Config<ForeignAssetsInstance> for Runtime {
+	type AdminOrigin = ForeignAssetReservesAdminOrigin;
+	type MaxReserves = ConstU32<5>;
+	type WeightInfo = weights::pallet_foreign_asset_reserves::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ForeignAssetReservesBenchmarkHelper;
+}
+
 parameter_types! {
 	// One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
 	pub const MultisigDepositBase: Balance = system_para_deposit(1, 88);
@@ -593,11 +609,13 @@ pub enum ProxyType {
 	NominationPools,
 	/// To be used with the remote proxy pallet to manage parachain lease auctions on the relay.
 	///
-	/// This variant cannot do anything on Asset Hub itself.
+	/// This variant cannot do anything on Asset Hub itself. It is honoured on the relay by
+	/// `RemoteProxyAssetHub`.
 	Auction,
 	/// To be used with the remote proxy pallet to manage parachain registration on the relay.
 	///
-	/// This variant cannot do anything on Asset Hub itself.
+	/// This variant cannot do anything on Asset Hub itself. It is honoured on the relay by
+	/// `RemoteProxyAssetHub`.
 	ParaRegistration,
 	/// Operator proxy for validators. Can perform operational tasks: validating, chilling,
 	/// kicking, and managing session keys. Cannot bond/unbond funds, change reward
@@ -606,6 +624,11 @@ pub enum ProxyType {
 	/// Contains `Staking` (validate, chill, kick), `StakingRcClient` (set_keys, purge_keys),
 	/// and `Utility` batching calls (batch, batch_all, force_batch).
 	StakingOperator,
+	/// Can spend an allowance of assets per period, set by the proxied account.
+	///
+	/// This variant cannot do anything through `Proxy`. Its calls are dispatched and metered by
+	/// `ProxyAllowances`.
+	SpendingAllowance,
 }
 
 impl InstanceFilter<RuntimeCall> for ProxyType {
@@ -704,6 +727,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				)
 			},
 			ProxyType::ParaRegistration => false, // Only for remote proxy
+			ProxyType::SpendingAllowance => false, // Only for proxy allowances
 			// AH specific proxy types that are not on the Relay:
 			ProxyType::Assets => {
 				matches!(
@@ -798,7 +822,10 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 			(ProxyType::Staking, ProxyType::StakingOperator) => true,
 			(
 				ProxyType::NonTransfer,
-				ProxyType::Assets | ProxyType::AssetOwner | ProxyType::AssetManager,
+				ProxyType::Assets |
+				ProxyType::AssetOwner |
+				ProxyType::AssetManager |
+				ProxyType::SpendingAllowance,
 			) => false,
 			(ProxyType::NonTransfer, _) => true,
 			_ => false,
@@ -921,6 +948,111 @@ impl cumulus_pallet_xcmp_queue::migration::v5::V5Config for Runtime {
 	type ChannelList = ParachainSystem;
 }
 
+/// Encodes the channel calls of the relay chain `Hrmp` pallet.
+pub struct HrmpRelayCalls;
+impl pallet_hrmp_auto_accept::RelayHrmpCalls for HrmpRelayCalls {
+	fn init_open_channel(
+		recipient: ParaId,
+		proposed_max_capacity: u32,
+		proposed_max_message_size: u32,
+	) -> Vec<u8> {
+		remote_calls::polkadot::RelayCall::Hrmp(remote_calls::HrmpCall::HrmpInitOpenChannel {
+			recipient,
+			proposed_max_capacity,
+			proposed_max_message_size,
+		})
+		.encode()
+	}
+
+	fn accept_open_channel(sender: ParaId) -> Vec<u8> {
+		remote_calls::polkadot::RelayCall::Hrmp(remote_calls::HrmpCall::HrmpAcceptOpenChannel {
+			sender,
+		})
+		.encode()
+	}
+}
+
+/// The parachains with an open channel to Asset Hub, as last reported by the relay chain.
+pub struct IngressChannels;
+impl Contains<ParaId> for IngressChannels {
+	fn contains(para: &ParaId) -> bool {
+		cumulus_pallet_parachain_system::RelevantMessagingState::<Runtime>::get()
+			.is_some_and(|state| state.ingress_channels.iter().any(|(sender, _)| sender == para))
+	}
+}
+
+impl pallet_hrmp_auto_accept::Config for Runtime {
+	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, GeneralAdmin>;
+	type SelfParaId = ParachainInfo;
+	type RelayCalls = HrmpRelayCalls;
+	type XcmSender = xcm_config::XcmRouter;
+	type IngressChannels = IngressChannels;
+	type AcceptTimeout = ConstU32<{ 24 * HOURS }>;
+	type WeightInfo = weights::pallet_hrmp_auto_accept::WeightInfo<Runtime>;
+}
+
+parameter_types! {
+	pub const XcmLockerMaxChains: u32 = 64;
+	pub const XcmLockerMaxLocks: u32 = 8;
+}
+
+impl pallet_xcm_asset_locker::Config for Runtime {
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type NativeBalance = Balances;
+	type NativeMatcher = xcm_builder::IsConcrete<xcm_config::DotLocation>;
+	type Assets = Assets;
+	type AssetsMatcher = xcm_config::TrustBackedAssetsConvertedConcreteId;
+	type LocationToAccountId = xcm_config::LocationToAccountId;
+	type RemoteLocks = PolkadotXcm;
+	type AdminOrigin = EitherOf<EnsureRoot<AccountId>, GeneralAdmin>;
+	type MaxChains = XcmLockerMaxChains;
+	type MaxLocks = XcmLockerMaxLocks;
+	type WeightInfo = weights::pallet_xcm_asset_locker::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = XcmAssetLockerBenchmarkHelper;
+}
+
+/// Encodes the coretime calls of the Coretime Chain `Broker` pallet.
+pub struct CoretimeBrokerCalls;
+impl pallet_coretime_purchase::BrokerCalls for CoretimeBrokerCalls {
+	fn purchase(price_limit: Balance) -> Vec<u8> {
+		remote_calls::polkadot::CoretimeChainCall::Broker(remote_calls::BrokerCall::Purchase {
+			price_limit,
+		})
+		.encode()
+	}
+
+	fn renew(core: u16) -> Vec<u8> {
+		remote_calls::polkadot::CoretimeChainCall::Broker(remote_calls::BrokerCall::Renew { core })
+			.encode()
+	}
+}
+
+parameter_types! {
+	pub CoretimeLocation: Location =
+		Location::new(1, Parachain(polkadot_runtime_constants::system_parachain::BROKER_ID));
+	// Covers the execution of the order on the Coretime Chain and of a refund back to here.
+	pub const CoretimePurchaseRemoteFee: Balance = 10 * CENTS;
+	pub const CoretimePurchaseDeliveryFee: Balance = 5 * CENTS;
+}
+
+impl pallet_coretime_purchase::Config for Runtime {
+	type AssetKind = Location;
+	type NativeAsset = DotLocation;
+	type Swap = AssetConversion;
+	type AccountIdToLocation =
+		xcm_builder::AliasesIntoAccountId32<xcm_config::RelayNetwork, AccountId>;
+	type XcmExecutor = xcm_executor::XcmExecutor<xcm_config::XcmConfig>;
+	type CoretimeLocation = CoretimeLocation;
+	type SelfLocation = xcm_config::AssetHubLocation;
+	type BrokerCalls = CoretimeBrokerCalls;
+	type RemoteFee = CoretimePurchaseRemoteFee;
+	type DeliveryFee = CoretimePurchaseDeliveryFee;
+	type WeightInfo = weights::pallet_coretime_purchase::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = CoretimePurchaseBenchmarkHelper;
+}
+
 parameter_types! {
 	pub const Period: u32 = 6 * HOURS;
 	pub const Offset: u32 = 0;
@@ -1550,12 +1682,15 @@ construct_runtime!(
 		ToKusamaXcmRouter: pallet_xcm_bridge_hub_router::<Instance1> = 34,
 		MessageQueue: pallet_message_queue = 35,
 		SnowbridgeSystemFrontend: snowbridge_pallet_system_frontend = 36,
+		HrmpAutoAccept: pallet_hrmp_auto_accept = 37,
+		XcmAssetLocker: pallet_xcm_asset_locker = 38,
 
 		// Handy utilities.
 		Utility: pallet_utility = 40,
 		Multisig: pallet_multisig = 41,
 		Proxy: pallet_proxy = 42,
 		Indices: pallet_indices = 43,
+		ProxyAllowances: pallet_proxy_allowances = 44,
 
 		Assets: pallet_assets::<Instance1> = 50,
 		Uniques: pallet_uniques = 51,
@@ -1563,6 +1698,8 @@ construct_runtime!(
 		ForeignAssets: pallet_assets::<Instance2> = 53,
 		PoolAssets: pallet_assets::<Instance3> = 54,
 		AssetConversion: pallet_asset_conversion = 55,
+		CoretimePurchase: pallet_coretime_purchase = 56,
+		ForeignAssetReserves: pallet_foreign_asset_reserves::<Instance2> = 57,
 
 		// OpenGov stuff
 		Treasury: pallet_treasury = 60,
@@ -1580,6 +1717,7 @@ construct_runtime!(
 
 		// Staking in the 80s
 		NominationPools: pallet_nomination_pools = 80,
+		StakingBridgeMonitor: pallet_staking_bridge_monitor = 81,
 		VoterList: pallet_bags_list::<Instance1> = 82,
 		DelegatedStaking: pallet_delegated_staking = 83,
 		StakingRcClient: pallet_staking_async_rc_client = 84,
@@ -1737,6 +1875,52 @@ impl
 	}
 }
 
+#[cfg(feature = "runtime-benchmarks")]
+pub struct CoretimePurchaseBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_coretime_purchase::BenchmarkHelper<Location, AccountId>
+	for CoretimePurchaseBenchmarkHelper
+{
+	fn setup_buyer(who: &AccountId) -> Location {
+		use pallet_asset_conversion_tx_payment::BenchmarkHelperTrait;
+
+		let (asset_id, _) = AssetConversionTxHelper::create_asset_id_parameter(1);
+		AssetConversionTxHelper::setup_balances_and_pool(asset_id.clone(), who.clone());
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(
+			polkadot_runtime_constants::system_parachain::BROKER_ID.into(),
+		);
+		asset_id
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct ForeignAssetReservesBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_foreign_asset_reserves::BenchmarkHelper<ForeignAssetReserveData>
+	for ForeignAssetReservesBenchmarkHelper
+{
+	fn reserve(index: u32) -> ForeignAssetReserveData {
+		(Location::new(1, Parachain(2_000 + index)), false).into()
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct XcmAssetLockerBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_xcm_asset_locker::BenchmarkHelper for XcmAssetLockerBenchmarkHelper {
+	fn lockable() -> (Location, Asset) {
+		use frame_support::traits::fungible::Mutate;
+
+		let who = AccountId::from([42u8; 32]);
+		Balances::set_balance(&who, 1_000 * UNITS);
+		let owner = AccountId32 { network: None, id: who.into() }.into();
+		(owner, (xcm_config::DotLocation::get(), 100 * UNITS).into())
+	}
+}
+
 #[cfg(feature = "runtime-benchmarks")]
 type StakingRcClientBench<T> = pallet_staking_async_rc_client::benchmarking::Pallet<T>;
 
@@ -1754,10 +1938,13 @@ mod benches {
 		[pallet_assets, Local]
 		[pallet_assets, Foreign]
 		[pallet_assets, Pool]
+		[pallet_foreign_asset_reserves, ForeignAssetReserves]
 		[pallet_assets_precompiles, AssetsPrecompiles]
 		[pallet_asset_conversion, AssetConversion]
 		[pallet_asset_conversion_tx_payment, AssetTxPayment]
 		[pallet_balances, Balances]
+		[pallet_coretime_purchase, CoretimePurchase]
+		[pallet_hrmp_auto_accept, HrmpAutoAccept]
 		[pallet_indices, Indices]
 		[pallet_message_queue, MessageQueue]
 		[pallet_migrations, MultiBlockMigrations]
@@ -1765,6 +1952,7 @@ mod benches {
 		[pallet_nfts, Nfts]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
+		[pallet_proxy_allowances, ProxyAllowances]
 		[pallet_scheduler, Scheduler]
 		[pallet_parameters, Parameters]
 		[pallet_session, SessionBench::<Runtime>]
@@ -1794,6 +1982,7 @@ mod benches {
 
 		// XCM
 		[pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
+		[pallet_xcm_asset_locker, XcmAssetLocker]
 		// Bridges
 		[pallet_xcm_bridge_hub_router, ToKusama]
 		[snowbridge_pallet_system_frontend, SnowbridgeSystemFrontend]
@@ -1804,6 +1993,7 @@ mod benches {
 		// Staking
 		[pallet_staking_async, Staking]
 		[pallet_staking_async_rc_client, StakingRcClientBench::<Runtime>]
+		[pallet_staking_bridge_monitor, StakingBridgeMonitor]
 		[pallet_bags_list, VoterList]
 		[pallet_dap, Dap]
 		// DelegatedStaking has no calls
@@ -2709,10 +2899,23 @@ pallet_revive::impl_runtime_apis_plus_revive_traits!(
 		}
 	}
 
+	impl pallet_staking_bridge_monitor::runtime_api::StakingBridgeHealthApi<Block, BlockNumber> for Runtime {
+		fn staking_bridge_health() -> pallet_staking_bridge_monitor::StakingBridgeHealth<BlockNumber> {
+			StakingBridgeMonitor::health()
+		}
+	}
+
+	#[api_version(2)]
 	impl system_parachains_common::apis::Inflation<Block> for Runtime {
 		fn experimental_issuance_prediction_info() -> system_parachains_common::apis::InflationInfo {
 			crate::staking::EraPayout::impl_experimental_inflation_info()
 		}
+
+		fn experimental_issuance_schedule(
+			horizon: BlockNumber,
+		) -> Vec<system_parachains_common::apis::IssuancePeriod> {
+			crate::staking::EraPayout::impl_experimental_issuance_schedule(horizon)
+		}
 	}
 
 	#[cfg(feature = "try-runtime")]
@@ -2891,11 +3094,13 @@ mod tests {
 		// Assets IS supertype of AssetOwner and AssetManager
 		assert!(ProxyType::Assets.is_superset(&ProxyType::AssetOwner));
 		assert!(ProxyType::Assets.is_superset(&ProxyType::AssetManager));
-		// NonTransfer is NOT supertype of Any, Assets, AssetOwner and AssetManager
+		// NonTransfer is NOT supertype of Any, Assets, AssetOwner, AssetManager and
+		// SpendingAllowance
 		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::Any));
 		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::Assets));
 		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::AssetOwner));
 		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::AssetManager));
+		assert!(!ProxyType::NonTransfer.is_superset(&ProxyType::SpendingAllowance));
 		// NonTransfer is supertype of remaining stuff
 		assert!(ProxyType::NonTransfer.is_superset(&ProxyType::CancelProxy));
 		assert!(ProxyType::NonTransfer.is_superset(&ProxyType::Collator));
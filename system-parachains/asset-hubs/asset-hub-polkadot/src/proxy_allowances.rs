@@ -0,0 +1,219 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Spending allowances for [`ProxyType::SpendingAllowance`] proxies.
+//!
+//! Allowances are set per asset, identified by its location as seen from Asset Hub, and measured
+//! in relay chain blocks. The following calls are metered:
+//!
+//! - Transfers of DOT, trust backed assets and foreign assets, including `transfer_all`.
+//! - XCM transfers, for all the fungible assets transferred. Delivery fees are not metered.
+//! - Batches of the `Utility` pallet, whose calls are metered one by one.
+//!
+//! Any other call is rejected.
+
+use crate::{
+	xcm_config::{DotLocation, TrustBackedAssetsPalletIndex},
+	AccountId, Balance, Balances, ForeignAssetsInstance, ProxyType, Runtime, RuntimeCall,
+	RuntimeHoldReason, TrustBackedAssetsCall,
+};
+use alloc::{vec, vec::Vec};
+use frame_support::{
+	parameter_types,
+	traits::{
+		fungible, fungibles,
+		tokens::{Fortitude::Polite, Preservation},
+		Get,
+	},
+	weights::Weight,
+};
+use pallet_proxy_allowances::InspectSpending;
+use system_parachains_constants::polkadot::currency::system_para_deposit;
+use xcm::{
+	latest::{
+		Assets as XcmAssets,
+		Fungibility::Fungible,
+		Junction::{GeneralIndex, PalletInstance},
+		Location,
+	},
+	VersionedAssets,
+};
+
+parameter_types! {
+	pub const AllowanceProxyType: ProxyType = ProxyType::SpendingAllowance;
+	pub const MaxAllowances: u32 = 16;
+	// The maximum encoded size of an allowance.
+	pub const AllowanceDeposit: Balance = system_para_deposit(0, 645);
+}
+
+type ForeignAssetsCall = pallet_assets::Call<Runtime, ForeignAssetsInstance>;
+
+fn preservation(keep_alive: bool) -> Preservation {
+	if keep_alive {
+		Preservation::Preserve
+	} else {
+		Preservation::Expendable
+	}
+}
+
+/// Location of a trust backed asset.
+fn trust_backed_asset(id: u32) -> Location {
+	Location::new(0, [PalletInstance(TrustBackedAssetsPalletIndex::get()), GeneralIndex(id.into())])
+}
+
+/// The fungible assets of an XCM transfer, or `None` if non-fungible assets are transferred.
+fn fungible_assets(assets: &VersionedAssets) -> Option<Vec<(Location, Balance)>> {
+	XcmAssets::try_from(assets.clone())
+		.ok()?
+		.into_inner()
+		.into_iter()
+		.map(|asset| match asset.fun {
+			Fungible(amount) => Some((asset.id.0, amount)),
+			_ => None,
+		})
+		.collect()
+}
+
+/// Inspects the assets spent by the calls of spending allowance proxies.
+pub struct AssetHubSpending;
+impl InspectSpending<RuntimeCall, AccountId, Location, Balance> for AssetHubSpending {
+	fn spending(who: &AccountId, call: &RuntimeCall) -> Option<Vec<(Location, Balance)>> {
+		let spent = match call {
+			RuntimeCall::Balances(
+				pallet_balances::Call::transfer_allow_death { value, .. } |
+				pallet_balances::Call::transfer_keep_alive { value, .. },
+			) => vec![(DotLocation::get(), *value)],
+			RuntimeCall::Balances(pallet_balances::Call::transfer_all { keep_alive, .. }) =>
+				vec![(
+					DotLocation::get(),
+					<Balances as fungible::Inspect<_>>::reducible_balance(
+						who,
+						preservation(*keep_alive),
+						Polite,
+					),
+				)],
+			RuntimeCall::Assets(
+				TrustBackedAssetsCall::transfer { id, amount, .. } |
+				TrustBackedAssetsCall::transfer_keep_alive { id, amount, .. },
+			) => vec![(trust_backed_asset(id.0), *amount)],
+			RuntimeCall::Assets(TrustBackedAssetsCall::transfer_all { id, keep_alive, .. }) =>
+				vec![(
+					trust_backed_asset(id.0),
+					<crate::Assets as fungibles::Inspect<_>>::reducible_balance(
+						id.0,
+						who,
+						preservation(*keep_alive),
+						Polite,
+					),
+				)],
+			RuntimeCall::ForeignAssets(
+				ForeignAssetsCall::transfer { id, amount, .. } |
+				ForeignAssetsCall::transfer_keep_alive { id, amount, .. },
+			) => vec![(id.clone(), *amount)],
+			RuntimeCall::ForeignAssets(ForeignAssetsCall::transfer_all {
+				id, keep_alive, ..
+			}) => vec![(
+				id.clone(),
+				<crate::ForeignAssets as fungibles::Inspect<_>>::reducible_balance(
+					id.clone(),
+					who,
+					preservation(*keep_alive),
+					Polite,
+				),
+			)],
+			RuntimeCall::PolkadotXcm(
+				pallet_xcm::Call::transfer_assets { assets, .. } |
+				pallet_xcm::Call::limited_reserve_transfer_assets { assets, .. } |
+				pallet_xcm::Call::limited_teleport_assets { assets, .. } |
+				pallet_xcm::Call::transfer_assets_using_type_and_then { assets, .. },
+			) => fungible_assets(assets)?,
+			_ => return None,
+		};
+		Some(spent)
+	}
+
+	fn spending_weight() -> Weight {
+		// `Asset` and `Account` for the reducible balance of `transfer_all` of the assets pallets.
+		<Runtime as frame_system::Config>::DbWeight::get().reads(2)
+	}
+
+	fn nested_calls(call: &RuntimeCall) -> Option<&[RuntimeCall]> {
+		match call {
+			RuntimeCall::Utility(
+				pallet_utility::Call::batch { calls } |
+				pallet_utility::Call::batch_all { calls } |
+				pallet_utility::Call::force_batch { calls },
+			) => Some(calls),
+			_ => None,
+		}
+	}
+
+	fn nested_calls_mut(call: &mut RuntimeCall) -> Option<&mut Vec<RuntimeCall>> {
+		match call {
+			RuntimeCall::Utility(
+				pallet_utility::Call::batch { calls } |
+				pallet_utility::Call::batch_all { calls } |
+				pallet_utility::Call::force_batch { calls },
+			) => Some(calls),
+			_ => None,
+		}
+	}
+}
+
+impl pallet_proxy_allowances::Config for Runtime {
+	type AssetKind = Location;
+	type Balance = Balance;
+	type AllowanceProxyType = AllowanceProxyType;
+	type Spending = AssetHubSpending;
+	type MaxAllowances = MaxAllowances;
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type AllowanceDeposit = AllowanceDeposit;
+	type WeightInfo = crate::weights::pallet_proxy_allowances::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = benchmarks::ProxyAllowancesBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarks {
+	use super::*;
+	use frame_support::traits::fungible::Mutate;
+	use system_parachains_constants::polkadot::currency::UNITS;
+
+	/// Spends DOT in the benchmarks.
+	pub struct ProxyAllowancesBenchmarkHelper;
+	impl pallet_proxy_allowances::BenchmarkHelper<AccountId, Location, RuntimeCall>
+		for ProxyAllowancesBenchmarkHelper
+	{
+		fn asset(index: u32) -> Location {
+			match index {
+				0 => DotLocation::get(),
+				id => trust_backed_asset(id),
+			}
+		}
+
+		fn spending_call() -> RuntimeCall {
+			RuntimeCall::Balances(pallet_balances::Call::transfer_keep_alive {
+				dest: AccountId::from([42u8; 32]).into(),
+				value: crate::ExistentialDeposit::get(),
+			})
+		}
+
+		fn fund(who: &AccountId) {
+			Balances::set_balance(who, 1_000 * UNITS);
+		}
+	}
+}
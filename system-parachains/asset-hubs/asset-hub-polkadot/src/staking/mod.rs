@@ -32,6 +32,7 @@ use frame_support::{
 use pallet_election_provider_multi_block::{self as multi_block, SolutionAccuracyOf};
 use pallet_staking_async::UseValidatorsMap;
 use pallet_staking_async_rc_client as rc_client;
+use remote_calls::AhClientCall;
 use sp_arithmetic::FixedU128;
 use sp_runtime::{
 	generic,
@@ -41,7 +42,7 @@ use sp_runtime::{
 };
 use sp_staking::SessionIndex;
 use stepped_curve::*;
-use system_parachains_common::apis::InflationInfo;
+use system_parachains_common::apis::{InflationInfo, IssuancePeriod};
 use xcm::v5::prelude::*;
 
 // stuff aliased to `parameters` pallet.
@@ -340,8 +341,11 @@ impl EraPayout {
 	/// The TI at the time of `HARD_CAP_START`.
 	pub const MARCH_2026_TI: Balance = 16_743_421_533_310_057_487;
 
-	// The yearly emission post hard pressure enactment.
-	fn yearly_after_hard_cap(relay_block_num: BlockNumber) -> Balance {
+	// The maximum number of two year periods reported by the issuance schedule (128 years).
+	const MAX_SCHEDULE_PERIODS: u32 = 64;
+
+	// The TI curve post hard pressure enactment, in relay chain blocks.
+	fn ti_curve() -> Option<SteppedCurve> {
 		let march_14_2026_ti = FixedU128::saturating_from_integer(Self::MARCH_2026_TI);
 		let target_ti = FixedU128::saturating_from_integer(Self::HARD_CAP_TARGET);
 
@@ -349,12 +353,11 @@ impl EraPayout {
 		// 2026.
 		let two_years_before_march =
 			FixedU128::saturating_from_integer(Self::HARD_CAP_START - (2 * RC_YEARS));
-		let relay_block_fp = FixedU128::saturating_from_integer(relay_block_num);
 		let step_duration = FixedU128::saturating_from_integer(2 * RC_YEARS);
 
 		let two_year_rate = Self::BI_ANNUAL_RATE;
 
-		let Ok(ti_curve) = SteppedCurve::try_new(
+		SteppedCurve::try_new(
 			// The start date of the curve.
 			two_years_before_march,
 			// The initial value of the curve.
@@ -363,9 +366,14 @@ impl EraPayout {
 			RemainingPct { target: target_ti, pct: two_year_rate },
 			// Step every two years.
 			step_duration,
-		) else {
-			return 0
-		};
+		)
+		.ok()
+	}
+
+	// The yearly emission post hard pressure enactment.
+	fn yearly_after_hard_cap(relay_block_num: BlockNumber) -> Balance {
+		let Some(ti_curve) = Self::ti_curve() else { return 0 };
+		let relay_block_fp = FixedU128::saturating_from_integer(relay_block_num);
 
 		// The last step size tells us the expected TI increase over the current two year
 		// period.
@@ -374,6 +382,34 @@ impl EraPayout {
 		FixedU128::from_rational(1, 2).saturating_mul_int(two_year_emission)
 	}
 
+	/// Projected issuance over the next `horizon` relay chain blocks, one entry per two year
+	/// period of the TI curve.
+	pub(crate) fn impl_experimental_issuance_schedule(horizon: BlockNumber) -> Vec<IssuancePeriod> {
+		let Some(ti_curve) = Self::ti_curve() else { return Vec::new() };
+		let now = <RelaychainDataProvider<Runtime> as BlockNumberProvider>::current_block_number();
+		let until = now.saturating_add(horizon);
+
+		let to_int = |x: FixedU128| x.into_inner() / FixedU128::DIV;
+		ti_curve
+			.periods(
+				FixedU128::saturating_from_integer(now),
+				FixedU128::saturating_from_integer(until),
+				Self::MAX_SCHEDULE_PERIODS,
+			)
+			.into_iter()
+			.map(|period| {
+				let total_issuance: Balance = to_int(period.value);
+				IssuancePeriod {
+					start: to_int(period.start).saturated_into(),
+					end: to_int(period.end).saturated_into(),
+					emission: to_int(period.step_size),
+					total_issuance,
+					remaining_to_cap: Self::HARD_CAP_TARGET.saturating_sub(total_issuance),
+				}
+			})
+			.collect()
+	}
+
 	/// Expected inflation in the next era.
 	pub(crate) fn impl_experimental_inflation_info() -> InflationInfo {
 		// We assume un-delayed 24h eras.
@@ -500,7 +536,7 @@ parameter_types! {
 
 impl pallet_staking_async_rc_client::Config for Runtime {
 	type RelayChainOrigin = EnsureRoot<AccountId>;
-	type AHStakingInterface = Staking;
+	type AHStakingInterface = pallet_staking_bridge_monitor::NoteSessionReports<Runtime, Staking>;
 	type SendToRelayChain = StakingXcmToRelayChain;
 	type MaxValidatorSetRetries = ConstU32<64>;
 	type ValidatorSetExportSession = ValidatorSetExportSession;
@@ -514,32 +550,14 @@ impl pallet_staking_async_rc_client::Config for Runtime {
 	type WeightInfo = weights::pallet_staking_async_rc_client::WeightInfo<Runtime>;
 }
 
-#[derive(Encode, Decode)]
-// Call indices taken from westend-next runtime.
-pub enum RelayChainRuntimePallets {
-	// Audit: index of `StakingAhClient` on the Relay Chain.
-	#[codec(index = 42)]
-	AhClient(AhClientCalls),
-}
-
-#[derive(Encode, Decode)]
-pub enum AhClientCalls {
-	// index of `fn validator_set` in `staking-async-ah-client`.
-	#[codec(index = 0)]
-	ValidatorSet(rc_client::ValidatorSetReport<AccountId>),
-	// index of `fn set_keys_from_ah` in `staking-async-ah-client`.
-	#[codec(index = 3)]
-	SetKeys { stash: AccountId, keys: Vec<u8> },
-	// index of `fn purge_keys_from_ah` in `staking-async-ah-client`.
-	#[codec(index = 4)]
-	PurgeKeys { stash: AccountId },
-}
-
 pub struct ValidatorSetToXcm;
 impl Convert<rc_client::ValidatorSetReport<AccountId>, Xcm<()>> for ValidatorSetToXcm {
 	fn convert(report: rc_client::ValidatorSetReport<AccountId>) -> Xcm<()> {
 		rc_client::build_transact_xcm(
-			RelayChainRuntimePallets::AhClient(AhClientCalls::ValidatorSet(report)).encode(),
+			remote_calls::polkadot::RelayCall::StakingAhClient(AhClientCall::ValidatorSet {
+				report,
+			})
+			.encode(),
 		)
 	}
 }
@@ -547,13 +565,14 @@ impl Convert<rc_client::ValidatorSetReport<AccountId>, Xcm<()>> for ValidatorSet
 pub struct KeysMessageToXcm;
 impl Convert<rc_client::KeysMessage<AccountId>, Xcm<()>> for KeysMessageToXcm {
 	fn convert(msg: rc_client::KeysMessage<AccountId>) -> Xcm<()> {
-		let encoded_call = match msg {
+		let call = match msg {
 			rc_client::KeysMessage::SetKeys { stash, keys } =>
-				RelayChainRuntimePallets::AhClient(AhClientCalls::SetKeys { stash, keys }).encode(),
-			rc_client::KeysMessage::PurgeKeys { stash } =>
-				RelayChainRuntimePallets::AhClient(AhClientCalls::PurgeKeys { stash }).encode(),
+				AhClientCall::SetKeysFromAh { stash, keys },
+			rc_client::KeysMessage::PurgeKeys { stash } => AhClientCall::PurgeKeysFromAh { stash },
 		};
-		rc_client::build_transact_xcm(encoded_call)
+		rc_client::build_transact_xcm(
+			remote_calls::polkadot::RelayCall::StakingAhClient(call).encode(),
+		)
 	}
 }
 
@@ -571,12 +590,15 @@ impl rc_client::SendToRelayChain for StakingXcmToRelayChain {
 	type Balance = Balance;
 
 	fn validator_set(report: rc_client::ValidatorSetReport<Self::AccountId>) -> Result<(), ()> {
-		rc_client::XCMSender::<
+		let id = report.id;
+		let result = rc_client::XCMSender::<
 			xcm_config::XcmRouter,
 			RelayLocation,
 			rc_client::ValidatorSetReport<Self::AccountId>,
 			ValidatorSetToXcm,
-		>::send(report)
+		>::send(report);
+		StakingBridgeMonitor::note_validator_set_sent(id, result.is_ok());
+		result
 	}
 
 	fn set_keys(
@@ -589,7 +611,7 @@ impl rc_client::SendToRelayChain for StakingXcmToRelayChain {
 				&RemoteKeysExecutionWeight::get(),
 			);
 
-		rc_client::XCMSender::<
+		let result = rc_client::XCMSender::<
 			xcm_config::XcmRouter,
 			RelayLocation,
 			rc_client::KeysMessage<Self::AccountId>,
@@ -605,7 +627,9 @@ impl rc_client::SendToRelayChain for StakingXcmToRelayChain {
 			stash,
 			max_delivery_and_remote_execution_fee,
 			execution_cost,
-		)
+		);
+		StakingBridgeMonitor::note_keys_sent(result.is_ok());
+		result
 	}
 
 	fn purge_keys(
@@ -617,7 +641,7 @@ impl rc_client::SendToRelayChain for StakingXcmToRelayChain {
 				&RemoteKeysExecutionWeight::get(),
 			);
 
-		rc_client::XCMSender::<
+		let result = rc_client::XCMSender::<
 			xcm_config::XcmRouter,
 			RelayLocation,
 			rc_client::KeysMessage<Self::AccountId>,
@@ -633,10 +657,47 @@ impl rc_client::SendToRelayChain for StakingXcmToRelayChain {
 			stash,
 			max_delivery_and_remote_execution_fee,
 			execution_cost,
-		)
+		);
+		StakingBridgeMonitor::note_keys_sent(result.is_ok());
+		result
 	}
 }
 
+/// The view of [`StakingBridgeMonitor`] on the state of [`StakingRcClient`].
+pub struct RcClientState;
+impl pallet_staking_bridge_monitor::StakingBridgeClient for RcClientState {
+	fn outgoing() -> Option<pallet_staking_bridge_monitor::Outgoing> {
+		rc_client::OutgoingValidatorSet::<Runtime>::get().map(|(report, retries_left)| {
+			pallet_staking_bridge_monitor::Outgoing {
+				message: pallet_staking_bridge_monitor::Message::ValidatorSet,
+				id: report.id,
+				retries_left,
+			}
+		})
+	}
+
+	fn latest_received() -> Option<(pallet_staking_bridge_monitor::Message, u32)> {
+		rc_client::LastSessionReportEndingIndex::<Runtime>::get()
+			.map(|index| (pallet_staking_bridge_monitor::Message::SessionReport, index))
+	}
+}
+
+parameter_types! {
+	/// Three relay chain sessions without a session report.
+	///
+	/// `RelaySessionDuration` is in relay chain blocks, the threshold is in Asset Hub blocks.
+	pub StakingBridgeSilenceThreshold: BlockNumber = (3 * RelaySessionDuration::get() as u64 *
+		polkadot_runtime_constants::time::MILLISECS_PER_BLOCK /
+		system_parachains_constants::async_backing::MILLISECS_PER_BLOCK) as BlockNumber;
+}
+
+impl pallet_staking_bridge_monitor::Config for Runtime {
+	type Client = RcClientState;
+	type MaxSendFailures = ConstU32<3>;
+	type SilenceThreshold = StakingBridgeSilenceThreshold;
+	type WeightInfo = weights::pallet_staking_bridge_monitor::WeightInfo<Runtime>;
+}
+
 impl<C> frame_system::offchain::CreateTransactionBase<C> for Runtime
 where
 	RuntimeCall: From<C>,
@@ -920,6 +981,91 @@ mod tests {
 		});
 	}
 
+	// The projected schedule follows the two year steps of the TI curve.
+	#[test]
+	fn issuance_schedule_follows_stepped_curve() {
+		ExtBuilder::<Runtime>::default().build().execute_with(|| {
+			let two_years: RC_BlockNumber = RC_YEARS * 2;
+			set_relay_number(MARCH_14_2026 + 42);
+
+			// Up to March 14, 2032: the current and the two following periods. The period
+			// starting right at the end of the horizon is not included.
+			let schedule = EraPayout::impl_experimental_issuance_schedule(3 * two_years - 42);
+			assert_eq!(schedule.len(), 3);
+
+			let two_year_rate = EraPayout::BI_ANNUAL_RATE;
+			let mut expected_ti = MARCH_TI;
+			for (index, period) in schedule.iter().enumerate() {
+				let start = MARCH_14_2026 + index as RC_BlockNumber * two_years;
+				assert_eq!(period.start, start);
+				assert_eq!(period.end, start + two_years);
+
+				let expected_emission = two_year_rate * (TARGET_TI - expected_ti);
+				expected_ti += expected_emission;
+				assert_relative_eq!(
+					period.emission as f64,
+					expected_emission as f64,
+					max_relative = 0.00001
+				);
+				assert_relative_eq!(
+					period.total_issuance as f64,
+					expected_ti as f64,
+					max_relative = 0.00001
+				);
+				assert_eq!(period.remaining_to_cap, TARGET_TI - period.total_issuance);
+			}
+
+			// Consecutive periods are contiguous and each one adds its emission to the TI.
+			for pair in schedule.windows(2) {
+				assert_eq!(pair[0].end, pair[1].start);
+				// Each value is rounded down on its own, allow for one unit of rounding.
+				let accumulated = pair[0].total_issuance + pair[1].emission;
+				assert!(pair[1].total_issuance - accumulated <= 1);
+				assert!(pair[1].emission < pair[0].emission);
+			}
+		});
+	}
+
+	// The projected emission of a period is what the curve actually issues over it.
+	#[test]
+	fn issuance_schedule_matches_issue() {
+		ExtBuilder::<Runtime>::default().build().execute_with(|| {
+			let two_years: RC_BlockNumber = RC_YEARS * 2;
+			for step in 0..5 {
+				let relay_number = MARCH_14_2026 + step * two_years;
+				set_relay_number(relay_number);
+
+				let schedule = EraPayout::impl_experimental_issuance_schedule(0);
+				assert_eq!(schedule.len(), 1);
+				assert_eq!(schedule[0].start, relay_number);
+
+				let daily_emission = EraPayout::issue(0 /* ignored */, MILLISECONDS_PER_DAY);
+				assert_relative_eq!(
+					daily_emission as f64 * 365.25 * 2.0,
+					schedule[0].emission as f64,
+					max_relative = 0.00001
+				);
+			}
+		});
+	}
+
+	// The schedule is bounded, and never exceeds the hard cap.
+	#[test]
+	fn issuance_schedule_bounded_below_hard_cap() {
+		ExtBuilder::<Runtime>::default().build().execute_with(|| {
+			set_relay_number(MARCH_14_2026);
+
+			let schedule = EraPayout::impl_experimental_issuance_schedule(RC_BlockNumber::MAX);
+			assert_eq!(schedule.len() as u32, EraPayout::MAX_SCHEDULE_PERIODS);
+
+			// After 128 years, we are within a couple of DOT of the cap.
+			let last = schedule.last().unwrap();
+			assert!(last.total_issuance < TARGET_TI);
+			assert!(last.remaining_to_cap < 2 * UNITS);
+			assert_eq!(last.remaining_to_cap, TARGET_TI - last.total_issuance);
+		});
+	}
+
 	// Emission is capped under anamolous era duration.
 	#[test]
 	fn emission_capped_with_anomalous_era_duration() {
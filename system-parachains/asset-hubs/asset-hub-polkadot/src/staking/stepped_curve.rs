@@ -34,6 +34,7 @@
 //! Originally extracted as a more bespoke version of https://github.com/paritytech/polkadot-sdk/pull/9556
 //! To be replaced after that is merged and available.
 
+use alloc::vec::Vec;
 use frame_support::pallet_prelude::{CheckedDiv, Zero};
 use scale_info::TypeInfo;
 use sp_arithmetic::{traits::Saturating, FixedU128};
@@ -53,6 +54,19 @@ pub struct RemainingPct {
 	pub pct: Perbill,
 }
 
+/// A single period of a [`SteppedCurve`], as returned by [`SteppedCurve::periods`].
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct CurvePeriod {
+	/// The point at which the period starts, i.e. at which its step takes place.
+	pub start: FixedU128,
+	/// The point at which the period ends (exclusive), i.e. the start of the next period.
+	pub end: FixedU128,
+	/// The value of the curve throughout the period, after its step.
+	pub value: FixedU128,
+	/// The magnitude of the step taken at `start`. Zero for the period before the first step.
+	pub step_size: FixedU128,
+}
+
 /// A stepped curve.
 ///
 /// Steps every `period` from the `initial_value` as defined by `step`.
@@ -121,6 +135,47 @@ impl SteppedCurve {
 		val_curr.saturating_sub(val_prev)
 	}
 
+	/// Returns the consecutive periods of the curve overlapping `[from, until)`, starting with the
+	/// period containing `from`.
+	///
+	/// Points before `start` are treated as `start`. At most `max_periods` are returned. A curve
+	/// with a zero `period` never steps, and has no periods.
+	pub fn periods(&self, from: FixedU128, until: FixedU128, max_periods: u32) -> Vec<CurvePeriod> {
+		if self.period.is_zero() {
+			return Vec::new();
+		}
+
+		// Index of the period containing `from`, saturated.
+		let from = from.max(self.start);
+		let first = (from - self.start).checked_div(&self.period).unwrap_or(FixedU128::max_value());
+		let first = (first.into_inner() / FixedU128::DIV).saturated_into::<u32>();
+
+		let mut periods = Vec::new();
+		let mut prev_value = None;
+		for index in first..first.saturating_add(max_periods) {
+			let start = self.start.saturating_add(
+				FixedU128::saturating_from_integer(index).saturating_mul(self.period),
+			);
+			if start >= until && !periods.is_empty() {
+				break;
+			}
+			let end = start.saturating_add(self.period);
+			// No further progress possible once the domain saturates.
+			if end == start {
+				break;
+			}
+
+			let value = self.evaluate(start);
+			let step_size = match prev_value {
+				Some(prev) => value.saturating_sub(prev),
+				None => self.last_step_size(start),
+			};
+			prev_value = Some(value);
+			periods.push(CurvePeriod { start, end, value, step_size });
+		}
+		periods
+	}
+
 	/// Evaluate the curve at a given point.
 	///
 	/// Max number of steps is `u32::MAX`.
@@ -342,6 +397,92 @@ mod stepped_curve_tests {
 		assert_eq!(step3, FixedU128::from_rational(125, 10));
 	}
 
+	#[test]
+	fn periods_follow_the_steps() {
+		let curve = SteppedCurve::try_new(
+			FixedU128::from_u32(100),
+			FixedU128::from_u32(50),
+			RemainingPct { target: FixedU128::from_u32(100), pct: Perbill::from_percent(20) },
+			FixedU128::from_u32(10),
+		)
+		.unwrap_or_default();
+
+		// From the middle of the first stepped period, up to the middle of the third.
+		let periods = curve.periods(FixedU128::from_u32(115), FixedU128::from_u32(135), 10);
+		assert_eq!(
+			periods,
+			vec![
+				// 100 - (100 - 50) * 0.8 = 60
+				CurvePeriod {
+					start: FixedU128::from_u32(110),
+					end: FixedU128::from_u32(120),
+					value: FixedU128::from_u32(60),
+					step_size: FixedU128::from_u32(10),
+				},
+				// 100 - (100 - 50) * 0.8^2 = 68
+				CurvePeriod {
+					start: FixedU128::from_u32(120),
+					end: FixedU128::from_u32(130),
+					value: FixedU128::from_u32(68),
+					step_size: FixedU128::from_u32(8),
+				},
+				// 100 - (100 - 50) * 0.8^3 = 74.4
+				CurvePeriod {
+					start: FixedU128::from_u32(130),
+					end: FixedU128::from_u32(140),
+					value: FixedU128::from_rational(744, 10),
+					step_size: FixedU128::from_rational(64, 10),
+				},
+			]
+		);
+
+		// Each period's step size matches `last_step_size` and `evaluate` anywhere within it.
+		for period in periods {
+			let mid = period.start.saturating_add(FixedU128::from_u32(5));
+			assert_eq!(curve.last_step_size(mid), period.step_size);
+			assert_eq!(curve.evaluate(mid), period.value);
+		}
+	}
+
+	#[test]
+	fn periods_before_start_begin_with_initial_value() {
+		let curve = SteppedCurve::try_new(
+			FixedU128::from_u32(100),
+			FixedU128::from_u32(50),
+			RemainingPct { target: FixedU128::from_u32(100), pct: Perbill::from_percent(50) },
+			FixedU128::from_u32(10),
+		)
+		.unwrap_or_default();
+
+		let periods = curve.periods(FixedU128::zero(), FixedU128::from_u32(115), 10);
+		assert_eq!(periods.len(), 2);
+		assert_eq!(periods[0].start, FixedU128::from_u32(100));
+		assert_eq!(periods[0].value, FixedU128::from_u32(50));
+		assert_eq!(periods[0].step_size, FixedU128::zero());
+		assert_eq!(periods[1].start, FixedU128::from_u32(110));
+		assert_eq!(periods[1].value, FixedU128::from_u32(75));
+		assert_eq!(periods[1].step_size, FixedU128::from_u32(25));
+	}
+
+	#[test]
+	fn periods_are_bounded() {
+		let curve = SteppedCurve::try_new(
+			FixedU128::zero(),
+			FixedU128::zero(),
+			RemainingPct { target: FixedU128::from_u32(100), pct: Perbill::from_percent(50) },
+			FixedU128::from_u32(1),
+		)
+		.unwrap_or_default();
+
+		// Capped by `max_periods`.
+		assert_eq!(curve.periods(FixedU128::zero(), FixedU128::max_value(), 7).len(), 7);
+		// An empty range still reports the period we are in.
+		assert_eq!(curve.periods(FixedU128::from_u32(3), FixedU128::from_u32(3), 7).len(), 1);
+		// No periods without a step duration.
+		let flat = SteppedCurve { period: FixedU128::zero(), ..curve };
+		assert!(flat.periods(FixedU128::zero(), FixedU128::max_value(), 7).is_empty());
+	}
+
 	#[test]
 	fn default_curve_is_zero_curve() {
 		let curve = SteppedCurve::default();
@@ -14,12 +14,24 @@
 // limitations under the License.
 
 use crate::{governance::Treasurer, *};
-use frame_support::traits::{
-	fungible::HoldConsideration, tokens::UnityOrOuterConversion, FromContains,
+use frame_support::{
+	storage_alias,
+	traits::{
+		fungible::HoldConsideration,
+		tokens::{Pay, PaymentStatus, UnityOrOuterConversion},
+		FromContains,
+	},
+	Blake2_128Concat, Twox64Concat,
 };
 use pallet_bounties::TransferAllFungibles;
 use parachains_common::pay::{AccountIdToLocalLocation, LocalPay, VersionedLocatableAccount};
-use polkadot_runtime_common::impls::{ContainsParts, VersionedLocatableAsset};
+use polkadot_runtime_common::impls::{
+	ContainsParts, LocatableAssetConverter, VersionedLocatableAsset,
+};
+use sp_runtime::{traits::TryConvert, DispatchError};
+use xcm::latest::{QueryId, QueryResponseInfo, Response};
+use xcm_builder::LocatableAssetId;
+use xcm_executor::traits::{ConvertLocation, QueryHandler, QueryResponseStatus};
 
 parameter_types! {
 	pub const SpendPeriod: BlockNumber = 24 * RC_DAYS;
@@ -31,11 +43,243 @@ parameter_types! {
 	pub TreasuryAccount: AccountId = Treasury::account_id();
 }
 
-pub type TreasuryPaymaster = parachains_common::pay::LocalPay<
-	NativeAndAssets,
-	TreasuryAccount,
-	xcm_config::LocationToAccountId,
->;
+/// Pays out treasury spends to accounts on Asset Hub.
+pub type LocalTreasuryPaymaster =
+	LocalPay<NativeAndAssets, TreasuryAccount, xcm_config::LocationToAccountId>;
+
+parameter_types! {
+	/// The amount of DOT sent along with every remote payment to pay for its execution on the
+	/// destination, and for returning the funds to the treasury if the payment fails. Half of it
+	/// ends up with the beneficiary of a successful payment.
+	pub const RemotePaymentFee: Balance = UNITS;
+	/// The number of blocks the treasury waits for a remote payment to be reported back before
+	/// it gives up and leaves the payment to governance.
+	pub const RemotePaymentTimeout: BlockNumber = 6 * HOURS;
+	/// Assets that Asset Hub is the reserve of, and so can be paid to accounts on sibling
+	/// parachains.
+	pub RemotelyPayableAssets: (Location, Location, Location) = (
+		xcm_config::DotLocation::get(),
+		xcm_config::TrustBackedAssetsPalletLocation::get(),
+		xcm_config::PoolAssetsPalletLocation::get(),
+	);
+}
+
+/// The identifier of payments made by [`LocalTreasuryPaymaster`]. They complete immediately and
+/// never clash with the query IDs handed out by the XCM pallet.
+const LOCAL_PAYMENT_ID: QueryId = QueryId::MAX;
+
+/// Remote payments which are not reported back yet, by the ID of their query: the sovereign
+/// account of the destination, the location of the asset on Asset Hub, the amount and the DOT fee
+/// moved to the sovereign account.
+#[storage_alias(verbatim)]
+pub type PendingRemotePayments =
+	StorageMap<TreasuryPaymaster, Twox64Concat, QueryId, (AccountId, Location, Balance, Balance)>;
+
+/// Remote payments which timed out without a report, by the ID of their query, in the same form
+/// as [`PendingRemotePayments`]. Their funds are left in the sovereign account of the destination
+/// for governance to settle once the outcome on the destination is known.
+#[storage_alias(verbatim)]
+pub type UnresolvedRemotePayments =
+	StorageMap<TreasuryPaymaster, Twox64Concat, QueryId, (AccountId, Location, Balance, Balance)>;
+
+/// The queries of the XCM pallet, to drop the query of a remote payment which timed out.
+#[storage_alias]
+type XcmQueries =
+	StorageMap<PolkadotXcm, Blake2_128Concat, QueryId, pallet_xcm::QueryStatus<BlockNumber>>;
+
+/// Pays out treasury spends to accounts on Asset Hub or on a sibling parachain.
+///
+/// Local beneficiaries are paid by [`LocalTreasuryPaymaster`]. For a beneficiary on a sibling
+/// parachain, the asset and [`RemotePaymentFee`] are moved to the sovereign account of the
+/// sibling and reserve transferred to the beneficiary. The sibling reports the outcome back to
+/// the XCM pallet, which [`Pay::check_payment`] reads. If the deposit fails, the sibling returns
+/// the funds to the treasury account before reporting the error.
+///
+/// A sibling which rejects the message before it sets its error handler, for example because it
+/// doesn't trust Asset Hub as the reserve of the asset, never reports back. But neither does a
+/// sibling whose report is delayed or lost after the payment was deposited, so a missing report
+/// proves nothing. Once [`RemotePaymentTimeout`] has passed without a report, the payment is
+/// reported as unknown, which concludes the spend without paying it again, and moved to
+/// [`UnresolvedRemotePayments`]. Its funds stay in the sovereign account of the sibling, backing
+/// any assets already minted there, and a report arriving later is dropped.
+///
+/// Only DOT and assets of the trust backed and pool assets pallets can be paid remotely, since
+/// Asset Hub is their reserve.
+pub struct TreasuryPaymaster;
+
+impl TreasuryPaymaster {
+	/// Returns the location of the beneficiary's chain and the beneficiary relative to it.
+	fn split_beneficiary(
+		who: &VersionedLocatableAccount,
+	) -> Result<(Location, Location), DispatchError> {
+		let invalid = DispatchError::Other("UnsupportedBeneficiary");
+		match who.clone() {
+			VersionedLocatableAccount::V4 { location, account_id } => Ok((
+				location.try_into().map_err(|_| invalid)?,
+				account_id.try_into().map_err(|_| invalid)?,
+			)),
+			VersionedLocatableAccount::V5 { location, account_id } => Ok((location, account_id)),
+		}
+	}
+
+	/// Whether Asset Hub is the reserve of the asset with the given `id`.
+	fn is_remotely_payable(id: &Location) -> bool {
+		let (dot, trust_backed, pool) = RemotelyPayableAssets::get();
+		id == &dot || id.starts_with(&trust_backed) || id.starts_with(&pool)
+	}
+
+	/// Moves `amount` of `asset_kind` and the [`RemotePaymentFee`] to the sovereign account of
+	/// `dest` and sends the message paying them to `beneficiary`.
+	fn pay_remote(
+		dest: Location,
+		beneficiary: Location,
+		asset_kind: VersionedLocatableAsset,
+		amount: Balance,
+	) -> Result<QueryId, DispatchError> {
+		use frame_support::traits::{fungibles::Mutate, tokens::Preservation::Expendable};
+		let unsupported_asset = DispatchError::Other("UnsupportedAsset");
+		let LocatableAssetId { location: asset_location, asset_id } =
+			LocatableAssetConverter::try_convert(asset_kind).map_err(|_| unsupported_asset)?;
+		if asset_location != Location::here() || !Self::is_remotely_payable(&asset_id.0) {
+			return Err(unsupported_asset);
+		}
+
+		let treasury = TreasuryAccount::get();
+		let sovereign = xcm_config::LocationToAccountId::convert_location(&dest)
+			.ok_or(DispatchError::Other("UnsupportedBeneficiary"))?;
+		let dot = xcm_config::DotLocation::get();
+		let fee = RemotePaymentFee::get();
+		let asset_location_on_ah = asset_id.0.clone();
+		<NativeAndAssets as Mutate<_>>::transfer(
+			asset_id.0.clone(),
+			&treasury,
+			&sovereign,
+			amount,
+			Expendable,
+		)?;
+		<NativeAndAssets as Mutate<_>>::transfer(
+			dot.clone(),
+			&treasury,
+			&sovereign,
+			fee,
+			Expendable,
+		)?;
+
+		let universal_location = xcm_config::UniversalLocation::get();
+		let reanchor = |location: Location| {
+			location.reanchored(&dest, &universal_location).map_err(|_| unsupported_asset)
+		};
+		let asset_hub = reanchor(Location::here())?;
+		let dot = reanchor(dot)?;
+		let assets: xcm::latest::Assets =
+			vec![(reanchor(asset_id.0)?, amount).into(), (dot.clone(), fee).into()].into();
+
+		let now = frame_system::Pallet::<Runtime>::block_number();
+		let query_id = PolkadotXcm::new_query(
+			dest.clone(),
+			now.saturating_add(RemotePaymentTimeout::get()),
+			Here,
+		);
+		// Barriers only let paid messages through which start with the assets and the fees, so the
+		// error handler and the report can't come first. Rejections before them only show as the
+		// timeout of the query.
+		let message = Xcm(vec![
+			ReserveAssetDeposited(assets),
+			PayFees { asset: (dot, fee / 2).into() },
+			SetErrorHandler(Xcm(vec![InitiateReserveWithdraw {
+				assets: Wild(AllCounted(2)),
+				reserve: asset_hub.clone(),
+				xcm: Xcm(vec![
+					PayFees { asset: (Location::parent(), fee / 2).into() },
+					DepositAsset {
+						assets: Wild(AllCounted(2)),
+						beneficiary: Junction::AccountId32 { network: None, id: treasury.into() }
+							.into(),
+					},
+				]),
+			}])),
+			SetAppendix(Xcm(vec![ReportError(QueryResponseInfo {
+				destination: asset_hub,
+				query_id,
+				max_weight: Weight::zero(),
+			})])),
+			DepositAsset { assets: Wild(AllCounted(2)), beneficiary },
+		]);
+		send_xcm::<xcm_config::XcmRouter>(dest, message)
+			.map_err(|_| DispatchError::Other("RemotePaymentNotSent"))?;
+		PendingRemotePayments::insert(query_id, (sovereign, asset_location_on_ah, amount, fee));
+
+		Ok(query_id)
+	}
+
+	/// Gives up on the timed out remote payment `id`, leaving its funds in the sovereign account of
+	/// its destination for governance to settle.
+	fn abandon_remote(id: QueryId) -> PaymentStatus {
+		XcmQueries::remove(id);
+		if let Some(payment) = PendingRemotePayments::take(id) {
+			UnresolvedRemotePayments::insert(id, payment);
+		}
+		PaymentStatus::Unknown
+	}
+}
+
+impl Pay for TreasuryPaymaster {
+	type Balance = Balance;
+	type Beneficiary = VersionedLocatableAccount;
+	type AssetKind = VersionedLocatableAsset;
+	type Id = QueryId;
+	type Error = DispatchError;
+
+	fn pay(
+		who: &Self::Beneficiary,
+		asset_kind: Self::AssetKind,
+		amount: Self::Balance,
+	) -> Result<Self::Id, Self::Error> {
+		match Self::split_beneficiary(who)? {
+			(location, _) if location == Location::here() =>
+				LocalTreasuryPaymaster::pay(who, asset_kind, amount).map(|_| LOCAL_PAYMENT_ID),
+			(dest, beneficiary)
+				if dest.parents == 1 && matches!(dest.interior().as_slice(), [Parachain(_)]) =>
+				Self::pay_remote(dest, beneficiary, asset_kind, amount),
+			_ => Err(DispatchError::Other("UnsupportedBeneficiary")),
+		}
+	}
+
+	fn check_payment(id: Self::Id) -> PaymentStatus {
+		if id == LOCAL_PAYMENT_ID {
+			return PaymentStatus::Success;
+		}
+		match PolkadotXcm::take_response(id) {
+			QueryResponseStatus::Ready { response: Response::ExecutionResult(None), .. } => {
+				PendingRemotePayments::remove(id);
+				PaymentStatus::Success
+			},
+			// The sibling returned the funds to the treasury before reporting the error.
+			QueryResponseStatus::Ready { response: Response::ExecutionResult(Some(_)), .. } => {
+				PendingRemotePayments::remove(id);
+				PaymentStatus::Failure
+			},
+			QueryResponseStatus::Pending { timeout }
+				if frame_system::Pallet::<Runtime>::block_number() > timeout =>
+				Self::abandon_remote(id),
+			QueryResponseStatus::Pending { .. } => PaymentStatus::InProgress,
+			_ => PaymentStatus::Unknown,
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_successful(who: &Self::Beneficiary, asset_kind: Self::AssetKind, amount: Balance) {
+		LocalTreasuryPaymaster::ensure_successful(who, asset_kind, amount)
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn ensure_concluded(id: Self::Id) {
+		// Local payments conclude as soon as they are made.
+		if id != LOCAL_PAYMENT_ID {
+			PolkadotXcm::expect_response(id, Response::ExecutionResult(None));
+		}
+	}
+}
 
 impl pallet_treasury::Config for Runtime {
 	type PalletId = TreasuryPalletId;
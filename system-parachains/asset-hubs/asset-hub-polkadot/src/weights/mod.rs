@@ -36,11 +36,14 @@ pub mod pallet_bounties;
 pub mod pallet_child_bounties;
 pub mod pallet_collator_selection;
 pub mod pallet_conviction_voting;
+pub mod pallet_coretime_purchase;
 pub mod pallet_dap;
 pub mod pallet_election_provider_multi_block;
 pub mod pallet_election_provider_multi_block_signed;
 pub mod pallet_election_provider_multi_block_unsigned;
 pub mod pallet_election_provider_multi_block_verifier;
+pub mod pallet_foreign_asset_reserves;
+pub mod pallet_hrmp_auto_accept;
 pub mod pallet_indices;
 pub mod pallet_message_queue;
 pub mod pallet_migrations;
@@ -50,11 +53,13 @@ pub mod pallet_nfts;
 pub mod pallet_parameters;
 pub mod pallet_preimage;
 pub mod pallet_proxy;
+pub mod pallet_proxy_allowances;
 pub mod pallet_referenda;
 pub mod pallet_scheduler;
 pub mod pallet_session;
 pub mod pallet_staking_async;
 pub mod pallet_staking_async_rc_client;
+pub mod pallet_staking_bridge_monitor;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
 pub mod pallet_treasury;
@@ -64,6 +69,7 @@ pub mod pallet_vesting;
 pub mod pallet_vesting_precompiles;
 pub mod pallet_whitelist;
 pub mod pallet_xcm;
+pub mod pallet_xcm_asset_locker;
 pub mod pallet_xcm_bridge_hub_router;
 pub mod paritydb_weights;
 pub mod polkadot_runtime_common_claims;
@@ -0,0 +1,60 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_staking_bridge_monitor`
+//!
+//! These weights are NOT generated by the benchmark CLI, they are estimates to be replaced by the
+//! output of:
+//!
+//! frame-omni-bencher v1 benchmark pallet --extrinsic=* --pallet=pallet_staking_bridge_monitor
+//!
+//! The storage accesses are those of `on_finalize` in the worst case of its benchmark. The
+//! reference time is a rough estimate of the execution, doubled as margin, and the proof size is
+//! the sum of the maximum encoded sizes of the storage items read, with 1000 bytes for the items
+//! without a maximum size.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_staking_bridge_monitor`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_staking_bridge_monitor::WeightInfo for WeightInfo<T> {
+	/// Storage: `StakingRcClient::OutgoingValidatorSet` (r:1 w:0)
+	/// Proof: `StakingRcClient::OutgoingValidatorSet` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StakingRcClient::LastSessionReportEndingIndex` (r:1 w:0)
+	/// Proof: `StakingRcClient::LastSessionReportEndingIndex` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `StakingBridgeMonitor::LatestReceived` (r:1 w:1)
+	/// Proof: `StakingBridgeMonitor::LatestReceived` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `StakingBridgeMonitor::Received` (r:4 w:1)
+	/// Proof: `StakingBridgeMonitor::Received` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `StakingBridgeMonitor::LastValidatorSet` (r:0 w:1)
+	/// Proof: `StakingBridgeMonitor::LastValidatorSet` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `StakingBridgeMonitor::Sent` (r:4 w:0)
+	/// Proof: `StakingBridgeMonitor::Sent` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `StakingBridgeMonitor::Degraded` (r:1 w:1)
+	/// Proof: `StakingBridgeMonitor::Degraded` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	fn on_finalize() -> Weight {
+		Weight::from_parts(86_240_000, 0)
+			.saturating_add(Weight::from_parts(0, 22976))
+			.saturating_add(T::DbWeight::get().reads(12))
+			.saturating_add(T::DbWeight::get().writes(4))
+	}
+}
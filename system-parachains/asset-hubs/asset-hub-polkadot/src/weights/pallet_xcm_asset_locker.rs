@@ -0,0 +1,86 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_xcm_asset_locker`
+//!
+//! These weights are NOT generated by the benchmark CLI, they are estimates to be replaced by the
+//! output of:
+//!
+//! frame-omni-bencher v1 benchmark pallet --extrinsic=* --pallet=pallet_xcm_asset_locker
+//!
+//! The storage accesses are those of the calls and of the `LockAsset` and `UnlockAsset`
+//! instructions, for the maximum number of allowed chains and locks. The reference time is a rough
+//! estimate of the execution, doubled as margin, and the proof size is the sum of the maximum
+//! encoded sizes of the storage items read, with 1000 bytes for the items without a maximum size.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_xcm_asset_locker`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_xcm_asset_locker::WeightInfo for WeightInfo<T> {
+	/// Storage: `XcmAssetLocker::AllowedChains` (r:1 w:1)
+	/// Proof: `XcmAssetLocker::AllowedChains` (`max_values`: Some(1), `max_size`: Some(41345), added: 41840, mode: `MaxEncodedLen`)
+	fn allow_chain() -> Weight {
+		Weight::from_parts(120_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 41840))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcmAssetLocker::AllowedChains` (r:1 w:1)
+	/// Proof: `XcmAssetLocker::AllowedChains` (`max_values`: Some(1), `max_size`: Some(41345), added: 41840, mode: `MaxEncodedLen`)
+	fn disallow_chain() -> Weight {
+		Weight::from_parts(120_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 41840))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `XcmAssetLocker::AllowedChains` (r:1 w:0)
+	/// Proof: `XcmAssetLocker::AllowedChains` (`max_values`: Some(1), `max_size`: Some(41345), added: 41840, mode: `MaxEncodedLen`)
+	/// Storage: `XcmAssetLocker::NativeLocks` (r:1 w:1)
+	/// Proof: `XcmAssetLocker::NativeLocks` (`max_values`: None, `max_size`: Some(5266), added: 7741, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	fn lock_asset() -> Weight {
+		Weight::from_parts(220_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 58626))
+			.saturating_add(T::DbWeight::get().reads(5))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `XcmAssetLocker::NativeLocks` (r:1 w:1)
+	/// Proof: `XcmAssetLocker::NativeLocks` (`max_values`: None, `max_size`: Some(5266), added: 7741, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Freezes` (r:1 w:1)
+	/// Proof: `Balances::Freezes` (`max_values`: None, `max_size`: Some(193), added: 2668, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Locks` (r:1 w:0)
+	/// Proof: `Balances::Locks` (`max_values`: None, `max_size`: Some(1299), added: 3774, mode: `MaxEncodedLen`)
+	fn unlock_asset() -> Weight {
+		Weight::from_parts(120_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 16786))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
@@ -17,15 +17,16 @@
 use crate::XcmOverBridgeHubPolkadot;
 
 use super::{
-	AccountId, AllPalletsWithSystem, Balance, Balances, CollatorSelection, ParachainInfo,
-	ParachainSystem, PolkadotXcm, PriceForParentDelivery, Runtime, RuntimeCall, RuntimeEvent,
-	RuntimeHoldReason, RuntimeOrigin, WeightToFee, XcmpQueue,
+	AccountId, AllPalletsWithSystem, AssetRate, Assets, Balance, Balances, CollatorSelection,
+	ParachainInfo, ParachainSystem, PolkadotXcm, PriceForParentDelivery, Runtime, RuntimeCall,
+	RuntimeEvent, RuntimeHoldReason, RuntimeOrigin, WeightToFee, XcmpQueue,
 };
 use frame_support::{
 	parameter_types,
 	traits::{
-		fungible::HoldConsideration, tokens::imbalance::ResolveTo, ConstU32, Contains, Equals,
-		Everything, LinearStoragePrice, Nothing,
+		fungible::HoldConsideration,
+		tokens::imbalance::{ResolveAssetTo, ResolveTo},
+		ConstU32, Contains, Equals, Everything, LinearStoragePrice, Nothing,
 	},
 };
 use frame_system::EnsureRoot;
@@ -37,7 +38,7 @@ use parachains_common::xcm_config::{
 use polkadot_parachain_primitives::primitives::Sibling;
 use sp_runtime::traits::AccountIdConversion;
 pub use system_parachains_constants::kusama::locations::{
-	AssetHubLocation, AssetHubPlurality, RelayChainLocation,
+	AssetHubLocation, AssetHubPlurality, AssetHubTrustBackedAssetsLocation, RelayChainLocation,
 };
 use system_parachains_constants::TREASURY_PALLET_ID;
 use xcm::latest::prelude::*;
@@ -46,14 +47,16 @@ use xcm_builder::{
 	AllowHrmpNotificationsFromRelayChain, AllowKnownQueryResponses, AllowSubscriptionsFrom,
 	AllowTopLevelPaidExecutionFrom, DenyReserveTransferToRelayChain, DenyThenTry,
 	DescribeAllTerminal, DescribeFamily, EnsureXcmOrigin, FrameTransactionalProcessor,
-	FungibleAdapter, HashedDescription, IsConcrete, LocationAsSuperuser, ParentIsPreset,
-	RelayChainAsNative, SendXcmFeeToAccount, SiblingParachainAsNative, SiblingParachainConvertsVia,
-	SignedAccountId32AsNative, SignedToAccountId32, SovereignSignedViaLocation, TakeWeightCredit,
-	TrailingSetTopicAsId, UsingComponents, WeightInfoBounds, WithComputedOrigin, WithUniqueTopic,
-	XcmFeeManagerFromComponents,
+	FungibleAdapter, FungiblesAdapter, HashedDescription, IsConcrete, LocationAsSuperuser,
+	NoChecking, ParentIsPreset, RelayChainAsNative, SendXcmFeeToAccount, SiblingParachainAsNative,
+	SiblingParachainConvertsVia, SignedAccountId32AsNative, SignedToAccountId32,
+	SovereignSignedViaLocation, TakeWeightCredit, TrailingSetTopicAsId, UsingComponents,
+	WeightInfoBounds, WithComputedOrigin, WithUniqueTopic, XcmFeeManagerFromComponents,
 };
 use xcm_executor::{traits::ConvertLocation, XcmExecutor};
 
+use system_parachains_common::xcm_fees::{AssetRateTrader, ReserveAssetsFrom};
+
 parameter_types! {
 	pub const RootLocation: Location = Location::here();
 	pub const RelayNetwork: NetworkId = NetworkId::Kusama;
@@ -73,6 +76,7 @@ parameter_types! {
 			.unwrap_or(TreasuryAccount::get());
 	// TODO: replace this with DAP account (for collecting fees) #1137
 	pub StakingPot: AccountId = CollatorSelection::account_id();
+	pub CheckingAccount: AccountId = PolkadotXcm::check_account();
 }
 
 /// Type for specifying how a `Location` can be converted into an `AccountId`.
@@ -104,6 +108,25 @@ pub type FungibleTransactor = FungibleAdapter<
 	(),
 >;
 
+/// Means for transacting the foreign assets of `pallet_assets` on this chain.
+pub type FungiblesTransactor = FungiblesAdapter<
+	// Use this implementation of `fungibles::*`:
+	Assets,
+	// Match the assets with their location as id:
+	assets_common::ForeignAssetsConvertedConcreteId<(), Balance, Location>,
+	// Convert an XCM `Location` into a local account ID:
+	LocationToAccountId,
+	// Our chain's account ID type (we can't get away without mentioning it explicitly):
+	AccountId,
+	// We don't track any teleports of foreign assets.
+	NoChecking,
+	// The account for checking, unused without teleports.
+	CheckingAccount,
+>;
+
+/// Means for transacting assets on this chain.
+pub type AssetTransactors = (FungibleTransactor, FungiblesTransactor);
+
 /// This is the type we use to convert an (incoming) XCM origin into a local `Origin` instance,
 /// ready for dispatching a transaction with Xcm's `Transact`.
 ///
@@ -191,15 +214,34 @@ pub type TrustedTeleporters = ConcreteAssetFromSystem<RelayChainLocation>;
 /// - Allow origins explicitly authorized by the alias target location.
 pub type TrustedAliasers = (AliasChildLocation, AuthorizedAliasers<Runtime>);
 
+/// All ways of paying for execution fees via XCM.
+pub type Traders = (
+	UsingComponents<
+		WeightToFee<Runtime>,
+		RelayChainLocation,
+		AccountId,
+		Balances,
+		ResolveTo<StakingPot, Balances>,
+	>,
+	// The assets with a rate in `AssetRate`, e.g. USDT.
+	AssetRateTrader<
+		WeightToFee<Runtime>,
+		AssetRate,
+		Assets,
+		AccountId,
+		ResolveAssetTo<StakingPot, Assets>,
+	>,
+);
+
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
 	type XcmSender = XcmRouter;
-	type AssetTransactor = FungibleTransactor;
+	type AssetTransactor = AssetTransactors;
 	type OriginConverter = XcmOriginToTransactDispatchOrigin;
-	// BridgeHub does not recognize a reserve location for any asset. Users must teleport KSM
-	// where allowed (e.g. with the Relay Chain).
-	type IsReserve = ();
+	// Users must teleport KSM where allowed (e.g. with the Relay Chain). Only the trust backed
+	// assets of Asset Hub are accepted as reserve assets, to pay for execution.
+	type IsReserve = ReserveAssetsFrom<AssetHubTrustBackedAssetsLocation, AssetHubLocation>;
 	type IsTeleporter = TrustedTeleporters;
 	type UniversalLocation = UniversalLocation;
 	type Barrier = Barrier;
@@ -208,13 +250,7 @@ impl xcm_executor::Config for XcmConfig {
 		RuntimeCall,
 		MaxInstructions,
 	>;
-	type Trader = UsingComponents<
-		WeightToFee<Runtime>,
-		RelayChainLocation,
-		AccountId,
-		Balances,
-		ResolveTo<StakingPot, Balances>,
-	>;
+	type Trader = Traders;
 	type ResponseHandler = PolkadotXcm;
 	type AssetTrap = PolkadotXcm;
 	type AssetLocker = ();
@@ -24,6 +24,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 extern crate alloc;
 
+pub mod assets;
 pub mod bridge_common_config;
 pub mod bridge_to_ethereum_config;
 pub mod bridge_to_kusama_config;
@@ -677,6 +678,8 @@ construct_runtime!(
 		// Monetary stuff.
 		Balances: pallet_balances = 10,
 		TransactionPayment: pallet_transaction_payment = 11,
+		Assets: pallet_assets = 12,
+		AssetRate: pallet_asset_rate = 13,
 
 		// Collator support. The order of these 4 are important and shall not change.
 		Authorship: pallet_authorship = 20,
@@ -731,10 +734,13 @@ mod benches {
 	use alloc::boxed::Box;
 	use polkadot_runtime_constants::system_parachain::AssetHubParaId;
 	use system_parachains_constants::polkadot::locations::AssetHubLocation;
+	use xcm::latest::Assets as XcmAssets;
 
 	frame_benchmarking::define_benchmarks!(
 		[frame_system, SystemBench::<Runtime>]
 		[frame_system_extensions, SystemExtensionsBench::<Runtime>]
+		[pallet_asset_rate, AssetRate]
+		[pallet_assets, Assets]
 		[pallet_balances, Balances]
 		[pallet_message_queue, MessageQueue]
 		[pallet_multisig, Multisig]
@@ -823,7 +829,8 @@ mod benches {
 			None
 		}
 
-		fn set_up_complex_asset_transfer() -> Option<(Assets, u32, Location, Box<dyn FnOnce()>)> {
+		fn set_up_complex_asset_transfer() -> Option<(XcmAssets, u32, Location, Box<dyn FnOnce()>)>
+		{
 			// Only supports native token teleports to system parachain
 			let native_location = Parent.into();
 			let dest = AssetHubLocation::get();
@@ -893,7 +900,7 @@ mod benches {
 			(0u64, Response::Version(Default::default()))
 		}
 
-		fn worst_case_asset_exchange() -> Result<(Assets, Assets), BenchmarkError> {
+		fn worst_case_asset_exchange() -> Result<(XcmAssets, XcmAssets), BenchmarkError> {
 			Err(BenchmarkError::Skip)
 		}
 
@@ -912,9 +919,9 @@ mod benches {
 			Ok(AssetHubLocation::get())
 		}
 
-		fn claimable_asset() -> Result<(Location, Location, Assets), BenchmarkError> {
+		fn claimable_asset() -> Result<(Location, Location, XcmAssets), BenchmarkError> {
 			let origin = AssetHubLocation::get();
-			let assets: Assets = (AssetId(DotRelayLocation::get()), 1_000 * UNITS).into();
+			let assets: XcmAssets = (AssetId(DotRelayLocation::get()), 1_000 * UNITS).into();
 			let ticket = Location { parents: 0, interior: Here };
 			Ok((origin, ticket, assets))
 		}
@@ -1352,7 +1359,11 @@ impl_runtime_apis! {
 
 	impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
-			let acceptable_assets = vec![AssetId(xcm_config::DotRelayLocation::get())];
+			// The relay chain token and the assets with a rate to it.
+			let acceptable_assets = [AssetId(xcm_config::DotRelayLocation::get())]
+				.into_iter()
+				.chain(pallet_asset_rate::ConversionRateToNative::<Runtime>::iter_keys().map(AssetId))
+				.collect();
 			PolkadotXcm::query_acceptable_payment_assets(xcm_version, acceptable_assets)
 		}
 
@@ -184,7 +184,11 @@ impl_runtime_apis! {
 		fn query_acceptable_payment_assets(
 			xcm_version: xcm::Version
 		) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
-			let acceptable_assets = vec![AssetId(xcm_config::DotLocation::get())];
+			// The relay chain token and the assets with a rate to it.
+			let acceptable_assets = [AssetId(xcm_config::DotLocation::get())]
+				.into_iter()
+				.chain(pallet_asset_rate::ConversionRateToNative::<Runtime>::iter_keys().map(AssetId))
+				.collect();
 			PolkadotXcm::query_acceptable_payment_assets(xcm_version, acceptable_assets)
 		}
 
@@ -357,6 +361,9 @@ impl_runtime_apis! {
 			}
 
 			use xcm::latest::prelude::*;
+			// `add_benchmarks!` refers to the `Assets` pallet, not to the XCM type of the prelude.
+			use crate::Assets;
+			use xcm::latest::Assets as XcmAssets;
 			use xcm_config::DotLocation;
 			use system_parachains_constants::polkadot::currency::UNITS;
 
@@ -389,7 +396,7 @@ impl_runtime_apis! {
 					None
 				}
 
-				fn set_up_complex_asset_transfer() -> Option<(Assets, u32, Location, Box<dyn FnOnce()>)> {
+				fn set_up_complex_asset_transfer() -> Option<(XcmAssets, u32, Location, Box<dyn FnOnce()>)> {
 					// Only supports native token teleports to system parachain
 					let native_location = Parent.into();
 					let dest = xcm_config::AssetHubLocation::get();
@@ -469,7 +476,7 @@ impl_runtime_apis! {
 					(0u64, Response::Version(Default::default()))
 				}
 
-				fn worst_case_asset_exchange() -> Result<(Assets, Assets), BenchmarkError> {
+				fn worst_case_asset_exchange() -> Result<(XcmAssets, XcmAssets), BenchmarkError> {
 					Err(BenchmarkError::Skip)
 				}
 
@@ -488,9 +495,9 @@ impl_runtime_apis! {
 					Ok(xcm_config::AssetHubLocation::get())
 				}
 
-				fn claimable_asset() -> Result<(Location, Location, Assets), BenchmarkError> {
+				fn claimable_asset() -> Result<(Location, Location, XcmAssets), BenchmarkError> {
 					let origin = xcm_config::AssetHubLocation::get();
-					let assets: Assets = (AssetId(DotLocation::get()), 1_000 * UNITS).into();
+					let assets: XcmAssets = (AssetId(DotLocation::get()), 1_000 * UNITS).into();
 					let ticket = Location { parents: 0, interior: Here };
 					Ok((origin, ticket, assets))
 				}
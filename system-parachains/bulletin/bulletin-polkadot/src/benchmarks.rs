@@ -17,6 +17,8 @@ frame_benchmarking::define_benchmarks!(
 	[frame_system, SystemBench::<Runtime>]
 	[cumulus_pallet_parachain_system, ParachainSystem]
 	[pallet_timestamp, Timestamp]
+	[pallet_asset_rate, AssetRate]
+	[pallet_assets, Assets]
 	[pallet_balances, Balances]
 	[pallet_collator_selection, CollatorSelection]
 	[pallet_session, SessionBench::<Runtime>]
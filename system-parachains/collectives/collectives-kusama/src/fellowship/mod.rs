@@ -0,0 +1,493 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+//! The Kusama Technical Fellowship.
+
+mod origins;
+mod tracks;
+use crate::{
+	fellowship::origins::EnsureCanFastPromoteTo,
+	impls::ToParentTreasury,
+	parameters::{FellowshipSalaryAsset, SalaryAssetId},
+	weights,
+	xcm_config::{LocationToAccountId, TreasurerBodyId},
+	AccountId, AssetHubLocation, AssetRateWithNative, Balance, Balances, FellowshipReferenda,
+	KusamaTreasuryAccount, Preimage, RelayChainLocation, Runtime, RuntimeCall, RuntimeEvent,
+	RuntimeOrigin, Scheduler, DAYS, FELLOWSHIP_TREASURY_PALLET_ID,
+};
+use frame_support::{
+	parameter_types,
+	traits::{
+		EitherOf, EitherOfDiverse, EnsureOriginWithArg, Get, MapSuccess, OriginTrait,
+		PalletInfoAccess, TryWithMorphedArg,
+	},
+	PalletId,
+};
+use frame_system::{EnsureRoot, EnsureRootWithSuccess};
+use kusama_runtime_constants::{currency::GRAND, time::HOURS, xcm::body::FELLOWSHIP_ADMIN_INDEX};
+pub use origins::{
+	pallet_origins as pallet_fellowship_origins, Architects, EnsureCanPromoteTo, EnsureCanRetainAt,
+	EnsureFellowship, Fellows, Masters, Members, ToVoice,
+};
+use pallet_ranked_collective::EnsureOfRank;
+use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
+use polkadot_runtime_common::impls::{
+	LocatableAssetConverter, VersionedLocatableAsset, VersionedLocationConverter,
+};
+use sp_arithmetic::Permill;
+use sp_core::{ConstU128, ConstU32};
+use sp_runtime::traits::{
+	AccountIdConversion, ConstU16, IdentityLookup, Replace, ReplaceWithDefault, TakeFirst,
+};
+use xcm_builder::{AliasesIntoAccountId32, PayOverXcm};
+
+#[cfg(feature = "runtime-benchmarks")]
+use crate::impls::benchmarks::{OpenHrmpChannel, PayWithEnsure};
+
+/// The Fellowship members' ranks.
+pub mod ranks {
+	use pallet_ranked_collective::Rank;
+
+	pub const DAN_1: Rank = 1; // aka Members.
+	pub const DAN_2: Rank = 2;
+	pub const DAN_3: Rank = 3; // aka Fellows.
+	pub const DAN_4: Rank = 4; // aka Architects.
+	pub const DAN_5: Rank = 5;
+	pub const DAN_6: Rank = 6;
+	pub const DAN_7: Rank = 7; // aka Masters.
+	pub const DAN_8: Rank = 8;
+	pub const DAN_9: Rank = 9;
+}
+
+parameter_types! {
+	pub const FellowshipAdminBodyId: BodyId = BodyId::Index(FELLOWSHIP_ADMIN_INDEX);
+	/// The submitter of the referenda moved from the Kusama Relay Chain, the Fellowship Treasury.
+	pub MovedReferendaSubmitter: AccountId =
+		FELLOWSHIP_TREASURY_PALLET_ID.into_account_truncating();
+}
+
+impl pallet_fellowship_origins::Config for Runtime {}
+
+pub type FellowshipReferendaInstance = pallet_referenda::Instance1;
+
+impl pallet_referenda::Config<FellowshipReferendaInstance> for Runtime {
+	type WeightInfo = weights::pallet_referenda_fellowship_referenda::WeightInfo<Runtime>;
+	type RuntimeCall = RuntimeCall;
+	type RuntimeEvent = RuntimeEvent;
+	type Scheduler = Scheduler;
+	type Currency = Balances;
+	// Proposals can be submitted by any of:
+	// - a Fellow (rank 3 and above);
+	// - an account in the governance-managed allow-list;
+	// - the voice of any rank, mapped to the corresponding member account;
+	// - Root, which submits the referenda moved from the Kusama Relay Chain.
+	type SubmitOrigin = EitherOf<
+		EitherOf<
+			EitherOf<
+				pallet_ranked_collective::EnsureMember<Runtime, FellowshipCollectiveInstance, 3>,
+				EnsureAllowedProposer,
+			>,
+			MapSuccess<
+				TryWithMorphedArg<
+					RuntimeOrigin,
+					<RuntimeOrigin as OriginTrait>::PalletsOrigin,
+					ToVoice,
+					EnsureOfRank<Runtime, FellowshipCollectiveInstance>,
+					(AccountId, u16),
+				>,
+				TakeFirst,
+			>,
+		>,
+		EnsureRootWithSuccess<AccountId, MovedReferendaSubmitter>,
+	>;
+	type CancelOrigin = Architects;
+	type KillOrigin = Masters;
+	type Slash = ToParentTreasury<KusamaTreasuryAccount, LocationToAccountId, Runtime>;
+	type Votes = pallet_ranked_collective::Votes;
+	type Tally = pallet_ranked_collective::TallyOf<Runtime, FellowshipCollectiveInstance>;
+	type SubmissionDeposit = ConstU128<0>;
+	type MaxQueued = ConstU32<100>;
+	type UndecidingTimeout = ConstU32<{ 7 * DAYS }>;
+	type AlarmInterval = ConstU32<1>;
+	type Tracks = tracks::TracksInfo;
+	type Preimages = Preimage;
+	type BlockNumberProvider = crate::System;
+}
+
+/// Passes a signed origin whose account is in the [`AllowedProposers`] allow-list.
+///
+/// Lets non-members (e.g. the RFC or tip bot) open Fellowship referenda. The allow-list lives in
+/// the [`AllowedProposers`] dynamic parameter (empty by default, managed via the `Parameters`
+/// pallet).
+///
+/// [`AllowedProposers`]: crate::dynamic_params::fellowship::AllowedProposers
+pub struct EnsureAllowedProposer;
+impl EnsureOriginWithArg<RuntimeOrigin, <RuntimeOrigin as OriginTrait>::PalletsOrigin>
+	for EnsureAllowedProposer
+{
+	type Success = AccountId;
+
+	fn try_origin(
+		o: RuntimeOrigin,
+		_proposal_origin: &<RuntimeOrigin as OriginTrait>::PalletsOrigin,
+	) -> Result<Self::Success, RuntimeOrigin> {
+		match frame_system::ensure_signed(o.clone()) {
+			Ok(who)
+				if crate::dynamic_params::fellowship::AllowedProposers::get().contains(&who) =>
+				Ok(who),
+			_ => Err(o),
+		}
+	}
+
+	#[cfg(feature = "runtime-benchmarks")]
+	fn try_successful_origin(
+		_proposal_origin: &<RuntimeOrigin as OriginTrait>::PalletsOrigin,
+	) -> Result<RuntimeOrigin, ()> {
+		// The allow-list can be empty, so no valid origin can be produced here. Referenda
+		// benchmarks satisfy `SubmitOrigin` through the ranked-member branch instead.
+		Err(())
+	}
+}
+
+pub type FellowshipCollectiveInstance = pallet_ranked_collective::Instance1;
+
+impl pallet_ranked_collective::Config<FellowshipCollectiveInstance> for Runtime {
+	type WeightInfo = weights::pallet_ranked_collective_fellowship_collective::WeightInfo<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+
+	// Promotions and the induction of new members are serviced by `FellowshipCore` pallet instance.
+	// Root only adds and promotes the members moved from the Kusama Relay Chain.
+	//
+	// The maximum value of `u16` set as a success value for the root to ensure the benchmarks will
+	// pass.
+	type PromoteOrigin = EnsureRootWithSuccess<Self::AccountId, ConstU16<65535>>;
+
+	// Demotion is by any of:
+	// - Root can demote arbitrarily.
+	// - the FellowshipAdmin origin (i.e. token holder referendum);
+	//
+	// The maximum value of `u16` set as a success value for the root to ensure the benchmarks will
+	// pass.
+	type DemoteOrigin = EitherOf<
+		EnsureRootWithSuccess<Self::AccountId, ConstU16<65535>>,
+		EitherOf<
+			MapSuccess<
+				EnsureXcm<IsVoiceOfBody<RelayChainLocation, FellowshipAdminBodyId>>,
+				Replace<ConstU16<{ ranks::DAN_9 }>>,
+			>,
+			MapSuccess<
+				EnsureXcm<IsVoiceOfBody<AssetHubLocation, FellowshipAdminBodyId>>,
+				Replace<ConstU16<{ ranks::DAN_9 }>>,
+			>,
+		>,
+	>;
+	// Exchange is by any of:
+	// - Root can exchange arbitrarily.
+	// - the Fellows origin
+	type ExchangeOrigin =
+		EitherOf<frame_system::EnsureRootWithSuccess<Self::AccountId, ConstU16<65535>>, Fellows>;
+	type AddOrigin = MapSuccess<Self::PromoteOrigin, ReplaceWithDefault<()>>;
+	type RemoveOrigin = Self::DemoteOrigin;
+	type Polls = FellowshipReferenda;
+	type MinRankOfClass = tracks::MinRankOfClass;
+	type MemberSwappedHandler = (crate::FellowshipCore, crate::FellowshipSalary);
+	type VoteWeight = pallet_ranked_collective::Geometric;
+	type MaxMemberCount = ();
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkSetup = (crate::FellowshipCore, crate::FellowshipSalary);
+}
+
+pub type FellowshipCoreInstance = pallet_core_fellowship::Instance1;
+
+impl pallet_core_fellowship::Config<FellowshipCoreInstance> for Runtime {
+	type WeightInfo = weights::pallet_core_fellowship_fellowship_core::WeightInfo<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type Members = pallet_ranked_collective::Pallet<Runtime, FellowshipCollectiveInstance>;
+	type Balance = Balance;
+	// Parameters are set by any of:
+	// - Root;
+	// - the FellowshipAdmin origin (i.e. token holder referendum);
+	// - a vote among all Fellows.
+	type ParamsOrigin = EitherOfDiverse<
+		EitherOf<
+			EnsureXcm<IsVoiceOfBody<RelayChainLocation, FellowshipAdminBodyId>>,
+			EnsureXcm<IsVoiceOfBody<AssetHubLocation, FellowshipAdminBodyId>>,
+		>,
+		Fellows,
+	>;
+	// Induction (creating a candidate) is by any of:
+	// - Root;
+	// - the FellowshipAdmin origin (i.e. token holder referendum);
+	// - a single Fellow;
+	// - a vote among all Members.
+	type InductOrigin = EitherOfDiverse<
+		EitherOf<
+			EnsureXcm<IsVoiceOfBody<RelayChainLocation, FellowshipAdminBodyId>>,
+			EnsureXcm<IsVoiceOfBody<AssetHubLocation, FellowshipAdminBodyId>>,
+		>,
+		EitherOfDiverse<
+			pallet_ranked_collective::EnsureMember<
+				Runtime,
+				FellowshipCollectiveInstance,
+				{ ranks::DAN_3 },
+			>,
+			Members,
+		>,
+	>;
+	// Approval (rank-retention) of a Member's current rank is by any of:
+	// - Root;
+	// - the FellowshipAdmin origin (i.e. token holder referendum);
+	// - a vote by the rank two above the current rank for all retention up to the Master rank.
+	type ApproveOrigin = EitherOf<
+		EitherOf<
+			MapSuccess<
+				EnsureXcm<IsVoiceOfBody<RelayChainLocation, FellowshipAdminBodyId>>,
+				Replace<ConstU16<{ ranks::DAN_9 }>>,
+			>,
+			MapSuccess<
+				EnsureXcm<IsVoiceOfBody<AssetHubLocation, FellowshipAdminBodyId>>,
+				Replace<ConstU16<{ ranks::DAN_9 }>>,
+			>,
+		>,
+		EnsureCanRetainAt,
+	>;
+	// Promotion is by any of:
+	// - Root can promote arbitrarily.
+	// - the FellowshipAdmin origin (i.e. token holder referendum);
+	// - a vote by the rank two above the new rank for all promotions up to the Master rank.
+	type PromoteOrigin = EitherOf<
+		EitherOf<
+			MapSuccess<
+				EnsureXcm<IsVoiceOfBody<RelayChainLocation, FellowshipAdminBodyId>>,
+				Replace<ConstU16<{ ranks::DAN_9 }>>,
+			>,
+			MapSuccess<
+				EnsureXcm<IsVoiceOfBody<AssetHubLocation, FellowshipAdminBodyId>>,
+				Replace<ConstU16<{ ranks::DAN_9 }>>,
+			>,
+		>,
+		EnsureCanPromoteTo,
+	>;
+	type FastPromoteOrigin = EnsureCanFastPromoteTo;
+	type EvidenceSize = ConstU32<65536>;
+	type MaxRank = ConstU16<9>;
+}
+
+pub type FellowshipSalaryInstance = pallet_salary::Instance1;
+
+use xcm::prelude::*;
+
+parameter_types! {
+	// The interior location on AssetHub for the paying account. This is the Fellowship Salary
+	// pallet instance. This sovereign account will need funding.
+	pub FellowshipSalaryInteriorLocation: InteriorLocation = PalletInstance(<crate::FellowshipSalary as PalletInfoAccess>::index() as u8).into();
+	// The budget for the Fellowship salary.
+	pub FellowshipSalaryBudget: u128 = crate::dynamic_params::fellowship_salary::SalaryConfig::get().budget;
+}
+
+/// [`PayOverXcm`] setup to pay the Fellowship salary on the AssetHub in the
+/// asset configured via [`crate::dynamic_params::fellowship_salary::SalaryConfig`].
+pub type FellowshipSalaryPaymaster = PayOverXcm<
+	FellowshipSalaryInteriorLocation,
+	crate::xcm_config::XcmConfig,
+	crate::PolkadotXcm,
+	ConstU32<{ 6 * HOURS }>,
+	AccountId,
+	(),
+	SalaryAssetId<FellowshipSalaryAsset>,
+	AliasesIntoAccountId32<(), AccountId>,
+>;
+
+impl pallet_salary::Config<FellowshipSalaryInstance> for Runtime {
+	type WeightInfo = weights::pallet_salary_fellowship_salary::WeightInfo<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Paymaster = FellowshipSalaryPaymaster;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Paymaster = PayWithEnsure<FellowshipSalaryPaymaster, OpenHrmpChannel<ConstU32<1000>>>;
+	type Members = pallet_ranked_collective::Pallet<Runtime, FellowshipCollectiveInstance>;
+
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Salary = pallet_core_fellowship::Pallet<Runtime, FellowshipCoreInstance>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Salary = frame_support::traits::tokens::ConvertRank<
+		crate::impls::benchmarks::RankToSalary<Balances>,
+	>;
+	// 15 days to register for a salary payment.
+	type RegistrationPeriod = ConstU32<{ 15 * DAYS }>;
+	// 15 days to claim the salary payment.
+	type PayoutPeriod = ConstU32<{ 15 * DAYS }>;
+	// Total monthly salary budget.
+	type Budget = FellowshipSalaryBudget;
+}
+
+parameter_types! {
+	pub const FellowshipTreasuryPalletId: PalletId = FELLOWSHIP_TREASURY_PALLET_ID;
+	pub const ProposalBond: Permill = Permill::from_percent(100);
+	pub const Burn: Permill = Permill::from_percent(0);
+	pub const MaxBalance: Balance = Balance::MAX;
+	// The asset's interior location for the paying account. This is the Fellowship Treasury
+	// pallet instance.
+	pub FellowshipTreasuryInteriorLocation: InteriorLocation =
+		PalletInstance(<crate::FellowshipTreasury as PalletInfoAccess>::index() as u8).into();
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+parameter_types! {
+	pub const ProposalBondForBenchmark: Permill = Permill::from_percent(5);
+}
+
+/// [`PayOverXcm`] setup to pay the Fellowship Treasury.
+pub type FellowshipTreasuryPaymaster = PayOverXcm<
+	FellowshipTreasuryInteriorLocation,
+	crate::xcm_config::XcmConfig,
+	crate::PolkadotXcm,
+	ConstU32<{ 6 * HOURS }>,
+	VersionedLocation,
+	VersionedLocatableAsset,
+	LocatableAssetConverter,
+	VersionedLocationConverter,
+>;
+
+pub type FellowshipTreasuryInstance = pallet_treasury::Instance1;
+
+impl pallet_treasury::Config<FellowshipTreasuryInstance> for Runtime {
+	type WeightInfo = weights::pallet_treasury_fellowship_treasury::WeightInfo<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+	type PalletId = FellowshipTreasuryPalletId;
+	type Currency = Balances;
+	type RejectOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		EitherOfDiverse<
+			EitherOf<
+				EnsureXcm<IsVoiceOfBody<RelayChainLocation, TreasurerBodyId>>,
+				EnsureXcm<IsVoiceOfBody<AssetHubLocation, TreasurerBodyId>>,
+			>,
+			Fellows,
+		>,
+	>;
+	type SpendPeriod = ConstU32<{ 7 * DAYS }>;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type MaxApprovals = ConstU32<100>;
+	type SpendOrigin = EitherOf<
+		EitherOf<
+			EnsureRootWithSuccess<AccountId, MaxBalance>,
+			EitherOf<
+				MapSuccess<
+					EnsureXcm<IsVoiceOfBody<RelayChainLocation, TreasurerBodyId>>,
+					Replace<ConstU128<{ 10_000 * GRAND }>>,
+				>,
+				MapSuccess<
+					EnsureXcm<IsVoiceOfBody<AssetHubLocation, TreasurerBodyId>>,
+					Replace<ConstU128<{ 10_000 * GRAND }>>,
+				>,
+			>,
+		>,
+		EitherOf<
+			MapSuccess<Architects, Replace<ConstU128<{ 10_000 * GRAND }>>>,
+			MapSuccess<Fellows, Replace<ConstU128<{ 10 * GRAND }>>>,
+		>,
+	>;
+	type AssetKind = VersionedLocatableAsset;
+	type Beneficiary = VersionedLocation;
+	type BeneficiaryLookup = IdentityLookup<Self::Beneficiary>;
+	#[cfg(not(feature = "runtime-benchmarks"))]
+	type Paymaster = FellowshipTreasuryPaymaster;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Paymaster = PayWithEnsure<FellowshipTreasuryPaymaster, OpenHrmpChannel<ConstU32<1000>>>;
+	type BalanceConverter = AssetRateWithNative;
+	type PayoutPeriod = ConstU32<{ 90 * DAYS }>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = polkadot_runtime_common::impls::benchmarks::TreasuryArguments<
+		sp_core::ConstU8<1>,
+		ConstU32<1000>,
+	>;
+	type BlockNumberProvider = crate::System;
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use frame_support::assert_ok;
+	use sp_runtime::traits::{MaybeConvert, TryConvert};
+
+	type MaxMemberCount =
+		<Runtime as pallet_ranked_collective::Config<FellowshipCollectiveInstance>>::MaxMemberCount;
+
+	#[test]
+	fn max_member_count_correct() {
+		for i in 0..10 {
+			let limit: Option<u16> = MaxMemberCount::maybe_convert(i);
+			assert!(limit.is_none(), "Fellowship has no member limit");
+		}
+	}
+
+	/// Accounts in the governance-managed allow-list can submit Fellowship referenda without being
+	/// ranked members, see <https://github.com/polkadot-fellows/runtimes/issues/629>.
+	#[test]
+	fn allowed_proposers_can_submit_fellowship_referenda() {
+		type SubmitOrigin =
+			<Runtime as pallet_referenda::Config<FellowshipReferendaInstance>>::SubmitOrigin;
+
+		sp_io::TestExternalities::default().execute_with(|| {
+			let bot = AccountId::from([1u8; 32]);
+			// The proposal-origin argument is ignored by the ranked-member and allow-list branches.
+			let proposal_origin = RuntimeOrigin::root().into_caller();
+
+			// The allow-list is empty by default, so a non-member signed account is rejected.
+			assert!(crate::dynamic_params::fellowship::AllowedProposers::get().is_empty());
+			assert!(SubmitOrigin::try_origin(RuntimeOrigin::signed(bot.clone()), &proposal_origin)
+				.is_err());
+
+			// Governance adds the bot to the allow-list through the `Parameters` pallet.
+			assert_ok!(crate::Parameters::set_parameter(
+				RuntimeOrigin::root(),
+				crate::parameters::RuntimeParameters::Fellowship(
+					crate::dynamic_params::fellowship::Parameters::AllowedProposers(
+						crate::dynamic_params::fellowship::AllowedProposers,
+						Some(alloc::vec![bot.clone()].try_into().unwrap()),
+					),
+				),
+			));
+
+			// The bot can now submit, and its account is returned as the submitter.
+			assert_eq!(
+				SubmitOrigin::try_origin(RuntimeOrigin::signed(bot.clone()), &proposal_origin).ok(),
+				Some(bot),
+			);
+		});
+	}
+
+	#[test]
+	fn salary_asset_id_defaults_to_usdt_on_asset_hub() {
+		use sp_io::TestExternalities;
+
+		// Provide minimal externalities, as some runtime storage access may occur.
+		let mut ext = TestExternalities::default();
+		ext.execute_with(|| {
+			let asset = SalaryAssetId::<FellowshipSalaryAsset>::try_convert(())
+				.expect("default salary asset is locatable");
+			assert_eq!(asset.location, Location::new(1, [Parachain(1000)]));
+			assert_eq!(
+				asset.asset_id,
+				AssetId(Location::new(0, [PalletInstance(50), GeneralIndex(1984)])),
+			);
+		});
+	}
+}
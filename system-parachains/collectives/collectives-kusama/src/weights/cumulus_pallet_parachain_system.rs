@@ -0,0 +1,85 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `cumulus_pallet_parachain_system`
+//!
+//! These weights are not yet generated by the benchmark CLI. They are taken from the
+//! Collectives Polkadot runtime and need to be replaced by the output of:
+//!
+//! frame-omni-bencher v1 benchmark pallet --extrinsic=* --pallet=cumulus_pallet_parachain_system
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `cumulus_pallet_parachain_system`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> cumulus_pallet_parachain_system::WeightInfo for WeightInfo<T> {
+	/// Storage: `ParachainSystem::LastDmqMqcHead` (r:1 w:1)
+	/// Proof: `ParachainSystem::LastDmqMqcHead` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `MessageQueue::BookStateFor` (r:1 w:1)
+	/// Proof: `MessageQueue::BookStateFor` (`max_values`: None, `max_size`: Some(52), added: 2527, mode: `MaxEncodedLen`)
+	/// Storage: `MessageQueue::ServiceHead` (r:1 w:1)
+	/// Proof: `MessageQueue::ServiceHead` (`max_values`: Some(1), `max_size`: Some(5), added: 500, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::LastProcessedDownwardMessage` (r:0 w:1)
+	/// Proof: `ParachainSystem::LastProcessedDownwardMessage` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `ParachainSystem::ProcessedDownwardMessages` (r:0 w:1)
+	/// Proof: `ParachainSystem::ProcessedDownwardMessages` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `MessageQueue::Pages` (r:0 w:1000)
+	/// Proof: `MessageQueue::Pages` (`max_values`: None, `max_size`: Some(65585), added: 68060, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 1000]`.
+	fn enqueue_inbound_downward_messages(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `42`
+		//  Estimated: `3517`
+		// Minimum execution time: 883_000 picoseconds.
+		Weight::from_parts(965_000, 0)
+			.saturating_add(Weight::from_parts(0, 3517))
+			// Standard Error: 13_932
+			.saturating_add(Weight::from_parts(119_890_058, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3))
+			.saturating_add(T::DbWeight::get().writes(5))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+	}
+	fn block_weight_tx_extension_max_weight() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_309_000 picoseconds.
+		Weight::from_parts(6_994_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn block_weight_tx_extension_stays_fraction_of_core() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_119_000 picoseconds.
+		Weight::from_parts(6_914_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+	fn block_weight_tx_extension_full_core() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 2_791_000 picoseconds.
+		Weight::from_parts(3_311_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+	}
+}
@@ -0,0 +1,51 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Cumulus.
+
+// Cumulus is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Cumulus is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
+
+pub mod block_weights;
+pub mod cumulus_pallet_parachain_system;
+pub mod cumulus_pallet_weight_reclaim;
+pub mod cumulus_pallet_xcmp_queue;
+pub mod extrinsic_weights;
+pub mod frame_system;
+pub mod frame_system_extensions;
+pub mod pallet_asset_rate;
+pub mod pallet_assets;
+pub mod pallet_balances;
+pub mod pallet_collator_selection;
+pub mod pallet_core_fellowship_fellowship_core;
+pub mod pallet_message_queue;
+pub mod pallet_multisig;
+pub mod pallet_parameters;
+pub mod pallet_preimage;
+pub mod pallet_proxy;
+pub mod pallet_ranked_collective_fellowship_collective;
+pub mod pallet_referenda_fellowship_referenda;
+pub mod pallet_remote_identity;
+pub mod pallet_salary_fellowship_salary;
+pub mod pallet_scheduler;
+pub mod pallet_session;
+pub mod pallet_timestamp;
+pub mod pallet_transaction_payment;
+pub mod pallet_treasury_fellowship_treasury;
+pub mod pallet_utility;
+pub mod pallet_xcm;
+pub mod paritydb_weights;
+pub mod rocksdb_weights;
+pub mod xcm;
+
+pub use block_weights::constants::BlockExecutionWeight;
+pub use extrinsic_weights::constants::ExtrinsicBaseWeight;
+pub use rocksdb_weights::constants::RocksDbWeight;
@@ -0,0 +1,155 @@
+use collectives_kusama_runtime::{
+	xcm_config::{AssetHubLocation, RelayChainLocation},
+	Runtime, RuntimeOrigin,
+};
+use frame_support::{assert_err, assert_ok};
+use parachains_runtimes_test_utils::GovernanceOrigin;
+use sp_runtime::Either;
+use xcm::prelude::*;
+
+#[test]
+fn governance_authorize_upgrade_works() {
+	use kusama_runtime_constants::system_parachain::COLLECTIVES_ID;
+
+	// no - random non-system para
+	assert_err!(
+		parachains_runtimes_test_utils::test_cases::can_governance_authorize_upgrade::<
+			Runtime,
+			RuntimeOrigin,
+		>(GovernanceOrigin::Location(Location::new(1, Parachain(12334)))),
+		Either::Right(InstructionError { index: 0, error: XcmError::Barrier })
+	);
+	// no - random system para
+	assert_err!(
+		parachains_runtimes_test_utils::test_cases::can_governance_authorize_upgrade::<
+			Runtime,
+			RuntimeOrigin,
+		>(GovernanceOrigin::Location(Location::new(1, Parachain(1765)))),
+		Either::Right(InstructionError { index: 0, error: XcmError::Barrier })
+	);
+
+	// no - Collectives
+	assert_err!(
+		parachains_runtimes_test_utils::test_cases::can_governance_authorize_upgrade::<
+			Runtime,
+			RuntimeOrigin,
+		>(GovernanceOrigin::Location(Location::new(1, Parachain(COLLECTIVES_ID)))),
+		Either::Right(InstructionError { index: 0, error: XcmError::Barrier })
+	);
+	// no - Collectives Voice of Fellows plurality
+	assert_err!(
+		parachains_runtimes_test_utils::test_cases::can_governance_authorize_upgrade::<
+			Runtime,
+			RuntimeOrigin,
+		>(GovernanceOrigin::LocationAndDescendOrigin(
+			Location::new(1, Parachain(COLLECTIVES_ID)),
+			Plurality { id: BodyId::Technical, part: BodyPart::Voice }.into()
+		)),
+		Either::Right(InstructionError { index: 0, error: XcmError::Barrier })
+	);
+
+	// ok - relaychain
+	assert_ok!(parachains_runtimes_test_utils::test_cases::can_governance_authorize_upgrade::<
+		Runtime,
+		RuntimeOrigin,
+	>(GovernanceOrigin::Location(RelayChainLocation::get())));
+
+	// ok - AssetHub
+	assert_ok!(parachains_runtimes_test_utils::test_cases::can_governance_authorize_upgrade::<
+		Runtime,
+		RuntimeOrigin,
+	>(GovernanceOrigin::Location(AssetHubLocation::get())));
+}
+
+#[test]
+fn remote_collectives_calls_compatibility() {
+	// if this test fails, make sure `remote_calls::kusama::CollectivesCall` has valid encoding
+	use codec::Encode;
+	use collectives_kusama_runtime::{
+		fellowship::pallet_fellowship_origins::Origin as FellowshipOrigins, OriginCaller,
+		RuntimeCall,
+	};
+	use frame_support::traits::{schedule::DispatchTime, Bounded};
+	use remote_calls::{
+		kusama::{CollectivesCall, CollectivesOrigin, FellowshipOrigin},
+		AccountLookup, BoundedCall, PreimageCall, RankedCollectiveCall, ReferendaCall, UtilityCall,
+		XcmCall,
+	};
+	use sp_runtime::MultiAddress;
+
+	let who = sp_runtime::AccountId32::new([1; 32]);
+	let dest = Location::new(1, [Parachain(1_000)]);
+	let message = VersionedXcm::from(Xcm::<()>(vec![ClearOrigin]));
+	let hash = sp_core::H256::repeat_byte(2);
+
+	for (remote, call) in [
+		(
+			CollectivesCall::PolkadotXcm(XcmCall::Send {
+				dest: dest.clone().into(),
+				message: message.clone(),
+			}),
+			RuntimeCall::PolkadotXcm(pallet_xcm::Call::send {
+				dest: Box::new(dest.into()),
+				message: Box::new(message),
+			}),
+		),
+		(
+			CollectivesCall::Preimage(PreimageCall::NotePreimage { bytes: vec![1, 2, 3] }),
+			RuntimeCall::Preimage(pallet_preimage::Call::note_preimage { bytes: vec![1, 2, 3] }),
+		),
+		(
+			CollectivesCall::Utility(UtilityCall::BatchAll {
+				calls: vec![
+					CollectivesCall::FellowshipCollective(RankedCollectiveCall::AddMember {
+						who: AccountLookup::Id(who.clone()),
+					}),
+					CollectivesCall::FellowshipCollective(RankedCollectiveCall::PromoteMember {
+						who: AccountLookup::Id(who.clone()),
+					}),
+				],
+			}),
+			RuntimeCall::Utility(pallet_utility::Call::batch_all {
+				calls: vec![
+					RuntimeCall::FellowshipCollective(pallet_ranked_collective::Call::add_member {
+						who: MultiAddress::Id(who.clone()),
+					}),
+					RuntimeCall::FellowshipCollective(
+						pallet_ranked_collective::Call::promote_member {
+							who: MultiAddress::Id(who),
+						},
+					),
+				],
+			}),
+		),
+		(
+			CollectivesCall::FellowshipReferenda(ReferendaCall::Submit {
+				proposal_origin: CollectivesOrigin::FellowshipOrigins(FellowshipOrigin::Fellows),
+				proposal: BoundedCall::Inline(vec![1, 2, 3]),
+				enactment_moment: remote_calls::DispatchTime::After(10),
+			}),
+			RuntimeCall::FellowshipReferenda(pallet_referenda::Call::submit {
+				proposal_origin: Box::new(OriginCaller::FellowshipOrigins(
+					FellowshipOrigins::Fellows,
+				)),
+				proposal: Bounded::Inline(vec![1, 2, 3].try_into().unwrap()),
+				enactment_moment: DispatchTime::After(10),
+			}),
+		),
+		(
+			CollectivesCall::FellowshipReferenda(ReferendaCall::Submit {
+				proposal_origin: CollectivesOrigin::FellowshipOrigins(FellowshipOrigin::Architects),
+				proposal: BoundedCall::Lookup { hash, len: 200 },
+				enactment_moment: remote_calls::DispatchTime::At(10),
+			}),
+			RuntimeCall::FellowshipReferenda(pallet_referenda::Call::submit {
+				proposal_origin: Box::new(OriginCaller::FellowshipOrigins(
+					FellowshipOrigins::Architects,
+				)),
+				proposal: Bounded::Lookup { hash, len: 200 },
+				enactment_moment: DispatchTime::At(10),
+			}),
+		),
+	] {
+		assert_eq!(remote.encode(), call.encode(), "{call:?}");
+	}
+}
@@ -34,7 +34,8 @@ mod tracks;
 pub use origins::pallet_origins as pallet_ambassador_origins;
 
 use crate::{
-	xcm_config::{AssetHubUsdt, FellowshipAdminBodyId},
+	parameters::{AmbassadorSalaryAsset, AmbassadorSalaryAssets, SalaryCycle},
+	xcm_config::FellowshipAdminBodyId,
 	AssetRateWithNative, *,
 };
 use frame_support::{
@@ -47,10 +48,7 @@ use pallet_ranked_collective::{MemberIndex, Rank, Votes};
 use polkadot_runtime_common::impls::{LocatableAssetConverter, VersionedLocationConverter};
 use sp_core::ConstU128;
 use sp_runtime::{
-	traits::{
-		CheckedReduceBy, Convert, ConvertToValue, IdentityLookup, MaybeConvert, Replace,
-		ReplaceWithDefault,
-	},
+	traits::{CheckedReduceBy, Convert, IdentityLookup, MaybeConvert, Replace, ReplaceWithDefault},
 	Permill,
 };
 use xcm::prelude::*;
@@ -254,24 +252,24 @@ parameter_types! {
 
 const USDT_UNITS: u128 = 1_000_000;
 
-/// [`PayOverXcm`] setup to pay the Ambassador salary on the AssetHub in USDt.
+/// [`PayOverXcm`] setup to pay each asset of the Ambassador salary on the AssetHub.
 pub type AmbassadorSalaryPaymaster = PayOverXcm<
 	AmbassadorSalaryLocation,
 	crate::xcm_config::XcmConfig,
 	crate::PolkadotXcm,
 	ConstU32<{ 6 * HOURS }>,
 	AccountId,
-	(),
-	ConvertToValue<AssetHubUsdt>,
+	VersionedLocatableAsset,
+	LocatableAssetConverter,
 	AliasesIntoAccountId32<(), AccountId>,
 >;
 
-pub type AmbassadorSalaryInstance = pallet_salary::Instance2;
-
-impl pallet_salary::Config<AmbassadorSalaryInstance> for Runtime {
-	type WeightInfo = weights::pallet_salary_ambassador_salary::WeightInfo<Runtime>;
-	type RuntimeEvent = RuntimeEvent;
+pub type AmbassadorSalaryPayoutsInstance = pallet_salary_payouts::Instance2;
 
+/// Pays the Ambassador salary in the assets chosen by the members, out of the assets and budgets
+/// configured via [`crate::dynamic_params::ambassador_salary::Assets`].
+impl pallet_salary_payouts::Config<AmbassadorSalaryPayoutsInstance> for Runtime {
+	type AssetKind = VersionedLocatableAsset;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type Paymaster = AmbassadorSalaryPaymaster;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -279,6 +277,31 @@ impl pallet_salary::Config<AmbassadorSalaryInstance> for Runtime {
 		AmbassadorSalaryPaymaster,
 		crate::impls::benchmarks::OpenHrmpChannel<ConstU32<1000>>,
 	>;
+	type Converter = AssetRateWithNative;
+	type ReferenceAsset = AmbassadorSalaryAsset;
+	type Assets = AmbassadorSalaryAssets;
+	type Cycle = SalaryCycle<AmbassadorSalaryInstance>;
+	// Ambassadors, who are paid a salary.
+	type MemberOrigin = pallet_ranked_collective::EnsureMember<
+		Runtime,
+		AmbassadorCollectiveInstance,
+		{ ranks::AMBASSADOR },
+	>;
+	type MaxSplits = ConstU32<4>;
+	type WeightInfo = weights::pallet_salary_payouts_ambassador_salary_payouts::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = crate::parameters::SalaryPayoutsBenchmarkHelper<
+		crate::parameters::AmbassadorSalaryAssetsParameter,
+	>;
+}
+
+pub type AmbassadorSalaryInstance = pallet_salary::Instance2;
+
+impl pallet_salary::Config<AmbassadorSalaryInstance> for Runtime {
+	type WeightInfo = weights::pallet_salary_ambassador_salary::WeightInfo<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+
+	type Paymaster = crate::AmbassadorSalaryPayouts;
 	type Members = pallet_ranked_collective::Pallet<Runtime, AmbassadorCollectiveInstance>;
 
 	#[cfg(not(feature = "runtime-benchmarks"))]
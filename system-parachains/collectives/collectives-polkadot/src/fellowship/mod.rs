@@ -21,7 +21,7 @@ mod tracks;
 use crate::{
 	fellowship::origins::EnsureCanFastPromoteTo,
 	impls::ToParentTreasury,
-	parameters::{FellowshipSalaryAsset, SalaryAssetId},
+	parameters::{FellowshipSalaryAsset, FellowshipSalaryAssets, SalaryCycle},
 	weights,
 	xcm_config::{LocationToAccountId, TreasurerBodyId},
 	AccountId, AssetHubLocation, AssetRateWithNative, Balance, Balances, FellowshipReferenda,
@@ -290,27 +290,51 @@ parameter_types! {
 	pub FellowshipSalaryBudget: u128 = crate::dynamic_params::fellowship_salary::SalaryConfig::get().budget;
 }
 
-/// [`PayOverXcm`] setup to pay the Fellowship salary on the AssetHub in the
-/// asset configured via [`crate::dynamic_params::fellowship_salary::SalaryConfig`].
+/// [`PayOverXcm`] setup to pay each asset of the Fellowship salary on the AssetHub.
 pub type FellowshipSalaryPaymaster = PayOverXcm<
 	FellowshipSalaryInteriorLocation,
 	crate::xcm_config::XcmConfig,
 	crate::PolkadotXcm,
 	ConstU32<{ 6 * HOURS }>,
 	AccountId,
-	(),
-	SalaryAssetId<FellowshipSalaryAsset>,
+	VersionedLocatableAsset,
+	LocatableAssetConverter,
 	AliasesIntoAccountId32<(), AccountId>,
 >;
 
-impl pallet_salary::Config<FellowshipSalaryInstance> for Runtime {
-	type WeightInfo = weights::pallet_salary_fellowship_salary::WeightInfo<Runtime>;
-	type RuntimeEvent = RuntimeEvent;
+pub type FellowshipSalaryPayoutsInstance = pallet_salary_payouts::Instance1;
 
+/// Pays the Fellowship salary in the assets chosen by the members, out of the assets and budgets
+/// configured via [`crate::dynamic_params::fellowship_salary::Assets`].
+impl pallet_salary_payouts::Config<FellowshipSalaryPayoutsInstance> for Runtime {
+	type AssetKind = VersionedLocatableAsset;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type Paymaster = FellowshipSalaryPaymaster;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Paymaster = PayWithEnsure<FellowshipSalaryPaymaster, OpenHrmpChannel<ConstU32<1000>>>;
+	type Converter = AssetRateWithNative;
+	type ReferenceAsset = FellowshipSalaryAsset;
+	type Assets = FellowshipSalaryAssets;
+	type Cycle = SalaryCycle<FellowshipSalaryInstance>;
+	// Members from rank 1, who are paid a salary.
+	type MemberOrigin = pallet_ranked_collective::EnsureMember<
+		Runtime,
+		FellowshipCollectiveInstance,
+		{ ranks::DAN_1 },
+	>;
+	type MaxSplits = ConstU32<4>;
+	type WeightInfo = weights::pallet_salary_payouts_fellowship_salary_payouts::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = crate::parameters::SalaryPayoutsBenchmarkHelper<
+		crate::parameters::FellowshipSalaryAssetsParameter,
+	>;
+}
+
+impl pallet_salary::Config<FellowshipSalaryInstance> for Runtime {
+	type WeightInfo = weights::pallet_salary_fellowship_salary::WeightInfo<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+
+	type Paymaster = crate::FellowshipSalaryPayouts;
 	type Members = pallet_ranked_collective::Pallet<Runtime, FellowshipCollectiveInstance>;
 
 	#[cfg(not(feature = "runtime-benchmarks"))]
@@ -416,6 +440,7 @@ impl pallet_treasury::Config<FellowshipTreasuryInstance> for Runtime {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::parameters::SalaryAssetId;
 	use frame_support::assert_ok;
 	use sp_runtime::traits::{MaybeConvert, TryConvert};
 
@@ -39,6 +39,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 extern crate alloc;
 
 pub mod ambassador;
+pub mod assets;
 // Genesis preset configurations.
 pub mod genesis_config_presets;
 pub mod impls;
@@ -442,6 +443,22 @@ impl pallet_proxy::Config for Runtime {
 	type BlockNumberProvider = System;
 }
 
+impl pallet_remote_identity::Config for Runtime {
+	// The time between creating a proof and using the proof in a transaction.
+	type MaxStorageRootsToKeep = ConstU32<{ polkadot_runtime_constants::time::MINUTES }>;
+	// Judgements need to be proven again after a week to propagate removals on the People chain.
+	type MaxJudgementAge = ConstU32<{ 7 * polkadot_runtime_constants::time::DAYS }>;
+	// Generous bounds, a relay chain proof of a para head has about ten nodes of at most 600
+	// bytes and the identity proof of the People chain is of similar size.
+	type MaxProofNodes = ConstU32<64>;
+	type MaxProofSize = ConstU32<{ 64 * 1024 }>;
+	type MaxRemovalsPerBlock = ConstU32<64>;
+	type RemoteIdentity = system_parachains_common::remote_identity::PeopleChainIdentityInterface<
+		polkadot_runtime_constants::system_parachain::PeopleParaId,
+	>;
+	type WeightInfo = weights::pallet_remote_identity::WeightInfo<Runtime>;
+}
+
 parameter_types! {
 	pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
 	pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
@@ -450,7 +467,7 @@ parameter_types! {
 
 impl cumulus_pallet_parachain_system::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
-	type OnSystemEvent = ();
+	type OnSystemEvent = RemoteIdentityPeople;
 	type SelfParaId = parachain_info::Pallet<Runtime>;
 	type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
 	type ReservedDmpWeight = ReservedDmpWeight;
@@ -769,6 +786,7 @@ construct_runtime!(
 		// Monetary stuff.
 		Balances: pallet_balances = 10,
 		TransactionPayment: pallet_transaction_payment = 11,
+		Assets: pallet_assets = 12,
 
 		// Collator support. the order of these 5 are important and shall not change.
 		Authorship: pallet_authorship = 20,
@@ -792,6 +810,7 @@ construct_runtime!(
 		Scheduler: pallet_scheduler = 44,
 		AssetRate: pallet_asset_rate = 45,
 		Parameters: pallet_parameters = 46,
+		RemoteIdentityPeople: pallet_remote_identity = 47,
 
 		// The main stage.
 
@@ -811,6 +830,8 @@ construct_runtime!(
 		FellowshipSalary: pallet_salary::<Instance1> = 64,
 		// pub type FellowshipTreasuryInstance = pallet_treasury::Instance1;
 		FellowshipTreasury: pallet_treasury::<Instance1> = 65,
+		// pub type FellowshipSalaryPayoutsInstance = pallet_salary_payouts::Instance1;
+		FellowshipSalaryPayouts: pallet_salary_payouts::<Instance1> = 66,
 
 		// Ambassador Program.
 		AmbassadorCollective: pallet_ranked_collective::<Instance2> = 70,
@@ -819,12 +840,15 @@ construct_runtime!(
 		AmbassadorCore: pallet_core_fellowship::<Instance2> = 73,
 		AmbassadorSalary: pallet_salary::<Instance2> = 74,
 		AmbassadorTreasury: pallet_treasury::<Instance2> = 75,
+		AmbassadorSalaryPayouts: pallet_salary_payouts::<Instance2> = 76,
 
 		// The Secretary Collective
 		// pub type SecretaryCollectiveInstance = pallet_ranked_collective::instance3;
 		SecretaryCollective: pallet_ranked_collective::<Instance3> = 80,
 		// pub type SecretarySalaryInstance = pallet_salary::Instance3;
 		SecretarySalary: pallet_salary::<Instance3> = 81,
+		// pub type SecretarySalaryPayoutsInstance = pallet_salary_payouts::Instance3;
+		SecretarySalaryPayouts: pallet_salary_payouts::<Instance3> = 82,
 	}
 );
 
@@ -892,15 +916,18 @@ mod benches {
 	use super::*;
 	use polkadot_runtime_constants::system_parachain::AssetHubParaId;
 	use system_parachains_constants::polkadot::locations::AssetHubLocation;
+	use xcm::latest::Assets as XcmAssets;
 
 	frame_benchmarking::define_benchmarks!(
 		[frame_system, SystemBench::<Runtime>]
 		[frame_system_extensions, SystemExtensionsBench::<Runtime>]
+		[pallet_assets, Assets]
 		[pallet_balances, Balances]
 		[pallet_message_queue, MessageQueue]
 		[pallet_multisig, Multisig]
 		[pallet_parameters, Parameters]
 		[pallet_proxy, Proxy]
+		[pallet_remote_identity, RemoteIdentityPeople]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_utility, Utility]
 		[pallet_timestamp, Timestamp]
@@ -916,6 +943,7 @@ mod benches {
 		[pallet_ranked_collective, FellowshipCollective]
 		[pallet_core_fellowship, FellowshipCore]
 		[pallet_salary, FellowshipSalary]
+		[pallet_salary_payouts, FellowshipSalaryPayouts]
 		[pallet_transaction_payment, TransactionPayment]
 		[pallet_treasury, FellowshipTreasury]
 		[pallet_asset_rate, AssetRate]
@@ -923,9 +951,11 @@ mod benches {
 		[pallet_ranked_collective, AmbassadorCollective]
 		[pallet_core_fellowship, AmbassadorCore]
 		[pallet_salary, AmbassadorSalary]
+		[pallet_salary_payouts, AmbassadorSalaryPayouts]
 		[pallet_treasury, AmbassadorTreasury]
 		[pallet_ranked_collective, SecretaryCollective]
 		[pallet_salary, SecretarySalary]
+		[pallet_salary_payouts, SecretarySalaryPayouts]
 		// XCM
 		[pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
 		[pallet_xcm_benchmarks::fungible, XcmBalances]
@@ -989,7 +1019,8 @@ mod benches {
 			None
 		}
 
-		fn set_up_complex_asset_transfer() -> Option<(Assets, u32, Location, Box<dyn FnOnce()>)> {
+		fn set_up_complex_asset_transfer() -> Option<(XcmAssets, u32, Location, Box<dyn FnOnce()>)>
+		{
 			// Only supports native token teleports to system parachain
 			let native_location = Parent.into();
 			let dest = AssetHubLocation::get();
@@ -1059,7 +1090,7 @@ mod benches {
 			(0u64, Response::Version(Default::default()))
 		}
 
-		fn worst_case_asset_exchange() -> Result<(Assets, Assets), BenchmarkError> {
+		fn worst_case_asset_exchange() -> Result<(XcmAssets, XcmAssets), BenchmarkError> {
 			Err(BenchmarkError::Skip)
 		}
 
@@ -1078,9 +1109,9 @@ mod benches {
 			Ok(AssetHubLocation::get())
 		}
 
-		fn claimable_asset() -> Result<(Location, Location, Assets), BenchmarkError> {
+		fn claimable_asset() -> Result<(Location, Location, XcmAssets), BenchmarkError> {
 			let origin = AssetHubLocation::get();
-			let assets: Assets = (AssetId(DotLocation::get()), 1_000 * UNITS).into();
+			let assets: XcmAssets = (AssetId(DotLocation::get()), 1_000 * UNITS).into();
 			let ticket = Location { parents: 0, interior: Here };
 			Ok((origin, ticket, assets))
 		}
@@ -1292,7 +1323,16 @@ impl_runtime_apis! {
 
 	impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
-			let acceptable_assets = vec![AssetId(xcm_config::DotLocation::get())];
+			use frame_support::traits::tokens::ConversionToAssetBalance;
+			// The relay chain token and the assets with a rate to it.
+			let acceptable_assets = [AssetId(xcm_config::DotLocation::get())]
+				.into_iter()
+				.chain(
+					pallet_assets::Asset::<Runtime>::iter_keys()
+						.filter(|asset| xcm_config::AssetHubAssetRates::to_asset_balance(1, asset.clone()).is_ok())
+						.map(AssetId),
+				)
+				.collect();
 			PolkadotXcm::query_acceptable_payment_assets(xcm_version, acceptable_assets)
 		}
 
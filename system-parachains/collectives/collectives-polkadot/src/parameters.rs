@@ -45,6 +45,20 @@ pub mod dynamic_params {
 				}),
 				budget: 250_000 * 1_000_000,
 			};
+
+		/// The assets the Fellowship salary can be paid in, in the order of fallback.
+		///
+		/// Defaults to USDT on Asset Hub with the whole budget of [`SalaryConfig`]. Other assets
+		/// need a rate in the `AssetRate` pallet.
+		#[codec(index = 1)]
+		pub static Assets: crate::parameters::SalaryAssets =
+			crate::parameters::SalaryAssets::truncate_from(vec![crate::parameters::SalaryAsset {
+				asset: Box::new(VersionedLocatableAsset::V5 {
+					location: crate::xcm_config::AssetHubUsdt::get().location,
+					asset_id: crate::xcm_config::AssetHubUsdt::get().asset_id,
+				}),
+				budget: 250_000 * 1_000_000,
+			}]);
 	}
 
 	/// Secretary Salary Parameters.
@@ -67,6 +81,19 @@ pub mod dynamic_params {
 				budget: 13_332 * 1_000_000,
 				salary_rank1: 6666 * 1_000_000,
 			};
+
+		/// The assets the Secretary salary can be paid in, in the order of fallback.
+		///
+		/// Defaults to USDT on Asset Hub with the whole budget of [`SalaryConfig`].
+		#[codec(index = 1)]
+		pub static Assets: crate::parameters::SalaryAssets =
+			crate::parameters::SalaryAssets::truncate_from(vec![crate::parameters::SalaryAsset {
+				asset: Box::new(VersionedLocatableAsset::V5 {
+					location: crate::xcm_config::AssetHubUsdt::get().location,
+					asset_id: crate::xcm_config::AssetHubUsdt::get().asset_id,
+				}),
+				budget: 13_332 * 1_000_000,
+			}]);
 	}
 
 	/// Parameters of the Polkadot Technical Fellowship.
@@ -79,6 +106,24 @@ pub mod dynamic_params {
 		#[codec(index = 0)]
 		pub static AllowedProposers: BoundedVec<AccountId, ConstU32<16>> = Default::default();
 	}
+
+	/// Ambassador Salary Parameters.
+	#[dynamic_pallet_params]
+	#[codec(index = 3)]
+	pub mod ambassador_salary {
+		/// The assets the Ambassador salary can be paid in, in the order of fallback.
+		///
+		/// Defaults to USDT on Asset Hub with the whole budget of the Ambassador salary.
+		#[codec(index = 0)]
+		pub static Assets: crate::parameters::SalaryAssets =
+			crate::parameters::SalaryAssets::truncate_from(vec![crate::parameters::SalaryAsset {
+				asset: Box::new(VersionedLocatableAsset::V5 {
+					location: crate::xcm_config::AssetHubUsdt::get().location,
+					asset_id: crate::xcm_config::AssetHubUsdt::get().asset_id,
+				}),
+				budget: 10_000 * 21 * 1_000_000,
+			}]);
+	}
 }
 
 parameter_types! {
@@ -90,6 +135,34 @@ parameter_types! {
 	/// parameter.
 	pub SecretarySalaryAsset: VersionedLocatableAsset =
 		*dynamic_params::secretary_salary::SalaryConfig::get().asset;
+	/// The Ambassador salary asset, USDT on Asset Hub.
+	pub AmbassadorSalaryAsset: VersionedLocatableAsset = VersionedLocatableAsset::V5 {
+		location: crate::xcm_config::AssetHubUsdt::get().location,
+		asset_id: crate::xcm_config::AssetHubUsdt::get().asset_id,
+	};
+	/// The assets of the Fellowship salary, read from the
+	/// [`dynamic_params::fellowship_salary::Assets`] parameter.
+	pub FellowshipSalaryAssets: Vec<(VersionedLocatableAsset, u128)> =
+		dynamic_params::fellowship_salary::Assets::get().into_inner().into_iter().map(Into::into).collect();
+	/// The assets of the Secretary salary, read from the
+	/// [`dynamic_params::secretary_salary::Assets`] parameter.
+	pub SecretarySalaryAssets: Vec<(VersionedLocatableAsset, u128)> =
+		dynamic_params::secretary_salary::Assets::get().into_inner().into_iter().map(Into::into).collect();
+	/// The assets of the Ambassador salary, read from the
+	/// [`dynamic_params::ambassador_salary::Assets`] parameter.
+	pub AmbassadorSalaryAssets: Vec<(VersionedLocatableAsset, u128)> =
+		dynamic_params::ambassador_salary::Assets::get().into_inner().into_iter().map(Into::into).collect();
+}
+
+/// The index of the current cycle of the `pallet_salary` instance `I`.
+pub struct SalaryCycle<I>(PhantomData<I>);
+impl<I: 'static> Get<u32> for SalaryCycle<I>
+where
+	Runtime: pallet_salary::Config<I>,
+{
+	fn get() -> u32 {
+		pallet_salary::Status::<Runtime, I>::get().map_or(0, |status| status.cycle_index)
+	}
 }
 
 /// Resolves a configured [`VersionedLocatableAsset`] to a [`LocatableAssetId`] for use as the
@@ -108,6 +181,90 @@ impl<Asset: Get<VersionedLocatableAsset>> TryConvert<(), LocatableAssetId>
 	}
 }
 
+/// Sets up the accepted assets of the salary whose `Assets` parameter is built by `P`.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SalaryPayoutsBenchmarkHelper<P>(PhantomData<P>);
+#[cfg(feature = "runtime-benchmarks")]
+impl<P: sp_runtime::traits::Convert<SalaryAssets, RuntimeParameters>>
+	pallet_salary_payouts::BenchmarkHelper<VersionedLocatableAsset>
+	for SalaryPayoutsBenchmarkHelper<P>
+{
+	fn setup_assets(count: u32) -> Vec<VersionedLocatableAsset> {
+		use xcm::v5::{
+			AssetId,
+			Junction::{GeneralIndex, PalletInstance},
+			Location,
+		};
+
+		let assets: Vec<_> = (0..count)
+			.map(|index| VersionedLocatableAsset::V5 {
+				location: AssetHubLocation::get(),
+				asset_id: AssetId(Location::new(
+					0,
+					[PalletInstance(50), GeneralIndex(index.into())],
+				)),
+			})
+			.collect();
+		let salary_assets = SalaryAssets::truncate_from(
+			assets
+				.iter()
+				.map(|asset| SalaryAsset { asset: Box::new(asset.clone()), budget: 1_000_000 })
+				.collect(),
+		);
+		pallet_parameters::Pallet::<Runtime>::set_parameter(
+			RuntimeOrigin::root(),
+			P::convert(salary_assets),
+		)
+		.expect("root sets the salary assets");
+		assets
+	}
+}
+
+/// Builds the [`dynamic_params::fellowship_salary::Assets`] parameter.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct FellowshipSalaryAssetsParameter;
+#[cfg(feature = "runtime-benchmarks")]
+impl sp_runtime::traits::Convert<SalaryAssets, RuntimeParameters>
+	for FellowshipSalaryAssetsParameter
+{
+	fn convert(assets: SalaryAssets) -> RuntimeParameters {
+		RuntimeParameters::FellowshipSalary(dynamic_params::fellowship_salary::Parameters::Assets(
+			dynamic_params::fellowship_salary::Assets,
+			Some(assets),
+		))
+	}
+}
+
+/// Builds the [`dynamic_params::secretary_salary::Assets`] parameter.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SecretarySalaryAssetsParameter;
+#[cfg(feature = "runtime-benchmarks")]
+impl sp_runtime::traits::Convert<SalaryAssets, RuntimeParameters>
+	for SecretarySalaryAssetsParameter
+{
+	fn convert(assets: SalaryAssets) -> RuntimeParameters {
+		RuntimeParameters::SecretarySalary(dynamic_params::secretary_salary::Parameters::Assets(
+			dynamic_params::secretary_salary::Assets,
+			Some(assets),
+		))
+	}
+}
+
+/// Builds the [`dynamic_params::ambassador_salary::Assets`] parameter.
+#[cfg(feature = "runtime-benchmarks")]
+pub struct AmbassadorSalaryAssetsParameter;
+#[cfg(feature = "runtime-benchmarks")]
+impl sp_runtime::traits::Convert<SalaryAssets, RuntimeParameters>
+	for AmbassadorSalaryAssetsParameter
+{
+	fn convert(assets: SalaryAssets) -> RuntimeParameters {
+		RuntimeParameters::AmbassadorSalary(dynamic_params::ambassador_salary::Parameters::Assets(
+			dynamic_params::ambassador_salary::Assets,
+			Some(assets),
+		))
+	}
+}
+
 impl pallet_parameters::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeParameters = RuntimeParameters;
@@ -166,6 +323,12 @@ impl EnsureOriginWithArg<RuntimeOrigin, RuntimeParametersKey> for DynamicParamet
 			>::ensure_origin(origin.clone())
 			.map(|_| ())
 			.map_err(|_| origin),
+			// The Ambassador salary assets can be set by Root, the FellowshipAdmin origin or the
+			// Head Ambassadors.
+			RuntimeParametersKey::AmbassadorSalary(_) =>
+				crate::ambassador::OpenGovOrHeadAmbassadors::ensure_origin(origin.clone())
+					.map(|_| ())
+					.map_err(|_| origin),
 		}
 	}
 
@@ -225,3 +388,34 @@ pub struct SecretarySalaryConfig {
 	/// value of the asset (e.g., USDT on Asset Hub with 6 decimals).
 	pub salary_rank1: u128,
 }
+
+/// The assets a salary can be paid in, at most 8.
+pub type SalaryAssets = BoundedVec<SalaryAsset, ConstU32<8>>;
+
+/// An asset a salary can be paid in.
+#[derive(
+	Encode,
+	Decode,
+	scale_info::TypeInfo,
+	DecodeWithMemTracking,
+	MaxEncodedLen,
+	Clone,
+	PartialEq,
+	Eq,
+	Debug,
+)]
+pub struct SalaryAsset {
+	/// The asset.
+	///
+	/// Assets other than the salary asset need a conversion rate in the `AssetRate` pallet.
+	pub asset: Box<VersionedLocatableAsset>,
+	/// The budget of the asset for a single period (i.e., `RegistrationPeriod` + `PayoutPeriod`),
+	/// expressed as the raw value of the `asset`.
+	pub budget: u128,
+}
+
+impl From<SalaryAsset> for (VersionedLocatableAsset, u128) {
+	fn from(salary_asset: SalaryAsset) -> Self {
+		(*salary_asset.asset, salary_asset.budget)
+	}
+}
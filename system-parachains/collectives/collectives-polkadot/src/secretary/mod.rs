@@ -18,12 +18,13 @@
 
 use crate::{
 	fellowship::FellowshipAdminBodyId,
-	parameters::{SalaryAssetId, SecretarySalaryAsset},
+	parameters::{SalaryCycle, SecretarySalaryAsset, SecretarySalaryAssets},
 	*,
 };
 use frame_support::traits::{tokens::GetSalary, EitherOf, Get, MapSuccess, NoOpPoll};
 use frame_system::{pallet_prelude::BlockNumberFor, EnsureRootWithSuccess};
 use pallet_xcm::{EnsureXcm, IsVoiceOfBody};
+use polkadot_runtime_common::impls::LocatableAssetConverter;
 use sp_core::ConstU32;
 use sp_runtime::traits::{ConstU16, Identity, Replace};
 use xcm_builder::{AliasesIntoAccountId32, PayOverXcm};
@@ -90,27 +91,24 @@ impl GetSalary<u16, AccountId, Balance> for SalaryForRank {
 	}
 }
 
-/// [`PayOverXcm`] setup to pay the Secretary salary on the AssetHub in the
-/// asset configured via [`crate::dynamic_params::secretary_salary::SalaryConfig`].
+/// [`PayOverXcm`] setup to pay each asset of the Secretary salary on the AssetHub.
 pub type SecretarySalaryPaymaster = PayOverXcm<
 	crate::fellowship::FellowshipSalaryInteriorLocation,
 	crate::xcm_config::XcmConfig,
 	crate::PolkadotXcm,
 	ConstU32<{ 6 * HOURS }>,
 	AccountId,
-	(),
-	SalaryAssetId<SecretarySalaryAsset>,
+	VersionedLocatableAsset,
+	LocatableAssetConverter,
 	AliasesIntoAccountId32<(), AccountId>,
 >;
 
-parameter_types! {
-	pub SecretarySalaryBudget: u128 = crate::dynamic_params::secretary_salary::SalaryConfig::get().budget;
-}
-
-impl pallet_salary::Config<SecretarySalaryInstance> for Runtime {
-	type WeightInfo = weights::pallet_salary_secretary_salary::WeightInfo<Runtime>;
-	type RuntimeEvent = RuntimeEvent;
+pub type SecretarySalaryPayoutsInstance = pallet_salary_payouts::Instance3;
 
+/// Pays the Secretary salary in the assets chosen by the members, out of the assets and budgets
+/// configured via [`crate::dynamic_params::secretary_salary::Assets`].
+impl pallet_salary_payouts::Config<SecretarySalaryPayoutsInstance> for Runtime {
+	type AssetKind = VersionedLocatableAsset;
 	#[cfg(not(feature = "runtime-benchmarks"))]
 	type Paymaster = SecretarySalaryPaymaster;
 	#[cfg(feature = "runtime-benchmarks")]
@@ -118,6 +116,32 @@ impl pallet_salary::Config<SecretarySalaryInstance> for Runtime {
 		SecretarySalaryPaymaster,
 		crate::impls::benchmarks::OpenHrmpChannel<ConstU32<1000>>,
 	>;
+	type Converter = AssetRateWithNative;
+	type ReferenceAsset = SecretarySalaryAsset;
+	type Assets = SecretarySalaryAssets;
+	type Cycle = SalaryCycle<SecretarySalaryInstance>;
+	type MemberOrigin = pallet_ranked_collective::EnsureMember<
+		Runtime,
+		SecretaryCollectiveInstance,
+		{ ranks::SECRETARY },
+	>;
+	type MaxSplits = ConstU32<4>;
+	type WeightInfo = weights::pallet_salary_payouts_secretary_salary_payouts::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = crate::parameters::SalaryPayoutsBenchmarkHelper<
+		crate::parameters::SecretarySalaryAssetsParameter,
+	>;
+}
+
+parameter_types! {
+	pub SecretarySalaryBudget: u128 = crate::dynamic_params::secretary_salary::SalaryConfig::get().budget;
+}
+
+impl pallet_salary::Config<SecretarySalaryInstance> for Runtime {
+	type WeightInfo = weights::pallet_salary_secretary_salary::WeightInfo<Runtime>;
+	type RuntimeEvent = RuntimeEvent;
+
+	type Paymaster = crate::SecretarySalaryPayouts;
 	type Members = pallet_ranked_collective::Pallet<Runtime, SecretaryCollectiveInstance>;
 
 	#[cfg(not(feature = "runtime-benchmarks"))]
@@ -23,6 +23,7 @@ pub mod frame_system;
 pub mod frame_system_extensions;
 pub mod pallet_alliance;
 pub mod pallet_asset_rate;
+pub mod pallet_assets;
 pub mod pallet_balances;
 pub mod pallet_collator_selection;
 pub mod pallet_collective;
@@ -38,8 +39,12 @@ pub mod pallet_ranked_collective_fellowship_collective;
 pub mod pallet_ranked_collective_secretary_collective;
 pub mod pallet_referenda_ambassador_referenda;
 pub mod pallet_referenda_fellowship_referenda;
+pub mod pallet_remote_identity;
 pub mod pallet_salary_ambassador_salary;
 pub mod pallet_salary_fellowship_salary;
+pub mod pallet_salary_payouts_ambassador_salary_payouts;
+pub mod pallet_salary_payouts_fellowship_salary_payouts;
+pub mod pallet_salary_payouts_secretary_salary_payouts;
 pub mod pallet_salary_secretary_salary;
 pub mod pallet_scheduler;
 pub mod pallet_session;
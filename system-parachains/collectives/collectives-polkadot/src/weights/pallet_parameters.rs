@@ -42,11 +42,11 @@
 //! Hand-tuned weights for `pallet_parameters`.
 //!
 //! `ref_time` is carried over from the Asset Hub Polkadot `set_parameter` benchmark.
-//! `proof_size` is derived from `Value::max_encoded_len() = 1240` (largest variant is
-//! `SecretarySalaryConfig` = `Box<VersionedLocatableAsset>` + 2 × `u128`, plus 2 enum
-//! discriminator bytes). Using the benchmarking convention:
-//!   `added`     = max_size + 2475 = 3715
-//!   `Estimated` = added    +  990 = 4705
+//! `proof_size` is derived from `Value::max_encoded_len() = 9779` (largest variant is
+//! `SalaryAssets` = 8 × (`Box<VersionedLocatableAsset>` + `u128`) plus the length prefix, plus 2
+//! enum discriminator bytes). Using the benchmarking convention:
+//!   `added`     = max_size + 2475 = 12254
+//!   `Estimated` = added    +  990 = 13244
 //!
 //! Should be regenerated with the benchmarking pipeline once the runtime is built.
 
@@ -62,14 +62,14 @@ use core::marker::PhantomData;
 pub struct WeightInfo<T>(PhantomData<T>);
 impl<T: frame_system::Config> pallet_parameters::WeightInfo for WeightInfo<T> {
 	/// Storage: `Parameters::Parameters` (r:1 w:1)
-	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(551), added: 3026, mode: `MaxEncodedLen`)
+	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(9779), added: 12254, mode: `MaxEncodedLen`)
 	fn set_parameter() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `4`
-		//  Estimated: `4016`
+		//  Estimated: `13244`
 		// Minimum execution time: 6_496_000 picoseconds.
 		Weight::from_parts(7_005_000, 0)
-			.saturating_add(Weight::from_parts(0, 4016))
+			.saturating_add(Weight::from_parts(0, 13244))
 			.saturating_add(T::DbWeight::get().reads(1))
 			.saturating_add(T::DbWeight::get().writes(1))
 	}
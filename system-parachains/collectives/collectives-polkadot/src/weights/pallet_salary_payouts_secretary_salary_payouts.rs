@@ -0,0 +1,50 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_salary_payouts`
+//!
+//! These weights are not yet generated by the benchmark CLI. They are estimated from the storage
+//! accesses of the call. They need to be replaced by the output of:
+//!
+//! frame-omni-bencher v1 benchmark pallet --extrinsic=* --pallet=pallet_salary_payouts
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_salary_payouts`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_salary_payouts::WeightInfo for WeightInfo<T> {
+	/// Storage: `SecretaryCollective::Members` (r:1 w:0)
+	/// Proof: `SecretaryCollective::Members` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `Parameters::Parameters` (r:1 w:0)
+	/// Proof: `Parameters::Parameters` (`max_values`: None, `max_size`: Some(9779), added: 12254, mode: `MaxEncodedLen`)
+	/// Storage: `SecretarySalaryPayouts::Preferences` (r:0 w:1)
+	/// Proof: `SecretarySalaryPayouts::Preferences` (`max_values`: None, `max_size`: Some(4881), added: 7356, mode: `MaxEncodedLen`)
+	fn set_preferences() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `13244`
+		Weight::from_parts(25_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 13244))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+}
@@ -17,7 +17,11 @@
 //! Shared types between system-parachains runtimes.
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub mod randomness;
+pub mod remote_identity;
+pub mod xcm_fees;
 
 /// Extra runtime APIs.
 pub mod apis {
@@ -32,16 +36,32 @@ pub mod apis {
 		pub issuance: sp_runtime::Perquintill,
 		/// Next amount that we anticipate to mint in an era.
 		///
-		/// On Kusama, the first item is the amount that goes to stakers and the second is the
-		/// leftover that is usually forwarded to the treasury.
-		///
-		/// On Polkadot, the first item is the total era emission and the second is always zero.
-		/// The staker/treasury split is performed downstream by pallet-dap based on its budget
+		/// The first item is the total era emission and the second is always zero. The
+		/// staker/treasury split is performed downstream by pallet-dap based on its budget
 		/// recipients and configured portions; mints are dripped every minute (or as configured).
 		/// For the DAP split, see the `budget_recipients` view function in pallet-dap.
 		pub next_mint: (polkadot_primitives::Balance, polkadot_primitives::Balance),
 	}
 
+	/// A single period of a projected issuance schedule.
+	///
+	/// All values are a deterministic projection of the issuance curve, and do not account for
+	/// burns or any other change of the total issuance outside of the curve.
+	#[derive(scale_info::TypeInfo, codec::Encode, codec::Decode, Eq, PartialEq, Clone)]
+	#[cfg_attr(feature = "std", derive(Debug))]
+	pub struct IssuancePeriod {
+		/// Relay chain block at which the period starts.
+		pub start: polkadot_primitives::BlockNumber,
+		/// Relay chain block at which the period ends (exclusive).
+		pub end: polkadot_primitives::BlockNumber,
+		/// The total amount emitted over the period.
+		pub emission: polkadot_primitives::Balance,
+		/// The projected total issuance at the end of the period.
+		pub total_issuance: polkadot_primitives::Balance,
+		/// The distance between `total_issuance` and the issuance hard cap.
+		pub remaining_to_cap: polkadot_primitives::Balance,
+	}
+
 	sp_api::decl_runtime_apis! {
 		pub trait Inflation {
 			/// Return the current estimates of the issuance amount.
@@ -49,6 +69,16 @@ pub mod apis {
 			/// This is marked as experimental in light of RFC#89. Nonetheless, its usage is highly
 			/// recommended over trying to read-storage, or re-create the onchain logic.
 			fn experimental_issuance_prediction_info() -> InflationInfo;
+
+			/// Return the projected issuance schedule over the next `horizon` relay chain blocks.
+			///
+			/// The first period is the one we are currently in, and periods are reported in full
+			/// even if they only partially overlap with the horizon. Runtimes may cap the number of
+			/// returned periods.
+			#[api_version(2)]
+			fn experimental_issuance_schedule(
+				horizon: polkadot_primitives::BlockNumber,
+			) -> alloc::vec::Vec<IssuancePeriod>;
 		}
 	}
 }
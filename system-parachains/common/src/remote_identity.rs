@@ -0,0 +1,56 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Reading the identities of the People chain with `pallet-remote-identity`.
+
+use core::marker::PhantomData;
+use cumulus_primitives_core::{ParaId, PersistedValidationData};
+use frame_support::traits::Get;
+use parachains_common::{AccountId, Balance, BlockNumber, Hash, Header};
+use sp_runtime::traits::BlakeTwo256;
+
+/// Remote identity interface that reads the identities of the People chain with the para id
+/// `PeopleParaId`.
+///
+/// The People chain and the local chain share the account, balance, hash and header types of
+/// `parachains-common`, and the relay chain storage root is taken from the relay parent.
+pub struct PeopleChainIdentityInterface<PeopleParaId>(PhantomData<PeopleParaId>);
+
+impl<PeopleParaId: Get<ParaId>> pallet_remote_identity::RemoteIdentityInterface<AccountId>
+	for PeopleChainIdentityInterface<PeopleParaId>
+{
+	type RemoteAccountId = AccountId;
+	type RemoteBalance = Balance;
+	type RelayBlockNumber = BlockNumber;
+	type RelayHash = Hash;
+	type RelayHasher = BlakeTwo256;
+	type RemoteHeader = Header;
+	type RemoteHasher = BlakeTwo256;
+
+	fn block_to_storage_root(
+		validation_data: &PersistedValidationData,
+	) -> Option<(Self::RelayBlockNumber, Self::RelayHash)> {
+		Some((validation_data.relay_parent_number, validation_data.relay_parent_storage_root))
+	}
+
+	fn remote_para_id() -> ParaId {
+		PeopleParaId::get()
+	}
+
+	fn local_to_remote_account_id(local: &AccountId) -> Option<Self::RemoteAccountId> {
+		Some(local.clone())
+	}
+}
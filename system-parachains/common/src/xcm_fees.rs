@@ -0,0 +1,180 @@
+// Copyright (C) Parity Technologies (UK) Ltd.
+// This file is part of Polkadot.
+
+// Polkadot is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// Polkadot is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with Polkadot. If not, see <http://www.gnu.org/licenses/>.
+
+//! Paying XCM execution fees in assets other than the native token.
+//!
+//! The system parachains don't have their own asset conversion pools. Instead, an asset can pay
+//! for execution once it has a rate to the native token, for example in a local `pallet_asset_rate`
+//! instance. The rates are set by root, which the relay chain or Asset Hub governance reach over
+//! XCM. They don't follow the DOT/KSM pools of Asset Hub, so governance has to keep them up to
+//! date. Where the rates are already kept for other uses, like the Fellowship on the Collectives,
+//! [`ReserveAssetRates`] reads them for the trader.
+
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use frame_support::{
+	traits::{fungibles, tokens::ConversionToAssetBalance, ContainsPair, Get, OnUnbalanced},
+	weights::Weight,
+};
+use polkadot_runtime_common::impls::VersionedLocatableAsset;
+use sp_runtime::traits::{Saturating, Zero};
+use xcm::latest::prelude::*;
+use xcm_executor::{traits::WeightTrader, AssetsInHolding};
+
+const LOG_TARGET: &str = "xcm::weight::asset-rate-trader";
+
+/// Matches the assets below `Prefix` coming from `Reserve`, e.g. the assets of the trust backed
+/// assets pallet of Asset Hub.
+pub struct ReserveAssetsFrom<Prefix, Reserve>(PhantomData<(Prefix, Reserve)>);
+impl<Prefix: Get<Location>, Reserve: Get<Location>> ContainsPair<Asset, Location>
+	for ReserveAssetsFrom<Prefix, Reserve>
+{
+	fn contains(asset: &Asset, origin: &Location) -> bool {
+		let prefix = Prefix::get();
+		origin == &Reserve::get() && asset.id.0 != prefix && asset.id.0.starts_with(&prefix)
+	}
+}
+
+/// The rates of `Rates`, which are keyed by the asset as seen from `Reserve`, for the assets of
+/// `Reserve` as seen from here.
+///
+/// E.g. the rate of `(PalletInstance(50), GeneralIndex(1984))` on Asset Hub is the rate of
+/// `(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)])` here.
+pub struct ReserveAssetRates<Rates, Reserve, UniversalLocation>(
+	PhantomData<(Rates, Reserve, UniversalLocation)>,
+);
+impl<Balance, Rates, Reserve, UniversalLocation>
+	ConversionToAssetBalance<Balance, Location, Balance>
+	for ReserveAssetRates<Rates, Reserve, UniversalLocation>
+where
+	Rates: ConversionToAssetBalance<Balance, VersionedLocatableAsset, Balance>,
+	Reserve: Get<Location>,
+	UniversalLocation: Get<InteriorLocation>,
+{
+	type Error = ();
+
+	fn to_asset_balance(balance: Balance, asset: Location) -> Result<Balance, Self::Error> {
+		let reserve = Reserve::get();
+		if asset == reserve || !asset.starts_with(&reserve) {
+			return Err(())
+		}
+		let asset_id = asset.reanchored(&reserve, &UniversalLocation::get()).map_err(|_| ())?;
+		let asset = VersionedLocatableAsset::V5 { location: reserve, asset_id: AssetId(asset_id) };
+		Rates::to_asset_balance(balance, asset).map_err(|_| ())
+	}
+}
+
+/// Weight trader which accepts any asset with a rate to the native token.
+///
+/// The fee is `WeightToFee` converted with the rate of `Rates`. The first asset of the holding
+/// with a rate, which exists in `Fungibles`, pays for all the weight bought by the message. The
+/// collected fees are issued in `Fungibles` and handed to `OnFees` when the trader is dropped.
+pub struct AssetRateTrader<WeightToFee, Rates, Fungibles, AccountId, OnFees>
+where
+	Fungibles: fungibles::Balanced<AccountId, AssetId = Location>,
+	OnFees: OnUnbalanced<fungibles::Credit<AccountId, Fungibles>>,
+{
+	/// The asset paying the fees, set once weight was bought.
+	asset: Option<Location>,
+	/// The weight bought so far.
+	weight: Weight,
+	/// The fees paid so far.
+	consumed: Fungibles::Balance,
+	_phantom: PhantomData<(WeightToFee, Rates, AccountId, OnFees)>,
+}
+
+impl<WeightToFee, Rates, Fungibles, AccountId, OnFees>
+	AssetRateTrader<WeightToFee, Rates, Fungibles, AccountId, OnFees>
+where
+	WeightToFee: frame_support::weights::WeightToFee<Balance = Fungibles::Balance>,
+	Rates: ConversionToAssetBalance<Fungibles::Balance, Location, Fungibles::Balance>,
+	Fungibles: fungibles::Balanced<AccountId, AssetId = Location>,
+	Fungibles::Balance: Into<u128>,
+	OnFees: OnUnbalanced<fungibles::Credit<AccountId, Fungibles>>,
+{
+	/// The fee for `weight` in `asset`, if it has a rate.
+	fn fee(weight: &Weight, asset: &Location) -> Option<Fungibles::Balance> {
+		Rates::to_asset_balance(WeightToFee::weight_to_fee(weight), asset.clone()).ok()
+	}
+}
+
+impl<WeightToFee, Rates, Fungibles, AccountId, OnFees> WeightTrader
+	for AssetRateTrader<WeightToFee, Rates, Fungibles, AccountId, OnFees>
+where
+	WeightToFee: frame_support::weights::WeightToFee<Balance = Fungibles::Balance>,
+	Rates: ConversionToAssetBalance<Fungibles::Balance, Location, Fungibles::Balance>,
+	Fungibles: fungibles::Balanced<AccountId, AssetId = Location>,
+	Fungibles::Balance: Into<u128>,
+	OnFees: OnUnbalanced<fungibles::Credit<AccountId, Fungibles>>,
+{
+	fn new() -> Self {
+		Self { asset: None, weight: Weight::zero(), consumed: Zero::zero(), _phantom: PhantomData }
+	}
+
+	fn buy_weight(
+		&mut self,
+		weight: Weight,
+		payment: AssetsInHolding,
+		_context: &XcmContext,
+	) -> Result<AssetsInHolding, XcmError> {
+		log::trace!(target: LOG_TARGET, "buy_weight weight: {weight:?}, payment: {payment:?}");
+		// Once an asset paid, the following weight has to be paid in the same asset.
+		let candidates: Vec<Location> = match &self.asset {
+			Some(asset) => alloc::vec![asset.clone()],
+			None => payment.fungible_assets_iter().map(|asset| asset.id.0).collect(),
+		};
+		for asset in candidates {
+			if !Fungibles::asset_exists(asset.clone()) {
+				continue
+			}
+			let Some(fee) = Self::fee(&weight, &asset) else { continue };
+			let required: Asset = (AssetId(asset.clone()), fee.into()).into();
+			let Ok(unused) = payment.clone().checked_sub(required) else { continue };
+			self.asset = Some(asset);
+			self.weight.saturating_accrue(weight);
+			self.consumed.saturating_accrue(fee);
+			return Ok(unused)
+		}
+		Err(XcmError::TooExpensive)
+	}
+
+	fn refund_weight(&mut self, weight: Weight, _context: &XcmContext) -> Option<Asset> {
+		log::trace!(target: LOG_TARGET, "refund_weight weight: {weight:?}");
+		let asset = self.asset.clone()?;
+		let weight = weight.min(self.weight);
+		let fee = Self::fee(&weight, &asset)?.min(self.consumed);
+		self.weight.saturating_reduce(weight);
+		self.consumed.saturating_reduce(fee);
+		if fee.is_zero() {
+			return None
+		}
+		Some((AssetId(asset), fee.into()).into())
+	}
+}
+
+impl<WeightToFee, Rates, Fungibles, AccountId, OnFees> Drop
+	for AssetRateTrader<WeightToFee, Rates, Fungibles, AccountId, OnFees>
+where
+	Fungibles: fungibles::Balanced<AccountId, AssetId = Location>,
+	OnFees: OnUnbalanced<fungibles::Credit<AccountId, Fungibles>>,
+{
+	fn drop(&mut self) {
+		let Some(asset) = self.asset.take() else { return };
+		if !self.consumed.is_zero() {
+			OnFees::on_unbalanced(Fungibles::issue(asset, self.consumed));
+		}
+	}
+}
@@ -13,6 +13,16 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+/// Universally recognized accounts.
+pub mod account {
+	use frame_support::PalletId;
+
+	/// Kusama treasury pallet id, used to convert into AccountId
+	pub const KUSAMA_TREASURY_PALLET_ID: PalletId = PalletId(*b"py/trsry");
+	/// Fellowship treasury pallet ID
+	pub const FELLOWSHIP_TREASURY_PALLET_ID: PalletId = PalletId(*b"py/feltr");
+}
+
 /// Consensus-related.
 pub mod consensus {
 	/// Maximum number of blocks simultaneously accepted by the Runtime, not yet included
@@ -109,8 +119,15 @@ pub mod locations {
 		pub RelayChainLocation: Location = Location::parent();
 		pub AssetHubLocation: Location =
 			Location::new(1, Parachain(kusama_runtime_constants::system_parachain::ASSET_HUB_ID));
+		/// The trust backed assets pallet of Asset Hub, e.g. USDT and USDC.
+		pub AssetHubTrustBackedAssetsLocation: Location = Location::new(
+			1,
+			[Parachain(kusama_runtime_constants::system_parachain::ASSET_HUB_ID), PalletInstance(50)],
+		);
 		pub PeopleLocation: Location =
 			Location::new(1, Parachain(kusama_runtime_constants::system_parachain::PEOPLE_ID));
+		pub CollectivesLocation: Location =
+			Location::new(1, Parachain(kusama_runtime_constants::system_parachain::COLLECTIVES_ID));
 	}
 
 	/// `Contains` implementation for the asset hub location pluralities.
@@ -134,6 +151,7 @@ pub mod locations {
 /// Fellowship-related constants.
 pub mod fellowship {
 	use frame_support::traits::Contains;
+	use kusama_runtime_constants::system_parachain::COLLECTIVES_ID;
 	use xcm::latest::prelude::*;
 
 	/// Fellowship Fellows rank (rank 3). The minimum rank with Fellowship privileges
@@ -145,6 +163,8 @@ pub mod fellowship {
 	/// Matches Fellowship voice locations in both Kusama and Polkadot Technical Fellowships.
 	///
 	/// - Kusama: `[Parent, Plurality { id: Technical, part: Voice }]`
+	/// - Collectives Kusama: `[Parent, Parachain(1003), Plurality { id: Technical, part: Voice },
+	///   GeneralIndex(rank)]` where `rank >= FELLOWS_RANK`
 	/// - Bridged Polkadot: `[Parent, Parent, GlobalConsensus(Polkadot), Parachain(1001), Plurality
 	///   { id: Technical, part: Voice }, GeneralIndex(rank)]` where `rank >= FELLOWS_RANK`
 	///
@@ -158,8 +178,13 @@ pub mod fellowship {
 	impl Contains<Location> for IsFellowshipVoice {
 		fn contains(l: &Location) -> bool {
 			match l.unpack() {
-				// Kusama Technical Fellowship
+				// Kusama Technical Fellowship on the Relay Chain
 				(1, [Plurality { id: BodyId::Technical, part: BodyPart::Voice }]) => true,
+				// Kusama Technical Fellowship on Collectives Kusama
+				(
+					1,
+					[Parachain(COLLECTIVES_ID), Plurality { id: BodyId::Technical, part: BodyPart::Voice }, GeneralIndex(rank)],
+				) if *rank >= FELLOWS_RANK => true,
 				// Polkadot Technical Fellowship
 				(
 					2,
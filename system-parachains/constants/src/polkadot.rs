@@ -132,6 +132,11 @@ pub mod locations {
 		pub RelayChainLocation: Location = Location::parent();
 		pub AssetHubLocation: Location =
 			Location::new(1, Parachain(polkadot_runtime_constants::system_parachain::ASSET_HUB_ID));
+		/// The trust backed assets pallet of Asset Hub, e.g. USDT and USDC.
+		pub AssetHubTrustBackedAssetsLocation: Location = Location::new(
+			1,
+			[Parachain(polkadot_runtime_constants::system_parachain::ASSET_HUB_ID), PalletInstance(50)],
+		);
 		pub PeopleLocation: Location =
 			Location::new(1, Parachain(polkadot_runtime_constants::system_parachain::PEOPLE_ID));
 
@@ -15,9 +15,7 @@
 // limitations under the License.
 
 use crate::*;
-use codec::{Decode, Encode};
 use cumulus_pallet_parachain_system::RelaychainDataProvider;
-use cumulus_primitives_core::relay_chain;
 use frame_support::{
 	parameter_types,
 	traits::{
@@ -32,38 +30,12 @@ use pallet_broker::{
 	CoreAssignment, CoreIndex, CoretimeInterface, PartsOf57600, RCBlockNumberOf, TaskId,
 };
 use parachains_common::{AccountId, Balance};
+use remote_calls::CoretimeCall;
 use sp_runtime::traits::{AccountIdConversion, MaybeConvert};
 use xcm::latest::prelude::*;
 use xcm_config::LocationToAccountId;
 use xcm_executor::traits::{ConvertLocation, TransactAsset};
 
-/// A type containing the encoding of the coretime pallet in the Relay chain runtime. Used to
-/// construct any remote calls. The codec index must correspond to the index of `Coretime` in the
-/// `construct_runtime` of the Relay chain.
-#[derive(Encode, Decode)]
-enum RelayRuntimePallets {
-	#[codec(index = 74)]
-	Coretime(CoretimeProviderCalls),
-}
-
-/// Call encoding for the calls needed from the relay coretime pallet.
-#[derive(Encode, Decode)]
-enum CoretimeProviderCalls {
-	#[codec(index = 1)]
-	RequestCoreCount(CoreIndex),
-	#[codec(index = 2)]
-	RequestRevenueInfoAt(relay_chain::BlockNumber),
-	#[codec(index = 3)]
-	CreditAccount(AccountId, Balance),
-	#[codec(index = 4)]
-	AssignCore(
-		CoreIndex,
-		relay_chain::BlockNumber,
-		Vec<(CoreAssignment, PartsOf57600)>,
-		Option<relay_chain::BlockNumber>,
-	),
-}
-
 parameter_types! {
 	/// The holding account into which burnt funds will be moved at the point of sale. This will be
 	/// burnt periodically.
@@ -124,6 +96,14 @@ parameter_types! {
 	pub storage CoretimeRevenue: Option<(BlockNumber, Balance)> = None;
 }
 
+/// Build the message that dispatches `call` on the coretime pallet of the Relay Chain.
+fn relay_coretime_call(call: CoretimeCall) -> Xcm<()> {
+	remote_calls::unpaid_transact(
+		OriginKind::Native,
+		&remote_calls::kusama::RelayCall::Coretime(call),
+	)
+}
+
 /// Type that implements the [`CoretimeInterface`] for the allocation of Coretime. Meant to operate
 /// from the parachain context. That is, the parachain provides a market (broker) for the sale of
 /// coretime, but assumes a `CoretimeProvider` (i.e. a Relay Chain) to actually provide cores.
@@ -134,20 +114,7 @@ impl CoretimeInterface for CoretimeAllocator {
 	type RelayChainBlockNumberProvider = RelaychainDataProvider<Runtime>;
 
 	fn request_core_count(count: CoreIndex) {
-		use crate::coretime::CoretimeProviderCalls::RequestCoreCount;
-		let request_core_count_call = RelayRuntimePallets::Coretime(RequestCoreCount(count));
-
-		let message = Xcm(vec![
-			Instruction::UnpaidExecution {
-				weight_limit: WeightLimit::Unlimited,
-				check_origin: None,
-			},
-			Instruction::Transact {
-				origin_kind: OriginKind::Native,
-				fallback_max_weight: None,
-				call: request_core_count_call.encode().into(),
-			},
-		]);
+		let message = relay_coretime_call(CoretimeCall::RequestCoreCount { count });
 
 		match PolkadotXcm::send_xcm(Here, Location::parent(), message) {
 			Ok(_) => log::debug!(
@@ -162,21 +129,7 @@ impl CoretimeInterface for CoretimeAllocator {
 	}
 
 	fn request_revenue_info_at(when: RCBlockNumberOf<Self>) {
-		use crate::coretime::CoretimeProviderCalls::RequestRevenueInfoAt;
-		let request_revenue_info_at_call =
-			RelayRuntimePallets::Coretime(RequestRevenueInfoAt(when));
-
-		let message = Xcm(vec![
-			Instruction::UnpaidExecution {
-				weight_limit: WeightLimit::Unlimited,
-				check_origin: None,
-			},
-			Instruction::Transact {
-				origin_kind: OriginKind::Native,
-				fallback_max_weight: None,
-				call: request_revenue_info_at_call.encode().into(),
-			},
-		]);
+		let message = relay_coretime_call(CoretimeCall::RequestRevenueAt { when });
 
 		match PolkadotXcm::send_xcm(Here, Location::parent(), message) {
 			Ok(_) => log::debug!(
@@ -191,8 +144,7 @@ impl CoretimeInterface for CoretimeAllocator {
 	}
 
 	fn credit_account(who: Self::AccountId, amount: Self::Balance) {
-		use crate::coretime::CoretimeProviderCalls::CreditAccount;
-		let _credit_account_call = RelayRuntimePallets::Coretime(CreditAccount(who, amount));
+		let _credit_account_call = CoretimeCall::CreditAccount { who, amount };
 
 		log::debug!(
 			target: "runtime::coretime",
@@ -206,61 +158,39 @@ impl CoretimeInterface for CoretimeAllocator {
 		assignment: Vec<(CoreAssignment, PartsOf57600)>,
 		end_hint: Option<RCBlockNumberOf<Self>>,
 	) {
-		use crate::coretime::CoretimeProviderCalls::AssignCore;
-
 		// The relay chain currently only allows `assign_core` to be called with a complete mask
-		// and only ever with increasing `begin`. The assignments must be truncated to avoid
-		// dropping that core's assignment completely.
-
-		// This shadowing of `assignment` is temporary and can be removed when the relay can accept
-		// multiple messages to assign a single core.
-		let assignment = if assignment.len() > 28 {
-			let mut total_parts = 0u16;
-			// Account for missing parts with a new `Idle` assignment at the start as
-			// `assign_core` on the relay assumes this is sorted. We'll add the rest of the
-			// assignments and sum the parts in one pass, so this is just initialized to 0.
-			let mut assignment_truncated = vec![(CoreAssignment::Idle, 0)];
-			// Truncate to first 27 non-idle assignments.
-			assignment_truncated.extend(
-				assignment
-					.into_iter()
-					.filter(|(a, _)| *a != CoreAssignment::Idle)
-					.take(27)
-					.inspect(|(_, parts)| total_parts += *parts)
-					.collect::<Vec<_>>(),
-			);
-
-			// Set the parts of the `Idle` assignment we injected at the start of the vec above.
-			assignment_truncated[0].1 = 57_600u16.saturating_sub(total_parts);
-			assignment_truncated
+		// and only ever with increasing `begin`. An assignment which does not fit one call is sent
+		// in chunks, which the relay chain applies at once when the last one arrives.
+		let messages = if assignment.len() > remote_calls::MAX_ASSIGNMENTS_PER_CALL as usize {
+			remote_calls::ChunkedCoreAssignmentCall::chunks(core, begin, assignment, end_hint)
+				.into_iter()
+				.map(|call| {
+					remote_calls::unpaid_transact(
+						OriginKind::Native,
+						&remote_calls::kusama::RelayCall::ChunkedCoreAssignment(call),
+					)
+				})
+				.collect()
 		} else {
-			assignment
+			vec![relay_coretime_call(CoretimeCall::AssignCore {
+				core,
+				begin,
+				assignment,
+				end_hint,
+			})]
 		};
 
-		let assign_core_call =
-			RelayRuntimePallets::Coretime(AssignCore(core, begin, assignment, end_hint));
-
-		let message = Xcm(vec![
-			Instruction::UnpaidExecution {
-				weight_limit: WeightLimit::Unlimited,
-				check_origin: None,
-			},
-			Instruction::Transact {
-				origin_kind: OriginKind::Native,
-				fallback_max_weight: None,
-				call: assign_core_call.encode().into(),
-			},
-		]);
-
-		match PolkadotXcm::send_xcm(Here, Location::parent(), message) {
-			Ok(_) => log::debug!(
-				target: "runtime::coretime",
-				"Core assignment sent successfully."
-			),
-			Err(e) => log::error!(
-				target: "runtime::coretime",
-				"Core assignment failed to send: {e:?}"
-			),
+		for message in messages {
+			match PolkadotXcm::send_xcm(Here, Location::parent(), message) {
+				Ok(_) => log::debug!(
+					target: "runtime::coretime",
+					"Core assignment sent successfully."
+				),
+				Err(e) => log::error!(
+					target: "runtime::coretime",
+					"Core assignment failed to send: {e:?}"
+				),
+			}
 		}
 	}
 
@@ -325,3 +255,22 @@ impl pallet_broker::Config for Runtime {
 	type PriceAdapter = pallet_broker::MinimumPrice<Balance, MinimumEndPrice>;
 	type MinimumCreditPurchase = MinimumCreditPurchase;
 }
+
+parameter_types! {
+	pub const CoretimeMarketPalletId: PalletId = PalletId(*b"py/ctmkt");
+	// One storage item; key size 32, value size 100.
+	pub const CoretimeListingDeposit: Balance = system_para_deposit(1, 132);
+	pub const AllowInterlace: bool = true;
+}
+
+impl pallet_coretime_market::Config for Runtime {
+	type Currency = Balances;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Regions = pallet_coretime_market::BrokerRegions<Runtime>;
+	type ListingDeposit = CoretimeListingDeposit;
+	type PalletId = CoretimeMarketPalletId;
+	type AllowInterlace = AllowInterlace;
+	type WeightInfo = weights::pallet_coretime_market::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = pallet_coretime_market::BrokerRegions<Runtime>;
+}
@@ -24,6 +24,7 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 
 extern crate alloc;
 
+pub mod assets;
 mod coretime;
 // Genesis preset configurations.
 pub mod genesis_config_presets;
@@ -330,7 +331,7 @@ parameter_types! {
 impl cumulus_pallet_parachain_system::Config for Runtime {
 	type WeightInfo = weights::cumulus_pallet_parachain_system::WeightInfo<Runtime>;
 	type RuntimeEvent = RuntimeEvent;
-	type OnSystemEvent = ();
+	type OnSystemEvent = RemoteIdentityPeople;
 	type SelfParaId = parachain_info::Pallet<Runtime>;
 	type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
 	type OutboundXcmpMessageSource = XcmpQueue;
@@ -535,7 +536,7 @@ pub enum ProxyType {
 	NonTransfer,
 	/// Proxy with the ability to reject time-delay proxy announcements.
 	CancelProxy,
-	/// Proxy for all Broker pallet calls.
+	/// Proxy for all Broker and CoretimeMarket pallet calls.
 	Broker,
 	/// Proxy for renewing coretime.
 	CoretimeRenewer,
@@ -578,6 +579,7 @@ impl InstanceFilter<RuntimeCall> for ProxyType {
 				matches!(
 					c,
 					RuntimeCall::Broker { .. } |
+						RuntimeCall::CoretimeMarket { .. } |
 						RuntimeCall::Utility { .. } |
 						RuntimeCall::Multisig { .. }
 				)
@@ -648,6 +650,22 @@ impl pallet_proxy::Config for Runtime {
 	type BlockNumberProvider = System;
 }
 
+impl pallet_remote_identity::Config for Runtime {
+	// The time between creating a proof and using the proof in a transaction.
+	type MaxStorageRootsToKeep = ConstU32<{ kusama_runtime_constants::time::MINUTES }>;
+	// Judgements need to be proven again after a week to propagate removals on the People chain.
+	type MaxJudgementAge = ConstU32<{ 7 * kusama_runtime_constants::time::DAYS }>;
+	// Generous bounds, a relay chain proof of a para head has about ten nodes of at most 600
+	// bytes and the identity proof of the People chain is of similar size.
+	type MaxProofNodes = ConstU32<64>;
+	type MaxProofSize = ConstU32<{ 64 * 1024 }>;
+	type MaxRemovalsPerBlock = ConstU32<64>;
+	type RemoteIdentity = system_parachains_common::remote_identity::PeopleChainIdentityInterface<
+		kusama_runtime_constants::system_parachain::PeopleParaId,
+	>;
+	type WeightInfo = weights::pallet_remote_identity::WeightInfo<Runtime>;
+}
+
 impl pallet_utility::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -669,6 +687,8 @@ construct_runtime!(
 		// Monetary stuff.
 		Balances: pallet_balances = 10,
 		TransactionPayment: pallet_transaction_payment = 11,
+		Assets: pallet_assets = 12,
+		AssetRate: pallet_asset_rate = 13,
 
 		// Collator support. The order of these 5 are important and shall not change.
 		Authorship: pallet_authorship = 20,
@@ -687,9 +707,11 @@ construct_runtime!(
 		Utility: pallet_utility = 40,
 		Multisig: pallet_multisig = 41,
 		Proxy: pallet_proxy = 42,
+		RemoteIdentityPeople: pallet_remote_identity = 43,
 
 		// The main stage.
 		Broker: pallet_broker = 50,
+		CoretimeMarket: pallet_coretime_market = 51,
 	}
 );
 
@@ -700,6 +722,7 @@ mod benches {
 	pub use frame_support::traits::WhitelistedStorageKeys;
 	use kusama_runtime_constants::system_parachain::AssetHubParaId;
 	use system_parachains_constants::kusama::locations::AssetHubLocation;
+	use xcm::latest::Assets as XcmAssets;
 
 	frame_benchmarking::define_benchmarks!(
 		[frame_system, SystemBench::<Runtime>]
@@ -707,15 +730,19 @@ mod benches {
 		[cumulus_pallet_parachain_system, ParachainSystem]
 		[cumulus_pallet_weight_reclaim, WeightReclaim]
 		[pallet_timestamp, Timestamp]
+		[pallet_asset_rate, AssetRate]
+		[pallet_assets, Assets]
 		[pallet_balances, Balances]
 		[pallet_broker, Broker]
 		[pallet_collator_selection, CollatorSelection]
+		[pallet_coretime_market, CoretimeMarket]
 		[pallet_session, SessionBench::<Runtime>]
 		[cumulus_pallet_xcmp_queue, XcmpQueue]
 		[pallet_xcm, PalletXcmExtrinsicsBenchmark::<Runtime>]
 		[pallet_message_queue, MessageQueue]
 		[pallet_multisig, Multisig]
 		[pallet_proxy, Proxy]
+		[pallet_remote_identity, RemoteIdentityPeople]
 		[pallet_transaction_payment, TransactionPayment]
 		[pallet_utility, Utility]
 		// NOTE: Make sure you point to the individual modules below.
@@ -806,7 +833,8 @@ mod benches {
 			))
 		}
 
-		fn set_up_complex_asset_transfer() -> Option<(Assets, u32, Location, Box<dyn FnOnce()>)> {
+		fn set_up_complex_asset_transfer() -> Option<(XcmAssets, u32, Location, Box<dyn FnOnce()>)>
+		{
 			// Only supports native token teleports to system parachain
 			let native_location = Parent.into();
 			let dest = AssetHubLocation::get();
@@ -877,7 +905,7 @@ mod benches {
 			(0u64, Response::Version(Default::default()))
 		}
 
-		fn worst_case_asset_exchange() -> Result<(Assets, Assets), BenchmarkError> {
+		fn worst_case_asset_exchange() -> Result<(XcmAssets, XcmAssets), BenchmarkError> {
 			Err(BenchmarkError::Skip)
 		}
 
@@ -896,9 +924,9 @@ mod benches {
 			Ok(AssetHubLocation::get())
 		}
 
-		fn claimable_asset() -> Result<(Location, Location, Assets), BenchmarkError> {
+		fn claimable_asset() -> Result<(Location, Location, XcmAssets), BenchmarkError> {
 			let origin = AssetHubLocation::get();
-			let assets: Assets = (AssetId(RelayChainLocation::get()), 1_000 * UNITS).into();
+			let assets: XcmAssets = (AssetId(RelayChainLocation::get()), 1_000 * UNITS).into();
 			let ticket = Location { parents: 0, interior: Here };
 			Ok((origin, ticket, assets))
 		}
@@ -1107,7 +1135,11 @@ impl_runtime_apis! {
 
 	impl xcm_runtime_apis::fees::XcmPaymentApi<Block> for Runtime {
 		fn query_acceptable_payment_assets(xcm_version: xcm::Version) -> Result<Vec<VersionedAssetId>, XcmPaymentApiError> {
-			let acceptable_assets = vec![AssetId(RelayChainLocation::get())];
+			// The relay chain token and the assets with a rate to it.
+			let acceptable_assets = [AssetId(RelayChainLocation::get())]
+				.into_iter()
+				.chain(pallet_asset_rate::ConversionRateToNative::<Runtime>::iter_keys().map(AssetId))
+				.collect();
 			PolkadotXcm::query_acceptable_payment_assets(xcm_version, acceptable_assets)
 		}
 
@@ -1173,6 +1205,16 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_coretime_market::runtime_api::CoretimeMarketApi<Block, AccountId, Balance, BlockNumber> for Runtime {
+		fn listings() -> Vec<pallet_coretime_market::ListingInfoOf<Runtime>> {
+			CoretimeMarket::listings()
+		}
+
+		fn listing(region: pallet_broker::RegionId) -> Option<pallet_coretime_market::ListingInfoOf<Runtime>> {
+			CoretimeMarket::listing(region)
+		}
+	}
+
 	impl cumulus_primitives_core::CollectCollationInfo<Block> for Runtime {
 		fn collect_collation_info(header: &<Block as BlockT>::Header) -> cumulus_primitives_core::CollationInfo {
 			ParachainSystem::collect_collation_info(header)
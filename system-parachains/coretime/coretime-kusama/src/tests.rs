@@ -278,3 +278,18 @@ fn governance_authorize_upgrade_works() {
 		RuntimeOrigin,
 	>(GovernanceOrigin::Location(AssetHubLocation::get())));
 }
+
+#[test]
+fn remote_broker_calls_compatibility() {
+	// if this fails, make sure `remote_calls::kusama::CoretimeChainCall` has valid encoding
+	use remote_calls::{kusama::CoretimeChainCall, BrokerCall};
+
+	assert_eq!(
+		RuntimeCall::Broker(pallet_broker::Call::purchase { price_limit: 100 * UNITS }).encode(),
+		CoretimeChainCall::Broker(BrokerCall::Purchase { price_limit: 100 * UNITS }).encode()
+	);
+	assert_eq!(
+		RuntimeCall::Broker(pallet_broker::Call::renew { core: 42 }).encode(),
+		CoretimeChainCall::Broker(BrokerCall::Renew { core: 42 }).encode()
+	);
+}
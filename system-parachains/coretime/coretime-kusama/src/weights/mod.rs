@@ -23,12 +23,16 @@ pub mod cumulus_pallet_xcmp_queue;
 pub mod extrinsic_weights;
 pub mod frame_system;
 pub mod frame_system_extensions;
+pub mod pallet_asset_rate;
+pub mod pallet_assets;
 pub mod pallet_balances;
 pub mod pallet_broker;
 pub mod pallet_collator_selection;
+pub mod pallet_coretime_market;
 pub mod pallet_message_queue;
 pub mod pallet_multisig;
 pub mod pallet_proxy;
+pub mod pallet_remote_identity;
 pub mod pallet_session;
 pub mod pallet_timestamp;
 pub mod pallet_transaction_payment;
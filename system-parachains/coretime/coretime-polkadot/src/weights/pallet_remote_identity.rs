@@ -0,0 +1,59 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_remote_identity`
+//!
+//! These weights are NOT generated by the benchmark CLI, they are estimates to be replaced by the
+//! output of:
+//!
+//! frame-omni-bencher v1 benchmark pallet --extrinsic=* --pallet=pallet_remote_identity
+//!
+//! The storage accesses are those of the worst case, the removal of a judgement. The reference
+//! time is about twice the generated `remote_proxy` weight of `pallet_remote_proxy` on Kusama
+//! Asset Hub for each of the two proofs, about twice the cost of hashing per byte of proof and one
+//! microsecond per node for inserting it into the proof database. The proof size is the sum of the
+//! maximum encoded sizes of the storage items read.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_remote_identity`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_remote_identity::WeightInfo for WeightInfo<T> {
+	/// Storage: `RemoteIdentityPeople::BlockToRoot` (r:1 w:0)
+	/// Proof: `RemoteIdentityPeople::BlockToRoot` (`max_values`: Some(1), `max_size`: Some(361), added: 856, mode: `MaxEncodedLen`)
+	/// Storage: `RemoteIdentityPeople::JudgedIdentities` (r:1 w:1)
+	/// Proof: `RemoteIdentityPeople::JudgedIdentities` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `RemoteIdentityPeople::LastProofBlock` (r:1 w:1)
+	/// Proof: `RemoteIdentityPeople::LastProofBlock` (`max_values`: None, `max_size`: Some(44), added: 2519, mode: `MaxEncodedLen`)
+	/// Storage: `RemoteIdentityPeople::ProofBlocksToPrune` (r:1 w:1)
+	/// Proof: `RemoteIdentityPeople::ProofBlocksToPrune` (`max_values`: None, `max_size`: Some(2062), added: 4537, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 32]`.
+	/// The range of component `p` is `[0, 32768]`.
+	fn prove_identity(n: u32, p: u32, ) -> Weight {
+		Weight::from_parts(70_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 10431))
+			.saturating_add(Weight::from_parts(1_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(Weight::from_parts(4_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(4))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+}
@@ -0,0 +1,84 @@
+// Copyright (c) 2023 Encointer Association
+// This file is part of Encointer
+//
+// Encointer is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Encointer is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Encointer.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Randomness of the ceremonies.
+//!
+//! The meetup assignments and the other random choices of `pallet_encointer_ceremonies` are
+//! drawn from [`CeremonyRandomness`]. When registration closes, i.e. when the ceremony enters the
+//! `Assigning` phase, the relay chain VRF output of one epoch ago is read from the relay state
+//! proof and fixed as the seed of the ceremony. Until the registration of the next ceremony
+//! closes, all randomness is derived from this seed and the subject alone.
+//!
+//! The seed was fixed by the relay chain before registration closed, and neither the parent hash
+//! nor any other input of the collators goes into it. The author of the block closing
+//! registration can at most pick between the randomness of two relay epochs, if an epoch change
+//! falls between the relay parents it may build on.
+
+use crate::{BlockNumber, EncointerScheduler, Hash, Runtime};
+use alloc::vec::Vec;
+use encointer_primitives::{ceremonies::CeremonyIndexType, scheduler::CeremonyPhaseType};
+use frame_support::{pallet_prelude::OptionQuery, storage_alias, traits::Randomness};
+use pallet_encointer_scheduler::OnCeremonyPhaseChange;
+use sp_runtime::traits::{BlakeTwo256, Hash as _};
+use system_parachains_common::randomness::RelayChainOneEpochAgoWithoutBlockNumber;
+
+const LOG_TARGET: &str = "runtime::ceremony-randomness";
+
+/// Subject of the relay chain randomness the seeds are drawn from.
+const SEED_SUBJECT: &[u8] = b"encointer/ceremony-seed";
+
+type RelayChainRandomness = RelayChainOneEpochAgoWithoutBlockNumber<Runtime, BlockNumber>;
+
+/// The seed of the randomness and the ceremony it was fixed for.
+#[storage_alias(verbatim)]
+pub type CeremonySeed =
+	StorageValue<EncointerCeremonyRandomness, (CeremonyIndexType, Hash), OptionQuery>;
+
+/// Randomness of the ceremonies, seeded by the relay chain VRF when registration closes.
+///
+/// Must run before `EncointerCeremonies` in `OnCeremonyPhaseChange`, so that the meetups are
+/// assigned with the seed of the ceremony.
+pub struct CeremonyRandomness;
+
+impl CeremonyRandomness {
+	/// Fix the seed of the current ceremony.
+	fn fix_seed() {
+		let (seed, _) = RelayChainRandomness::random(SEED_SUBJECT);
+		let cindex = EncointerScheduler::current_ceremony_index();
+		log::debug!(target: LOG_TARGET, "fixed seed {seed:?} for ceremony {cindex}");
+		CeremonySeed::put((cindex, seed));
+	}
+}
+
+impl OnCeremonyPhaseChange for CeremonyRandomness {
+	fn on_ceremony_phase_change(new_phase: CeremonyPhaseType) {
+		if new_phase == CeremonyPhaseType::Assigning {
+			Self::fix_seed();
+		}
+	}
+}
+
+impl Randomness<Hash, BlockNumber> for CeremonyRandomness {
+	fn random(subject: &[u8]) -> (Hash, BlockNumber) {
+		let Some((_, seed)) = CeremonySeed::get() else {
+			// No registration has closed since the seed was introduced.
+			return RelayChainRandomness::random(subject);
+		};
+		let mut subject: Vec<u8> = subject.to_vec();
+		subject.extend_from_slice(seed.as_ref());
+		(BlakeTwo256::hash(&subject), 0)
+	}
+}
@@ -0,0 +1,202 @@
+// Copyright (c) 2023 Encointer Association
+// This file is part of Encointer
+//
+// Encointer is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// Encointer is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with Encointer.  If not, see <http://www.gnu.org/licenses/>.
+
+//! Community currencies as foreign assets on Asset Hub.
+//!
+//! Every community currency of `pallet_encointer_balances` is exposed over XCM at
+//! `PalletInstance(63)/GeneralKey(<cid>)`, with this chain as its reserve. Asset Hub holds
+//! the currency of a community in its sovereign account here, while the derivative circulates
+//! in its `ForeignAssets` pallet. Demurrage on the sovereign account is settled by
+//! `pallet_community_reserves` before every withdrawal. Communities which the council enabled it
+//! for compensate it from their treasury, so holders on Asset Hub receive the nominal amount they
+//! were credited with. For the other communities the reserve shrinks like any balance, and the
+//! holders redeeming last on Asset Hub bear the demurrage of all.
+
+use crate::{
+	xcm_config::{AssetHubLocation, CheckingAccount, LocationToAccountId, UniversalLocation},
+	AccountId, AssetBalance, EncointerBalances, EncointerTreasuries, MoreThanHalfCouncil,
+	ParachainInfo, Runtime,
+};
+use alloc::vec;
+use codec::{Decode, Encode};
+use encointer_primitives::communities::CommunityIdentifier;
+use frame_support::{
+	parameter_types,
+	traits::{fungibles, Equals},
+};
+use polkadot_parachain_primitives::primitives::Sibling;
+use remote_calls::{kusama::AssetHubCall, AccountLookup, ForeignAssetReserve, ForeignAssetsCall};
+use sp_runtime::traits::{AccountIdConversion, Convert, MaybeEquivalence};
+use system_parachains_constants::kusama::currency::UNITS;
+use xcm::latest::prelude::*;
+use xcm_builder::{ConvertedConcreteId, FungiblesAdapter, NoChecking};
+use xcm_executor::traits::JustTry;
+
+/// Length of an encoded `CommunityIdentifier`: a 5 byte geohash and a 4 byte digest.
+const COMMUNITY_IDENTIFIER_LENGTH: u8 = 9;
+
+parameter_types! {
+	pub const EncointerBalancesPalletIndex: u8 = 63;
+	/// KSM withdrawn from the sovereign account on Asset Hub to pay for the registration of a
+	/// community currency. Whatever is not used is refunded to the sovereign account.
+	pub const CommunityCurrencyRegistrationFee: u128 = UNITS / 10;
+}
+
+/// Converts between the local location of a community currency and its `CommunityIdentifier`.
+pub struct CommunityCurrencyConvert;
+impl MaybeEquivalence<Location, CommunityIdentifier> for CommunityCurrencyConvert {
+	fn convert(location: &Location) -> Option<CommunityIdentifier> {
+		match location.unpack() {
+			(0, [PalletInstance(index), GeneralKey { length, data }])
+				if *index == EncointerBalancesPalletIndex::get() &&
+					*length == COMMUNITY_IDENTIFIER_LENGTH =>
+				CommunityIdentifier::decode(&mut &data[..COMMUNITY_IDENTIFIER_LENGTH as usize]).ok(),
+			_ => None,
+		}
+	}
+
+	fn convert_back(cid: &CommunityIdentifier) -> Option<Location> {
+		let mut data = [0u8; 32];
+		cid.using_encoded(|encoded| data[..encoded.len()].copy_from_slice(encoded));
+		Some(Location::new(
+			0,
+			[
+				PalletInstance(EncointerBalancesPalletIndex::get()),
+				GeneralKey { length: COMMUNITY_IDENTIFIER_LENGTH, data },
+			],
+		))
+	}
+}
+
+/// Matches the community currencies among the assets of an XCM message.
+pub type CommunityCurrencyMatcher =
+	ConvertedConcreteId<CommunityIdentifier, AssetBalance, CommunityCurrencyConvert, JustTry>;
+
+/// Means for transacting community currencies, keeping track of the reserves held by Asset Hub.
+pub type CommunityCurrencyTransactor = pallet_community_reserves::ReserveAccounting<
+	Runtime,
+	FungiblesAdapter<
+		EncointerBalances,
+		CommunityCurrencyMatcher,
+		LocationToAccountId,
+		AccountId,
+		// Community currencies are only reserve transferred, never teleported.
+		NoChecking,
+		CheckingAccount,
+	>,
+>;
+
+/// The treasury of a community funds the demurrage compensation of its currency.
+pub struct CommunityTreasury;
+impl Convert<CommunityIdentifier, AccountId> for CommunityTreasury {
+	fn convert(cid: CommunityIdentifier) -> AccountId {
+		EncointerTreasuries::get_community_treasury_account_unchecked(Some(cid))
+	}
+}
+
+/// Registers a community currency in the `ForeignAssets` pallet of Asset Hub.
+///
+/// The sovereign account of this chain on Asset Hub creates the asset, becomes its owner and
+/// declares this chain as its only reserve. The registration is paid in KSM by the sovereign
+/// account.
+pub struct AssetHubRegistrar;
+impl pallet_community_reserves::ForeignAssetRegistrar<CommunityIdentifier> for AssetHubRegistrar {
+	fn register(cid: &CommunityIdentifier) -> Result<XcmHash, SendError> {
+		let id = CommunityCurrencyConvert::convert_back(cid)
+			.and_then(|local| {
+				local.reanchored(&AssetHubLocation::get(), &UniversalLocation::get()).ok()
+			})
+			.ok_or(SendError::Unroutable)?;
+		let here = Location::new(1, [Parachain(ParachainInfo::parachain_id().into())]);
+		let sovereign: AccountId =
+			Sibling::from(ParachainInfo::parachain_id()).into_account_truncating();
+		// `pallet_assets` refuses a zero minimum balance, which community currencies may have.
+		let min_balance =
+			<EncointerBalances as fungibles::Inspect<AccountId>>::minimum_balance(*cid).max(1);
+		let fee: Asset = (Parent, CommunityCurrencyRegistrationFee::get()).into();
+
+		let create = AssetHubCall::ForeignAssets(ForeignAssetsCall::Create {
+			id: id.clone(),
+			admin: AccountLookup::Id(sovereign),
+			min_balance,
+		});
+		let set_reserves = AssetHubCall::ForeignAssets(ForeignAssetsCall::SetReserves {
+			id,
+			reserves: vec![ForeignAssetReserve { reserve: here.clone(), teleportable: false }],
+		});
+		let message = Xcm(vec![
+			WithdrawAsset(fee.clone().into()),
+			BuyExecution { fees: fee, weight_limit: Unlimited },
+			// `ForeignCreators` only accepts the creation from the location of the reserve.
+			Transact {
+				origin_kind: OriginKind::Xcm,
+				fallback_max_weight: None,
+				call: create.encode().into(),
+			},
+			// The reserves can only be set by the owner, which is the sovereign account.
+			Transact {
+				origin_kind: OriginKind::SovereignAccount,
+				fallback_max_weight: None,
+				call: set_reserves.encode().into(),
+			},
+			RefundSurplus,
+			DepositAsset { assets: Wild(AllCounted(1)), beneficiary: here },
+		]);
+
+		send_xcm::<crate::xcm_config::XcmRouter>(AssetHubLocation::get(), message)
+			.map(|(message_id, _)| message_id)
+	}
+}
+
+impl pallet_community_reserves::Config for Runtime {
+	type Currencies = EncointerBalances;
+	type AssetMatcher = CommunityCurrencyMatcher;
+	type LocationToAccountId = LocationToAccountId;
+	type RemoteReserves = Equals<AssetHubLocation>;
+	type RegisterOrigin = MoreThanHalfCouncil;
+	type CompensationFunds = CommunityTreasury;
+	type Registrar = AssetHubRegistrar;
+	type WeightInfo = crate::weights::pallet_community_reserves::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = benchmarks::CommunityCurrency;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub mod benchmarks {
+	use super::*;
+	use crate::impls::benchmarks::{EnsureSuccessful, OpenHrmpChannel};
+	use encointer_primitives::balances::BalanceType;
+	use frame_support::traits::ConstU32;
+	use kusama_runtime_constants::system_parachain::ASSET_HUB_ID;
+
+	/// Provides a community currency to register in the benchmarks and opens the channel to Asset
+	/// Hub the registration is sent through.
+	pub struct CommunityCurrency;
+	impl pallet_community_reserves::BenchmarkHelper<CommunityIdentifier> for CommunityCurrency {
+		fn setup_asset() -> CommunityIdentifier {
+			let cid = CommunityIdentifier::default();
+			let holder: AccountId = [1u8; 32].into();
+			pallet_encointer_balances::Pallet::<Runtime>::issue(
+				cid,
+				&holder,
+				BalanceType::from_num(1),
+			)
+			.expect("issuing to a fresh account can not fail; qed");
+			OpenHrmpChannel::<ConstU32<ASSET_HUB_ID>>::ensure_successful();
+			cid
+		}
+	}
+}
@@ -34,6 +34,8 @@ include!(concat!(env!("OUT_DIR"), "/wasm_binary.rs"));
 extern crate alloc;
 
 // Genesis preset configurations.
+pub mod ceremony_randomness;
+pub mod community_currencies;
 pub mod genesis_config_presets;
 pub mod treasuries_xcm_payout;
 mod weights;
@@ -437,8 +439,6 @@ type ConsensusHook = cumulus_pallet_aura_ext::FixedVelocityConsensusHook<
 	UNINCLUDED_SEGMENT_CAPACITY,
 >;
 
-impl pallet_insecure_randomness_collective_flip::Config for Runtime {}
-
 impl parachain_info::Config for Runtime {}
 
 impl cumulus_pallet_aura_ext::Config for Runtime {}
@@ -535,8 +535,13 @@ impl pallet_encointer_scheduler::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	// attention!: EncointerDemocracy must be first hook as it potentially changes the rules for
 	// following hooks
-	type OnCeremonyPhaseChange =
-		(EncointerDemocracy, EncointerCeremonies, EncointerReputationRings);
+	// `CeremonyRandomness` must come before EncointerCeremonies, which assigns the meetups with it
+	type OnCeremonyPhaseChange = (
+		EncointerDemocracy,
+		ceremony_randomness::CeremonyRandomness,
+		EncointerCeremonies,
+		EncointerReputationRings,
+	);
 	type MomentsPerDay = MomentsPerDay;
 	type CeremonyMaster = MoreThanHalfCouncil;
 	type WeightInfo = weights::pallet_encointer_scheduler::WeightInfo<Runtime>;
@@ -546,7 +551,7 @@ impl pallet_encointer_ceremonies::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type Public = <Signature as Verify>::Signer;
 	type Signature = Signature;
-	type RandomnessSource = RandomnessCollectiveFlip;
+	type RandomnessSource = ceremony_randomness::CeremonyRandomness;
 	type MeetupSizeTarget = MeetupSizeTarget;
 	type MeetupMinSize = MeetupMinSize;
 	type MeetupNewbieLimitDivider = MeetupNewbieLimitDivider;
@@ -838,7 +843,7 @@ construct_runtime! {
 		// System support stuff.
 		System: frame_system = 0,
 		ParachainSystem: cumulus_pallet_parachain_system = 1,
-		RandomnessCollectiveFlip: pallet_insecure_randomness_collective_flip = 2,
+		// RandomnessCollectiveFlip = 2 removed
 		Timestamp: pallet_timestamp = 3,
 		ParachainInfo: parachain_info = 4,
 		WeightReclaim: cumulus_pallet_weight_reclaim = 5,
@@ -882,6 +887,7 @@ construct_runtime! {
 		EncointerTreasuries: pallet_encointer_treasuries = 68,
 		EncointerOfflinePayment: pallet_encointer_offline_payment = 69,
 		EncointerReputationRings: pallet_encointer_reputation_rings = 70,
+		CommunityReserves: pallet_community_reserves = 71,
 	}
 }
 
@@ -954,11 +960,21 @@ pub type UncheckedExtrinsic =
 pub mod migrations {
 	use super::*;
 
+	parameter_types! {
+		pub const RandomnessCollectiveFlipPalletName: &'static str = "RandomnessCollectiveFlip";
+	}
+
+	pub type RemoveRandomnessCollectiveFlipPallet = frame_support::migrations::RemovePallet<
+		RandomnessCollectiveFlipPalletName,
+		<Runtime as frame_system::Config>::DbWeight,
+	>;
+
 	/// Unreleased migrations. Add new ones here:
 	pub type Unreleased = (
 		pallet_encointer_democracy::migrations::v2::MigrateV1toV2<Runtime>,
 		cumulus_pallet_xcmp_queue::migration::v6::MigrateV5ToV6<Runtime>,
 		cumulus_pallet_parachain_system::migration::Migration<Runtime>,
+		RemoveRandomnessCollectiveFlipPallet,
 	);
 
 	/// All migrations that will run on the next runtime upgrade.
@@ -998,6 +1014,7 @@ mod benches {
 		[pallet_transaction_payment, TransactionPayment]
 		[pallet_utility, Utility]
 		[pallet_proxy, Proxy]
+		[pallet_community_reserves, CommunityReserves]
 		[pallet_encointer_balances, EncointerBalances]
 		[pallet_encointer_bazaar, EncointerBazaar]
 		[pallet_encointer_ceremonies, EncointerCeremonies]
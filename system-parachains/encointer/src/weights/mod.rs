@@ -30,6 +30,7 @@ pub mod pallet_asset_tx_payment;
 pub mod pallet_balances;
 pub mod pallet_collator_selection;
 pub mod pallet_collective;
+pub mod pallet_community_reserves;
 pub mod pallet_encointer_balances;
 pub mod pallet_encointer_bazaar;
 pub mod pallet_encointer_ceremonies;
@@ -16,9 +16,10 @@
 //! Almost identical to ../asset-hubs/asset-hub-kusama
 
 use super::{
-	AccountId, Balance, Balances, CollatorSelection, FeeAssetId, ParachainInfo, ParachainSystem,
-	PolkadotXcm, Runtime, RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin,
-	ToParentBaseDeliveryFee, TransactionByteFee, WeightToFee, XcmpQueue,
+	community_currencies::CommunityCurrencyTransactor, AccountId, Balance, Balances,
+	CollatorSelection, FeeAssetId, ParachainInfo, ParachainSystem, PolkadotXcm, Runtime,
+	RuntimeCall, RuntimeEvent, RuntimeHoldReason, RuntimeOrigin, ToParentBaseDeliveryFee,
+	TransactionByteFee, WeightToFee, XcmpQueue,
 };
 use frame_support::{
 	parameter_types,
@@ -205,7 +206,7 @@ impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
 	type XcmSender = XcmRouter;
 	type XcmRecorder = PolkadotXcm;
-	type AssetTransactor = FungibleTransactor;
+	type AssetTransactor = (FungibleTransactor, CommunityCurrencyTransactor);
 	type OriginConverter = XcmOriginToTransactDispatchOrigin;
 	type IsReserve = ();
 	type IsTeleporter = TrustedTeleporters;
@@ -1,10 +1,14 @@
+use codec::Encode;
+use cumulus_primitives_core::{relay_chain::well_known_keys, PersistedValidationData};
 use encointer_kusama_runtime::{
+	ceremony_randomness::CeremonyRandomness,
 	xcm_config::{AssetHubLocation, RelayChainLocation},
-	Runtime, RuntimeOrigin,
+	BuildStorage, CeremonyPhaseType, Runtime, RuntimeOrigin,
 };
-use frame_support::{assert_err, assert_ok};
+use frame_support::{assert_err, assert_ok, traits::Randomness};
+use pallet_encointer_scheduler::OnCeremonyPhaseChange;
 use parachains_runtimes_test_utils::GovernanceOrigin;
-use sp_runtime::Either;
+use sp_runtime::{random_number_generator::RandomNumberGenerator, traits::BlakeTwo256, Either};
 use xcm::prelude::*;
 
 #[test]
@@ -38,3 +42,83 @@ fn governance_authorize_upgrade_works() {
 		RuntimeOrigin,
 	>(GovernanceOrigin::Location(AssetHubLocation::get())));
 }
+
+fn new_test_ext() -> sp_io::TestExternalities {
+	frame_system::GenesisConfig::<Runtime>::default()
+		.build_storage()
+		.unwrap()
+		.into()
+}
+
+/// Put a relay state proof with `randomness` as the VRF output of one epoch ago.
+fn set_relay_randomness(randomness: [u8; 32]) {
+	use sp_trie::TrieMut;
+
+	let (mut db, mut root) = sp_trie::MemoryDB::<BlakeTwo256>::default_with_root();
+	let mut trie =
+		sp_trie::TrieDBMutBuilder::<sp_trie::LayoutV1<_>>::new(&mut db, &mut root).build();
+	trie.insert(well_known_keys::ONE_EPOCH_AGO_RANDOMNESS, &randomness.encode())
+		.unwrap();
+	drop(trie);
+
+	cumulus_pallet_parachain_system::RelayStateProof::<Runtime>::put(sp_trie::StorageProof::new(
+		db.drain().into_values().map(|d| d.0),
+	));
+	cumulus_pallet_parachain_system::ValidationData::<Runtime>::put(PersistedValidationData {
+		relay_parent_storage_root: root,
+		..Default::default()
+	});
+}
+
+/// Shuffle the participants of a meetup like the assignment does.
+fn shuffled_participants() -> Vec<u32> {
+	let mut participants: Vec<u32> = (0..100).collect();
+	RandomNumberGenerator::<BlakeTwo256>::new(CeremonyRandomness::random_seed().0)
+		.shuffle(&mut participants);
+	participants
+}
+
+#[test]
+fn assignment_shuffles_are_fixed_by_the_relay_epoch() {
+	let shuffle = |relay_randomness: [u8; 32], parent_hash: [u8; 32], block_number: u32| {
+		new_test_ext().execute_with(|| {
+			frame_system::Pallet::<Runtime>::initialize(
+				&block_number,
+				&parent_hash.into(),
+				&Default::default(),
+			);
+			set_relay_randomness(relay_randomness);
+			CeremonyRandomness::on_ceremony_phase_change(CeremonyPhaseType::Assigning);
+			shuffled_participants()
+		})
+	};
+
+	// The block author controls the parent hash and can delay the block, neither matters.
+	let participants = shuffle([1; 32], [0; 32], 10);
+	assert_eq!(participants, shuffle([1; 32], [7; 32], 10));
+	assert_eq!(participants, shuffle([1; 32], [42; 32], 1_000));
+	assert_ne!(participants, (0..100).collect::<Vec<_>>());
+
+	// Another relay epoch gives another shuffle.
+	assert_ne!(participants, shuffle([2; 32], [0; 32], 10));
+}
+
+#[test]
+fn ceremony_randomness_is_fixed_when_registration_closes() {
+	new_test_ext().execute_with(|| {
+		set_relay_randomness([1; 32]);
+		CeremonyRandomness::on_ceremony_phase_change(CeremonyPhaseType::Assigning);
+		let participants = shuffled_participants();
+
+		// Later relay epochs don't change the randomness of the ceremony.
+		set_relay_randomness([2; 32]);
+		for phase in [CeremonyPhaseType::Attesting, CeremonyPhaseType::Registering] {
+			CeremonyRandomness::on_ceremony_phase_change(phase);
+			assert_eq!(shuffled_participants(), participants);
+		}
+
+		// Until the registration of the next ceremony closes.
+		CeremonyRandomness::on_ceremony_phase_change(CeremonyPhaseType::Assigning);
+		assert_ne!(shuffled_participants(), participants);
+	});
+}
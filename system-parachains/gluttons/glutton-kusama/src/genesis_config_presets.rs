@@ -19,6 +19,7 @@
 use crate::*;
 use cumulus_primitives_core::ParaId;
 use sp_genesis_builder::PresetId;
+use system_parachains_constants::genesis_presets::SAFE_XCM_VERSION;
 
 fn glutton_kusama_genesis(id: ParaId) -> serde_json::Value {
 	serde_json::json!({
@@ -26,6 +27,9 @@ fn glutton_kusama_genesis(id: ParaId) -> serde_json::Value {
 			parachain_id: id,
 			..Default::default()
 		},
+		"polkadotXcm": {
+			"safeXcmVersion": Some(SAFE_XCM_VERSION),
+		},
 	})
 }
 
@@ -35,6 +35,16 @@
 //! only be able receive XCM messages from Kusama via DMP. This way the Glutton
 //! parachains will be able to listen for upgrades that are coming from the
 //! Relay chain.
+//!
+//! Messages from sibling parachains are received over HRMP but not executed.
+//! Bursts of messages are sent to siblings by the load profiles.
+//!
+//! ### Load profiles
+//!
+//! The load of `Glutton` can be driven by the scheduled profiles of
+//! `GluttonProfiles`: ramps, bursts, PoV-heavy or compute-heavy blocks and
+//! bursts of XCM messages to siblings. The profiles are managed through sudo and
+//! monitored through the `GluttonProfilesApi` runtime API.
 
 #![cfg_attr(not(feature = "std"), no_std)]
 #![recursion_limit = "256"]
@@ -53,6 +63,7 @@ pub mod xcm_config;
 use alloc::{borrow::Cow, vec, vec::Vec};
 use cumulus_pallet_parachain_system::RelayNumberStrictlyIncreases;
 use cumulus_primitives_core::{AggregateMessageOrigin, ParaId};
+use parachains_common::message_queue::{NarrowOriginToSibling, ParaIdToSibling};
 use sp_api::impl_runtime_apis;
 use sp_core::OpaqueMetadata;
 use sp_runtime::{
@@ -70,7 +81,7 @@ pub use frame_support::{
 	dispatch::DispatchClass,
 	genesis_builder_helper::{build_state, get_preset},
 	parameter_types,
-	traits::{Everything, IsInVec, Randomness},
+	traits::{Everything, IsInVec, Randomness, StorageMapShim, TransformOrigin},
 	weights::{
 		constants::{
 			BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_REF_TIME_PER_SECOND,
@@ -83,11 +94,12 @@ use frame_system::{
 	limits::{BlockLength, BlockWeights},
 	EnsureRoot,
 };
-use parachains_common::{AccountId, Signature};
+use parachains_common::{AccountId, Balance, Signature};
 use sp_runtime::traits::ConstU32;
 #[cfg(any(feature = "std", test))]
 pub use sp_runtime::BuildStorage;
-pub use sp_runtime::{Perbill, Permill};
+pub use sp_runtime::{FixedU64, Perbill, Permill};
+use system_parachains_constants::kusama::currency::SYSTEM_PARA_EXISTENTIAL_DEPOSIT;
 
 #[sp_version::runtime_version]
 pub const VERSION: RuntimeVersion = RuntimeVersion {
@@ -186,6 +198,7 @@ impl frame_system::Config for Runtime {
 parameter_types! {
 	// We do anything the parent chain tells us in this runtime.
 	pub const ReservedDmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(2);
+	pub const ReservedXcmpWeight: Weight = MAXIMUM_BLOCK_WEIGHT.saturating_div(4);
 	pub const RelayOrigin: AggregateMessageOrigin = AggregateMessageOrigin::Parent;
 }
 
@@ -193,11 +206,11 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type OnSystemEvent = ();
 	type SelfParaId = parachain_info::Pallet<Runtime>;
-	type OutboundXcmpMessageSource = ();
+	type OutboundXcmpMessageSource = XcmpQueue;
 	type DmpQueue = frame_support::traits::EnqueueWithOrigin<MessageQueue, RelayOrigin>;
 	type ReservedDmpWeight = ReservedDmpWeight;
-	type XcmpMessageHandler = ();
-	type ReservedXcmpWeight = ();
+	type XcmpMessageHandler = XcmpQueue;
+	type ReservedXcmpWeight = ReservedXcmpWeight;
 	type CheckAssociatedRelayNumber = RelayNumberStrictlyIncreases;
 	type ConsensusHook = cumulus_pallet_parachain_system::consensus_hook::ExpectParentIncluded;
 	type WeightInfo = weights::cumulus_pallet_parachain_system::WeightInfo<Runtime>;
@@ -206,6 +219,33 @@ impl cumulus_pallet_parachain_system::Config for Runtime {
 
 impl parachain_info::Config for Runtime {}
 
+parameter_types! {
+	pub const ExistentialDeposit: Balance = SYSTEM_PARA_EXISTENTIAL_DEPOSIT;
+}
+
+/// Only backs the `Currency` of `PolkadotXcm`, no account holds a balance on the Glutton.
+impl pallet_balances::Config for Runtime {
+	type Balance = Balance;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ExistentialDeposit;
+	// The accounts of `System` have no data, the balances are kept in `Balances`.
+	type AccountStore = StorageMapShim<
+		pallet_balances::Account<Runtime>,
+		AccountId,
+		pallet_balances::AccountData<Balance>,
+	>;
+	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
+	type MaxLocks = ConstU32<50>;
+	type MaxReserves = ConstU32<50>;
+	type ReserveIdentifier = [u8; 8];
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type FreezeIdentifier = ();
+	type MaxFreezes = ConstU32<0>;
+	type DoneSlashHandler = ();
+}
+
 parameter_types! {
 	pub MessageQueueServiceWeight: Weight = Perbill::from_percent(35) * RuntimeBlockWeights::get().max_block;
 	pub MessageQueueIdleServiceWeight: Weight = Perbill::from_percent(20) * RuntimeBlockWeights::get().max_block;
@@ -224,20 +264,87 @@ impl pallet_message_queue::Config for Runtime {
 		RuntimeCall,
 	>;
 	type Size = u32;
-	type QueueChangeHandler = ();
-	type QueuePausedQuery = (); // No XCMP queue pallet deployed.
+	type QueueChangeHandler = NarrowOriginToSibling<XcmpQueue>;
+	type QueuePausedQuery = NarrowOriginToSibling<XcmpQueue>;
 	type HeapSize = sp_core::ConstU32<{ 64 * 1024 }>;
 	type MaxStale = sp_core::ConstU32<8>;
 	type ServiceWeight = MessageQueueServiceWeight;
 	type IdleMaxServiceWeight = MessageQueueIdleServiceWeight;
 }
 
+impl cumulus_pallet_xcmp_queue::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	type ChannelInfo = ParachainSystem;
+	type VersionWrapper = PolkadotXcm;
+	// Enqueue XCMP messages from siblings for later processing.
+	type XcmpQueue = TransformOrigin<MessageQueue, AggregateMessageOrigin, ParaId, ParaIdToSibling>;
+	type MaxActiveOutboundChannels = ConstU32<128>;
+	// Most on-chain HRMP channels are configured to use 102400 bytes of max message size, so we
+	// need to set the page size larger than that until we reduce the channel size on-chain.
+	type MaxPageSize = ConstU32<{ 103 * 1024 }>;
+	type MaxInboundSuspended = ConstU32<1_000>;
+	type ControllerOrigin = EnsureRoot<AccountId>;
+	type ControllerOriginConverter = xcm_config::XcmOriginToTransactDispatchOrigin;
+	type WeightInfo = weights::cumulus_pallet_xcmp_queue::WeightInfo<Runtime>;
+	type PriceForSiblingDelivery =
+		polkadot_runtime_common::xcm_sender::NoPriceForMessageDelivery<ParaId>;
+}
+
 impl pallet_glutton::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = weights::pallet_glutton::WeightInfo<Runtime>;
 	type AdminOrigin = EnsureRoot<AccountId>;
 }
 
+/// Applies the load of the running profile to `Glutton`.
+pub struct GluttonLoad;
+impl pallet_glutton_profiles::LoadTarget for GluttonLoad {
+	fn set_load(load: &pallet_glutton_profiles::Load) -> Weight {
+		use pallet_glutton::WeightInfo as _;
+
+		// The loads are checked against `RESOURCE_HARD_LIMIT` when a profile is set.
+		let _ = Glutton::set_compute(RuntimeOrigin::root(), load.compute);
+		let _ = Glutton::set_storage(RuntimeOrigin::root(), load.storage);
+		weights::pallet_glutton::WeightInfo::<Runtime>::set_compute()
+			.saturating_add(weights::pallet_glutton::WeightInfo::<Runtime>::set_storage())
+	}
+}
+
+parameter_types! {
+	pub const GluttonMaxLoad: FixedU64 = pallet_glutton::RESOURCE_HARD_LIMIT;
+}
+
+impl pallet_glutton_profiles::Config for Runtime {
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type Glutton = GluttonLoad;
+	type XcmSender = XcmpQueue;
+	type MaxLoad = GluttonMaxLoad;
+	type MaxPhases = ConstU32<32>;
+	type MaxBurstMessages = ConstU32<64>;
+	type MaxMessageSize = ConstU32<{ 64 * 1024 }>;
+	type WeightInfo = weights::pallet_glutton_profiles::WeightInfo<Runtime>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = GluttonProfilesBenchmarkHelper;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+pub struct GluttonProfilesBenchmarkHelper;
+
+#[cfg(feature = "runtime-benchmarks")]
+impl pallet_glutton_profiles::BenchmarkHelper for GluttonProfilesBenchmarkHelper {
+	fn ensure_delivery(para: ParaId) {
+		ParachainSystem::open_outbound_hrmp_channel_for_benchmarks_or_tests(para);
+		let sibling =
+			xcm::latest::Location::new(1, [xcm::latest::Junction::Parachain(para.into())]);
+		PolkadotXcm::force_xcm_version(
+			RuntimeOrigin::root(),
+			alloc::boxed::Box::new(sibling),
+			xcm::latest::VERSION,
+		)
+		.expect("root can set the version of a sibling; qed");
+	}
+}
+
 impl pallet_sudo::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type RuntimeCall = RuntimeCall;
@@ -250,13 +357,17 @@ construct_runtime! {
 		System: frame_system = 0,
 		ParachainSystem: cumulus_pallet_parachain_system = 1,
 		ParachainInfo: parachain_info = 2,
+		Balances: pallet_balances = 3,
 
-		// DMP handler.
+		// XCM handlers.
 		CumulusXcm: cumulus_pallet_xcm = 10,
 		MessageQueue: pallet_message_queue = 11,
+		XcmpQueue: cumulus_pallet_xcmp_queue = 12,
+		PolkadotXcm: pallet_xcm = 13,
 
 		// The main stage.
 		Glutton: pallet_glutton = 20,
+		GluttonProfiles: pallet_glutton_profiles = 21,
 
 		// Sudo.
 		Sudo: pallet_sudo = 255,
@@ -310,8 +421,10 @@ mod benches {
 		[frame_system, SystemBench::<Runtime>]
 		[frame_system_extensions, SystemExtensionsBench::<Runtime>]
 		[pallet_glutton, Glutton]
+		[pallet_glutton_profiles, GluttonProfiles]
 		[pallet_message_queue, MessageQueue]
 		[cumulus_pallet_parachain_system, ParachainSystem]
+		[cumulus_pallet_xcmp_queue, XcmpQueue]
 	);
 
 	impl frame_system_benchmarking::Config for Runtime {
@@ -454,6 +567,12 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl pallet_glutton_profiles::runtime_api::GluttonProfilesApi<Block, BlockNumber> for Runtime {
+		fn status() -> pallet_glutton_profiles::LoadStatus<BlockNumber> {
+			GluttonProfiles::status()
+		}
+	}
+
 	impl cumulus_primitives_core::GetParachainInfo<Block> for Runtime {
 		fn parachain_id() -> ParaId {
 			ParachainInfo::parachain_id()
@@ -15,7 +15,9 @@
 // along with Cumulus.  If not, see <http://www.gnu.org/licenses/>.
 
 pub mod cumulus_pallet_parachain_system;
+pub mod cumulus_pallet_xcmp_queue;
 pub mod frame_system;
 pub mod frame_system_extensions;
 pub mod pallet_glutton;
+pub mod pallet_glutton_profiles;
 pub mod pallet_message_queue;
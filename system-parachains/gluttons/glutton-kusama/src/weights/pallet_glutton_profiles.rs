@@ -0,0 +1,138 @@
+// Copyright (C) Parity Technologies and the various Polkadot contributors, see Contributions.md
+// for a list of specific contributors.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Weights for `pallet_glutton_profiles`
+//!
+//! These weights are NOT generated by the benchmark CLI, they are estimates to be replaced by the
+//! output of:
+//!
+//! frame-omni-bencher v1 benchmark pallet --extrinsic=* --pallet=pallet_glutton_profiles
+//!
+//! The storage accesses are those of the calls and hooks, with the messages of a burst taken as the
+//! enqueueing of an XCMP message of the maximum size. The reference time is a rough estimate of the
+//! execution, doubled as margin, and the proof size is the sum of the maximum encoded sizes of the
+//! storage items read, with 1000 bytes for the items without a maximum size.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::Weight};
+use core::marker::PhantomData;
+
+/// Weight functions for `pallet_glutton_profiles`.
+pub struct WeightInfo<T>(PhantomData<T>);
+impl<T: frame_system::Config> pallet_glutton_profiles::WeightInfo for WeightInfo<T> {
+	/// Storage: `GluttonProfiles::Profiles` (r:0 w:1)
+	/// Proof: `GluttonProfiles::Profiles` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// The range of component `p` is `[1, 32]`.
+	fn set_profile(p: u32, ) -> Weight {
+		Weight::from_parts(16_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 0))
+			.saturating_add(Weight::from_parts(300_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `GluttonProfiles::Current` (r:1 w:0)
+	/// Proof: `GluttonProfiles::Current` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `GluttonProfiles::Profiles` (r:1 w:1)
+	/// Proof: `GluttonProfiles::Profiles` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	fn remove_profile() -> Weight {
+		Weight::from_parts(28_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 4044))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `GluttonProfiles::Profiles` (r:1 w:0)
+	/// Proof: `GluttonProfiles::Profiles` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `GluttonProfiles::Schedule` (r:1 w:1)
+	/// Proof: `GluttonProfiles::Schedule` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn schedule() -> Weight {
+		Weight::from_parts(28_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 6036))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `GluttonProfiles::Schedule` (r:1 w:1)
+	/// Proof: `GluttonProfiles::Schedule` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	fn cancel_schedule() -> Weight {
+		Weight::from_parts(20_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2495))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(1))
+	}
+	/// Storage: `GluttonProfiles::Profiles` (r:1 w:0)
+	/// Proof: `GluttonProfiles::Profiles` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `GluttonProfiles::Current` (r:0 w:1)
+	/// Proof: `GluttonProfiles::Current` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `GluttonProfiles::CurrentPhase` (r:0 w:1)
+	/// Proof: `GluttonProfiles::CurrentPhase` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GluttonProfiles::Metrics` (r:0 w:1)
+	/// Proof: `GluttonProfiles::Metrics` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	fn start() -> Weight {
+		Weight::from_parts(26_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 3541))
+			.saturating_add(T::DbWeight::get().reads(1))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `GluttonProfiles::Current` (r:1 w:1)
+	/// Proof: `GluttonProfiles::Current` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	/// Storage: `GluttonProfiles::CurrentPhase` (r:0 w:1)
+	/// Proof: `GluttonProfiles::CurrentPhase` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GluttonProfiles::AppliedLoad` (r:1 w:1)
+	/// Proof: `GluttonProfiles::AppliedLoad` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn stop() -> Weight {
+		Weight::from_parts(24_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 1014))
+			.saturating_add(T::DbWeight::get().reads(2))
+			.saturating_add(T::DbWeight::get().writes(3))
+	}
+	/// Storage: `GluttonProfiles::Schedule` (r:1 w:0)
+	/// Proof: `GluttonProfiles::Schedule` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// Storage: `GluttonProfiles::Current` (r:1 w:0)
+	/// Proof: `GluttonProfiles::Current` (`max_values`: Some(1), `max_size`: Some(8), added: 503, mode: `MaxEncodedLen`)
+	fn on_initialize_idle() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 2998))
+			.saturating_add(T::DbWeight::get().reads(2))
+	}
+	/// Storage: `GluttonProfiles::Profiles` (r:1 w:0)
+	/// Proof: `GluttonProfiles::Profiles` (`max_values`: None, `max_size`: Some(1066), added: 3541, mode: `MaxEncodedLen`)
+	/// Storage: `GluttonProfiles::CurrentPhase` (r:0 w:1)
+	/// Proof: `GluttonProfiles::CurrentPhase` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `GluttonProfiles::AppliedLoad` (r:1 w:0)
+	/// Proof: `GluttonProfiles::AppliedLoad` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `GluttonProfiles::Metrics` (r:1 w:1)
+	/// Proof: `GluttonProfiles::Metrics` (`max_values`: Some(1), `max_size`: Some(32), added: 527, mode: `MaxEncodedLen`)
+	/// Storage: `XcmpQueue::DeliveryFeeFactor` (r:1 w:0)
+	/// Proof: `XcmpQueue::DeliveryFeeFactor` (`max_values`: None, `max_size`: Some(28), added: 2503, mode: `MaxEncodedLen`)
+	/// Storage: `ParachainSystem::RelevantMessagingState` (r:1 w:0)
+	/// Proof: `ParachainSystem::RelevantMessagingState` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `XcmpQueue::OutboundXcmpStatus` (r:1 w:1)
+	/// Proof: `XcmpQueue::OutboundXcmpStatus` (`max_values`: Some(1), `max_size`: Some(1282), added: 1777, mode: `MaxEncodedLen`)
+	/// Storage: `XcmpQueue::OutboundXcmpMessages` (r:0 w:64)
+	/// Proof: `XcmpQueue::OutboundXcmpMessages` (`max_values`: None, `max_size`: Some(105506), added: 107981, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 64]`.
+	fn on_initialize_phase(m: u32, ) -> Weight {
+		Weight::from_parts(32_000_000, 0)
+			.saturating_add(Weight::from_parts(0, 9859))
+			.saturating_add(Weight::from_parts(90_000_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(6))
+			.saturating_add(T::DbWeight::get().writes(2))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 65536).saturating_mul(m.into()))
+	}
+}
@@ -14,17 +14,18 @@
 // limitations under the License.
 
 use super::{
-	AccountId, AllPalletsWithSystem, ParachainInfo, Runtime, RuntimeCall, RuntimeEvent,
-	RuntimeOrigin,
+	AccountId, AllPalletsWithSystem, Balances, ParachainInfo, PolkadotXcm, Runtime, RuntimeCall,
+	RuntimeEvent, RuntimeOrigin, XcmpQueue,
 };
 use frame_support::{
 	match_types, parameter_types,
-	traits::{Everything, Nothing},
+	traits::{ConstU32, Disabled, Everything, Nothing},
 };
+use frame_system::EnsureRoot;
 use xcm::latest::prelude::*;
 use xcm_builder::{
-	AllowExplicitUnpaidExecutionFrom, FixedWeightBounds, FrameTransactionalProcessor,
-	ParentAsSuperuser, ParentIsPreset, SovereignSignedViaLocation,
+	AllowExplicitUnpaidExecutionFrom, AllowKnownQueryResponses, EnsureXcmOrigin, FixedWeightBounds,
+	FrameTransactionalProcessor, ParentAsSuperuser, ParentIsPreset, SovereignSignedViaLocation,
 };
 
 parameter_types! {
@@ -68,12 +69,16 @@ impl xcm_executor::Config for XcmConfig {
 	type IsReserve = (); // balances not supported
 	type IsTeleporter = (); // balances not supported
 	type UniversalLocation = UniversalLocation;
-	type Barrier = AllowExplicitUnpaidExecutionFrom<JustTheParent>;
+	type Barrier = (
+		AllowExplicitUnpaidExecutionFrom<JustTheParent>,
+		// The versions of the siblings the burst messages are sent to.
+		AllowKnownQueryResponses<PolkadotXcm>,
+	);
 	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>; // balances not supported
 	type Trader = (); // balances not supported
-	type ResponseHandler = (); // Don't handle responses for now.
+	type ResponseHandler = PolkadotXcm;
 	type AssetTrap = (); // don't trap for now
-	type SubscriptionService = (); // don't handle subscriptions for now
+	type SubscriptionService = PolkadotXcm;
 	type PalletInstancesInfo = AllPalletsWithSystem;
 	type MaxAssetsIntoHolding = MaxAssetsIntoHolding;
 	type AssetLocker = ();
@@ -91,6 +96,35 @@ impl xcm_executor::Config for XcmConfig {
 	type XcmEventEmitter = ();
 }
 
+impl pallet_xcm::Config for Runtime {
+	type RuntimeEvent = RuntimeEvent;
+	// Only `GluttonProfiles` sends messages, users can neither send nor execute any.
+	type SendXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, ()>;
+	type XcmRouter = XcmpQueue;
+	type ExecuteXcmOrigin = EnsureXcmOrigin<RuntimeOrigin, ()>;
+	type XcmExecuteFilter = Nothing;
+	type XcmExecutor = xcm_executor::XcmExecutor<XcmConfig>;
+	type XcmTeleportFilter = Nothing;
+	type XcmReserveTransferFilter = Nothing;
+	type Weigher = FixedWeightBounds<UnitWeightCost, RuntimeCall, MaxInstructions>;
+	type UniversalLocation = UniversalLocation;
+	type RuntimeOrigin = RuntimeOrigin;
+	type RuntimeCall = RuntimeCall;
+	const VERSION_DISCOVERY_QUEUE_SIZE: u32 = 100;
+	type AdvertisedXcmVersion = pallet_xcm::CurrentXcmVersion;
+	type Currency = Balances;
+	type CurrencyMatcher = ();
+	type TrustedLockers = ();
+	type SovereignAccountOf = ParentIsPreset<AccountId>;
+	type MaxLockers = ConstU32<0>;
+	// Only the admin calls of root are usable.
+	type WeightInfo = pallet_xcm::TestWeightInfo;
+	type AdminOrigin = EnsureRoot<AccountId>;
+	type MaxRemoteLockConsumers = ConstU32<0>;
+	type RemoteLockConsumerIdentifier = ();
+	type AuthorizedAliasConsideration = Disabled;
+}
+
 impl cumulus_pallet_xcm::Config for Runtime {
 	type RuntimeEvent = RuntimeEvent;
 	type XcmExecutor = xcm_executor::XcmExecutor<XcmConfig>;
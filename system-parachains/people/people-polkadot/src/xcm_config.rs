@@ -24,7 +24,10 @@ use frame_support::{
 	parameter_types,
 	traits::{
 		fungible::{HoldConsideration, ItemOf},
-		tokens::{imbalance::ResolveTo, ConversionToAssetBalance},
+		tokens::{
+			imbalance::{ResolveAssetTo, ResolveTo},
+			ConversionToAssetBalance,
+		},
 		ConstU32, Contains, Equals, Everything, LinearStoragePrice, Nothing,
 	},
 };
@@ -56,8 +59,9 @@ use xcm_builder::{
 };
 use xcm_executor::{traits::ConvertLocation, XcmExecutor};
 
+use system_parachains_common::xcm_fees::{AssetRateTrader, ReserveAssetsFrom};
 pub use system_parachains_constants::polkadot::locations::{
-	AssetHubLocation, AssetHubPlurality, RelayChainLocation,
+	AssetHubLocation, AssetHubPlurality, AssetHubTrustBackedAssetsLocation, RelayChainLocation,
 };
 
 parameter_types! {
@@ -292,16 +296,29 @@ pub type Traders = (
 		FungibleHollar,
 		ResolveTo<StakingPot, FungibleHollar>,
 	>,
+	// The assets of Asset Hub with a rate in `AssetRate`.
+	AssetRateTrader<
+		WeightToNativeFee,
+		AssetRate,
+		AssetsPallet,
+		AccountId,
+		ResolveAssetTo<StakingPot, AssetsPallet>,
+	>,
 );
 
+/// The reserves of the foreign assets on this chain.
+pub type TrustedReserves =
+	(HollarFromHydration, ReserveAssetsFrom<AssetHubTrustBackedAssetsLocation, AssetHubLocation>);
+
 pub struct XcmConfig;
 impl xcm_executor::Config for XcmConfig {
 	type RuntimeCall = RuntimeCall;
 	type XcmSender = XcmRouter;
 	type AssetTransactor = AssetTransactors;
 	type OriginConverter = XcmOriginToTransactDispatchOrigin;
-	/// We only accept HOLLAR from Hydration.
-	type IsReserve = HollarFromHydration;
+	/// We only accept HOLLAR from Hydration and the trust backed assets of Asset Hub from Asset
+	/// Hub.
+	type IsReserve = TrustedReserves;
 	/// Only allow teleportation of DOT.
 	type IsTeleporter = ConcreteAssetFromSystem<RelayLocation>;
 	type UniversalLocation = UniversalLocation;